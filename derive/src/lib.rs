@@ -0,0 +1,446 @@
+//! Derive [`Parse`](https://docs.rs/winnow/latest/winnow/trait.Parse.html) for
+//! [`winnow`](https://docs.rs/winnow)
+//!
+//! This is re-exported by `winnow` with the `unstable-derive` feature, see
+//! [`derive(Parse)`][macro@Parse].
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned as _;
+use syn::{
+    Attribute, Data, DeriveInput, Error, Expr, Fields, GenericArgument, Ident, Lit, LitStr,
+    PathArguments, Result, Token, Type, WherePredicate,
+};
+
+/// Generate a parser for a struct or enum, implementing `winnow::Parse`
+///
+/// Fields are parsed in order, like with `seq!`, and the variants of an enum are tried in order,
+/// like with `alt`.  By default, a field is parsed with the `Parse` implementation of its type.
+///
+/// The implementation is generic over the input `I` and error `E`, requiring
+/// `I: Stream + StreamIsPartial` and `E: ParserError<I>` along with what the attributes below
+/// need, like `I: Compare<&'static str>` for a `literal = "..."`.  Parsers given with `with`
+/// can't be inspected, so their requirements must be added with `#[parse(bound = "...")]`.
+///
+/// # Attributes
+///
+/// On a struct or enum:
+/// - `#[parse(bound = "<predicates>")]`: add `where` predicates, which may refer to `I` and `E`
+///
+/// On an enum variant:
+/// - `#[parse(tag = <literal>)]`: match the literal before the fields
+///
+/// On a field:
+/// - `#[parse(with = <parser>)]`: parse the field with the expression, which may refer to
+///   earlier fields
+/// - `#[parse(literal = <literal>)]`: match the literal before the field, may be repeated
+/// - `#[parse(skip)]`: don't parse the field, using [`Default`]
+/// - `#[parse(binary = big | little | native)]`: parse an integer or float field as bytes, see
+///   `winnow::binary`
+/// - `#[parse(separator = <literal>)]`: parse a collection field, like a [`Vec`], with
+///   `separated`, parsing the items with `with`, `binary` or their `Parse` implementation
+/// - `#[parse(occurrences = <range>)]`: how many items the `separator` field has, defaulting to
+///   `0..`
+///
+/// Literals may be strings, byte strings, `char`s or bytes.
+///
+/// # Example
+///
+/// ```rust
+/// use winnow::ascii::dec_uint;
+/// use winnow::error::ContextError;
+/// use winnow::stream::{AsBStr, AsChar, Stream};
+/// use winnow::Parse;
+///
+/// #[derive(Debug, PartialEq, Parse)]
+/// enum Method {
+///     #[parse(tag = "GET")]
+///     Get,
+///     #[parse(tag = "POST")]
+///     Post,
+/// }
+///
+/// #[derive(Debug, PartialEq, Parse)]
+/// #[parse(bound = "<I as Stream>::Token: AsChar + Clone, <I as Stream>::Slice: AsBStr")]
+/// struct Request {
+///     method: Method,
+///     #[parse(literal = ' ', separator = ',', with = dec_uint)]
+///     ids: Vec<u32>,
+///     #[parse(skip)]
+///     retries: u8,
+/// }
+///
+/// let mut input = "POST 1,22";
+/// let request: Result<_, ContextError> = Request::parse_next(&mut input);
+/// assert_eq!(
+///     request,
+///     Ok(Request {
+///         method: Method::Post,
+///         ids: vec![1, 22],
+///         retries: 0,
+///     }),
+/// );
+///
+/// #[derive(Debug, PartialEq, Parse)]
+/// struct Header(
+///     #[parse(literal = b"HD", binary = big)] u8,
+///     #[parse(binary = big)] u16,
+///     #[parse(binary = little)] u16,
+/// );
+///
+/// let mut input = &b"HD\x01\x00\x02\x03\x00"[..];
+/// let header: Result<_, ContextError> = Header::parse_next(&mut input);
+/// assert_eq!(header, Ok(Header(1, 2, 3)));
+/// ```
+#[proc_macro_derive(Parse, attributes(parse))]
+pub fn derive_parse(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+    let mut bounds = Bounds::default();
+    for attr in parse_attrs(&input.attrs) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("bound") {
+                let predicates: LitStr = meta.value()?.parse()?;
+                let predicates = predicates
+                    .parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?;
+                bounds
+                    .predicates
+                    .extend(predicates.into_iter().map(|p| quote!(#p)));
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `parse` attribute on a type"))
+            }
+        })?;
+    }
+
+    let label = name.to_string();
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let parse = fields(&data.fields, quote!(Self), &mut bounds)?;
+            quote! {
+                ::winnow::combinator::trace(#label, |__input: &mut I| -> ::core::result::Result<Self, E> {
+                    #parse
+                })
+            }
+        }
+        Data::Enum(data) => {
+            if data.variants.is_empty() {
+                return Err(Error::new_spanned(
+                    input,
+                    "can't derive `Parse` for an enum without variants",
+                ));
+            }
+            let mut cases = Vec::with_capacity(data.variants.len());
+            for variant in &data.variants {
+                let mut tag = TokenStream::new();
+                for attr in parse_attrs(&variant.attrs) {
+                    attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("tag") {
+                            let lit: Lit = meta.value()?.parse()?;
+                            tag.extend(literal(&lit, &mut bounds)?);
+                            Ok(())
+                        } else {
+                            Err(meta.error("unsupported `parse` attribute on a variant"))
+                        }
+                    })?;
+                }
+                let ident = &variant.ident;
+                let parse = fields(&variant.fields, quote!(Self::#ident), &mut bounds)?;
+                let label = format!("{name}::{ident}");
+                cases.push(quote! {
+                    ::winnow::combinator::trace(#label, |__input: &mut I| -> ::core::result::Result<Self, E> {
+                        #tag
+                        #parse
+                    })
+                });
+            }
+            let alt = alt(cases);
+            quote! {
+                ::winnow::combinator::trace(#label, #alt)
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                input,
+                "can't derive `Parse` for a union",
+            ))
+        }
+    };
+
+    let mut generics = input.generics.clone();
+    generics.params.push(syn::parse_quote!(I));
+    generics.params.push(syn::parse_quote!(E));
+    let (impl_generics, _, _) = generics.split_for_impl();
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut predicates = where_clause
+        .map(|w| w.predicates.iter().map(|p| quote!(#p)).collect::<Vec<_>>())
+        .unwrap_or_default();
+    predicates.push(quote!(
+        I: ::winnow::stream::Stream + ::winnow::stream::StreamIsPartial
+    ));
+    predicates.push(quote!(E: ::winnow::error::ParserError<I>));
+    predicates.extend(bounds.predicates);
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::winnow::Parse<I, E> for #name #ty_generics
+        where
+            #(#predicates,)*
+        {
+            fn parse_next(input: &mut I) -> ::core::result::Result<Self, E> {
+                ::winnow::Parser::<I, Self, E>::parse_next(&mut #body, input)
+            }
+        }
+    })
+}
+
+/// `where` predicates needed by the generated parser
+#[derive(Default)]
+struct Bounds {
+    predicates: Vec<TokenStream>,
+}
+
+impl Bounds {
+    fn push(&mut self, predicate: TokenStream) {
+        let text = predicate.to_string();
+        if !self.predicates.iter().any(|p| p.to_string() == text) {
+            self.predicates.push(predicate);
+        }
+    }
+}
+
+fn parse_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("parse"))
+}
+
+/// Parse `fields` in order, then construct them with `path`
+fn fields(fields: &Fields, path: TokenStream, bounds: &mut Bounds) -> Result<TokenStream> {
+    let mut stmts = Vec::with_capacity(fields.len());
+    let mut bindings = Vec::with_capacity(fields.len());
+    for (index, field) in fields.iter().enumerate() {
+        let attrs = FieldAttrs::new(&field.attrs)?;
+        for lit in &attrs.literals {
+            stmts.push(literal(lit, bounds)?);
+        }
+
+        let ty = &field.ty;
+        let value = if attrs.skip {
+            if let Some(span) = attrs.parser_span {
+                return Err(Error::new(span, "`skip` fields are not parsed"));
+            }
+            quote!(::core::default::Default::default())
+        } else {
+            let parser = match &attrs.separator {
+                Some(separator) => {
+                    let item = item_type(ty)?;
+                    let parser = item_parser(item, &attrs, bounds)?;
+                    // Pin the item type as `Accumulate` can't infer it
+                    let item = quote! {{
+                        let mut __parser = #parser;
+                        move |__input: &mut I| -> ::core::result::Result<#item, E> {
+                            ::winnow::Parser::<I, #item, E>::parse_next(&mut __parser, __input)
+                        }
+                    }};
+                    let separator = literal_parser(separator, bounds)?;
+                    let occurrences = attrs
+                        .occurrences
+                        .as_ref()
+                        .map(|occurrences| quote!(#occurrences))
+                        .unwrap_or_else(|| quote!(0..));
+                    quote!(::winnow::combinator::separated(#occurrences, #item, #separator))
+                }
+                None => {
+                    if let Some(occurrences) = &attrs.occurrences {
+                        return Err(Error::new_spanned(
+                            occurrences,
+                            "`occurrences` requires a `separator`",
+                        ));
+                    }
+                    item_parser(ty, &attrs, bounds)?
+                }
+            };
+            quote! {
+                ::winnow::Parser::<I, #ty, E>::parse_next(&mut #parser, __input)?
+            }
+        };
+
+        let binding = field
+            .ident
+            .clone()
+            .unwrap_or_else(|| format_ident!("field_{}", index));
+        stmts.push(quote! {
+            let #binding: #ty = #value;
+        });
+        bindings.push(binding);
+    }
+
+    let construct = match fields {
+        Fields::Named(_) => quote!(#path { #(#bindings,)* }),
+        Fields::Unnamed(_) => quote!(#path ( #(#bindings,)* )),
+        Fields::Unit => path,
+    };
+    Ok(quote! {
+        #(#stmts)*
+        ::core::result::Result::Ok(#construct)
+    })
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    literals: Vec<Lit>,
+    with: Option<Expr>,
+    binary: Option<Ident>,
+    skip: bool,
+    separator: Option<Lit>,
+    occurrences: Option<Expr>,
+    /// Where `with` or `binary` was set
+    parser_span: Option<proc_macro2::Span>,
+}
+
+impl FieldAttrs {
+    fn new(attrs: &[Attribute]) -> Result<Self> {
+        let mut field = Self::default();
+        for attr in parse_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("with") || meta.path.is_ident("binary") {
+                    if field.parser_span.is_some() {
+                        return Err(meta.error("only one of `with` and `binary` may be set"));
+                    }
+                    field.parser_span = Some(meta.path.span());
+                    if meta.path.is_ident("with") {
+                        field.with = Some(meta.value()?.parse()?);
+                    } else {
+                        field.binary = Some(meta.value()?.parse()?);
+                    }
+                } else if meta.path.is_ident("literal") {
+                    field.literals.push(meta.value()?.parse()?);
+                } else if meta.path.is_ident("skip") {
+                    field.skip = true;
+                } else if meta.path.is_ident("separator") {
+                    field.separator = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("occurrences") {
+                    field.occurrences = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("unsupported `parse` attribute on a field"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(field)
+    }
+}
+
+/// Parser for a field, or an item of a `separator` field
+fn item_parser(ty: &Type, attrs: &FieldAttrs, bounds: &mut Bounds) -> Result<TokenStream> {
+    if let Some(with) = &attrs.with {
+        return Ok(quote!((#with)));
+    }
+    if let Some(endian) = &attrs.binary {
+        return binary(ty, endian, bounds);
+    }
+    bounds.push(quote!(#ty: ::winnow::Parse<I, E>));
+    Ok(quote!(<#ty as ::winnow::Parse<I, E>>::parse_next))
+}
+
+/// The item type of a collection, like the `T` of `Vec<T>`
+fn item_type(ty: &Type) -> Result<&Type> {
+    if let Type::Path(path) = ty {
+        if let Some(segment) = path.path.segments.last() {
+            if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                for arg in &args.args {
+                    if let GenericArgument::Type(item) = arg {
+                        return Ok(item);
+                    }
+                }
+            }
+        }
+    }
+    Err(Error::new_spanned(
+        ty,
+        "`separator` requires a collection type, like `Vec<T>`",
+    ))
+}
+
+fn binary(ty: &Type, endian: &Ident, bounds: &mut Bounds) -> Result<TokenStream> {
+    let endian = match endian.to_string().as_str() {
+        "big" => quote!(::winnow::binary::Endianness::Big),
+        "little" => quote!(::winnow::binary::Endianness::Little),
+        "native" => quote!(::winnow::binary::Endianness::Native),
+        _ => {
+            return Err(Error::new_spanned(
+                endian,
+                "expected `big`, `little` or `native`",
+            ))
+        }
+    };
+    let number = match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.get_ident(),
+        _ => None,
+    };
+    let parser = match number.map(ToString::to_string).as_deref() {
+        Some("u8") => quote!(::winnow::binary::u8),
+        Some("i8") => quote!(::winnow::binary::i8),
+        Some(
+            number @ ("u16" | "u32" | "u64" | "u128" | "i16" | "i32" | "i64" | "i128" | "f32"
+            | "f64"),
+        ) => {
+            let number = format_ident!("{}", number);
+            quote!(::winnow::binary::#number(#endian))
+        }
+        _ => {
+            return Err(Error::new_spanned(
+                ty,
+                "`binary` requires an integer or float type",
+            ))
+        }
+    };
+    bounds.push(quote!(I: ::winnow::stream::Stream<Token = u8>));
+    Ok(parser)
+}
+
+/// Statement matching `lit`
+fn literal(lit: &Lit, bounds: &mut Bounds) -> Result<TokenStream> {
+    let parser = literal_parser(lit, bounds)?;
+    Ok(quote! {
+        ::winnow::Parser::<I, _, E>::parse_next(&mut #parser, __input)?;
+    })
+}
+
+fn literal_parser(lit: &Lit, bounds: &mut Bounds) -> Result<TokenStream> {
+    let ty = match lit {
+        Lit::Str(_) => quote!(&'static str),
+        Lit::ByteStr(bytes) => {
+            let len = bytes.value().len();
+            quote!(&'static [u8; #len])
+        }
+        Lit::Char(_) => quote!(char),
+        Lit::Byte(_) => quote!(u8),
+        _ => {
+            return Err(Error::new_spanned(
+                lit,
+                "expected a string, byte string, `char` or byte literal",
+            ))
+        }
+    };
+    bounds.push(quote!(I: ::winnow::stream::Compare<#ty>));
+    Ok(quote!(::winnow::token::literal(#lit)))
+}
+
+/// `alt` over `cases`, nesting to stay within the supported tuple sizes
+fn alt(mut cases: Vec<TokenStream>) -> TokenStream {
+    const MAX: usize = 21;
+    while MAX < cases.len() {
+        cases = cases
+            .chunks(MAX)
+            .map(|chunk| quote!(::winnow::combinator::alt((#(#chunk,)*))))
+            .collect();
+    }
+    quote!(::winnow::combinator::alt((#(#cases,)*)))
+}
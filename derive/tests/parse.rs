@@ -0,0 +1,156 @@
+use winnow::ascii::dec_uint;
+use winnow::binary::be_u8;
+use winnow::error::{ContextError, ErrMode};
+use winnow::prelude::*;
+use winnow::stream::{AsBStr, AsChar, Stream};
+use winnow::token::{any, take};
+use winnow::Parse;
+
+type Error = ErrMode<ContextError>;
+
+#[derive(Debug, PartialEq, Parse)]
+#[parse(bound = "<I as Stream>::Token: AsChar + Clone, <I as Stream>::Slice: AsBStr")]
+struct Number(#[parse(with = dec_uint)] u32);
+
+#[derive(Debug, PartialEq, Parse)]
+#[parse(bound = "<I as Stream>::Token: AsChar + Clone, <I as Stream>::Slice: AsBStr")]
+enum Value {
+    #[parse(tag = "null")]
+    Null,
+    #[parse(tag = '[')]
+    List(
+        #[parse(separator = ", ", occurrences = 1..)] Vec<Number>,
+        #[parse(literal = ']', skip)] (),
+    ),
+    Number(Number),
+}
+
+#[test]
+fn enum_variants() {
+    fn value(input: &mut &str) -> ModalResult<Value> {
+        Value::parse_next(input)
+    }
+
+    assert_eq!(value.parse_peek("null!"), Ok(("!", Value::Null)));
+    assert_eq!(value.parse_peek("42"), Ok(("", Value::Number(Number(42)))));
+    assert_eq!(
+        value.parse_peek("[1, 2]"),
+        Ok(("", Value::List(vec![Number(1), Number(2)], ())))
+    );
+    assert!(value.parse_peek("[]").is_err());
+    assert!(value.parse_peek("nul").is_err());
+}
+
+#[test]
+fn earlier_fields() {
+    #[derive(Debug, PartialEq, Parse)]
+    #[parse(bound = "I: Stream<Token = u8, Slice = S>")]
+    struct Chunk<S> {
+        #[parse(with = be_u8)]
+        len: u8,
+        #[parse(with = take(len))]
+        data: S,
+    }
+
+    let mut input = &b"\x03abcd"[..];
+    let chunk: Result<Chunk<&[u8]>, Error> = Chunk::parse_next(&mut input);
+    assert_eq!(
+        chunk,
+        Ok(Chunk {
+            len: 3,
+            data: &b"abc"[..]
+        })
+    );
+    assert_eq!(input, b"d");
+}
+
+#[test]
+fn binary() {
+    #[derive(Debug, PartialEq, Parse)]
+    struct Record {
+        #[parse(literal = b"RC", binary = little)]
+        id: u16,
+        #[parse(literal = b'\0', binary = big)]
+        value: i32,
+        #[parse(binary = native, separator = b',', occurrences = 2)]
+        pair: Vec<u8>,
+        #[parse(skip)]
+        checked: bool,
+    }
+
+    let mut input = &b"RC\x01\x00\x00\xff\xff\xff\xfe\x07,\x08"[..];
+    let record: Result<Record, Error> = Record::parse_next(&mut input);
+    assert_eq!(
+        record,
+        Ok(Record {
+            id: 1,
+            value: -2,
+            pair: vec![7, 8],
+            checked: false,
+        })
+    );
+}
+
+#[test]
+fn many_variants() {
+    #[derive(Debug, PartialEq, Parse)]
+    #[parse(bound = "I: Stream<Token = char>")]
+    enum Letter {
+        #[parse(tag = 'a')]
+        A,
+        #[parse(tag = 'b')]
+        B,
+        #[parse(tag = 'c')]
+        C,
+        #[parse(tag = 'd')]
+        D,
+        #[parse(tag = 'e')]
+        E,
+        #[parse(tag = 'f')]
+        F,
+        #[parse(tag = 'g')]
+        G,
+        #[parse(tag = 'h')]
+        H,
+        #[parse(tag = 'i')]
+        I,
+        #[parse(tag = 'j')]
+        J,
+        #[parse(tag = 'k')]
+        K,
+        #[parse(tag = 'l')]
+        L,
+        #[parse(tag = 'm')]
+        M,
+        #[parse(tag = 'n')]
+        N,
+        #[parse(tag = 'o')]
+        O,
+        #[parse(tag = 'p')]
+        P,
+        #[parse(tag = 'q')]
+        Q,
+        #[parse(tag = 'r')]
+        R,
+        #[parse(tag = 's')]
+        S,
+        #[parse(tag = 't')]
+        T,
+        #[parse(tag = 'u')]
+        U,
+        #[parse(tag = 'v')]
+        V,
+        #[parse(tag = 'w')]
+        W,
+        Other(#[parse(with = any)] char),
+    }
+
+    let letter = |input: &str| {
+        <Letter as Parse<_, ContextError>>::parse_next
+            .parse(input)
+            .ok()
+    };
+    assert_eq!(letter("a"), Some(Letter::A));
+    assert_eq!(letter("w"), Some(Letter::W));
+    assert_eq!(letter("x"), Some(Letter::Other('x')));
+}
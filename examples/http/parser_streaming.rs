@@ -1,3 +1,4 @@
+use winnow::abnf::{is_wsp, token};
 use winnow::combinator::seq;
 use winnow::{
     ascii::line_ending, combinator::repeat, prelude::*, stream::Partial, token::take_while,
@@ -54,7 +55,7 @@ fn request<'s>(input: &mut Stream<'s>) -> ModalResult<(Request<'s>, Vec<Header<'
 
 fn request_line<'s>(input: &mut Stream<'s>) -> ModalResult<Request<'s>> {
     seq!( Request {
-        method: take_while(1.., is_token),
+        method: token,
         _: take_while(1.., is_space),
         uri: take_while(1.., is_not_space),
         _: take_while(1.., is_space),
@@ -72,7 +73,7 @@ fn http_version<'s>(input: &mut Stream<'s>) -> ModalResult<&'s [u8]> {
 }
 
 fn message_header_value<'s>(input: &mut Stream<'s>) -> ModalResult<&'s [u8]> {
-    let _ = take_while(1.., is_horizontal_space).parse_next(input)?;
+    let _ = take_while(1.., is_wsp).parse_next(input)?;
     let data = take_while(1.., till_line_ending).parse_next(input)?;
     let _ = line_ending.parse_next(input)?;
 
@@ -81,42 +82,13 @@ fn message_header_value<'s>(input: &mut Stream<'s>) -> ModalResult<&'s [u8]> {
 
 fn message_header<'s>(input: &mut Stream<'s>) -> ModalResult<Header<'s>> {
     seq!(Header {
-        name: take_while(1.., is_token),
+        name: token,
         _: ':',
         value: repeat(1.., message_header_value),
     })
     .parse_next(input)
 }
 
-#[rustfmt::skip]
-#[allow(clippy::match_same_arms)]
-#[allow(clippy::match_like_matches_macro)]
-fn is_token(c: u8) -> bool {
-  match c {
-    128..=255 => false,
-    0..=31    => false,
-    b'('      => false,
-    b')'      => false,
-    b'<'      => false,
-    b'>'      => false,
-    b'@'      => false,
-    b','      => false,
-    b';'      => false,
-    b':'      => false,
-    b'\\'     => false,
-    b'"'      => false,
-    b'/'      => false,
-    b'['      => false,
-    b']'      => false,
-    b'?'      => false,
-    b'='      => false,
-    b'{'      => false,
-    b'}'      => false,
-    b' '      => false,
-    _         => true,
-  }
-}
-
 fn is_version(c: u8) -> bool {
     c.is_ascii_digit() || c == b'.'
 }
@@ -132,7 +104,3 @@ fn is_space(c: u8) -> bool {
 fn is_not_space(c: u8) -> bool {
     c != b' '
 }
-
-fn is_horizontal_space(c: u8) -> bool {
-    c == b' ' || c == b'\t'
-}
@@ -2,11 +2,9 @@ mod parser;
 
 use std::io::Read;
 
-use winnow::error::ContextError;
-use winnow::error::ErrMode;
+use winnow::combinator::iterator;
 use winnow::error::Needed;
 use winnow::prelude::*;
-use winnow::stream::Offset;
 
 fn main() -> Result<(), lexopt::Error> {
     let args = Args::parse()?;
@@ -21,6 +19,10 @@ fn main() -> Result<(), lexopt::Error> {
     let min_buffer_growth = 100;
     let buffer_growth_factor = 2;
     let mut buffer = circular::Buffer::with_capacity(buffer_size);
+    // Nothing is parsed from the empty input, leaving the parser ready for the first read
+    let mut records = iterator(parser::Stream::new(""), record)
+        .suspend()
+        .map_err(|e| fmt_lexopt(e.to_string()))?;
     loop {
         let read = file.read(buffer.space()).map_err(to_lexopt)?;
         eprintln!("read {read}");
@@ -37,52 +39,57 @@ fn main() -> Result<(), lexopt::Error> {
         }
         buffer.fill(read);
 
-        loop {
-            let mut input =
-                parser::Stream::new(std::str::from_utf8(buffer.data()).map_err(to_lexopt)?);
-            let start = input.checkpoint();
-            match parser::ndjson::<ContextError>.parse_next(&mut input) {
-                Ok(value) => {
-                    println!("{value:?}");
-                    println!();
-                    // Tell the buffer how much we read
-                    let consumed = input.offset_from(&start);
-                    buffer.consume(consumed);
-                }
-                Err(ErrMode::Backtrack(e)) | Err(ErrMode::Cut(e)) => {
-                    return Err(fmt_lexopt(e.to_string()));
-                }
-                Err(ErrMode::Incomplete(Needed::Size(size))) => {
-                    // Without the format telling us how much space is required, we really should
-                    // treat this the same as `Unknown` but are doing this to demonstrate how to
-                    // handle `Size`.
-                    //
-                    // Even when the format has a header to tell us `Size`, we could hit incidental
-                    // `Size(1)`s, so make sure we buffer more space than that to avoid reading
-                    // one byte at a time
-                    let head_room = size.get().max(min_buffer_growth);
-                    let new_capacity = buffer.available_data() + head_room;
-                    eprintln!("growing buffer to {new_capacity}");
-                    buffer.grow(new_capacity);
-                    if buffer.available_space() < head_room {
-                        eprintln!("buffer shift");
-                        buffer.shift();
-                    }
-                    break;
-                }
-                Err(ErrMode::Incomplete(Needed::Unknown)) => {
-                    let new_capacity = buffer_growth_factor * buffer.capacity();
-                    eprintln!("growing buffer to {new_capacity}");
-                    buffer.grow(new_capacity);
-                    break;
+        let data = std::str::from_utf8(buffer.data()).map_err(to_lexopt)?;
+        let mut it = records.resume(parser::Stream::new(data));
+        for value in &mut it {
+            println!("{value:?}");
+            println!();
+        }
+        // Tell the buffer how much we read
+        let consumed = data.len() - it.remaining().len();
+        let needed = it.needed();
+        records = it.suspend().map_err(|e| fmt_lexopt(e.to_string()))?;
+        buffer.consume(consumed);
+
+        match needed {
+            Some(Needed::Size(size)) => {
+                // Without the format telling us how much space is required, we really should
+                // treat this the same as `Unknown` but are doing this to demonstrate how to
+                // handle `Size`.
+                //
+                // Even when the format has a header to tell us `Size`, we could hit incidental
+                // `Size(1)`s, so make sure we buffer more space than that to avoid reading
+                // one byte at a time
+                let head_room = size.get().max(min_buffer_growth);
+                let new_capacity = buffer.available_data() + head_room;
+                eprintln!("growing buffer to {new_capacity}");
+                buffer.grow(new_capacity);
+                if buffer.available_space() < head_room {
+                    eprintln!("buffer shift");
+                    buffer.shift();
                 }
             }
+            Some(Needed::Unknown) => {
+                let new_capacity = buffer_growth_factor * buffer.capacity();
+                eprintln!("growing buffer to {new_capacity}");
+                buffer.grow(new_capacity);
+            }
+            None => {
+                return Err(fmt_lexopt(format!(
+                    "invalid record at offset {}",
+                    records.offset()
+                )));
+            }
         }
     }
 
     Ok(())
 }
 
+fn record<'i>(input: &mut parser::Stream<'i>) -> ModalResult<Option<parser::JsonValue>> {
+    parser::ndjson.parse_next(input)
+}
+
 #[derive(Default)]
 struct Args {
     input: Option<std::path::PathBuf>,
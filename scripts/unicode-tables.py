@@ -0,0 +1,147 @@
+#!/usr/bin/env python3
+"""Generate `src/unicode/tables.rs` from the Unicode Character Database
+
+Use the version of `char::UNICODE_VERSION` in the MSRV, so the tables are never newer than the `char`
+methods of a supported toolchain.
+
+Usage, with `UCD.zip` from https://www.unicode.org/Public/<version>/ucd/ extracted into `ucd/`:
+
+    ./scripts/unicode-tables.py ucd > src/unicode/tables.rs && cargo fmt
+"""
+
+import pathlib
+import re
+import sys
+
+# (name, doc, file, property values)
+TABLES = [
+    ("XID_START", "XID_Start", "DerivedCoreProperties.txt", {"XID_Start"}),
+    ("XID_CONTINUE", "XID_Continue", "DerivedCoreProperties.txt", {"XID_Continue"}),
+    ("WHITE_SPACE", "White_Space", "PropList.txt", {"White_Space"}),
+    ("PATTERN_SYNTAX", "Pattern_Syntax", "PropList.txt", {"Pattern_Syntax"}),
+    (
+        "LETTER",
+        "General_Category=Letter",
+        "extracted/DerivedGeneralCategory.txt",
+        {"Lu", "Ll", "Lt", "Lm", "Lo"},
+    ),
+    (
+        "UPPERCASE_LETTER",
+        "General_Category=Uppercase_Letter",
+        "extracted/DerivedGeneralCategory.txt",
+        {"Lu"},
+    ),
+    (
+        "LOWERCASE_LETTER",
+        "General_Category=Lowercase_Letter",
+        "extracted/DerivedGeneralCategory.txt",
+        {"Ll"},
+    ),
+    (
+        "MARK",
+        "General_Category=Mark",
+        "extracted/DerivedGeneralCategory.txt",
+        {"Mn", "Mc", "Me"},
+    ),
+    (
+        "DECIMAL_NUMBER",
+        "General_Category=Decimal_Number",
+        "extracted/DerivedGeneralCategory.txt",
+        {"Nd"},
+    ),
+    (
+        "NUMBER",
+        "General_Category=Number",
+        "extracted/DerivedGeneralCategory.txt",
+        {"Nd", "Nl", "No"},
+    ),
+    (
+        "PUNCTUATION",
+        "General_Category=Punctuation",
+        "extracted/DerivedGeneralCategory.txt",
+        {"Pc", "Pd", "Ps", "Pe", "Pi", "Pf", "Po"},
+    ),
+    (
+        "SYMBOL",
+        "General_Category=Symbol",
+        "extracted/DerivedGeneralCategory.txt",
+        {"Sm", "Sc", "Sk", "So"},
+    ),
+    (
+        "SEPARATOR",
+        "General_Category=Separator",
+        "extracted/DerivedGeneralCategory.txt",
+        {"Zs", "Zl", "Zp"},
+    ),
+    (
+        "CONTROL",
+        "General_Category=Control",
+        "extracted/DerivedGeneralCategory.txt",
+        {"Cc"},
+    ),
+]
+
+
+def read(path, values):
+    """Code point ranges of lines whose property is in `values`, and the UCD version"""
+    version = None
+    ranges = []
+    with open(path, encoding="utf-8") as file:
+        for line in file:
+            if version is None:
+                match = re.match(r"# \S+-(\d+\.\d+\.\d+)\.txt", line)
+                if match:
+                    version = match.group(1)
+            data = line.split("#", 1)[0].strip()
+            if not data:
+                continue
+            code_points, value = (field.strip() for field in data.split(";")[:2])
+            if value not in values:
+                continue
+            start, _, end = code_points.partition("..")
+            ranges.append((int(start, 16), int(end or start, 16)))
+    return merge(ranges), version
+
+
+def merge(ranges):
+    merged = []
+    for start, end in sorted(ranges):
+        if merged and start <= merged[-1][1] + 1:
+            merged[-1] = (merged[-1][0], max(merged[-1][1], end))
+        else:
+            merged.append((start, end))
+    return merged
+
+
+def main(ucd):
+    ucd = pathlib.Path(ucd)
+    tables = []
+    versions = set()
+    for name, doc, file, values in TABLES:
+        ranges, version = read(ucd / file, values)
+        versions.add(version)
+        tables.append((name, doc, ranges))
+    if len(versions) != 1:
+        sys.exit(f"mixed UCD versions: {versions}")
+
+    version = versions.pop()
+    print("//! Character ranges of Unicode properties")
+    print("//!")
+    print(f"//! Generated from the Unicode Character Database, version {version}.")
+    print("//! Regenerate with `scripts/unicode-tables.py`.")
+    print()
+    print("/// Version of the Unicode Character Database the tables are generated from")
+    print(f"pub const UNICODE_VERSION: (u8, u8, u8) = ({version.replace('.', ', ')});")
+    for name, doc, ranges in tables:
+        print()
+        print(f"/// `{doc}`")
+        print(f"pub(super) const {name}: &[(char, char)] = &[")
+        for start, end in ranges:
+            print(f"    ('\\u{{{start:x}}}', '\\u{{{end:x}}}'),")
+        print("];")
+
+
+if __name__ == "__main__":
+    if len(sys.argv) != 2:
+        sys.exit(__doc__)
+    main(sys.argv[1])
@@ -4,6 +4,7 @@
 //!
 //! * [Whitespace](#whitespace)
 //!   + [Wrapper combinators that eat whitespace before and after a parser](#wrapper-combinators-that-eat-whitespace-before-and-after-a-parser)
+//!   + [Skipping whitespace and comments before every token](#skipping-whitespace-and-comments-before-every-token)
 //! * [Comments](#comments)
 //!   + [`// C++/EOL-style comments`](#-ceol-style-comments)
 //!   + [`/* C-style comments */`](#-c-style-comments-)
@@ -51,6 +52,41 @@
 //! &inner)`. You can use your own parser instead of `multispace0` if you want to skip a different set
 //! of lexemes.
 //!
+//! ### Skipping whitespace and comments before every token
+//!
+//! Instead of wrapping every token, wrap the input in [`Trivia`][crate::stream::Trivia] and token
+//! parsers will skip whitespace and comments on their own.  Tokens built out of several parsers,
+//! like identifiers and strings, are wrapped in [`lexeme`][crate::combinator::lexeme] so nothing
+//! is skipped within them.
+//!
+//! ```rust
+//! use winnow::prelude::*;
+//! use winnow::{
+//!   ascii::{alpha1, alphanumeric0, multispace1},
+//!   combinator::{alt, lexeme, repeat, separated_pair},
+//!   stream::Trivia,
+//!   token::take_until,
+//! };
+//!
+//! fn trivia(input: &mut &str) -> ModalResult<()> {
+//!   repeat(0.., alt((
+//!     multispace1.void(),
+//!     ("/*", take_until(0.., "*/"), "*/").void(),
+//!   ))).parse_next(input)
+//! }
+//!
+//! fn ident<'a>(input: &mut Trivia<&'a str>) -> ModalResult<&'a str> {
+//!   lexeme((alpha1, alphanumeric0).take()).parse_next(input)
+//! }
+//!
+//! fn assign<'a>(input: &mut Trivia<&'a str>) -> ModalResult<(&'a str, &'a str)> {
+//!   separated_pair(ident, '=', ident).parse_next(input)
+//! }
+//!
+//! let input = Trivia::new(" x1 /* set */ = y2 ", trivia);
+//! assert_eq!(assign.parse(input), Ok(("x1", "y2")));
+//! ```
+//!
 //! ## Comments
 //!
 //! ### `// C++/EOL-style comments`
@@ -128,6 +164,8 @@
 //! of its sub-parsers. The [`take`][crate::Parser::take] parser produces a `&str` of the
 //! input text that was parsed, which in this case is the entire `&str` `hello_world123abc`.
 //!
+//! For identifiers beyond ASCII, like Rust's own, see [`unicode::identifier`].
+//!
 //! ## Literal Values
 //!
 //! ### Escaped Strings
@@ -136,10 +174,28 @@
 #![doc = include_str!("../../examples/string/parser.rs")]
 //! ```
 //!
-//! See also [`take_escaped`] and [`escaped`].
+//! See also [`take_escaped`] and [`escaped`], or [`unescape`] for the string literals of JSON, Rust
+//! and C.
 //!
 //! ### Integers
 //!
+//! [`int_literal`] parses the integer literals of most languages, with radix prefixes, digit
+//! separators, a sign and type suffixes:
+//!
+//! ```rust
+//! use winnow::prelude::*;
+//! use winnow::ascii::{int_literal, IntValue, Separators};
+//!
+//! fn integer(input: &mut &str) -> ModalResult<i64> {
+//!   int_literal()
+//!     .separators(&['_'], Separators::Anywhere)
+//!     .map(|int: IntValue<i64>| int.value)
+//!     .parse_next(input)
+//! }
+//!
+//! assert_eq!(integer.parse_peek("0xA4_3F_11_28"), Ok(("", 0xA4_3F_11_28)));
+//! ```
+//!
 //! The following recipes all return string slices rather than integer values. How to obtain an
 //! integer value instead is demonstrated for hexadecimal integers. The others are similar.
 //!
@@ -315,12 +371,19 @@
 //! }
 //! ```
 //!
-//! See also [`float`]
+//! See also [`float`], [`float_exact`], [`hex_float`] and [`float_parts`]
 
 #![allow(unused_imports)]
 use crate::ascii::dec_int;
 use crate::ascii::dec_uint;
 use crate::ascii::escaped;
 use crate::ascii::float;
+use crate::ascii::float_exact;
+use crate::ascii::float_parts;
+use crate::ascii::hex_float;
 use crate::ascii::hex_uint;
+use crate::ascii::int_literal;
 use crate::ascii::take_escaped;
+#[cfg(feature = "alloc")]
+use crate::ascii::unescape;
+use crate::unicode;
@@ -109,7 +109,7 @@
 //! For example, `value(Null, many0(_))` will avoid creating and pushing to a `Vec`.
 //! Previously, users had to select `count_many0` over `many0` to avoid creating a `Vec`.
 //!
-//! Instead, `repeat` returns an `impl Accumulate<T>` which could be a `Vec`, a `usize` for `count`
+//! Instead, `repeat` returns an `impl TryAccumulate<T>` which could be a `Vec`, a `usize` for `count`
 //! variants, or `()` to do no extra work.
 //!
 //! #### Eliding Backtracked Errors
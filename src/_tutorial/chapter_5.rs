@@ -203,8 +203,8 @@
 //! ```
 //!
 //! If you look closely at [`separated`] and [`repeat`], they aren't limited to collecting
-//! the result into a [`Vec`], but rather anything that implements the [`Accumulate`] trait.
-//! [`Accumulate`] is for instance also implemented for [`HashSet`], [`String`] and `()`.
+//! the result into a [`Vec`], but rather anything that implements the [`TryAccumulate`] trait.
+//! [`TryAccumulate`] is for instance also implemented for [`HashSet`], [`String`] and `()`.
 //!
 //! This lets us build more complex parsers than we did in
 //! [`chapter_2`] by accumulating the results into a `()` and [`take`][Parser::take]-ing
@@ -290,8 +290,8 @@ use super::chapter_3;
 use crate::combinator;
 use crate::combinator::repeat;
 use crate::combinator::separated;
-use crate::stream::Accumulate;
 use crate::stream::Stream;
+use crate::stream::TryAccumulate;
 use crate::Parser;
 use std::collections::HashSet;
 use std::vec::Vec;
@@ -31,6 +31,14 @@
 //! #     assert_eq!(output, "");
 //! # }
 //! ```
+//!
+//! The trace goes to stderr by default.  To record it instead, like for tools or tests, see
+//! `winnow::combinator::TraceSink`.
+//!
+//! On large grammars, narrow the trace down with the `WINNOW_TRACE` environment variable, like
+//! `WINNOW_TRACE=json_value,depth<=5` to only trace 5 levels within `json_value`, or
+//! `WINNOW_TRACE=final-error` to only trace the parsers leading to the error.  See
+//! `winnow::combinator::TraceFilter` for more.
 
 pub use super::chapter_7 as previous;
 pub use crate::_tutorial as table_of_contents;
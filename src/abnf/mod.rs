@@ -0,0 +1,509 @@
+//! Core rules of [ABNF (RFC 5234)](https://www.rfc-editor.org/rfc/rfc5234#appendix-B.1) and common
+//! productions of IETF protocols
+//!
+//! Single-byte rules are predicates, like [`is_alpha`] for `ALPHA`, for use with
+//! [`take_while`] and [`one_of`], and parsers of one byte, like [`alpha`].
+//! Parsers, like [`crlf`] for `CRLF`, report errors with the name of their production as a
+//! [`StrContext::Label`].
+//!
+//! As strings in ABNF are case-insensitive, so is [`is_hexdig`].
+//!
+//! # Example
+//!
+//! ```rust
+//! # use winnow::prelude::*;
+//! use winnow::abnf::{crlf, is_vchar, is_wsp, token};
+//! use winnow::token::take_while;
+//!
+//! /// `field-line = field-name ":" OWS field-value OWS` of RFC 9112, on one line
+//! fn field_line<'i>(input: &mut &'i [u8]) -> ModalResult<(&'i [u8], &'i [u8])> {
+//!     let name = token.parse_next(input)?;
+//!     b':'.parse_next(input)?;
+//!     take_while(0.., is_wsp).parse_next(input)?;
+//!     let value = take_while(0.., (is_vchar, is_wsp)).parse_next(input)?;
+//!     crlf.parse_next(input)?;
+//!     Ok((name, value))
+//! }
+//!
+//! assert_eq!(
+//!     field_line.parse_peek(&b"Host: www.example.com\r\n"[..]),
+//!     Ok((&b""[..], (&b"Host"[..], &b"www.example.com"[..])))
+//! );
+//! ```
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests;
+
+use crate::combinator::{alt, repeat, trace};
+use crate::error::{AddContext, ParserError, StrContext};
+use crate::stream::{Compare, Stream, StreamIsPartial};
+use crate::token::{any, one_of, take_while};
+use crate::Parser;
+use crate::Result;
+
+/// `ALPHA = %x41-5A / %x61-7A`
+#[inline]
+pub fn is_alpha(c: u8) -> bool {
+    c.is_ascii_alphabetic()
+}
+
+/// `BIT = "0" / "1"`
+#[inline]
+pub fn is_bit(c: u8) -> bool {
+    c == b'0' || c == b'1'
+}
+
+/// `CHAR = %x01-7F`
+#[inline]
+pub fn is_char(c: u8) -> bool {
+    matches!(c, 0x01..=0x7f)
+}
+
+/// `CTL = %x00-1F / %x7F`
+#[inline]
+pub fn is_ctl(c: u8) -> bool {
+    c.is_ascii_control()
+}
+
+/// `DIGIT = %x30-39`
+#[inline]
+pub fn is_digit(c: u8) -> bool {
+    c.is_ascii_digit()
+}
+
+/// `HEXDIG = DIGIT / "A" / "B" / "C" / "D" / "E" / "F"`, in either case
+#[inline]
+pub fn is_hexdig(c: u8) -> bool {
+    c.is_ascii_hexdigit()
+}
+
+/// `VCHAR = %x21-7E`
+#[inline]
+pub fn is_vchar(c: u8) -> bool {
+    c.is_ascii_graphic()
+}
+
+/// `WSP = SP / HTAB`
+#[inline]
+pub fn is_wsp(c: u8) -> bool {
+    c == b' ' || c == b'\t'
+}
+
+/// `tchar` of [RFC 9110](https://www.rfc-editor.org/rfc/rfc9110#section-5.6.2): visible
+/// characters except delimiters
+///
+/// ```text
+/// tchar = "!" / "#" / "$" / "%" / "&" / "'" / "*" / "+" / "-" / "." /
+///         "^" / "_" / "`" / "|" / "~" / DIGIT / ALPHA
+/// ```
+#[inline]
+pub fn is_tchar(c: u8) -> bool {
+    matches!(
+        c,
+        b'!' | b'#'
+            | b'$'
+            | b'%'
+            | b'&'
+            | b'\''
+            | b'*'
+            | b'+'
+            | b'-'
+            | b'.'
+            | b'^'
+            | b'_'
+            | b'`'
+            | b'|'
+            | b'~'
+    ) || c.is_ascii_alphanumeric()
+}
+
+/// `obs-text = %x80-FF` of [RFC 9110](https://www.rfc-editor.org/rfc/rfc9110#section-5.5)
+#[inline]
+pub fn is_obs_text(c: u8) -> bool {
+    0x80 <= c
+}
+
+/// `qdtext = HTAB / SP / %x21 / %x23-5B / %x5D-7E / obs-text` of
+/// [RFC 9110](https://www.rfc-editor.org/rfc/rfc9110#section-5.6.4): text of a
+/// [`quoted_string`] besides `"` and `\`
+#[inline]
+pub fn is_qdtext(c: u8) -> bool {
+    is_wsp(c) || (is_vchar(c) && c != b'"' && c != b'\\') || is_obs_text(c)
+}
+
+/// `ctext = HTAB / SP / %x21-27 / %x2A-5B / %x5D-7E / obs-text` of
+/// [RFC 9110](https://www.rfc-editor.org/rfc/rfc9110#section-5.6.5): text of a [`comment`]
+/// besides `(`, `)` and `\`
+#[inline]
+pub fn is_ctext(c: u8) -> bool {
+    is_wsp(c) || (is_vchar(c) && c != b'(' && c != b')' && c != b'\\') || is_obs_text(c)
+}
+
+/// Whether `c` can follow the `\` of a `quoted-pair`: `HTAB / SP / VCHAR / obs-text`
+#[inline]
+fn is_quoted_pair(c: u8) -> bool {
+    is_wsp(c) || is_vchar(c) || is_obs_text(c)
+}
+
+/// `ALPHA`, see [`is_alpha`]
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::abnf::alpha;
+///
+/// assert_eq!(alpha::<_, ()>.parse_peek(&b"a1"[..]), Ok((&b"1"[..], b'a')));
+/// assert!(alpha::<_, ()>.parse_peek(&b"1"[..]).is_err());
+/// ```
+#[inline(always)]
+pub fn alpha<Input, Error>(input: &mut Input) -> Result<u8, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("ALPHA", one_of(is_alpha))
+        .context(StrContext::Label("ALPHA"))
+        .parse_next(input)
+}
+
+/// `DIGIT`, see [`is_digit`]
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::abnf::digit;
+///
+/// assert_eq!(digit::<_, ()>.parse_peek(&b"7a"[..]), Ok((&b"a"[..], b'7')));
+/// assert!(digit::<_, ()>.parse_peek(&b"a"[..]).is_err());
+/// ```
+#[inline(always)]
+pub fn digit<Input, Error>(input: &mut Input) -> Result<u8, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("DIGIT", one_of(is_digit))
+        .context(StrContext::Label("DIGIT"))
+        .parse_next(input)
+}
+
+/// `HEXDIG`, see [`is_hexdig`]
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::abnf::hexdig;
+///
+/// assert_eq!(hexdig::<_, ()>.parse_peek(&b"Dx"[..]), Ok((&b"x"[..], b'D')));
+/// assert!(hexdig::<_, ()>.parse_peek(&b"x"[..]).is_err());
+/// ```
+#[inline(always)]
+pub fn hexdig<Input, Error>(input: &mut Input) -> Result<u8, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("HEXDIG", one_of(is_hexdig))
+        .context(StrContext::Label("HEXDIG"))
+        .parse_next(input)
+}
+
+/// `VCHAR`, see [`is_vchar`]
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::abnf::vchar;
+///
+/// assert_eq!(vchar::<_, ()>.parse_peek(&b"~ "[..]), Ok((&b" "[..], b'~')));
+/// assert!(vchar::<_, ()>.parse_peek(&b" "[..]).is_err());
+/// ```
+#[inline(always)]
+pub fn vchar<Input, Error>(input: &mut Input) -> Result<u8, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("VCHAR", one_of(is_vchar))
+        .context(StrContext::Label("VCHAR"))
+        .parse_next(input)
+}
+
+/// `WSP`, see [`is_wsp`]
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::abnf::wsp;
+///
+/// assert_eq!(wsp::<_, ()>.parse_peek(&b"\ta"[..]), Ok((&b"a"[..], b'\t')));
+/// assert!(wsp::<_, ()>.parse_peek(&b"a"[..]).is_err());
+/// ```
+#[inline(always)]
+pub fn wsp<Input, Error>(input: &mut Input) -> Result<u8, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("WSP", one_of(is_wsp))
+        .context(StrContext::Label("WSP"))
+        .parse_next(input)
+}
+
+/// `CRLF = CR LF`: an Internet standard newline
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::abnf::crlf;
+///
+/// assert_eq!(crlf::<_, ()>.parse_peek(&b"\r\nrest"[..]), Ok((&b"rest"[..], &b"\r\n"[..])));
+/// assert!(crlf::<_, ()>.parse_peek(&b"\nrest"[..]).is_err());
+/// ```
+#[inline(always)]
+pub fn crlf<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<&'static str>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("CRLF", "\r\n")
+        .context(StrContext::Label("CRLF"))
+        .parse_next(input)
+}
+
+/// `LWSP = *(WSP / CRLF WSP)`: linear whitespace, which may span lines
+///
+/// Beware that this accepts lines of only whitespace, which are empty lines to many protocols.
+///
+/// *Complete version*: Will return the whole input if there's only linear whitespace.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::abnf::lwsp;
+///
+/// assert_eq!(
+///     lwsp::<_, ()>.parse_peek(&b" \r\n\tx"[..]),
+///     Ok((&b"x"[..], &b" \r\n\t"[..]))
+/// );
+/// // A newline without whitespace after it ends `LWSP`
+/// assert_eq!(
+///     lwsp::<_, ()>.parse_peek(&b" \r\nx"[..]),
+///     Ok((&b"\r\nx"[..], &b" "[..]))
+/// );
+/// ```
+#[inline(always)]
+pub fn lwsp<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8> + Compare<&'static str>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace(
+        "LWSP",
+        repeat::<_, _, (), _, _>(
+            0..,
+            alt((one_of(is_wsp).void(), ("\r\n", one_of(is_wsp)).void())),
+        )
+        .take(),
+    )
+    .context(StrContext::Label("LWSP"))
+    .parse_next(input)
+}
+
+/// `token = 1*tchar` of [RFC 9110](https://www.rfc-editor.org/rfc/rfc9110#section-5.6.2), like
+/// field names and methods
+///
+/// See [`is_tchar`].
+///
+/// *Complete version*: Will return an error if there's not enough input data,
+/// or the whole input if no terminating byte is found.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data,
+/// or if no terminating byte is found.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::abnf::token;
+///
+/// assert_eq!(token::<_, ()>.parse_peek(&b"gzip, br"[..]), Ok((&b", br"[..], &b"gzip"[..])));
+/// assert!(token::<_, ()>.parse_peek(&b"\"gzip\""[..]).is_err());
+/// ```
+#[inline(always)]
+pub fn token<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("token", take_while(1.., is_tchar))
+        .context(StrContext::Label("token"))
+        .parse_next(input)
+}
+
+/// `quoted-string = DQUOTE *( qdtext / quoted-pair ) DQUOTE` of
+/// [RFC 9110](https://www.rfc-editor.org/rfc/rfc9110#section-5.6.4), returning the text between
+/// the quotes
+///
+/// `quoted-pair = "\" ( HTAB / SP / VCHAR / obs-text )` is left as is; the byte after each `\`
+/// stands for itself.
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::abnf::quoted_string;
+///
+/// assert_eq!(
+///     quoted_string::<_, ()>.parse_peek(&br#""a \"b\"";q=1"#[..]),
+///     Ok((&b";q=1"[..], &br#"a \"b\""#[..]))
+/// );
+/// assert!(quoted_string::<_, ()>.parse_peek(&b"\"open"[..]).is_err());
+/// ```
+#[inline(always)]
+pub fn quoted_string<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("quoted-string", |input: &mut Input| {
+        one_of(b'"').parse_next(input)?;
+        let text = repeat::<_, _, (), _, _>(
+            0..,
+            alt((
+                take_while(1.., is_qdtext).void(),
+                (one_of(b'\\'), one_of(is_quoted_pair)).void(),
+            )),
+        )
+        .take()
+        .parse_next(input)?;
+        one_of(b'"').parse_next(input)?;
+        Ok(text)
+    })
+    .context(StrContext::Label("quoted-string"))
+    .parse_next(input)
+}
+
+/// `comment = "(" *( ctext / quoted-pair / comment ) ")"` of
+/// [RFC 9110](https://www.rfc-editor.org/rfc/rfc9110#section-5.6.5), returning the whole comment
+///
+/// Nested comments are matched without recursion, so deep nesting can't overflow the stack.
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::abnf::comment;
+///
+/// assert_eq!(
+///     comment::<_, ()>.parse_peek(&b"(X11; (Linux) \\)) rest"[..]),
+///     Ok((&b" rest"[..], &b"(X11; (Linux) \\))"[..]))
+/// );
+/// assert!(comment::<_, ()>.parse_peek(&b"(open (nested)"[..]).is_err());
+/// ```
+#[inline(always)]
+pub fn comment<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace(
+        "comment",
+        (|input: &mut Input| {
+            one_of(b'(').parse_next(input)?;
+            let mut depth = 1_usize;
+            while depth != 0 {
+                match any.parse_next(input)? {
+                    b'(' => depth += 1,
+                    b')' => depth -= 1,
+                    b'\\' => {
+                        one_of(is_quoted_pair).parse_next(input)?;
+                    }
+                    c if is_ctext(c) => {}
+                    _ => return Err(ParserError::from_input(input)),
+                }
+            }
+            Ok(())
+        })
+        .take(),
+    )
+    .context(StrContext::Label("comment"))
+    .parse_next(input)
+}
+
+/// `obs-fold = OWS CRLF RWS` of [RFC 9112](https://www.rfc-editor.org/rfc/rfc9112#section-5.2):
+/// an obsolete line folding within a field value
+///
+/// Recipients should replace it with a space.
+///
+/// *Complete version*: Will return an error if there's not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::abnf::obs_fold;
+///
+/// assert_eq!(
+///     obs_fold::<_, ()>.parse_peek(&b" \r\n\tmore"[..]),
+///     Ok((&b"more"[..], &b" \r\n\t"[..]))
+/// );
+/// // The next field
+/// assert!(obs_fold::<_, ()>.parse_peek(&b"\r\nHost: x"[..]).is_err());
+/// ```
+#[inline(always)]
+pub fn obs_fold<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8> + Compare<&'static str>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace(
+        "obs-fold",
+        (take_while(0.., is_wsp), "\r\n", take_while(1.., is_wsp)).take(),
+    )
+    .context(StrContext::Label("obs-fold"))
+    .parse_next(input)
+}
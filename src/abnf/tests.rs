@@ -0,0 +1,318 @@
+use super::*;
+
+use crate::error::{ContextError, ErrMode, Needed};
+use crate::prelude::*;
+use crate::Partial;
+
+type Complete<'i> = ModalResult<(&'i [u8], &'i [u8]), ContextError>;
+type Streaming<'i> = ModalResult<(Partial<&'i [u8]>, &'i [u8]), ContextError>;
+
+fn label<Output>(result: ModalResult<Output, ContextError>) -> Option<StrContext> {
+    match result {
+        Err(ErrMode::Backtrack(err)) => err.context().next().cloned(),
+        _ => None,
+    }
+}
+
+#[test]
+fn core_rules() {
+    assert!((0..=255)
+        .filter(|&c| is_alpha(c))
+        .eq((b'A'..=b'Z').chain(b'a'..=b'z')));
+    assert!((0..=255).filter(|&c| is_bit(c)).eq(b'0'..=b'1'));
+    assert!((0..=255).filter(|&c| is_char(c)).eq(0x01..=0x7f));
+    assert!((0..=255)
+        .filter(|&c| is_ctl(c))
+        .eq((0x00..=0x1f).chain([0x7f])));
+    assert!((0..=255).filter(|&c| is_digit(c)).eq(b'0'..=b'9'));
+    assert!((0..=255)
+        .filter(|&c| is_hexdig(c))
+        .eq((b'0'..=b'9').chain(b'A'..=b'F').chain(b'a'..=b'f')));
+    assert!((0..=255).filter(|&c| is_vchar(c)).eq(0x21..=0x7e));
+    assert!((0..=255).filter(|&c| is_wsp(c)).eq([b'\t', b' ']));
+}
+
+#[test]
+fn core_rule_parsers() {
+    type Rule = fn(&mut &[u8]) -> ModalResult<u8, ContextError>;
+    type PartialRule = fn(&mut Partial<&[u8]>) -> ModalResult<u8, ContextError>;
+    type Predicate = fn(u8) -> bool;
+    let rules: [(Rule, PartialRule, Predicate, &str); 5] = [
+        (|i| alpha(i), |i| alpha(i), is_alpha, "ALPHA"),
+        (|i| digit(i), |i| digit(i), is_digit, "DIGIT"),
+        (|i| hexdig(i), |i| hexdig(i), is_hexdig, "HEXDIG"),
+        (|i| vchar(i), |i| vchar(i), is_vchar, "VCHAR"),
+        (|i| wsp(i), |i| wsp(i), is_wsp, "WSP"),
+    ];
+    for (mut complete, mut streaming, is_rule, name) in rules {
+        for c in 0..=255 {
+            let input = [c, b'!'];
+            let result = complete.parse_peek(&input[..]);
+            if is_rule(c) {
+                assert_eq!(result, Ok((&b"!"[..], c)), "{name} {c:#x}");
+            } else {
+                assert_eq!(
+                    label(result),
+                    Some(StrContext::Label(name)),
+                    "{name} {c:#x}"
+                );
+            }
+        }
+        assert_eq!(
+            label(complete.parse_peek(&b""[..])),
+            Some(StrContext::Label(name))
+        );
+        assert_eq!(
+            streaming.parse_peek(Partial::new(&b""[..])),
+            Err(ErrMode::Incomplete(Needed::new(1))),
+            "{name}"
+        );
+    }
+
+    // RFC 5234, section 2.3: `%d13` or `%x0D`
+    fn hex_value<'i>(input: &mut &'i [u8]) -> ModalResult<&'i [u8], ContextError> {
+        ("%x", repeat::<_, _, (), _, _>(1.., hexdig))
+            .take()
+            .parse_next(input)
+    }
+    assert_eq!(
+        hex_value.parse_peek(&b"%x0D"[..]),
+        Ok((&b""[..], &b"%x0D"[..]))
+    );
+    fn dec_value<'i>(input: &mut &'i [u8]) -> ModalResult<&'i [u8], ContextError> {
+        ("%d", repeat::<_, _, (), _, _>(1.., digit))
+            .take()
+            .parse_next(input)
+    }
+    assert_eq!(
+        dec_value.parse_peek(&b"%d13"[..]),
+        Ok((&b""[..], &b"%d13"[..]))
+    );
+    // RFC 5234, section 3.1: `WSP` and `VCHAR` within a rule definition
+    assert_eq!(
+        repeat::<_, _, (), _, _>(1.., alt((wsp::<_, ContextError>, vchar)))
+            .take()
+            .parse_peek(&b"foo = %x61 ; a\r\n"[..]),
+        Ok((&b"\r\n"[..], &b"foo = %x61 ; a"[..]))
+    );
+    // RFC 5234, section 4: rule names begin with `ALPHA`
+    assert_eq!(
+        (
+            alpha::<_, ContextError>,
+            take_while(0.., (is_alpha, is_digit, b'-'))
+        )
+            .take()
+            .parse_peek(&b"rule-1 ="[..]),
+        Ok((&b" ="[..], &b"rule-1"[..]))
+    );
+    assert_eq!(
+        label(alpha.parse_peek(&b"1rule"[..])),
+        Some(StrContext::Label("ALPHA"))
+    );
+}
+
+#[test]
+fn http_rules() {
+    // RFC 9110, section 5.6.2: VCHAR, except delimiters
+    let delimiters = b"\"(),/:;<=>?@[\\]{}";
+    assert!((0..=255)
+        .filter(|&c| is_tchar(c))
+        .eq((0x21..=0x7e).filter(|c| !delimiters.contains(c))));
+    assert!((0..=255).filter(|&c| is_qdtext(c)).eq([b'\t', b' ', 0x21]
+        .into_iter()
+        .chain(0x23..=0x5b)
+        .chain(0x5d..=0x7e)
+        .chain(0x80..=0xff)));
+    assert!((0..=255).filter(|&c| is_ctext(c)).eq([b'\t', b' ']
+        .into_iter()
+        .chain(0x21..=0x27)
+        .chain(0x2a..=0x5b)
+        .chain(0x5d..=0x7e)
+        .chain(0x80..=0xff)));
+}
+
+#[test]
+fn crlf_lwsp() {
+    fn crlf_complete(input: &[u8]) -> Complete<'_> {
+        crlf.parse_peek(input)
+    }
+    assert_eq!(crlf_complete(b"\r\n"), Ok((&b""[..], &b"\r\n"[..])));
+    assert_eq!(label(crlf_complete(b"\n")), Some(StrContext::Label("CRLF")));
+
+    fn lwsp_complete(input: &[u8]) -> Complete<'_> {
+        lwsp.parse_peek(input)
+    }
+    assert_eq!(lwsp_complete(b""), Ok((&b""[..], &b""[..])));
+    assert_eq!(
+        lwsp_complete(b"\t \r\n \r\n\tx"),
+        Ok((&b"x"[..], &b"\t \r\n \r\n\t"[..]))
+    );
+    assert_eq!(lwsp_complete(b"\r\n"), Ok((&b"\r\n"[..], &b""[..])));
+
+    fn lwsp_streaming(input: &[u8]) -> Streaming<'_> {
+        lwsp.parse_peek(Partial::new(input))
+    }
+    assert_eq!(
+        lwsp_streaming(b" \r"),
+        Err(ErrMode::Incomplete(Needed::Unknown))
+    );
+    assert_eq!(
+        lwsp_streaming(b" \r\n"),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+    assert_eq!(
+        lwsp_streaming(b" \r\nx"),
+        Ok((Partial::new(&b"\r\nx"[..]), &b" "[..]))
+    );
+}
+
+#[test]
+fn token_rule() {
+    fn complete(input: &[u8]) -> Complete<'_> {
+        token.parse_peek(input)
+    }
+    // RFC 9110, section 9.1: method names
+    assert_eq!(
+        complete(b"GET / HTTP/1.1"),
+        Ok((&b" / HTTP/1.1"[..], &b"GET"[..]))
+    );
+    // RFC 9110, section 5.1: field names
+    assert_eq!(
+        complete(b"Content-Type: text/html"),
+        Ok((&b": text/html"[..], &b"Content-Type"[..]))
+    );
+    assert_eq!(label(complete(b"(x)")), Some(StrContext::Label("token")));
+
+    fn streaming(input: &[u8]) -> Streaming<'_> {
+        token.parse_peek(Partial::new(input))
+    }
+    assert_eq!(
+        streaming(b"chunked"),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+}
+
+#[test]
+fn quoted_string_rule() {
+    fn complete(input: &[u8]) -> Complete<'_> {
+        quoted_string.parse_peek(input)
+    }
+    // RFC 9110, section 8.8.3: entity-tags
+    assert_eq!(complete(br#""xyzzy""#), Ok((&b""[..], &b"xyzzy"[..])));
+    assert_eq!(complete(br#""""#), Ok((&b""[..], &b""[..])));
+    assert_eq!(
+        complete(b"\"caf\xe9 \\\"x\\\\\", next"),
+        Ok((&b", next"[..], &b"caf\xe9 \\\"x\\\\"[..]))
+    );
+    assert_eq!(
+        label(complete(br#"xyzzy""#)),
+        Some(StrContext::Label("quoted-string"))
+    );
+    assert_eq!(
+        label(complete(b"\"a\x7fb\"")),
+        Some(StrContext::Label("quoted-string"))
+    );
+    assert_eq!(
+        label(complete(b"\"a\\\nb\"")),
+        Some(StrContext::Label("quoted-string"))
+    );
+
+    fn streaming(input: &[u8]) -> Streaming<'_> {
+        quoted_string.parse_peek(Partial::new(input))
+    }
+    assert_eq!(
+        streaming(br#""ab"c"#),
+        Ok((Partial::new(&b"c"[..]), &b"ab"[..]))
+    );
+    assert_eq!(
+        streaming(br#""ab"#),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+    assert_eq!(
+        streaming(br#""ab\"#),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+}
+
+#[test]
+fn comment_rule() {
+    fn complete(input: &[u8]) -> Complete<'_> {
+        comment.parse_peek(input)
+    }
+    // RFC 9110, section 7.6.3: `Via: 1.0 fred, 1.1 p.example.net (Apache/1.1)`
+    assert_eq!(
+        complete(b"(Apache/1.1)"),
+        Ok((&b""[..], &b"(Apache/1.1)"[..]))
+    );
+    // RFC 9110, section 10.1.5: `User-Agent: CERN-LineMode/2.15 libwww/2.17b3`, with comments
+    assert_eq!(
+        complete(b"(X11; Linux x86_64) Gecko"),
+        Ok((&b" Gecko"[..], &b"(X11; Linux x86_64)"[..]))
+    );
+    assert_eq!(
+        complete(b"(a (b (c) \\( d) e)f"),
+        Ok((&b"f"[..], &b"(a (b (c) \\( d) e)"[..]))
+    );
+    assert_eq!(
+        label(complete(b"(a (b)")),
+        Some(StrContext::Label("comment"))
+    );
+    assert_eq!(
+        label(complete(b"(a\rb)")),
+        Some(StrContext::Label("comment"))
+    );
+    assert_eq!(label(complete(b"a)")), Some(StrContext::Label("comment")));
+
+    fn streaming(input: &[u8]) -> Streaming<'_> {
+        comment.parse_peek(Partial::new(input))
+    }
+    assert_eq!(
+        streaming(b"(a (b)"),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+    assert_eq!(streaming(b"(a)"), Ok((Partial::new(&b""[..]), &b"(a)"[..])));
+}
+
+#[test]
+fn obs_fold_rule() {
+    fn complete(input: &[u8]) -> Complete<'_> {
+        obs_fold.parse_peek(input)
+    }
+    // RFC 9112, section 5.2
+    assert_eq!(
+        complete(b"\r\n  folded"),
+        Ok((&b"folded"[..], &b"\r\n  "[..]))
+    );
+    assert_eq!(
+        complete(b"\t\r\n\tfolded"),
+        Ok((&b"folded"[..], &b"\t\r\n\t"[..]))
+    );
+    assert_eq!(
+        label(complete(b" \r\nHost")),
+        Some(StrContext::Label("obs-fold"))
+    );
+
+    fn streaming(input: &[u8]) -> Streaming<'_> {
+        obs_fold.parse_peek(Partial::new(input))
+    }
+    assert_eq!(
+        streaming(b" \r\n"),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+}
+
+#[test]
+fn comment_deep_nesting() {
+    // Deep enough to overflow the stack if recursive, less with `debug` as tracing each level is slow
+    let depth = if cfg!(feature = "debug") {
+        1_000
+    } else {
+        100_000
+    };
+    let mut deep = crate::lib::std::vec![b'('; depth];
+    deep.extend_from_slice(&crate::lib::std::vec![b')'; depth]);
+    assert_eq!(
+        comment::<_, ContextError>.parse_peek(&deep[..]),
+        Ok((&b""[..], &deep[..]))
+    );
+}
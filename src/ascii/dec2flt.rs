@@ -0,0 +1,377 @@
+//! Correctly rounded conversion of decimal and hexadecimal digits to floats
+//!
+//! Numbers that fit the fast path are converted with a single, exact multiplication or division.
+//! Others are converted exactly by dividing big integers, rounding the quotient half to even.
+
+use super::sealed::SealedMarker;
+use super::Float;
+use crate::lib::std::cmp::Ordering;
+
+/// Significant decimal digits to keep
+///
+/// No float needs more than 767 digits to round correctly, so later digits only matter for
+/// whether they are zero.
+const MAX_DIGITS: usize = 800;
+
+/// Significant digits of a decimal number, pushed while scanning it
+pub(super) struct Decimal {
+    digits: [u8; MAX_DIGITS],
+    len: usize,
+    /// Nonzero digits were dropped past [`MAX_DIGITS`]
+    truncated: bool,
+    /// Power of ten of the last kept digit
+    exponent: i64,
+}
+
+impl Decimal {
+    pub(super) fn new() -> Self {
+        Self {
+            digits: [0; MAX_DIGITS],
+            len: 0,
+            truncated: false,
+            exponent: 0,
+        }
+    }
+
+    /// Add the next digit, from the integer part or the `fraction`
+    #[inline]
+    pub(super) fn push(&mut self, digit: u8, fraction: bool) {
+        if self.len == 0 && digit == 0 {
+            // Leading zero
+            if fraction {
+                self.exponent -= 1;
+            }
+        } else if self.len < MAX_DIGITS {
+            self.digits[self.len] = digit;
+            self.len += 1;
+            if fraction {
+                self.exponent -= 1;
+            }
+        } else {
+            self.truncated |= digit != 0;
+            if !fraction {
+                self.exponent += 1;
+            }
+        }
+    }
+
+    /// The closest `F` to the digits times `10^exponent`
+    pub(super) fn to_float<F: Float>(&self, negative: bool, exponent: i64) -> F {
+        let exponent = self.exponent.saturating_add(exponent);
+        if !self.truncated && self.len <= 19 {
+            let mantissa = self.digits[..self.len]
+                .iter()
+                .fold(0u64, |mantissa, &digit| mantissa * 10 + u64::from(digit));
+            if let Some(value) = F::fast_path(mantissa, exponent, negative, SealedMarker) {
+                return value;
+            }
+        }
+        with_sign::<F>(self.to_bits::<F>(exponent), negative)
+    }
+
+    fn to_bits<F: Float>(&self, exponent: i64) -> u64 {
+        if self.len == 0 {
+            return 0;
+        }
+        let scientific = (self.len as i64 - 1).saturating_add(exponent);
+        if F::MAX_EXPONENT_10 < scientific {
+            return infinity_bits::<F>();
+        }
+        if scientific < F::MIN_EXPONENT_10 {
+            return 0;
+        }
+
+        let mut numerator = Big::from_digits(&self.digits[..self.len]);
+        let mut exponent = exponent;
+        if self.truncated {
+            // Any nonzero digit past the kept ones rounds the same
+            numerator.mul_small(10);
+            numerator.add_small(1);
+            exponent -= 1;
+        }
+        let mut denominator = Big::from_u32(1);
+        if exponent < 0 {
+            denominator.mul_pow10(exponent.unsigned_abs());
+        } else {
+            numerator.mul_pow10(exponent.unsigned_abs());
+        }
+
+        // Scale the quotient to two more bits than the mantissa, to round it
+        let bits = F::MANTISSA_BITS + 3;
+        let shift = numerator.bit_len() as i64 - denominator.bit_len() as i64 - i64::from(bits);
+        if shift < 0 {
+            numerator.shl(shift.unsigned_abs() as usize);
+        } else {
+            denominator.shl(shift as usize);
+        }
+        let (quotient, inexact) = numerator.divide(&denominator, bits + 1);
+        round::<F>(quotient, shift, inexact)
+    }
+}
+
+/// Significant bits of a hexadecimal number, pushed while scanning it
+pub(super) struct Hex {
+    mantissa: u64,
+    /// Nonzero bits were dropped from `mantissa`
+    inexact: bool,
+    /// Power of two of the last kept digit
+    exponent: i64,
+}
+
+impl Hex {
+    pub(super) fn new() -> Self {
+        Self {
+            mantissa: 0,
+            inexact: false,
+            exponent: 0,
+        }
+    }
+
+    /// Add the next digit, from the integer part or the `fraction`
+    #[inline]
+    pub(super) fn push(&mut self, digit: u8, fraction: bool) {
+        if self.mantissa == 0 && digit == 0 {
+            // Leading zero
+            if fraction {
+                self.exponent -= 4;
+            }
+        } else if self.mantissa < 1 << 59 {
+            self.mantissa = self.mantissa << 4 | u64::from(digit);
+            if fraction {
+                self.exponent -= 4;
+            }
+        } else {
+            self.inexact |= digit != 0;
+            if !fraction {
+                self.exponent += 4;
+            }
+        }
+    }
+
+    /// The closest `F` to the digits times `2^exponent`
+    pub(super) fn to_float<F: Float>(&self, negative: bool, exponent: i64) -> F {
+        // Clamped far past the range of any float, to not overflow while rounding
+        let exponent = self
+            .exponent
+            .saturating_add(exponent)
+            .clamp(-(1 << 32), 1 << 32);
+        with_sign::<F>(round::<F>(self.mantissa, exponent, self.inexact), negative)
+    }
+}
+
+pub(super) fn infinity<F: Float>(negative: bool) -> F {
+    with_sign::<F>(infinity_bits::<F>(), negative)
+}
+
+pub(super) fn nan<F: Float>() -> F {
+    F::from_ieee_bits(
+        infinity_bits::<F>() | 1 << (F::MANTISSA_BITS - 1),
+        SealedMarker,
+    )
+}
+
+fn infinity_bits<F: Float>() -> u64 {
+    ((2 * F::EXPONENT_BIAS + 1) as u64) << F::MANTISSA_BITS
+}
+
+fn with_sign<F: Float>(bits: u64, negative: bool) -> F {
+    F::from_ieee_bits(bits | u64::from(negative) << (F::BITS - 1), SealedMarker)
+}
+
+/// Round `mantissa * 2^exponent` to the bits of the closest `F`, half to even
+///
+/// `inexact` is whether the value is a little more than that, from bits dropped below `mantissa`.
+/// `mantissa` must then have more bits than `F`, to round correctly.
+fn round<F: Float>(mantissa: u64, exponent: i64, inexact: bool) -> u64 {
+    if mantissa == 0 {
+        return 0;
+    }
+    let precision = i64::from(F::MANTISSA_BITS) + 1;
+    let min_exponent = 1 - F::EXPONENT_BIAS;
+    let top = i64::from(u64::BITS - mantissa.leading_zeros()) - 1 + exponent;
+    // Power of two of the last mantissa bit, which is fixed for subnormals
+    let mut ulp = (top.max(min_exponent)) - (precision - 1);
+    let dropped = ulp - exponent;
+    let mut rounded = if dropped <= 0 {
+        debug_assert!(!inexact, "mantissa is too short to round");
+        mantissa << dropped.unsigned_abs()
+    } else if dropped >= i64::from(u64::BITS) {
+        // Less than half of the smallest subnormal
+        0
+    } else {
+        let kept = mantissa >> dropped;
+        let rest = mantissa & ((1 << dropped) - 1);
+        let half = 1 << (dropped - 1);
+        match rest.cmp(&half) {
+            Ordering::Greater => kept + 1,
+            Ordering::Equal if inexact || kept & 1 == 1 => kept + 1,
+            Ordering::Equal | Ordering::Less => kept,
+        }
+    };
+    if rounded == 1 << precision {
+        rounded >>= 1;
+        ulp += 1;
+    }
+
+    if rounded < 1 << (precision - 1) {
+        // Subnormal or zero
+        return rounded;
+    }
+    let biased = ulp + (precision - 1) + F::EXPONENT_BIAS;
+    if 2 * F::EXPONENT_BIAS < biased {
+        return infinity_bits::<F>();
+    }
+    (biased as u64) << F::MANTISSA_BITS | (rounded & ((1 << F::MANTISSA_BITS) - 1))
+}
+
+/// Limbs for the largest numbers of [`Decimal::to_bits`], about 3,800 bits
+const LIMBS: usize = 128;
+
+/// Unsigned big integer of fixed capacity
+#[derive(Clone)]
+struct Big {
+    /// Least-significant limb first
+    limbs: [u32; LIMBS],
+    /// Limbs in use, without leading zero limbs
+    len: usize,
+}
+
+impl Big {
+    fn from_u32(value: u32) -> Self {
+        let mut big = Self {
+            limbs: [0; LIMBS],
+            len: 0,
+        };
+        big.add_small(value);
+        big
+    }
+
+    fn from_digits(digits: &[u8]) -> Self {
+        let mut big = Self::from_u32(0);
+        for chunk in digits.chunks(9) {
+            let value = chunk
+                .iter()
+                .fold(0, |value, &digit| value * 10 + u32::from(digit));
+            big.mul_small(10u32.pow(chunk.len() as u32));
+            big.add_small(value);
+        }
+        big
+    }
+
+    fn mul_small(&mut self, factor: u32) {
+        let mut carry = 0;
+        for limb in &mut self.limbs[..self.len] {
+            let product = u64::from(*limb) * u64::from(factor) + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+        if carry != 0 {
+            self.limbs[self.len] = carry as u32;
+            self.len += 1;
+        }
+    }
+
+    fn add_small(&mut self, value: u32) {
+        let mut carry = u64::from(value);
+        for limb in &mut self.limbs[..self.len] {
+            if carry == 0 {
+                return;
+            }
+            let sum = u64::from(*limb) + carry;
+            *limb = sum as u32;
+            carry = sum >> 32;
+        }
+        if carry != 0 {
+            self.limbs[self.len] = carry as u32;
+            self.len += 1;
+        }
+    }
+
+    fn mul_pow10(&mut self, mut exponent: u64) {
+        while 9 <= exponent {
+            self.mul_small(1_000_000_000);
+            exponent -= 9;
+        }
+        self.mul_small(10u32.pow(exponent as u32));
+    }
+
+    fn bit_len(&self) -> usize {
+        match self.len.checked_sub(1) {
+            Some(top) => top * 32 + (32 - self.limbs[top].leading_zeros() as usize),
+            None => 0,
+        }
+    }
+
+    fn shl(&mut self, bits: usize) {
+        if self.len == 0 {
+            return;
+        }
+        let limbs = bits / 32;
+        let bits = bits % 32;
+        let len = self.len;
+        if bits == 0 {
+            self.limbs.copy_within(..len, limbs);
+        } else {
+            self.limbs[len + limbs] = self.limbs[len - 1] >> (32 - bits);
+            for index in (1..len).rev() {
+                self.limbs[index + limbs] =
+                    self.limbs[index] << bits | self.limbs[index - 1] >> (32 - bits);
+            }
+            self.limbs[limbs] = self.limbs[0] << bits;
+        }
+        self.limbs[..limbs].fill(0);
+        self.len += limbs + 1;
+        self.trim();
+    }
+
+    fn shr1(&mut self) {
+        for index in 0..self.len {
+            let next = self.limbs.get(index + 1).copied().unwrap_or(0);
+            self.limbs[index] = self.limbs[index] >> 1 | next << 31;
+        }
+        self.trim();
+    }
+
+    fn sub(&mut self, other: &Self) {
+        let mut borrow = false;
+        for index in 0..self.len {
+            let (difference, overflow) = self.limbs[index].overflowing_sub(other.limbs[index]);
+            let (difference, underflow) = difference.overflowing_sub(u32::from(borrow));
+            self.limbs[index] = difference;
+            borrow = overflow || underflow;
+        }
+        debug_assert!(!borrow, "subtracting a larger number");
+        self.trim();
+    }
+
+    fn trim(&mut self) {
+        while self.len > 0 && self.limbs[self.len - 1] == 0 {
+            self.len -= 1;
+        }
+    }
+
+    /// Divide by `divisor`, for a quotient of at most `bits` bits, returning whether there's a
+    /// remainder
+    fn divide(mut self, divisor: &Self, bits: u32) -> (u64, bool) {
+        let mut divisor = divisor.clone();
+        divisor.shl(bits as usize - 1);
+        let mut quotient = 0;
+        for bit in (0..bits).rev() {
+            if self.cmp(&divisor) != Ordering::Less {
+                self.sub(&divisor);
+                quotient |= 1 << bit;
+            }
+            divisor.shr1();
+        }
+        (quotient, self.len != 0)
+    }
+
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.len.cmp(&other.len).then_with(|| {
+            self.limbs[..self.len]
+                .iter()
+                .rev()
+                .cmp(other.limbs[..other.len].iter().rev())
+        })
+    }
+}
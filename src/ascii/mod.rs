@@ -1,16 +1,26 @@
 //! Character specific parsers and combinators
 //!
 //! Functions recognizing specific characters
+//!
+//! These only recognize ASCII character classes.  For Unicode ones, see [`unicode`][crate::unicode].
+
+mod dec2flt;
+#[cfg(feature = "alloc")]
+mod unescape;
 
 #[cfg(test)]
 mod tests;
 
 use crate::lib::std::ops::{Add, Shl};
 
+#[cfg(feature = "alloc")]
+pub use unescape::{unescape, Dialect, Unescape, UnescapeError, UnescapeErrorKind};
+
 use crate::combinator::alt;
 use crate::combinator::dispatch;
 use crate::combinator::empty;
 use crate::combinator::fail;
+use crate::combinator::lexeme_;
 use crate::combinator::opt;
 use crate::combinator::peek;
 use crate::combinator::trace;
@@ -1113,15 +1123,17 @@ where
     Error: ParserError<Input>,
 {
     trace("dec_uint", move |input: &mut Input| {
-        alt(((one_of('1'..='9'), digit0).void(), one_of('0').void()))
-            .take()
-            .verify_map(|s: <Input as Stream>::Slice| {
-                let s = s.as_bstr();
-                // SAFETY: Only 7-bit ASCII characters are parsed
-                let s = unsafe { crate::lib::std::str::from_utf8_unchecked(s) };
-                Output::try_from_dec_uint(s)
-            })
-            .parse_next(input)
+        lexeme_(input, |input| {
+            alt(((one_of('1'..='9'), digit0).void(), one_of('0').void()))
+                .take()
+                .verify_map(|s: <Input as Stream>::Slice| {
+                    let s = s.as_bstr();
+                    // SAFETY: Only 7-bit ASCII characters are parsed
+                    let s = unsafe { crate::lib::std::str::from_utf8_unchecked(s) };
+                    Output::try_from_dec_uint(s)
+                })
+                .parse_next(input)
+        })
     })
     .parse_next(input)
 }
@@ -1198,20 +1210,22 @@ where
     Error: ParserError<Input>,
 {
     trace("dec_int", move |input: &mut Input| {
-        let sign = opt(dispatch! {any.map(AsChar::as_char);
-            '+' => empty.value(true),
-            '-' => empty.value(false),
-            _ => fail,
-        });
-        alt(((sign, one_of('1'..='9'), digit0).void(), one_of('0').void()))
-            .take()
-            .verify_map(|s: <Input as Stream>::Slice| {
-                let s = s.as_bstr();
-                // SAFETY: Only 7-bit ASCII characters are parsed
-                let s = unsafe { crate::lib::std::str::from_utf8_unchecked(s) };
-                Output::try_from_dec_int(s)
-            })
-            .parse_next(input)
+        lexeme_(input, |input| {
+            let sign = opt(dispatch! {any.map(AsChar::as_char);
+                '+' => empty.value(true),
+                '-' => empty.value(false),
+                _ => fail,
+            });
+            alt(((sign, one_of('1'..='9'), digit0).void(), one_of('0').void()))
+                .take()
+                .verify_map(|s: <Input as Stream>::Slice| {
+                    let s = s.as_bstr();
+                    // SAFETY: Only 7-bit ASCII characters are parsed
+                    let s = unsafe { crate::lib::std::str::from_utf8_unchecked(s) };
+                    Output::try_from_dec_int(s)
+                })
+                .parse_next(input)
+        })
     })
     .parse_next(input)
 }
@@ -1316,50 +1330,52 @@ where
     Error: ParserError<Input>,
 {
     trace("hex_uint", move |input: &mut Input| {
-        let invalid_offset = input
-            .offset_for(|c| {
-                let c = c.as_char();
-                !"0123456789abcdefABCDEF".contains(c)
-            })
-            .unwrap_or_else(|| input.eof_offset());
-        let max_nibbles = Output::max_nibbles(sealed::SealedMarker);
-        let max_offset = input.offset_at(max_nibbles);
-        let offset = match max_offset {
-            Ok(max_offset) => {
-                if max_offset < invalid_offset {
-                    // Overflow
-                    return Err(ParserError::from_input(input));
-                } else {
-                    invalid_offset
+        lexeme_(input, |input| {
+            let invalid_offset = input
+                .offset_for(|c| {
+                    let c = c.as_char();
+                    !"0123456789abcdefABCDEF".contains(c)
+                })
+                .unwrap_or_else(|| input.eof_offset());
+            let max_nibbles = Output::max_nibbles(sealed::SealedMarker);
+            let max_offset = input.offset_at(max_nibbles);
+            let offset = match max_offset {
+                Ok(max_offset) => {
+                    if max_offset < invalid_offset {
+                        // Overflow
+                        return Err(ParserError::from_input(input));
+                    } else {
+                        invalid_offset
+                    }
                 }
-            }
-            Err(_) => {
-                if <Input as StreamIsPartial>::is_partial_supported()
-                    && input.is_partial()
-                    && invalid_offset == input.eof_offset()
-                {
-                    // Only the next byte is guaranteed required
-                    return Err(ParserError::incomplete(input, Needed::new(1)));
-                } else {
-                    invalid_offset
+                Err(_) => {
+                    if <Input as StreamIsPartial>::is_partial_supported()
+                        && input.is_partial()
+                        && invalid_offset == input.eof_offset()
+                    {
+                        // Only the next byte is guaranteed required
+                        return Err(ParserError::incomplete(input, Needed::new(1)));
+                    } else {
+                        invalid_offset
+                    }
                 }
+            };
+            if offset == 0 {
+                // Must be at least one digit
+                return Err(ParserError::from_input(input));
+            }
+            let parsed = input.next_slice(offset);
+
+            let mut res = Output::default();
+            for c in parsed.as_bstr() {
+                let nibble = *c as char;
+                let nibble = nibble.to_digit(16).unwrap_or(0) as u8;
+                let nibble = Output::from(nibble);
+                res = (res << Output::from(4)) + nibble;
             }
-        };
-        if offset == 0 {
-            // Must be at least one digit
-            return Err(ParserError::from_input(input));
-        }
-        let parsed = input.next_slice(offset);
-
-        let mut res = Output::default();
-        for c in parsed.as_bstr() {
-            let nibble = *c as char;
-            let nibble = nibble.to_digit(16).unwrap_or(0) as u8;
-            let nibble = Output::from(nibble);
-            res = (res << Output::from(4)) + nibble;
-        }
 
-        Ok(res)
+            Ok(res)
+        })
     })
     .parse_next(input)
 }
@@ -1407,8 +1423,366 @@ impl HexUint for u128 {
     }
 }
 
+/// Recognizes an integer literal of a programming or configuration language
+///
+/// By default, this accepts unsigned decimal digits and the radix prefixes `0x`, `0o` and `0b` (see
+/// [`IntLiteral::DEFAULT_PREFIXES`]).  The builder methods of [`IntLiteral`] configure:
+/// - [`IntLiteral::prefixes`] and the [`IntLiteral::radix`] without a prefix
+/// - [`IntLiteral::separators`], like `_` or `'`, and where they may go
+/// - [`IntLiteral::sign`] for a leading `+` or `-`
+/// - [`IntLiteral::suffixes`], like `u8` or `L`, and the range of values each allows
+///
+/// The output is an [`IntValue`] with the value, the radix and the suffix.  The value must fit in
+/// its type, like [`u8`] or [`i64`], and in the range of its suffix, or an error is returned.
+///
+/// *Complete version*: Can parse until the end of input.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::{int_literal, IntValue, Separators};
+///
+/// /// Rust integer literals
+/// fn rust_int(input: &mut &str) -> ModalResult<IntValue<u64>> {
+///     int_literal()
+///         .separators(&['_'], Separators::Anywhere)
+///         .suffixes(&[
+///             ("u8", 0, u8::MAX as u128),
+///             ("u16", 0, u16::MAX as u128),
+///             ("u32", 0, u32::MAX as u128),
+///             ("u64", 0, u64::MAX as u128),
+///         ])
+///         .parse_next(input)
+/// }
+///
+/// assert_eq!(
+///     rust_int.parse_peek("0xff_u8;"),
+///     Ok((";", IntValue { value: 0xff, radix: 16, suffix: Some("u8") }))
+/// );
+/// // Out of the range of the suffix
+/// assert!(rust_int.parse_peek("256u8").is_err());
+/// assert_eq!(
+///     rust_int.parse_peek("1_000_000"),
+///     Ok(("", IntValue { value: 1_000_000, radix: 10, suffix: None }))
+/// );
+/// // Overflow
+/// assert!(rust_int.parse_peek("0x1_0000_0000_0000_0000").is_err());
+///
+/// /// C++ integer literals
+/// fn cpp_int(input: &mut &str) -> ModalResult<i64> {
+///     int_literal()
+///         .prefixes(&[("0x", 16), ("0X", 16), ("0b", 2), ("0B", 2)])
+///         .separators(&['\''], Separators::BetweenDigits)
+///         .sign(true)
+///         .suffixes(&[
+///             ("LL", i64::MIN as i128, i64::MAX as u128),
+///             ("L", i64::MIN as i128, i64::MAX as u128),
+///         ])
+///         .map(|int: IntValue<i64>| int.value)
+///         .parse_next(input)
+/// }
+///
+/// assert_eq!(cpp_int.parse_peek("-1'000L"), Ok(("", -1000)));
+/// // A separator must be between digits
+/// assert_eq!(cpp_int.parse_peek("1''0"), Ok(("''0", 1)));
+/// ```
+#[inline(always)]
+#[doc(alias = "integer")]
+#[doc(alias = "number")]
+pub fn int_literal() -> IntLiteral {
+    IntLiteral {
+        prefixes: IntLiteral::DEFAULT_PREFIXES,
+        radix: 10,
+        separators: &[],
+        placement: Separators::BetweenDigits,
+        sign: false,
+        suffixes: &[],
+    }
+}
+
+/// [`Parser`] implementation for [`int_literal`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct IntLiteral {
+    prefixes: &'static [(&'static str, u32)],
+    radix: u32,
+    separators: &'static [char],
+    placement: Separators,
+    sign: bool,
+    suffixes: &'static [(&'static str, i128, u128)],
+}
+
+impl IntLiteral {
+    /// `0x`, `0o` and `0b`, in either case
+    pub const DEFAULT_PREFIXES: &'static [(&'static str, u32)] = &[
+        ("0x", 16),
+        ("0X", 16),
+        ("0o", 8),
+        ("0O", 8),
+        ("0b", 2),
+        ("0B", 2),
+    ];
+
+    /// Radix prefixes, each with the radix of the digits following it
+    ///
+    /// The first matching prefix is used.
+    ///
+    /// # Panics
+    ///
+    /// If a radix is not in `2..=36`
+    #[inline(always)]
+    pub fn prefixes(mut self, prefixes: &'static [(&'static str, u32)]) -> Self {
+        assert!(
+            prefixes.iter().all(|(_, radix)| (2..=36).contains(radix)),
+            "radix must be in `2..=36`"
+        );
+        self.prefixes = prefixes;
+        self
+    }
+
+    /// Radix of digits without a prefix, defaulting to 10
+    ///
+    /// # Panics
+    ///
+    /// If `radix` is not in `2..=36`
+    #[inline(always)]
+    pub fn radix(mut self, radix: u32) -> Self {
+        assert!((2..=36).contains(&radix), "radix must be in `2..=36`");
+        self.radix = radix;
+        self
+    }
+
+    /// Characters allowed to separate digits, placed according to `placement`
+    #[inline(always)]
+    pub fn separators(mut self, separators: &'static [char], placement: Separators) -> Self {
+        self.separators = separators;
+        self.placement = placement;
+        self
+    }
+
+    /// Whether to accept a leading `+` or `-`
+    #[inline(always)]
+    pub fn sign(mut self, sign: bool) -> Self {
+        self.sign = sign;
+        self
+    }
+
+    /// Type suffixes allowed after the digits, each with the minimum and maximum value of its type
+    ///
+    /// The longest matching suffix is used.
+    #[inline(always)]
+    pub fn suffixes(mut self, suffixes: &'static [(&'static str, i128, u128)]) -> Self {
+        self.suffixes = suffixes;
+        self
+    }
+}
+
+impl<Input, Output, Error> Parser<Input, IntValue<Output>, Error> for IntLiteral
+where
+    Input: StreamIsPartial + Stream + Compare<&'static str>,
+    <Input as Stream>::Token: AsChar,
+    Output: TryFrom<u128> + TryFrom<i128>,
+    Error: ParserError<Input>,
+{
+    #[inline]
+    fn parse_next(&mut self, input: &mut Input) -> Result<IntValue<Output>, Error> {
+        trace("int_literal", move |input: &mut Input| {
+            lexeme_(input, |input| {
+                let start = input.checkpoint();
+                self.int_literal(input).map_err(|err: Error| {
+                    if err.is_incomplete() {
+                        err
+                    } else {
+                        input.reset(&start);
+                        ParserError::from_input(input)
+                    }
+                })
+            })
+        })
+        .parse_next(input)
+    }
+}
+
+impl IntLiteral {
+    fn int_literal<Input, Output, Error>(
+        &self,
+        input: &mut Input,
+    ) -> Result<IntValue<Output>, Error>
+    where
+        Input: StreamIsPartial + Stream + Compare<&'static str>,
+        <Input as Stream>::Token: AsChar,
+        Output: TryFrom<u128> + TryFrom<i128>,
+        Error: ParserError<Input>,
+    {
+        let partial = <Input as StreamIsPartial>::is_partial_supported() && input.is_partial();
+
+        let mut negative = false;
+        if self.sign {
+            match input.iter_offsets().next().map(|(_, c)| c.as_char()) {
+                Some('+') => {
+                    let _ = input.next_token();
+                }
+                Some('-') => {
+                    let _ = input.next_token();
+                    negative = true;
+                }
+                Some(_) => {}
+                None if partial => return Err(ParserError::incomplete(input, Needed::new(1))),
+                None => return Err(ParserError::from_input(input)),
+            }
+        }
+
+        let mut radix = self.radix;
+        let mut prefixed = false;
+        for &(prefix, prefix_radix) in self.prefixes {
+            match input.compare(prefix) {
+                CompareResult::Ok(len) => {
+                    let _ = input.next_slice(len);
+                    radix = prefix_radix;
+                    prefixed = true;
+                    break;
+                }
+                CompareResult::Incomplete if partial => {
+                    return Err(ParserError::incomplete(input, Needed::Unknown));
+                }
+                CompareResult::Incomplete | CompareResult::Error => {}
+            }
+        }
+
+        let mut magnitude = 0u128;
+        let mut digits = 0usize;
+        let mut previous_digit = false;
+        // Offset just past the last token of the literal, once that token has been accepted
+        let mut end = 0;
+        let mut accepted = false;
+        let mut at_eof = true;
+        for (offset, token) in input.iter_offsets() {
+            if accepted {
+                end = offset;
+                accepted = false;
+            }
+            let c = token.as_char();
+            if let Some(digit) = c.to_digit(radix) {
+                magnitude = magnitude
+                    .checked_mul(u128::from(radix))
+                    .and_then(|m| m.checked_add(u128::from(digit)))
+                    .ok_or_else(|| ParserError::from_input(input))?;
+                digits += 1;
+                previous_digit = true;
+                accepted = true;
+            } else if self.separators.contains(&c) {
+                match self.placement {
+                    Separators::BetweenDigits if previous_digit => {
+                        // Only part of the literal if a digit follows
+                        previous_digit = false;
+                    }
+                    Separators::Anywhere if prefixed || digits != 0 => {
+                        accepted = true;
+                    }
+                    _ => {
+                        at_eof = false;
+                        break;
+                    }
+                }
+            } else {
+                at_eof = false;
+                break;
+            }
+        }
+        if at_eof {
+            if partial {
+                return Err(ParserError::incomplete(input, Needed::new(1)));
+            }
+            if accepted {
+                end = input.eof_offset();
+            }
+        }
+        if digits == 0 {
+            return Err(ParserError::from_input(input));
+        }
+        let _ = input.next_slice(end);
+
+        let mut suffix = None;
+        for &candidate in self.suffixes {
+            match input.compare(candidate.0) {
+                CompareResult::Ok(_) => {
+                    if suffix
+                        .map(|(s, _, _): (&str, _, _)| s.len() < candidate.0.len())
+                        .unwrap_or(true)
+                    {
+                        suffix = Some(candidate);
+                    }
+                }
+                CompareResult::Incomplete if partial => {
+                    return Err(ParserError::incomplete(input, Needed::Unknown));
+                }
+                CompareResult::Incomplete | CompareResult::Error => {}
+            }
+        }
+        if let Some((suffix, min, max)) = suffix {
+            let CompareResult::Ok(len) = input.compare(suffix) else {
+                unreachable!("suffix was matched");
+            };
+            let _ = input.next_slice(len);
+            let in_range = if negative {
+                min <= 0 && magnitude <= min.unsigned_abs()
+            } else {
+                magnitude <= max
+                    && i128::try_from(magnitude)
+                        .map(|value| min <= value)
+                        .unwrap_or(true)
+            };
+            if !in_range {
+                return Err(ParserError::from_input(input));
+            }
+        }
+
+        let value = if negative {
+            if magnitude > 1 << 127 {
+                return Err(ParserError::from_input(input));
+            }
+            Output::try_from((magnitude as i128).wrapping_neg()).ok()
+        } else {
+            Output::try_from(magnitude).ok()
+        };
+        let value = value.ok_or_else(|| ParserError::from_input(input))?;
+        Ok(IntValue {
+            value,
+            radix,
+            suffix: suffix.map(|(suffix, _, _)| suffix),
+        })
+    }
+}
+
+/// Where separators may go in an [`int_literal`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Separators {
+    /// Each separator is between two digits, like `1_000` in Python or `1'000` in C++
+    ///
+    /// The literal ends before a separator that isn't.
+    BetweenDigits,
+    /// Separators may repeat and follow the radix prefix or any digit, like `0x_ff__u8` in Rust
+    Anywhere,
+}
+
+/// Integer literal, from [`int_literal`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct IntValue<Output> {
+    /// Value of the literal, including its sign
+    pub value: Output,
+    /// Radix of the digits, from the prefix if any
+    pub radix: u32,
+    /// Type suffix, if any
+    pub suffix: Option<&'static str>,
+}
+
 /// Recognizes floating point number in text format and returns a [`f32`] or [`f64`].
 ///
+/// The recognized slice is converted with [`ParseSlice`], so `Output` can be any type parsed from
+/// it.  To convert [`f32`] and [`f64`] while recognizing the digits, see [`float_exact`].
+///
 /// *Complete version*: Can parse until the end of input.
 ///
 /// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
@@ -1471,9 +1845,11 @@ where
     Error: ParserError<Input>,
 {
     trace("float", move |input: &mut Input| {
-        let s = take_float_or_exceptions(input)?;
-        s.parse_slice()
-            .ok_or_else(|| ParserError::from_input(input))
+        lexeme_(input, |input| {
+            let s = take_float_or_exceptions(input)?;
+            s.parse_slice()
+                .ok_or_else(|| ParserError::from_input(input))
+        })
     })
     .parse_next(input)
 }
@@ -1534,6 +1910,450 @@ where
     .parse_next(input)
 }
 
+/// Recognizes floating point number in text format and returns a [`f32`] or [`f64`], converting
+/// it in-crate
+///
+/// This accepts the same numbers as [`float`], but the digits are converted while they are
+/// recognized, rounding correctly without relying on `std` or [`ParseSlice`], so `&[u8]` and
+/// other byte streams don't go through UTF-8.  For hexadecimal floats, see [`hex_float`].  For the
+/// digits without rounding, see [`float_parts`].
+///
+/// *Complete version*: Can parse until the end of input.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `&str` [Stream] into an `f64`:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// pub fn float_exact(input: &mut &str) -> ModalResult<f64>
+/// # {
+/// #     winnow::ascii::float_exact.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::error::Needed::Size;
+/// use winnow::ascii::float_exact;
+///
+/// fn parser<'s>(s: &mut &'s str) -> ModalResult<f64> {
+///   float_exact(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek("11e-1"), Ok(("", 1.1)));
+/// assert_eq!(parser.parse_peek("123E-02"), Ok(("", 1.23)));
+/// assert_eq!(parser.parse_peek("123K-01"), Ok(("K-01", 123.0)));
+/// assert!(parser.parse_peek("abc").is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::Needed};
+/// # use winnow::error::Needed::Size;
+/// # use winnow::Partial;
+/// use winnow::ascii::float_exact;
+///
+/// fn parser<'s>(s: &mut Partial<&'s str>) -> ModalResult<f64> {
+///   float_exact(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(Partial::new("11e-1 ")), Ok((Partial::new(" "), 1.1)));
+/// assert_eq!(parser.parse_peek(Partial::new("11e-1")), Err(ErrMode::Incomplete(Needed::new(1))));
+/// assert_eq!(parser.parse_peek(Partial::new("123E-02")), Err(ErrMode::Incomplete(Needed::new(1))));
+/// assert_eq!(parser.parse_peek(Partial::new("123K-01")), Ok((Partial::new("K-01"), 123.0)));
+/// assert!(parser.parse_peek(Partial::new("abc")).is_err());
+/// ```
+#[inline(always)]
+pub fn float_exact<Input, Output, Error>(input: &mut Input) -> Result<Output, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<Caseless<&'static str>>,
+    <Input as Stream>::Token: AsChar,
+    Output: Float,
+    Error: ParserError<Input>,
+{
+    trace("float_exact", move |input: &mut Input| {
+        lexeme_(input, |input| {
+            if let Some('N' | 'n') = peek_char(input)? {
+                Caseless("nan").void().parse_next(input)?;
+                return Ok(dec2flt::nan());
+            }
+            let negative = take_sign(input)?;
+            if let Some('I' | 'i') = peek_char(input)? {
+                (Caseless("inf"), opt(Caseless("inity")))
+                    .void()
+                    .parse_next(input)?;
+                return Ok(dec2flt::infinity(negative));
+            }
+            let mut decimal = dec2flt::Decimal::new();
+            let (_, _) = take_significand(input, 10, |digit, fraction| {
+                decimal.push(digit, fraction);
+            })?;
+            let (exponent, _) = take_exponent(input, ['e', 'E'], false)?;
+            Ok(decimal.to_float(negative, exponent))
+        })
+    })
+    .parse_next(input)
+}
+
+/// Recognizes a hexadecimal floating point number, like `0x1.8p3`, and returns a [`f32`] or
+/// [`f64`]
+///
+/// This is the syntax of C99 and of `{:a}` formatting in many languages: an optional sign, `0x`,
+/// hexadecimal digits with an optional point, and a required power of two, in decimal.  The result
+/// is correctly rounded.
+///
+/// *Complete version*: Can parse until the end of input.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::hex_float;
+///
+/// fn parser(s: &mut &str) -> ModalResult<f64> {
+///   hex_float(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek("0x1.8p3"), Ok(("", 12.0)));
+/// assert_eq!(parser.parse_peek("-0X.8P-1;"), Ok((";", -0.25)));
+/// assert_eq!(parser.parse_peek("0x1.fffffffffffffp1023"), Ok(("", f64::MAX)));
+/// assert!(parser.parse_peek("0x1.8").is_err());
+/// ```
+#[inline(always)]
+#[doc(alias = "hexf")]
+pub fn hex_float<Input, Output, Error>(input: &mut Input) -> Result<Output, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<Caseless<&'static str>>,
+    <Input as Stream>::Token: AsChar,
+    Output: Float,
+    Error: ParserError<Input>,
+{
+    trace("hex_float", move |input: &mut Input| {
+        lexeme_(input, |input| {
+            let negative = take_sign(input)?;
+            Caseless("0x").void().parse_next(input)?;
+            let mut hex = dec2flt::Hex::new();
+            let (_, _) = take_significand(input, 16, |digit, fraction| {
+                hex.push(digit, fraction);
+            })?;
+            let (exponent, _) = take_exponent(input, ['p', 'P'], true)?;
+            Ok(hex.to_float(negative, exponent))
+        })
+    })
+    .parse_next(input)
+}
+
+/// Recognizes a decimal number, returning its [`DecimalParts`] without rounding
+///
+/// This accepts the same numbers as [`float`], except for infinity and NaN, for consumers of
+/// arbitrary precision, like the numbers of JSON.  An exponent that doesn't fit in [`i64`] is an
+/// error.
+///
+/// *Complete version*: Can parse until the end of input.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::{float_parts, DecimalParts};
+///
+/// fn parser<'s>(s: &mut &'s str) -> ModalResult<DecimalParts<&'s str>> {
+///   float_parts(s)
+/// }
+///
+/// assert_eq!(
+///     parser.parse_peek("-12.50e-3;"),
+///     Ok((";", DecimalParts { negative: true, integer: "12", fraction: "50", exponent: -3 }))
+/// );
+/// assert_eq!(
+///     parser.parse_peek(".5"),
+///     Ok(("", DecimalParts { negative: false, integer: "", fraction: "5", exponent: 0 }))
+/// );
+/// assert!(parser.parse_peek("inf").is_err());
+/// ```
+#[inline(always)]
+#[doc(alias = "decimal")]
+#[doc(alias = "number")]
+pub fn float_parts<Input, Error>(
+    input: &mut Input,
+) -> Result<DecimalParts<<Input as Stream>::Slice>, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    trace("float_parts", move |input: &mut Input| {
+        lexeme_(input, |input| {
+            let negative = take_sign(input)?;
+            let (integer, fraction) = take_significand(input, 10, |_, _| {})?;
+            let (exponent, exact) = take_exponent(input, ['e', 'E'], false)?;
+            if !exact {
+                return Err(ParserError::from_input(input));
+            }
+            Ok(DecimalParts {
+                negative,
+                integer,
+                fraction,
+                exponent,
+            })
+        })
+    })
+    .parse_next(input)
+}
+
+/// Decimal number, from [`float_parts`]
+///
+/// The value is `integer.fraction` times `10^exponent`, negated if `negative`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DecimalParts<Slice> {
+    /// Whether there is a `-` sign
+    pub negative: bool,
+    /// Digits before the point, possibly none
+    pub integer: Slice,
+    /// Digits after the point, possibly none
+    pub fraction: Slice,
+    /// Power of ten, `0` without an exponent
+    pub exponent: i64,
+}
+
+/// Floating point types for [`float_exact`] and [`hex_float`]
+pub trait Float: Sized {
+    #[doc(hidden)]
+    const BITS: u32;
+    #[doc(hidden)]
+    const MANTISSA_BITS: u32;
+    #[doc(hidden)]
+    const EXPONENT_BIAS: i64;
+    /// Power of ten of the largest finite values
+    #[doc(hidden)]
+    const MAX_EXPONENT_10: i64;
+    /// Power of ten of values that round to zero
+    #[doc(hidden)]
+    const MIN_EXPONENT_10: i64;
+
+    #[doc(hidden)]
+    fn from_ieee_bits(bits: u64, _: sealed::SealedMarker) -> Self;
+
+    /// Convert exactly representable values with one rounding
+    #[doc(hidden)]
+    fn fast_path(
+        mantissa: u64,
+        exponent: i64,
+        negative: bool,
+        _: sealed::SealedMarker,
+    ) -> Option<Self>;
+}
+
+impl Float for f32 {
+    const BITS: u32 = 32;
+    const MANTISSA_BITS: u32 = 23;
+    const EXPONENT_BIAS: i64 = 127;
+    const MAX_EXPONENT_10: i64 = 38;
+    const MIN_EXPONENT_10: i64 = -46;
+
+    #[inline(always)]
+    fn from_ieee_bits(bits: u64, _: sealed::SealedMarker) -> Self {
+        f32::from_bits(bits as u32)
+    }
+
+    #[inline]
+    fn fast_path(
+        mantissa: u64,
+        exponent: i64,
+        negative: bool,
+        _: sealed::SealedMarker,
+    ) -> Option<Self> {
+        const POWERS: [f32; 11] = [1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10];
+        if 1 << 24 < mantissa {
+            return None;
+        }
+        let power = *POWERS.get(exponent.unsigned_abs() as usize)?;
+        let value = if exponent < 0 {
+            mantissa as f32 / power
+        } else {
+            mantissa as f32 * power
+        };
+        Some(if negative { -value } else { value })
+    }
+}
+
+impl Float for f64 {
+    const BITS: u32 = 64;
+    const MANTISSA_BITS: u32 = 52;
+    const EXPONENT_BIAS: i64 = 1023;
+    const MAX_EXPONENT_10: i64 = 308;
+    const MIN_EXPONENT_10: i64 = -324;
+
+    #[inline(always)]
+    fn from_ieee_bits(bits: u64, _: sealed::SealedMarker) -> Self {
+        f64::from_bits(bits)
+    }
+
+    #[inline]
+    fn fast_path(
+        mantissa: u64,
+        exponent: i64,
+        negative: bool,
+        _: sealed::SealedMarker,
+    ) -> Option<Self> {
+        const POWERS: [f64; 23] = [
+            1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15,
+            1e16, 1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
+        ];
+        if 1 << 53 < mantissa {
+            return None;
+        }
+        let power = *POWERS.get(exponent.unsigned_abs() as usize)?;
+        let value = if exponent < 0 {
+            mantissa as f64 / power
+        } else {
+            mantissa as f64 * power
+        };
+        Some(if negative { -value } else { value })
+    }
+}
+
+/// Next character, without consuming it
+fn peek_char<Input, Error>(input: &mut Input) -> Result<Option<char>, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    match input.peek_token() {
+        Some(token) => Ok(Some(token.as_char())),
+        None if <Input as StreamIsPartial>::is_partial_supported() && input.is_partial() => {
+            Err(ParserError::incomplete(input, Needed::new(1)))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Optional `+` or `-`, returning whether it is negative
+fn take_sign<Input, Error>(input: &mut Input) -> Result<bool, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    let sign = peek_char(input)?;
+    if let Some('+' | '-') = sign {
+        let _ = input.next_token();
+    }
+    Ok(sign == Some('-'))
+}
+
+/// Digits of `radix` and their length, passing each to `digit`
+fn take_digits<Input, Error>(
+    input: &mut Input,
+    radix: u32,
+    mut digit: impl FnMut(u8),
+) -> Result<(<Input as Stream>::Slice, usize), Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    let mut end = None;
+    for (offset, token) in input.iter_offsets() {
+        match token.as_char().to_digit(radix) {
+            Some(value) => digit(value as u8),
+            None => {
+                end = Some(offset);
+                break;
+            }
+        }
+    }
+    let end = match end {
+        Some(end) => end,
+        None if <Input as StreamIsPartial>::is_partial_supported() && input.is_partial() => {
+            return Err(ParserError::incomplete(input, Needed::new(1)));
+        }
+        None => input.eof_offset(),
+    };
+    Ok((input.next_slice(end), end))
+}
+
+/// Digits with an optional point, like `1`, `1.`, `1.5` or `.5`, passing each digit to `digit`
+/// with whether it is in the fraction
+fn take_significand<Input, Error>(
+    input: &mut Input,
+    radix: u32,
+    mut digit: impl FnMut(u8, bool),
+) -> Result<(<Input as Stream>::Slice, <Input as Stream>::Slice), Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    let (integer, integer_len) = take_digits(input, radix, |value| digit(value, false))?;
+    let point = peek_char(input)? == Some('.');
+    if integer_len == 0 && !point {
+        return Err(ParserError::from_input(input));
+    }
+    let fraction = if point {
+        let _ = input.next_token();
+        let (fraction, fraction_len) = take_digits(input, radix, |value| digit(value, true))?;
+        if integer_len == 0 && fraction_len == 0 {
+            return Err(ParserError::from_input(input));
+        }
+        fraction
+    } else {
+        input.next_slice(0)
+    };
+    Ok((integer, fraction))
+}
+
+/// Exponent after one of `markers`, `0` if there is none, and whether it fits in `i64` without
+/// saturating
+fn take_exponent<Input, Error>(
+    input: &mut Input,
+    markers: [char; 2],
+    required: bool,
+) -> Result<(i64, bool), Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    match peek_char(input)? {
+        Some(c) if markers.contains(&c) => {
+            let _ = input.next_token();
+        }
+        _ if required => return Err(ParserError::from_input(input)),
+        _ => return Ok((0, true)),
+    }
+    let negative = take_sign(input)?;
+    let mut exponent = Some(0i64);
+    let (_, len) = take_digits(input, 10, |digit| {
+        exponent = exponent
+            .and_then(|exponent| exponent.checked_mul(10))
+            .and_then(|exponent| {
+                if negative {
+                    exponent.checked_sub(i64::from(digit))
+                } else {
+                    exponent.checked_add(i64::from(digit))
+                }
+            });
+    })?;
+    if len == 0 {
+        return Err(ParserError::from_input(input));
+    }
+    Ok(match exponent {
+        Some(exponent) => (exponent, true),
+        None if negative => (i64::MIN, false),
+        None => (i64::MAX, false),
+    })
+}
+
 /// Recognize the input slice with escaped characters.
 ///
 /// Arguments:
@@ -1655,9 +2475,7 @@ where
                 if opt(control_char).parse_next(input)?.is_some() {
                     let _ = escapable.parse_next(input)?;
                 } else {
-                    let offset = input.offset_from(&start);
-                    input.reset(&start);
-                    return Ok(input.next_slice(offset));
+                    return Ok(input.taken_since(&start));
                 }
             }
         }
@@ -2042,6 +2042,244 @@ Err(
       }
     }
 
+    proptest! {
+      #[test]
+      #[cfg(feature = "std")]
+      #[cfg_attr(miri, ignore)]  // See https://github.com/AltSysrq/proptest/issues/253
+      fn float_round_trip(bits in proptest::num::u64::ANY, bits32 in proptest::num::u32::ANY) {
+          let value = f64::from_bits(bits);
+          if !value.is_nan() {
+              for s in [format!("{value:e}"), format!("{value:?}")] {
+                  let parsed = float_exact::<_, f64, ErrMode<()>>
+                      .parse_peek(s.as_str())
+                      .unwrap()
+                      .1;
+                  assert_eq!(parsed.to_bits(), bits, "{s}");
+              }
+              if value.is_finite() && value != 0.0 {
+                  let exponent = ((bits >> 52) & 0x7ff) as i64;
+                  let (lead, exponent) = if exponent == 0 { (0, -1022) } else { (1, exponent - 1023) };
+                  let sign = if value < 0.0 { "-" } else { "" };
+                  let s = format!("{sign}0x{lead}.{:013x}p{exponent}", bits & 0x000f_ffff_ffff_ffff);
+                  let parsed = hex_float::<_, f64, ErrMode<()>>.parse_peek(s.as_str()).unwrap().1;
+                  assert_eq!(parsed.to_bits(), bits, "{s}");
+              }
+          }
+          let value = f32::from_bits(bits32);
+          if !value.is_nan() {
+              for s in [format!("{value:e}"), format!("{value:?}")] {
+                  let parsed = float_exact::<_, f32, ErrMode<()>>
+                      .parse_peek(s.as_str())
+                      .unwrap()
+                      .1;
+                  assert_eq!(parsed.to_bits(), bits32, "{s}");
+              }
+          }
+      }
+
+      #[test]
+      #[cfg(feature = "std")]
+      #[cfg_attr(miri, ignore)]  // See https://github.com/AltSysrq/proptest/issues/253
+      fn float_digits(s in "[0-9]{1,40}(\\.[0-9]{0,40})?([eE][+-]?[0-9]{1,3})?") {
+          let parsed = float_exact::<_, f64, ErrMode<()>>.parse_peek(s.as_str()).unwrap();
+          assert_eq!(parsed.0, "");
+          assert_eq!(parsed.1.to_bits(), s.parse::<f64>().unwrap().to_bits());
+          let parsed = float_exact::<_, f32, ErrMode<()>>.parse_peek(s.as_str()).unwrap();
+          assert_eq!(parsed.1.to_bits(), s.parse::<f32>().unwrap().to_bits());
+      }
+    }
+
+    #[test]
+    fn float_rounding() {
+        fn f64_bits(input: &str) -> u64 {
+            float_exact::<_, f64, InputError<_>>
+                .parse_peek(input)
+                .unwrap()
+                .1
+                .to_bits()
+        }
+        fn f32_bits(input: &str) -> u32 {
+            float_exact::<_, f32, InputError<_>>
+                .parse_peek(input)
+                .unwrap()
+                .1
+                .to_bits()
+        }
+
+        // 2^53 + 1 is halfway, rounding to even unless anything follows
+        assert_eq!(f64_bits("9007199254740993"), 0x4340_0000_0000_0000);
+        assert_eq!(
+            f64_bits("9007199254740993.00000000000000000000001"),
+            0x4340_0000_0000_0001
+        );
+        assert_eq!(f64_bits("9007199254740995"), 0x4340_0000_0000_0002);
+        // Largest finite value and the start of rounding to infinity
+        assert_eq!(f64_bits("1.7976931348623157e308"), f64::MAX.to_bits());
+        assert_eq!(f64_bits("1.7976931348623158e308"), f64::MAX.to_bits());
+        assert_eq!(f64_bits("1.7976931348623159e308"), f64::INFINITY.to_bits());
+        assert_eq!(f64_bits("1e400"), f64::INFINITY.to_bits());
+        // Subnormals and the halfway point to the smallest one
+        assert_eq!(f64_bits("4.9406564584124654e-324"), 1);
+        assert_eq!(f64_bits("2.4703282292062328e-324"), 1);
+        assert_eq!(f64_bits("2.4703282292062327e-324"), 0);
+        assert_eq!(f64_bits("2.2250738585072011e-308"), 0x000f_ffff_ffff_ffff);
+        assert_eq!(f64_bits("2.2250738585072012e-308"), 0x0010_0000_0000_0000);
+        assert_eq!(f64_bits("-0.0"), 0x8000_0000_0000_0000);
+        assert_eq!(f64_bits("0e999999999999999999999"), 0);
+        assert_eq!(f64_bits("-1e-999999999999999999999"), 0x8000_0000_0000_0000);
+
+        assert_eq!(f32_bits("16777217"), 0x4b80_0000);
+        assert_eq!(f32_bits("3.4028235e38"), f32::MAX.to_bits());
+        assert_eq!(f32_bits("3.4028235677e38"), f32::MAX.to_bits());
+        assert_eq!(f32_bits("3.4028235678e38"), f32::INFINITY.to_bits());
+        assert_eq!(f32_bits("1.4e-45"), 1);
+        assert_eq!(f32_bits("7.0064923e-46"), 0);
+        assert_eq!(f32_bits("7.0064924e-46"), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn float_many_digits() {
+        fn f64_bits(input: &str) -> u64 {
+            float_exact::<_, f64, InputError<_>>
+                .parse_peek(input)
+                .unwrap()
+                .1
+                .to_bits()
+        }
+
+        // Exactly halfway between 1 and the next value, in full
+        let halfway = "1.00000000000000011102230246251565404236316680908203125";
+        assert_eq!(f64_bits(halfway), 0x3ff0_0000_0000_0000);
+        // Past the kept digits
+        let mut above = String::from(halfway);
+        above.push_str(&"0".repeat(1000));
+        above.push('1');
+        assert_eq!(f64_bits(&above), 0x3ff0_0000_0000_0001);
+        let mut below = String::from("1.000000000000000111022302462515654042363166809082031249");
+        below.push_str(&"9".repeat(1000));
+        assert_eq!(f64_bits(&below), 0x3ff0_0000_0000_0000);
+
+        let mut small = String::from("0.");
+        small.push_str(&"0".repeat(1000));
+        small.push_str("1e1000");
+        assert_eq!(f64_bits(&small), 0x3fb9_9999_9999_999a);
+        let mut large = "1".repeat(1000);
+        large.push_str("e-990");
+        assert_eq!(f64_bits(&large), 0x41d0_8e8d_71c7_1c72);
+    }
+
+    #[test]
+    fn float_bytes() {
+        assert_eq!(
+            float_exact::<_, f64, InputError<_>>.parse_peek(&b"-1.5e3;"[..]),
+            Ok((&b";"[..], -1500.0))
+        );
+        assert_eq!(
+            float_exact::<_, f32, InputError<_>>.parse_peek(&b".25"[..]),
+            Ok((&b""[..], 0.25))
+        );
+    }
+
+    #[test]
+    fn float_other_types() {
+        #[derive(Debug, PartialEq)]
+        struct Decimal(usize);
+
+        impl core::str::FromStr for Decimal {
+            type Err = ();
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self(s.len()))
+            }
+        }
+
+        assert_eq!(
+            float::<_, Decimal, InputError<_>>.parse_peek("1.000000000000000000001;"),
+            Ok((";", Decimal(23)))
+        );
+    }
+
+    #[test]
+    fn hex_float_tests() {
+        fn f64_bits(input: &str) -> u64 {
+            hex_float::<_, f64, InputError<_>>
+                .parse_peek(input)
+                .unwrap()
+                .1
+                .to_bits()
+        }
+
+        assert_eq!(f64_bits("0x1p0"), 1.0f64.to_bits());
+        assert_eq!(f64_bits("0x.1p4"), 1.0f64.to_bits());
+        assert_eq!(f64_bits("0x10.P-4"), 1.0f64.to_bits());
+        assert_eq!(f64_bits("0x0.0p0"), 0);
+        assert_eq!(f64_bits("-0x0p0"), 0x8000_0000_0000_0000);
+        assert_eq!(f64_bits("0x1.921fb54442d18p1"), 0x4009_21fb_5444_2d18);
+        // Ties to even, unless a later digit is nonzero
+        assert_eq!(f64_bits("0x1.00000000000008p0"), 0x3ff0_0000_0000_0000);
+        assert_eq!(f64_bits("0x1.00000000000018p0"), 0x3ff0_0000_0000_0002);
+        assert_eq!(
+            f64_bits("0x1.000000000000080000000000000001p0"),
+            0x3ff0_0000_0000_0001
+        );
+        // Subnormals
+        assert_eq!(f64_bits("0x1p-1074"), 1);
+        assert_eq!(f64_bits("0x1p-1075"), 0);
+        assert_eq!(f64_bits("0x1.8p-1075"), 1);
+        assert_eq!(f64_bits("0x0.fffffffffffffp-1022"), 0x000f_ffff_ffff_ffff);
+        // Overflow
+        assert_eq!(f64_bits("0x1.fffffffffffff8p1023"), f64::INFINITY.to_bits());
+        assert_eq!(
+            f64_bits("0x1p99999999999999999999"),
+            f64::INFINITY.to_bits()
+        );
+        assert_eq!(
+            hex_float::<_, f32, InputError<_>>
+                .parse_peek("0x1.fffffep127")
+                .map(|(_, f)| f.to_bits()),
+            Ok(f32::MAX.to_bits())
+        );
+
+        assert!(hex_float::<_, f64, InputError<_>>
+            .parse_peek("0x1")
+            .is_err());
+        assert!(hex_float::<_, f64, InputError<_>>
+            .parse_peek("0x.p1")
+            .is_err());
+        assert!(hex_float::<_, f64, InputError<_>>
+            .parse_peek("1p1")
+            .is_err());
+    }
+
+    #[test]
+    fn float_parts_tests() {
+        fn parts(input: &str) -> Result<(&str, DecimalParts<&str>), ErrMode<InputError<&str>>> {
+            float_parts.parse_peek(input)
+        }
+        let value = |negative, integer, fraction, exponent| DecimalParts {
+            negative,
+            integer,
+            fraction,
+            exponent,
+        };
+
+        assert_eq!(parts("0"), Ok(("", value(false, "0", "", 0))));
+        assert_eq!(parts("+1.e5"), Ok(("", value(false, "1", "", 5))));
+        assert_eq!(
+            parts("-00012.34000E+08,"),
+            Ok((",", value(true, "00012", "34000", 8)))
+        );
+        assert_eq!(
+            parts("9223372036854775807e-9223372036854775808"),
+            Ok(("", value(false, "9223372036854775807", "", i64::MIN)))
+        );
+        assert!(parts("1e9223372036854775808").is_err());
+        assert!(parts("nan").is_err());
+        assert!(parts(".").is_err());
+        assert!(parts("1e").is_err());
+    }
+
     #[cfg(feature = "std")]
     fn parse_f64(i: &mut &str) -> ModalResult<f64, ()> {
         match take_float_or_exceptions.parse_next(i) {
@@ -2849,6 +3087,132 @@ Ok(
             .raw()
         );
     }
+
+    #[test]
+    fn int_literal_radix() {
+        fn int<'i>(input: &mut &'i str) -> TestResult<&'i str, IntValue<u32>> {
+            int_literal().parse_next(input)
+        }
+
+        let value = |value, radix| IntValue {
+            value,
+            radix,
+            suffix: None,
+        };
+        assert_eq!(int.parse_peek("42;"), Ok((";", value(42, 10))));
+        assert_eq!(int.parse_peek("0"), Ok(("", value(0, 10))));
+        assert_eq!(int.parse_peek("0xFf"), Ok(("", value(0xff, 16))));
+        assert_eq!(int.parse_peek("0o17"), Ok(("", value(0o17, 8))));
+        assert_eq!(int.parse_peek("0B101"), Ok(("", value(0b101, 2))));
+        assert_eq!(int.parse_peek("0b12"), Ok(("2", value(0b1, 2))));
+        assert!(int.parse_peek("0x").is_err());
+        assert!(int.parse_peek("-1").is_err());
+        assert!(int.parse_peek("_1").is_err());
+
+        fn octal<'i>(input: &mut &'i str) -> TestResult<&'i str, IntValue<u32>> {
+            int_literal()
+                .prefixes(&[("0x", 16)])
+                .radix(8)
+                .parse_next(input)
+        }
+        assert_eq!(octal.parse_peek("0755"), Ok(("", value(0o755, 8))));
+        assert_eq!(octal.parse_peek("0o7"), Ok(("o7", value(0, 8))));
+    }
+
+    #[test]
+    fn int_literal_separators() {
+        fn python<'i>(input: &mut &'i str) -> TestResult<&'i str, u32> {
+            int_literal()
+                .separators(&['_'], Separators::BetweenDigits)
+                .map(|int: IntValue<u32>| int.value)
+                .parse_next(input)
+        }
+        assert_eq!(python.parse_peek("1_000_000"), Ok(("", 1_000_000)));
+        assert_eq!(python.parse_peek("1__0"), Ok(("__0", 1)));
+        assert_eq!(python.parse_peek("1_"), Ok(("_", 1)));
+        assert!(python.parse_peek("0x_f").is_err());
+        assert!(python.parse_peek("_1").is_err());
+
+        fn rust<'i>(input: &mut &'i str) -> TestResult<&'i str, u32> {
+            int_literal()
+                .separators(&['_'], Separators::Anywhere)
+                .map(|int: IntValue<u32>| int.value)
+                .parse_next(input)
+        }
+        assert_eq!(rust.parse_peek("1__0_"), Ok(("", 10)));
+        assert_eq!(rust.parse_peek("0x_f"), Ok(("", 0xf)));
+        assert!(rust.parse_peek("0x_").is_err());
+        assert!(rust.parse_peek("_1").is_err());
+    }
+
+    #[test]
+    fn int_literal_sign_suffix_overflow() {
+        fn int<'i, O: TryFrom<u128> + TryFrom<i128>>(
+            input: &mut &'i str,
+        ) -> TestResult<&'i str, IntValue<O>> {
+            int_literal()
+                .sign(true)
+                .suffixes(&[
+                    ("u", 0, u32::MAX as u128),
+                    ("ul", 0, u64::MAX as u128),
+                    ("l", i64::MIN as i128, i64::MAX as u128),
+                ])
+                .parse_next(input)
+        }
+
+        assert_eq!(
+            int::<i8>.parse_peek("-128l;"),
+            Ok((
+                ";",
+                IntValue {
+                    value: -128,
+                    radix: 10,
+                    suffix: Some("l")
+                }
+            ))
+        );
+        assert_eq!(
+            int::<u64>.parse_peek("4294967296ul").map(|(_, i)| i.suffix),
+            Ok(Some("ul"))
+        );
+        // Out of the range of the suffix
+        assert!(int::<u64>.parse_peek("4294967296u").is_err());
+        assert!(int::<i64>.parse_peek("-1u").is_err());
+        assert!(int::<i128>.parse_peek("9223372036854775808l").is_err());
+        assert_eq!(
+            int::<i128>
+                .parse_peek("-9223372036854775808l")
+                .map(|(_, i)| i.value),
+            Ok(i64::MIN.into())
+        );
+        assert!(int::<i128>.parse_peek("-9223372036854775809l").is_err());
+        assert_eq!(int::<i8>.parse_peek("+127").map(|(_, i)| i.value), Ok(127));
+        assert!(int::<i8>.parse_peek("128").is_err());
+        assert!(int::<i8>.parse_peek("-129").is_err());
+        assert_eq!(int::<u8>.parse_peek("-0").map(|(_, i)| i.value), Ok(0));
+        assert!(int::<u8>.parse_peek("-1").is_err());
+        assert!(int::<u8>.parse_peek("0x100").is_err());
+        assert_eq!(
+            int::<i128>
+                .parse_peek("-0x80000000000000000000000000000000")
+                .map(|(_, i)| i.value),
+            Ok(i128::MIN)
+        );
+        assert_eq!(
+            int::<u128>
+                .parse_peek("340282366920938463463374607431768211455")
+                .map(|(_, i)| i.value),
+            Ok(u128::MAX)
+        );
+        assert!(int::<u128>
+            .parse_peek("340282366920938463463374607431768211456")
+            .is_err());
+        // Errors are at the start of the literal
+        assert_eq!(
+            int::<u8>.parse_peek("-300").unwrap_err(),
+            ErrMode::Backtrack(InputError::at("-300"))
+        );
+    }
 }
 
 mod partial {
@@ -4653,4 +5017,274 @@ Err(
             .raw()
         );
     }
+
+    #[test]
+    fn int_literal_partial() {
+        use crate::error::ErrMode;
+
+        fn int<'i>(input: &mut Partial<&'i str>) -> TestResult<Partial<&'i str>, IntValue<u32>> {
+            int_literal()
+                .separators(&['_'], Separators::BetweenDigits)
+                .suffixes(&[("u32", 0, u32::MAX as u128)])
+                .parse_next(input)
+        }
+
+        assert_eq!(
+            int.parse_peek(Partial::new("12;")),
+            Ok((
+                Partial::new(";"),
+                IntValue {
+                    value: 12,
+                    radix: 10,
+                    suffix: None
+                }
+            ))
+        );
+        assert_eq!(
+            int.parse_peek(Partial::new("12")),
+            Err(ErrMode::Incomplete(Needed::new(1)))
+        );
+        assert_eq!(
+            int.parse_peek(Partial::new("0")),
+            Err(ErrMode::Incomplete(Needed::Unknown))
+        );
+        assert_eq!(
+            int.parse_peek(Partial::new("1_")),
+            Err(ErrMode::Incomplete(Needed::new(1)))
+        );
+        assert_eq!(
+            int.parse_peek(Partial::new("12u3")),
+            Err(ErrMode::Incomplete(Needed::Unknown))
+        );
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod unescape {
+    use super::*;
+
+    use crate::error::{ErrMode, ExternalError, Needed};
+    use crate::lib::std::borrow::Cow;
+    use crate::lib::std::borrow::ToOwned;
+    use crate::prelude::*;
+    use crate::Partial;
+
+    type Error = ExternalError<UnescapeError>;
+
+    fn unescape_error(
+        kind: UnescapeErrorKind,
+        span: crate::lib::std::ops::Range<usize>,
+    ) -> ErrMode<Error> {
+        ErrMode::Backtrack(ExternalError(Some(UnescapeError { kind, span })))
+    }
+
+    fn str_peek<'i>(
+        mut parser: Unescape,
+        input: &'i str,
+    ) -> ModalResult<(&'i str, Cow<'i, str>), Error> {
+        parser.parse_peek(input)
+    }
+
+    fn str_of(dialect: Dialect, input: &str) -> ModalResult<Cow<'_, str>, Error> {
+        str_peek(unescape(dialect), input).map(|(_, output)| output)
+    }
+
+    fn bytes_of(dialect: Dialect, input: &[u8]) -> ModalResult<Cow<'_, [u8]>, Error> {
+        unescape(dialect)
+            .parse_peek(input)
+            .map(|(_, output)| output)
+    }
+
+    #[test]
+    fn borrowed_without_escapes() {
+        assert_eq!(
+            str_peek(unescape(Dialect::Json), "abc\"rest"),
+            Ok(("\"rest", Cow::Borrowed("abc")))
+        );
+        assert_eq!(bytes_of(Dialect::C, b"abc"), Ok(Cow::Borrowed(&b"abc"[..])));
+        assert_eq!(
+            str_peek(unescape(Dialect::Rust).terminator('\''), "a\"b'"),
+            Ok(("'", Cow::Borrowed("a\"b")))
+        );
+    }
+
+    #[test]
+    fn json() {
+        let parse = |input| {
+            str_peek(unescape(Dialect::Json), input)
+                .map(|(rest, output)| (rest, output.into_owned()))
+        };
+        assert_eq!(
+            parse(r#"a\"\\\/\b\f\n\r\tz""#),
+            Ok(("\"", "a\"\\/\u{8}\u{c}\n\r\tz".to_owned()))
+        );
+        assert_eq!(
+            parse(r#"\u00e9\uD83D\uDE00""#),
+            Ok(("\"", "é\u{1f600}".to_owned()))
+        );
+        assert_eq!(
+            str_of(Dialect::Json, r"ab\x"),
+            Err(unescape_error(UnescapeErrorKind::Unknown, 2..4))
+        );
+        assert_eq!(
+            str_of(Dialect::Json, r"\u12"),
+            Err(unescape_error(UnescapeErrorKind::Malformed, 0..4))
+        );
+        assert_eq!(
+            str_of(Dialect::Json, r"a\ud83d\n"),
+            Err(unescape_error(UnescapeErrorKind::LoneSurrogate, 1..7))
+        );
+        assert_eq!(
+            str_of(Dialect::Json, r"\ud83d\u0041"),
+            Err(unescape_error(UnescapeErrorKind::LoneSurrogate, 0..6))
+        );
+        assert_eq!(
+            str_of(Dialect::Json, r"\ude00"),
+            Err(unescape_error(UnescapeErrorKind::LoneSurrogate, 0..6))
+        );
+        assert_eq!(
+            str_of(Dialect::Json, "\\n\tx"),
+            Err(unescape_error(UnescapeErrorKind::ControlCharacter, 2..3))
+        );
+        assert_eq!(
+            str_of(Dialect::Json, "\\"),
+            Err(unescape_error(UnescapeErrorKind::Malformed, 0..1))
+        );
+    }
+
+    #[test]
+    fn rust() {
+        assert_eq!(
+            str_of(Dialect::Rust, r#"\0\'\"\x41\u{1F600}\u{e9}"#),
+            Ok(Cow::Owned("\0'\"A\u{1f600}é".to_owned()))
+        );
+        assert_eq!(
+            str_of(Dialect::Rust, "a\\\n   \tb"),
+            Ok(Cow::Owned("ab".to_owned()))
+        );
+        assert_eq!(
+            str_of(Dialect::Rust, r"\x80"),
+            Err(unescape_error(UnescapeErrorKind::OutOfRange, 0..4))
+        );
+        assert_eq!(
+            bytes_of(Dialect::Rust, br"\x80\xff"),
+            Ok(Cow::Owned(b"\x80\xff".to_vec()))
+        );
+        assert_eq!(
+            str_of(Dialect::Rust, r"\x4"),
+            Err(unescape_error(UnescapeErrorKind::Malformed, 0..3))
+        );
+        assert_eq!(
+            str_of(Dialect::Rust, r"\u{}"),
+            Err(unescape_error(UnescapeErrorKind::Malformed, 0..3))
+        );
+        assert_eq!(
+            str_of(Dialect::Rust, r"\u41"),
+            Err(unescape_error(UnescapeErrorKind::Malformed, 0..2))
+        );
+        assert_eq!(
+            str_of(Dialect::Rust, r"\u{110000}"),
+            Err(unescape_error(UnescapeErrorKind::OutOfRange, 0..10))
+        );
+        assert_eq!(
+            str_of(Dialect::Rust, r"é\u{d800}"),
+            Err(unescape_error(UnescapeErrorKind::LoneSurrogate, 2..10))
+        );
+        assert_eq!(
+            str_of(Dialect::Rust, r"\a"),
+            Err(unescape_error(UnescapeErrorKind::Unknown, 0..2))
+        );
+        assert_eq!(
+            bytes_of(Dialect::Rust, b"\\\xe9x\""),
+            Err(unescape_error(UnescapeErrorKind::Unknown, 0..2))
+        );
+        assert_eq!(
+            str_of(Dialect::Rust, "\\éx\""),
+            Err(unescape_error(UnescapeErrorKind::Unknown, 0..3))
+        );
+    }
+
+    #[test]
+    fn c() {
+        assert_eq!(
+            str_of(Dialect::C, r#"\a\v\?\'\"\101\0\x41\u00e9\U0001F600"#),
+            Ok(Cow::Owned("\u{7}\u{b}?'\"A\0Aé\u{1f600}".to_owned()))
+        );
+        assert_eq!(
+            str_of(Dialect::C, r"\1234"),
+            Ok(Cow::Owned("S4".to_owned()))
+        );
+        assert_eq!(
+            bytes_of(Dialect::C, br"\377\x0000ff"),
+            Ok(Cow::Owned(b"\xff\xff".to_vec()))
+        );
+        assert_eq!(
+            str_of(Dialect::C, r"\377"),
+            Err(unescape_error(UnescapeErrorKind::OutOfRange, 0..4))
+        );
+        assert_eq!(
+            bytes_of(Dialect::C, br"\x100"),
+            Err(unescape_error(UnescapeErrorKind::OutOfRange, 0..5))
+        );
+        assert_eq!(
+            str_of(Dialect::C, r"\xg"),
+            Err(unescape_error(UnescapeErrorKind::Malformed, 0..2))
+        );
+        assert_eq!(
+            str_of(Dialect::C, r"\uD800"),
+            Err(unescape_error(UnescapeErrorKind::LoneSurrogate, 0..6))
+        );
+        assert_eq!(
+            str_of(Dialect::C, r"\U00110000"),
+            Err(unescape_error(UnescapeErrorKind::OutOfRange, 0..10))
+        );
+    }
+
+    #[test]
+    fn extra_escapes() {
+        assert_eq!(
+            str_peek(
+                unescape(Dialect::Json).escapes(&[('e', '\u{1b}'), ('n', 'N')]),
+                r#"\e\n\t""#
+            ),
+            Ok(("\"", Cow::Owned("\u{1b}N\t".to_owned())))
+        );
+    }
+
+    #[test]
+    fn partial() {
+        fn json<'i>(input: &mut Partial<&'i str>) -> ModalResult<Cow<'i, str>, Error> {
+            unescape(Dialect::Json).parse_next(input)
+        }
+
+        assert_eq!(
+            json.parse_peek(Partial::new("ab\"")),
+            Ok((Partial::new("\""), Cow::Borrowed("ab")))
+        );
+        assert_eq!(
+            json.parse_peek(Partial::new("ab")),
+            Err(ErrMode::Incomplete(Needed::new(1)))
+        );
+        assert_eq!(
+            json.parse_peek(Partial::new(r"\u00")),
+            Err(ErrMode::Incomplete(Needed::new(1)))
+        );
+        assert_eq!(
+            json.parse_peek(Partial::new(r"\ud83d")),
+            Err(ErrMode::Incomplete(Needed::new(1)))
+        );
+        assert_eq!(
+            json.parse_peek(Partial::new(r#"\u0041""#)),
+            Ok((Partial::new("\""), Cow::Owned("A".to_owned())))
+        );
+
+        fn c<'i>(input: &mut Partial<&'i [u8]>) -> ModalResult<Cow<'i, [u8]>, Error> {
+            unescape(Dialect::C).parse_next(input)
+        }
+
+        assert_eq!(
+            c.parse_peek(Partial::new(&br"\x41"[..])),
+            Err(ErrMode::Incomplete(Needed::new(1)))
+        );
+    }
 }
@@ -0,0 +1,462 @@
+use super::peek_char;
+use crate::combinator::trace;
+use crate::error::{FromExternalError, Needed, ParserError};
+use crate::lib::std::borrow::Cow;
+use crate::lib::std::fmt;
+use crate::lib::std::ops::Range;
+use crate::lib::std::string::String;
+use crate::lib::std::vec::Vec;
+use crate::stream::{AsBStr, AsChar, Stream, StreamIsPartial};
+use crate::Parser;
+use crate::Result;
+
+/// Unescape the contents of a string literal of a [`Dialect`]
+///
+/// This stops before the terminator, `"` by default, or at the end of input, returning:
+/// - [`Cow<str>`] for `&str` input
+/// - [`Cow<[u8]>`][Cow] for `&[u8]` input, which may also have byte escapes past ASCII, like
+///   `\xff`
+///
+/// The output is borrowed from the input unless the literal has escapes.
+///
+/// Invalid literals are reported with an [`UnescapeError`], with the span of the invalid escape
+/// or character from the start of the literal.
+///
+/// *Complete version*: Can parse until the end of input.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use std::borrow::Cow;
+/// use winnow::ascii::{unescape, Dialect};
+/// use winnow::combinator::delimited;
+///
+/// fn json_string<'i>(input: &mut &'i str) -> ModalResult<Cow<'i, str>> {
+///     delimited('"', unescape(Dialect::Json), '"').parse_next(input)
+/// }
+///
+/// assert_eq!(json_string.parse_peek(r#""plain""#), Ok(("", Cow::Borrowed("plain"))));
+/// assert_eq!(
+///     json_string.parse_peek(r#""tab\t😀""#),
+///     Ok(("", Cow::Owned("tab\t\u{1f600}".to_owned())))
+/// );
+/// // Lone surrogate
+/// assert!(json_string.parse_peek(r#""\ud83d""#).is_err());
+///
+/// fn rust_bytes<'i>(input: &mut &'i [u8]) -> ModalResult<Cow<'i, [u8]>> {
+///     delimited('"', unescape(Dialect::Rust), '"').parse_next(input)
+/// }
+///
+/// assert_eq!(
+///     rust_bytes.parse_peek(br#""\xff\u{e9}""#),
+///     Ok((&b""[..], Cow::Owned(b"\xff\xc3\xa9".to_vec())))
+/// );
+/// ```
+#[inline(always)]
+pub fn unescape(dialect: Dialect) -> Unescape {
+    Unescape {
+        dialect,
+        escapes: &[],
+        terminator: '"',
+    }
+}
+
+/// String literal syntax for [`unescape`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Dialect {
+    /// [JSON](https://www.rfc-editor.org/rfc/rfc8259#section-7) strings
+    ///
+    /// Escapes are `\"`, `\\`, `\/`, `\b`, `\f`, `\n`, `\r`, `\t` and `\uXXXX`, with surrogate
+    /// pairs joined.  Control characters must be escaped.
+    Json,
+    /// Rust strings and byte strings
+    ///
+    /// Escapes are `\n`, `\r`, `\t`, `\\`, `\0`, `\'`, `\"`, `\xXX`, `\u{X}` to `\u{XXXXXX}`,
+    /// and a `\` before a newline to skip it and the whitespace after it.
+    Rust,
+    /// C strings
+    ///
+    /// Escapes are `\a`, `\b`, `\f`, `\n`, `\r`, `\t`, `\v`, `\\`, `\'`, `\"`, `\?`, one to three
+    /// octal digits, `\x` with hexadecimal digits, `\uXXXX` and `\UXXXXXXXX`.
+    C,
+}
+
+/// [`Parser`] implementation for [`unescape`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Unescape {
+    dialect: Dialect,
+    escapes: &'static [(char, char)],
+    terminator: char,
+}
+
+impl Unescape {
+    /// Extra escapes, each character after a `\` with its replacement
+    ///
+    /// These take precedence over the escapes of the dialect.
+    #[inline(always)]
+    pub fn escapes(mut self, escapes: &'static [(char, char)]) -> Self {
+        self.escapes = escapes;
+        self
+    }
+
+    /// Character ending the literal, defaulting to `"`
+    #[inline(always)]
+    pub fn terminator(mut self, terminator: char) -> Self {
+        self.terminator = terminator;
+        self
+    }
+}
+
+impl<'i, Input, Error> Parser<Input, Cow<'i, str>, Error> for Unescape
+where
+    Input: StreamIsPartial + Stream<Slice = &'i str>,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input> + FromExternalError<Input, UnescapeError>,
+{
+    #[inline]
+    fn parse_next(&mut self, input: &mut Input) -> Result<Cow<'i, str>, Error> {
+        trace("unescape", |input: &mut Input| {
+            Ok(match self.unescape(input, false)? {
+                Unescaped::Borrowed(s) => Cow::Borrowed(s),
+                Unescaped::Owned(bytes) => {
+                    Cow::Owned(String::from_utf8(bytes).expect("unescaped `str` is UTF-8"))
+                }
+            })
+        })
+        .parse_next(input)
+    }
+}
+
+impl<'i, Input, Error> Parser<Input, Cow<'i, [u8]>, Error> for Unescape
+where
+    Input: StreamIsPartial + Stream<Slice = &'i [u8]>,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input> + FromExternalError<Input, UnescapeError>,
+{
+    #[inline]
+    fn parse_next(&mut self, input: &mut Input) -> Result<Cow<'i, [u8]>, Error> {
+        trace("unescape", |input: &mut Input| {
+            Ok(match self.unescape(input, true)? {
+                Unescaped::Borrowed(s) => Cow::Borrowed(s),
+                Unescaped::Owned(bytes) => Cow::Owned(bytes),
+            })
+        })
+        .parse_next(input)
+    }
+}
+
+enum Unescaped<Slice> {
+    Borrowed(Slice),
+    Owned(Vec<u8>),
+}
+
+/// Length of an escape, or why it is invalid
+type Escape = crate::lib::std::result::Result<usize, (UnescapeErrorKind, usize)>;
+
+impl Unescape {
+    /// Unescape into UTF-8, or any bytes if `bytes`
+    fn unescape<Input, Error>(
+        &self,
+        input: &mut Input,
+        bytes: bool,
+    ) -> Result<Unescaped<<Input as Stream>::Slice>, Error>
+    where
+        Input: StreamIsPartial + Stream,
+        <Input as Stream>::Slice: AsBStr,
+        <Input as Stream>::Token: AsChar,
+        Error: ParserError<Input> + FromExternalError<Input, UnescapeError>,
+    {
+        let start = input.checkpoint();
+        let mut owned: Option<Vec<u8>> = None;
+        // From the start of the literal, for error spans
+        let mut offset = 0;
+        loop {
+            let special = input
+                .iter_offsets()
+                .map(|(offset, token)| (offset, token.as_char()))
+                .find(|&(_, c)| {
+                    c == '\\'
+                        || c == self.terminator
+                        || (self.dialect == Dialect::Json && c < '\u{20}')
+                });
+            let (end, c) = match special {
+                Some((end, c)) => (end, Some(c)),
+                None if <Input as StreamIsPartial>::is_partial_supported()
+                    && input.is_partial() =>
+                {
+                    return Err(ParserError::incomplete(input, Needed::new(1)));
+                }
+                None => (input.eof_offset(), None),
+            };
+            let run = input.next_slice(end);
+            offset += end;
+
+            let (kind, len) = match c {
+                Some('\\') => {
+                    let buffer = owned.get_or_insert_with(Vec::new);
+                    buffer.extend_from_slice(run.as_bstr());
+                    match self.escape(input, buffer, bytes)? {
+                        Ok(len) => {
+                            offset += len;
+                            continue;
+                        }
+                        Err(invalid) => invalid,
+                    }
+                }
+                Some(c) if c != self.terminator => (UnescapeErrorKind::ControlCharacter, 1),
+                _ => {
+                    return Ok(match owned {
+                        Some(mut buffer) => {
+                            buffer.extend_from_slice(run.as_bstr());
+                            Unescaped::Owned(buffer)
+                        }
+                        None => Unescaped::Borrowed(run),
+                    });
+                }
+            };
+            input.reset(&start);
+            return Err(Error::from_external_error(
+                input,
+                UnescapeError {
+                    kind,
+                    span: offset..offset + len,
+                },
+            ));
+        }
+    }
+
+    /// Unescape the escape at the start of `input` into `buffer`
+    fn escape<Input, Error>(
+        &self,
+        input: &mut Input,
+        buffer: &mut Vec<u8>,
+        bytes: bool,
+    ) -> Result<Escape, Error>
+    where
+        Input: StreamIsPartial + Stream,
+        <Input as Stream>::Token: AsChar,
+        Error: ParserError<Input>,
+    {
+        let start = input.checkpoint();
+        let _ = input.next_token();
+        let Some(c) = peek_char(input)? else {
+            return Ok(Err((UnescapeErrorKind::Malformed, 1)));
+        };
+        let _ = input.next_token();
+        // `c` is a single token, which may be more than one byte of `str` or only one byte of
+        // `[u8]` that isn't UTF-8
+        let mut len = input.offset_from(&start);
+
+        if let Some(&(_, replacement)) = self.escapes.iter().find(|(escape, _)| *escape == c) {
+            push_char(buffer, replacement);
+            return Ok(Ok(len));
+        }
+        let simple = match (self.dialect, c) {
+            (_, '\\') | (_, '"') => Some(c),
+            (_, 'n') => Some('\n'),
+            (_, 'r') => Some('\r'),
+            (_, 't') => Some('\t'),
+            (Dialect::Json | Dialect::C, 'b') => Some('\u{8}'),
+            (Dialect::Json | Dialect::C, 'f') => Some('\u{c}'),
+            (Dialect::Json, '/') => Some('/'),
+            (Dialect::Rust | Dialect::C, '\'') => Some('\''),
+            (Dialect::Rust, '0') => Some('\0'),
+            (Dialect::C, 'a') => Some('\u{7}'),
+            (Dialect::C, 'v') => Some('\u{b}'),
+            (Dialect::C, '?') => Some('?'),
+            _ => None,
+        };
+        if let Some(simple) = simple {
+            push_char(buffer, simple);
+            return Ok(Ok(len));
+        }
+
+        match (self.dialect, c) {
+            (Dialect::Json, 'u') => {
+                let (unit, digits) = take_digits(input, 16, 4)?;
+                len += digits;
+                if digits < 4 {
+                    return Ok(Err((UnescapeErrorKind::Malformed, len)));
+                }
+                let c = match unit {
+                    0xd800..=0xdbff => {
+                        let Some(low) = take_json_low_surrogate(input)? else {
+                            return Ok(Err((UnescapeErrorKind::LoneSurrogate, len)));
+                        };
+                        len += 6;
+                        0x10000 + ((unit - 0xd800) << 10) + (low - 0xdc00)
+                    }
+                    _ => unit,
+                };
+                Ok(push_code_point(buffer, c, len))
+            }
+            (Dialect::Rust, 'x') => {
+                let (value, digits) = take_digits(input, 16, 2)?;
+                len += digits;
+                if digits < 2 {
+                    return Ok(Err((UnescapeErrorKind::Malformed, len)));
+                }
+                Ok(push_byte(buffer, value, bytes, len))
+            }
+            (Dialect::Rust, 'u') => {
+                if peek_char(input)? != Some('{') {
+                    return Ok(Err((UnescapeErrorKind::Malformed, len)));
+                }
+                let _ = input.next_token();
+                let (value, digits) = take_digits(input, 16, 6)?;
+                len += 1 + digits;
+                if digits == 0 || peek_char(input)? != Some('}') {
+                    return Ok(Err((UnescapeErrorKind::Malformed, len)));
+                }
+                let _ = input.next_token();
+                len += 1;
+                Ok(push_code_point(buffer, value, len))
+            }
+            (Dialect::Rust, '\n') => {
+                while let Some(' ' | '\t' | '\n' | '\r') = peek_char(input)? {
+                    let _ = input.next_token();
+                    len += 1;
+                }
+                Ok(Ok(len))
+            }
+            (Dialect::C, '0'..='7') => {
+                let (value, digits) = take_digits(input, 8, 2)?;
+                len += digits;
+                let value = (c as u32 - '0' as u32) * 8u32.pow(digits as u32) + value;
+                Ok(push_byte(buffer, value, bytes, len))
+            }
+            (Dialect::C, 'x') => {
+                let (value, digits) = take_digits(input, 16, usize::MAX)?;
+                len += digits;
+                if digits == 0 {
+                    return Ok(Err((UnescapeErrorKind::Malformed, len)));
+                }
+                Ok(push_byte(buffer, value, bytes, len))
+            }
+            (Dialect::C, 'u' | 'U') => {
+                let expected = if c == 'u' { 4 } else { 8 };
+                let (value, digits) = take_digits(input, 16, expected)?;
+                len += digits;
+                if digits < expected {
+                    return Ok(Err((UnescapeErrorKind::Malformed, len)));
+                }
+                Ok(push_code_point(buffer, value, len))
+            }
+            _ => Ok(Err((UnescapeErrorKind::Unknown, len))),
+        }
+    }
+}
+
+/// Up to `max` digits of `radix` and how many there were, saturating the value
+fn take_digits<Input, Error>(
+    input: &mut Input,
+    radix: u32,
+    max: usize,
+) -> Result<(u32, usize), Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    let mut value = 0u32;
+    let mut digits = 0;
+    while digits < max {
+        let Some(digit) = peek_char(input)?.and_then(|c| c.to_digit(radix)) else {
+            break;
+        };
+        let _ = input.next_token();
+        value = value.saturating_mul(radix).saturating_add(digit);
+        digits += 1;
+    }
+    Ok((value, digits))
+}
+
+/// `\uXXXX` of a low surrogate, after a high one
+fn take_json_low_surrogate<Input, Error>(input: &mut Input) -> Result<Option<u32>, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar,
+    Error: ParserError<Input>,
+{
+    if peek_char(input)? != Some('\\') {
+        return Ok(None);
+    }
+    let _ = input.next_token();
+    if peek_char(input)? != Some('u') {
+        return Ok(None);
+    }
+    let _ = input.next_token();
+    match take_digits(input, 16, 4)? {
+        (unit @ 0xdc00..=0xdfff, 4) => Ok(Some(unit)),
+        _ => Ok(None),
+    }
+}
+
+fn push_char(buffer: &mut Vec<u8>, c: char) {
+    buffer.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+}
+
+fn push_code_point(buffer: &mut Vec<u8>, value: u32, len: usize) -> Escape {
+    match char::from_u32(value) {
+        Some(c) => {
+            push_char(buffer, c);
+            Ok(len)
+        }
+        None if (0xd800..=0xdfff).contains(&value) => Err((UnescapeErrorKind::LoneSurrogate, len)),
+        None => Err((UnescapeErrorKind::OutOfRange, len)),
+    }
+}
+
+/// Push a byte escape, which is only a character in ASCII unless unescaping `bytes`
+fn push_byte(buffer: &mut Vec<u8>, value: u32, bytes: bool, len: usize) -> Escape {
+    match u8::try_from(value) {
+        Ok(byte) if byte.is_ascii() || bytes => {
+            buffer.push(byte);
+            Ok(len)
+        }
+        _ => Err((UnescapeErrorKind::OutOfRange, len)),
+    }
+}
+
+/// Invalid string literal, from [`unescape`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnescapeError {
+    /// What is invalid
+    pub kind: UnescapeErrorKind,
+    /// Offsets of the invalid escape or character, from the start of the literal
+    pub span: Range<usize>,
+}
+
+/// Kind of [`UnescapeError`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UnescapeErrorKind {
+    /// The character after `\` isn't an escape
+    Unknown,
+    /// The digits or braces of an escape are missing
+    Malformed,
+    /// The escaped value isn't a character, or a byte in byte strings
+    OutOfRange,
+    /// A UTF-16 surrogate without its pair
+    LoneSurrogate,
+    /// A control character that must be escaped
+    ControlCharacter,
+}
+
+impl fmt::Display for UnescapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            UnescapeErrorKind::Unknown => "unknown escape",
+            UnescapeErrorKind::Malformed => "malformed escape",
+            UnescapeErrorKind::OutOfRange => "escape out of range",
+            UnescapeErrorKind::LoneSurrogate => "lone surrogate escape",
+            UnescapeErrorKind::ControlCharacter => "unescaped control character",
+        };
+        write!(f, "{kind} at {}..{}", self.span.start, self.span.end)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnescapeError {}
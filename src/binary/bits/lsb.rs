@@ -0,0 +1,361 @@
+//! Bit level parsers reading the least-significant bit of each byte first
+//!
+//! The parsers in [`bits`][super] read the most-significant bit of each byte first.  Formats
+//! like Deflate, GIF's LZW and many radio protocols instead pack bits starting from the
+//! least-significant bit, with the first bit read being the least-significant bit of the value.
+//!
+//! The bit stream is the same `(I, usize)` as for [`bits`][super], where the offset is the number
+//! of bits of the first byte already read, so [`bits`] and [`bytes`] work the same for both bit
+//! orders.  Don't mix parsers of different bit orders within a byte.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use winnow::prelude::*;
+//! # use winnow::error::ContextError;
+//! # use winnow::error::ErrMode;
+//! use winnow::binary::bits::lsb::{bits, bool, take};
+//!
+//! /// Header of a Deflate block
+//! fn block_header(input: &mut &[u8]) -> ModalResult<(bool, u8)> {
+//!     bits::<_, _, ErrMode<ContextError>, _, _>((bool, take(2usize))).parse_next(input)
+//! }
+//!
+//! // An empty, final block of fixed Huffman codes
+//! let input = &[0x03, 0x00][..];
+//! assert_eq!(block_header.parse_peek(input), Ok((&[0x00][..], (true, 0b01))));
+//! ```
+
+use crate::combinator::trace;
+use crate::error::{Needed, ParserError};
+use crate::lib::std::ops::{AddAssign, Shl, Shr};
+use crate::stream::{Stream, StreamIsPartial, ToUsize};
+use crate::{Parser, Result};
+
+pub use super::{bits, bytes};
+
+use super::BYTE;
+
+/// Parse taking `count` bits, least-significant bit first
+///
+/// The first bit read is the least-significant bit of the output.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `(&[u8], usize)` bit [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::error::ContextError;
+/// pub fn take<'i>(count: usize) -> impl Parser<(&'i [u8], usize), u8, ContextError>
+/// # {
+/// #     winnow::binary::bits::lsb::take(count)
+/// # }
+/// ```
+///
+/// # Example
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::Bytes;
+/// # use winnow::error::ContextError;
+/// use winnow::binary::bits::lsb::take;
+///
+/// type Stream<'i> = &'i Bytes;
+///
+/// fn stream(b: &[u8]) -> Stream<'_> {
+///     Bytes::new(b)
+/// }
+///
+/// // Consumes 0 bits, returns 0
+/// assert_eq!(take::<_, usize, _, ContextError>(0usize).parse_peek((stream(&[0b00010010]), 0)), Ok(((stream(&[0b00010010]), 0), 0)));
+///
+/// // Consumes 4 bits, returns their values and increase offset to 4
+/// assert_eq!(take::<_, usize, _, ContextError>(4usize).parse_peek((stream(&[0b00010010]), 0)), Ok(((stream(&[0b00010010]), 4), 0b00000010)));
+///
+/// // Consumes 4 bits, offset is 4, returns their values and increase offset to 0 of next byte
+/// assert_eq!(take::<_, usize, _, ContextError>(4usize).parse_peek((stream(&[0b00010010]), 4)), Ok(((stream(&[]), 0), 0b00000001)));
+///
+/// // Bits of later bytes are more significant
+/// assert_eq!(take::<_, usize, _, ContextError>(12usize).parse_peek((stream(&[0b00010010, 0b11110011]), 0)), Ok(((stream(&[0b11110011]), 4), 0b0011_00010010)));
+///
+/// // Tries to consume 12 bits but only 8 are available
+/// assert!(take::<_, usize, _, ContextError>(12usize).parse_peek((stream(&[0b00010010]), 0)).is_err());
+/// ```
+#[inline(always)]
+pub fn take<Input, Output, Count, Error>(count: Count) -> impl Parser<(Input, usize), Output, Error>
+where
+    Input: Stream<Token = u8> + StreamIsPartial + Clone,
+    Output: From<u8> + AddAssign + Shl<usize, Output = Output> + Shr<usize, Output = Output>,
+    Count: ToUsize,
+    Error: ParserError<(Input, usize)>,
+{
+    let count = count.to_usize();
+    trace("take", move |input: &mut (Input, usize)| {
+        if <Input as StreamIsPartial>::is_partial_supported() {
+            take_::<_, _, _, true>(input, count)
+        } else {
+            take_::<_, _, _, false>(input, count)
+        }
+    })
+}
+
+fn take_<I, O, E: ParserError<(I, usize)>, const PARTIAL: bool>(
+    bit_input: &mut (I, usize),
+    count: usize,
+) -> Result<O, E>
+where
+    I: StreamIsPartial,
+    I: Stream<Token = u8> + Clone,
+    O: From<u8> + AddAssign + Shl<usize, Output = O>,
+{
+    if count == 0 {
+        return Ok(0u8.into());
+    }
+    let (mut input, bit_offset) = bit_input.clone();
+    if input.eof_offset() * BYTE < count + bit_offset {
+        if PARTIAL && input.is_partial() {
+            return Err(ParserError::incomplete(bit_input, Needed::new(count)));
+        } else {
+            return Err(ParserError::from_input(&(input, bit_offset)));
+        }
+    }
+
+    let mut acc: O = 0_u8.into();
+    let mut offset = bit_offset;
+    let mut shift = 0;
+    for (_, byte) in input.iter_offsets() {
+        let width = (BYTE - offset).min(count - shift);
+        let mask = u8::MAX >> (BYTE - width);
+        acc += O::from((byte >> offset) & mask) << shift;
+        shift += width;
+        offset = 0;
+        if shift == count {
+            break;
+        }
+    }
+    let end = bit_offset + count;
+    let _ = input.next_slice(end / BYTE);
+    *bit_input = (input, end % BYTE);
+    Ok(acc)
+}
+
+/// Parse taking `count` bits, least-significant bit first, and comparing them to `pattern`
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `(&[u8], usize)` bit [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::error::ContextError;
+/// pub fn pattern<'i>(pattern: u8, count: usize) -> impl Parser<(&'i [u8], usize), u8, ContextError>
+/// # {
+/// #     winnow::binary::bits::lsb::pattern(pattern, count)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::Bytes;
+/// # use winnow::error::ContextError;
+/// use winnow::binary::bits::lsb::pattern;
+///
+/// type Stream<'i> = &'i Bytes;
+///
+/// fn stream(b: &[u8]) -> Stream<'_> {
+///     Bytes::new(b)
+/// }
+///
+/// // The lowest 4 bits of 0b0000_0101 match the lowest 4 bits of 0b1111_0101.
+/// assert_eq!(
+///     pattern::<_, usize, _, ContextError>(0b0000_0101, 4usize).parse_peek((stream(&[0b1111_0101]), 0)),
+///     Ok(((stream(&[0b1111_0101]), 4), 0b0000_0101))
+/// );
+///
+/// // The lowest 2 bits of 0b1111_0101 and 0b0000_0010 are different.
+/// assert!(pattern::<_, usize, _, ContextError>(0b0000_0010, 2usize).parse_peek((stream(&[0b1111_0101]), 0)).is_err());
+/// ```
+#[inline(always)]
+#[doc(alias = "literal")]
+#[doc(alias = "just")]
+#[doc(alias = "tag")]
+pub fn pattern<Input, Output, Count, Error: ParserError<(Input, usize)>>(
+    pattern: Output,
+    count: Count,
+) -> impl Parser<(Input, usize), Output, Error>
+where
+    Input: Stream<Token = u8> + StreamIsPartial + Clone,
+    Count: ToUsize,
+    Output: From<u8>
+        + AddAssign
+        + Shl<usize, Output = Output>
+        + Shr<usize, Output = Output>
+        + PartialEq,
+{
+    let count = count.to_usize();
+    trace("pattern", move |input: &mut (Input, usize)| {
+        let start = input.checkpoint();
+
+        take(count).parse_next(input).and_then(|o| {
+            if pattern == o {
+                Ok(o)
+            } else {
+                input.reset(&start);
+                Err(ParserError::from_input(input))
+            }
+        })
+    })
+}
+
+/// Parses one bit as a bool, least-significant bit first
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `(&[u8], usize)` bit [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::error::ContextError;
+/// pub fn bool(input: &mut (&[u8], usize)) -> ModalResult<bool>
+/// # {
+/// #     winnow::binary::bits::lsb::bool.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::Bytes;
+/// use winnow::binary::bits::lsb::bool;
+///
+/// type Stream<'i> = &'i Bytes;
+///
+/// fn stream(b: &[u8]) -> Stream<'_> {
+///     Bytes::new(b)
+/// }
+///
+/// fn parse(input: &mut (Stream<'_>, usize)) -> ModalResult<bool> {
+///     bool.parse_next(input)
+/// }
+///
+/// assert_eq!(parse.parse_peek((stream(&[0b00000001]), 0)), Ok(((stream(&[0b00000001]), 1), true)));
+/// assert_eq!(parse.parse_peek((stream(&[0b00000001]), 1)), Ok(((stream(&[0b00000001]), 2), false)));
+/// ```
+#[doc(alias = "any")]
+pub fn bool<Input, Error: ParserError<(Input, usize)>>(
+    input: &mut (Input, usize),
+) -> Result<bool, Error>
+where
+    Input: Stream<Token = u8> + StreamIsPartial + Clone,
+{
+    trace("bool", |input: &mut (Input, usize)| {
+        let bit: u32 = take(1usize).parse_next(input)?;
+        Ok(bit != 0)
+    })
+    .parse_next(input)
+}
+
+/// Parse a unary code, least-significant bit first, counting the bits before the first
+/// `terminator` bit
+///
+/// The `terminator` bit is consumed.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `(&[u8], usize)` bit [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::error::ContextError;
+/// pub fn unary<'i>(terminator: bool) -> impl Parser<(&'i [u8], usize), usize, ContextError>
+/// # {
+/// #     winnow::binary::bits::lsb::unary(terminator)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::Bytes;
+/// # use winnow::error::ContextError;
+/// use winnow::binary::bits::lsb::unary;
+///
+/// type Stream<'i> = &'i Bytes;
+///
+/// fn stream(b: &[u8]) -> Stream<'_> {
+///     Bytes::new(b)
+/// }
+///
+/// assert_eq!(
+///     unary::<_, ContextError>(false).parse_peek((stream(&[0b0000_0111]), 0)),
+///     Ok(((stream(&[0b0000_0111]), 4), 3))
+/// );
+/// ```
+#[inline(always)]
+pub fn unary<Input, Error>(terminator: bool) -> impl Parser<(Input, usize), usize, Error>
+where
+    Input: Stream<Token = u8> + StreamIsPartial + Clone,
+    Error: ParserError<(Input, usize)>,
+{
+    trace("unary", move |input: &mut (Input, usize)| {
+        super::unary_::<_, _, true>(input, terminator)
+    })
+}
+
+/// Parse a symbol of a canonical Huffman code, reading bits least-significant bit first
+///
+/// Like in Deflate, codes are read one bit at a time, starting with their most-significant bit.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `(&[u8], usize)` bit [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::error::ContextError;
+/// # use winnow::binary::bits::HuffmanTable;
+/// pub fn huffman<'i: 't, 't>(table: &'t HuffmanTable) -> impl Parser<(&'i [u8], usize), u16, ContextError> + 't
+/// # {
+/// #     winnow::binary::bits::lsb::huffman(table)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::Bytes;
+/// # use winnow::error::ContextError;
+/// use winnow::binary::bits::HuffmanTable;
+/// use winnow::binary::bits::lsb::huffman;
+///
+/// type Stream<'i> = &'i Bytes;
+///
+/// fn stream(b: &[u8]) -> Stream<'_> {
+///     Bytes::new(b)
+/// }
+///
+/// // A: 010, B: 011, C: 100, D: 101, E: 110, F: 00, G: 1110, H: 1111
+/// let table = HuffmanTable::new(&[3, 3, 3, 3, 3, 2, 4, 4]).unwrap();
+///
+/// // `00`, `010` and `1111`
+/// let input = stream(&[0b1110_1000, 0b0000_0001]);
+/// assert_eq!(
+///     (huffman(&table), huffman(&table), huffman(&table)).parse_peek((input, 0)),
+///     Ok::<_, ContextError>(((stream(&[0b0000_0001]), 1), (5, 0, 7)))
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn huffman<'t, Input, Error>(
+    table: &'t super::HuffmanTable,
+) -> impl Parser<(Input, usize), u16, Error> + 't
+where
+    Input: 't,
+    Error: 't,
+    Input: Stream<Token = u8> + StreamIsPartial + Clone,
+    Error: ParserError<(Input, usize)>,
+{
+    trace("huffman", move |input: &mut (Input, usize)| {
+        super::huffman_::<_, _, true>(input, table)
+    })
+}
@@ -1,5 +1,9 @@
 //! Bit level parsers
 //!
+//! These read the most-significant bit of each byte first, see [`lsb`] for the
+//! least-significant bit first.
+
+pub mod lsb;
 
 #[cfg(test)]
 mod tests;
@@ -396,3 +400,383 @@ where
     })
     .parse_next(input)
 }
+
+/// Parse a unary code, counting the bits before the first `terminator` bit
+///
+/// The `terminator` bit is consumed.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `(&[u8], usize)` bit [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::error::ContextError;
+/// pub fn unary<'i>(terminator: bool) -> impl Parser<(&'i [u8], usize), usize, ContextError>
+/// # {
+/// #     winnow::binary::bits::unary(terminator)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::Bytes;
+/// # use winnow::error::ContextError;
+/// use winnow::binary::bits::unary;
+///
+/// type Stream<'i> = &'i Bytes;
+///
+/// fn stream(b: &[u8]) -> Stream<'_> {
+///     Bytes::new(b)
+/// }
+///
+/// assert_eq!(
+///     unary::<_, ContextError>(false).parse_peek((stream(&[0b1110_0000]), 0)),
+///     Ok(((stream(&[0b1110_0000]), 4), 3))
+/// );
+/// assert_eq!(
+///     unary::<_, ContextError>(true).parse_peek((stream(&[0b1110_0000]), 0)),
+///     Ok(((stream(&[0b1110_0000]), 1), 0))
+/// );
+/// assert!(unary::<_, ContextError>(false).parse_peek((stream(&[0b1111_1111]), 0)).is_err());
+/// ```
+#[inline(always)]
+pub fn unary<Input, Error>(terminator: bool) -> impl Parser<(Input, usize), usize, Error>
+where
+    Input: Stream<Token = u8> + StreamIsPartial + Clone,
+    Error: ParserError<(Input, usize)>,
+{
+    trace("unary", move |input: &mut (Input, usize)| {
+        unary_::<_, _, false>(input, terminator)
+    })
+}
+
+/// Parse an unsigned Exp-Golomb code, like `ue(v)` of H.264
+///
+/// The value is encoded as `n` zero bits, followed by the `n + 1` bits of `value + 1`.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `(&[u8], usize)` bit [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::error::ContextError;
+/// pub fn exp_golomb(input: &mut (&[u8], usize)) -> ModalResult<u64>
+/// # {
+/// #     winnow::binary::bits::exp_golomb.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::Bytes;
+/// # use winnow::error::ContextError;
+/// use winnow::binary::bits::exp_golomb;
+///
+/// type Stream<'i> = &'i Bytes;
+///
+/// fn stream(b: &[u8]) -> Stream<'_> {
+///     Bytes::new(b)
+/// }
+///
+/// fn parse(input: &mut (Stream<'_>, usize)) -> ModalResult<(u64, u64, u64)> {
+///     (exp_golomb, exp_golomb, exp_golomb).parse_next(input)
+/// }
+///
+/// // `1`, `010` and `011`
+/// assert_eq!(parse.parse_peek((stream(&[0b1010_0110]), 0)), Ok(((stream(&[0b1010_0110]), 7), (0, 1, 2))));
+/// ```
+pub fn exp_golomb<Input, Error>(input: &mut (Input, usize)) -> Result<u64, Error>
+where
+    Input: Stream<Token = u8> + StreamIsPartial + Clone,
+    Error: ParserError<(Input, usize)>,
+{
+    trace("exp_golomb", |input: &mut (Input, usize)| {
+        let start = input.clone();
+        let mut zeros = 0;
+        loop {
+            match next_bit::<_, false>(input) {
+                Some(true) => break,
+                Some(false) => zeros += 1,
+                None => {
+                    *input = start;
+                    return Err(end_of_input(input, zeros + 1));
+                }
+            }
+        }
+        if u64::BITS as usize <= zeros {
+            *input = start;
+            return Err(ParserError::from_input(input));
+        }
+
+        let mut value = 1u64;
+        for read in 0..zeros {
+            match next_bit::<_, false>(input) {
+                Some(bit) => value = value << 1 | u64::from(bit),
+                None => {
+                    *input = start;
+                    return Err(end_of_input(input, zeros - read));
+                }
+            }
+        }
+        Ok(value - 1)
+    })
+    .parse_next(input)
+}
+
+/// Parse a signed Exp-Golomb code, like `se(v)` of H.264
+///
+/// The [`exp_golomb`] code `k` is mapped to `0, 1, -1, 2, -2, ...`.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `(&[u8], usize)` bit [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::error::ContextError;
+/// pub fn signed_exp_golomb(input: &mut (&[u8], usize)) -> ModalResult<i64>
+/// # {
+/// #     winnow::binary::bits::signed_exp_golomb.parse_next(input)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::Bytes;
+/// # use winnow::error::ContextError;
+/// use winnow::binary::bits::signed_exp_golomb;
+///
+/// type Stream<'i> = &'i Bytes;
+///
+/// fn stream(b: &[u8]) -> Stream<'_> {
+///     Bytes::new(b)
+/// }
+///
+/// fn parse(input: &mut (Stream<'_>, usize)) -> ModalResult<(i64, i64, i64)> {
+///     (signed_exp_golomb, signed_exp_golomb, signed_exp_golomb).parse_next(input)
+/// }
+///
+/// // `1`, `010` and `011`
+/// assert_eq!(parse.parse_peek((stream(&[0b1010_0110]), 0)), Ok(((stream(&[0b1010_0110]), 7), (0, 1, -1))));
+/// ```
+pub fn signed_exp_golomb<Input, Error>(input: &mut (Input, usize)) -> Result<i64, Error>
+where
+    Input: Stream<Token = u8> + StreamIsPartial + Clone,
+    Error: ParserError<(Input, usize)>,
+{
+    trace("signed_exp_golomb", |input: &mut (Input, usize)| {
+        let k = exp_golomb(input)?;
+        // `k / 2` is at most `i64::MAX` as `k < u64::MAX`
+        let magnitude = (k / 2) as i64;
+        if k % 2 == 1 {
+            Ok(magnitude + 1)
+        } else {
+            Ok(-magnitude)
+        }
+    })
+    .parse_next(input)
+}
+
+/// Longest code supported by [`HuffmanTable`]
+#[cfg(feature = "alloc")]
+const MAX_CODE_LENGTH: usize = 16;
+
+/// Canonical Huffman code, for decoding with [`huffman`]
+///
+/// Codes are assigned to symbols from their lengths like in Deflate ([RFC 1951]): shorter codes
+/// come first and codes of the same length are in the order of their symbols.
+///
+/// [RFC 1951]: https://www.rfc-editor.org/rfc/rfc1951#section-3.2.2
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HuffmanTable {
+    /// Number of codes of each length
+    counts: [u16; MAX_CODE_LENGTH + 1],
+    /// Symbols, ordered by their code
+    symbols: crate::lib::std::vec::Vec<u16>,
+}
+
+#[cfg(feature = "alloc")]
+impl HuffmanTable {
+    /// Build the table from the code length of each symbol
+    ///
+    /// Symbols are the indices into `lengths` and a length of `0` means the symbol is unused.
+    /// Returns `None` if there are more than `2^16` symbols, a length is above 16, or there are
+    /// more codes of a length than fit.  Unused codes are allowed, failing when decoded.
+    pub fn new(lengths: &[u8]) -> Option<Self> {
+        if usize::from(u16::MAX) < lengths.len() {
+            return None;
+        }
+        let mut counts = [0u16; MAX_CODE_LENGTH + 1];
+        for &length in lengths {
+            *counts.get_mut(usize::from(length))? += 1;
+        }
+        counts[0] = 0;
+
+        let mut left = 1i32;
+        for count in &counts[1..] {
+            left = (left << 1) - i32::from(*count);
+            if left < 0 {
+                return None;
+            }
+        }
+
+        let mut offsets = [0u16; MAX_CODE_LENGTH + 1];
+        for length in 1..MAX_CODE_LENGTH {
+            offsets[length + 1] = offsets[length] + counts[length];
+        }
+        let used = counts.iter().map(|count| usize::from(*count)).sum();
+        let mut symbols = crate::lib::std::vec![0; used];
+        for (symbol, &length) in lengths.iter().enumerate() {
+            if length != 0 {
+                let offset = &mut offsets[usize::from(length)];
+                symbols[usize::from(*offset)] = symbol as u16;
+                *offset += 1;
+            }
+        }
+        Some(Self { counts, symbols })
+    }
+}
+
+/// Parse a symbol of a canonical Huffman code
+///
+/// Codes are read one bit at a time, starting with their most-significant bit.
+///
+/// # Effective Signature
+///
+/// Assuming you are parsing a `(&[u8], usize)` bit [Stream]:
+/// ```rust
+/// # use winnow::prelude::*;;
+/// # use winnow::error::ContextError;
+/// # use winnow::binary::bits::HuffmanTable;
+/// pub fn huffman<'i: 't, 't>(table: &'t HuffmanTable) -> impl Parser<(&'i [u8], usize), u16, ContextError> + 't
+/// # {
+/// #     winnow::binary::bits::huffman(table)
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::Bytes;
+/// # use winnow::error::ContextError;
+/// use winnow::binary::bits::{huffman, HuffmanTable};
+///
+/// type Stream<'i> = &'i Bytes;
+///
+/// fn stream(b: &[u8]) -> Stream<'_> {
+///     Bytes::new(b)
+/// }
+///
+/// // A: 010, B: 011, C: 100, D: 101, E: 110, F: 00, G: 1110, H: 1111
+/// let table = HuffmanTable::new(&[3, 3, 3, 3, 3, 2, 4, 4]).unwrap();
+///
+/// // `00`, `010` and `1111`
+/// let input = stream(&[0b0001_0111, 0b1000_0000]);
+/// assert_eq!(
+///     (huffman(&table), huffman(&table), huffman(&table)).parse_peek((input, 0)),
+///     Ok::<_, ContextError>(((stream(&[0b1000_0000]), 1), (5, 0, 7)))
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn huffman<'t, Input, Error>(
+    table: &'t HuffmanTable,
+) -> impl Parser<(Input, usize), u16, Error> + 't
+where
+    Input: 't,
+    Error: 't,
+    Input: Stream<Token = u8> + StreamIsPartial + Clone,
+    Error: ParserError<(Input, usize)>,
+{
+    trace("huffman", move |input: &mut (Input, usize)| {
+        huffman_::<_, _, false>(input, table)
+    })
+}
+
+fn unary_<I, E, const LSB: bool>(input: &mut (I, usize), terminator: bool) -> Result<usize, E>
+where
+    I: Stream<Token = u8> + StreamIsPartial + Clone,
+    E: ParserError<(I, usize)>,
+{
+    let start = input.clone();
+    let mut count = 0;
+    loop {
+        match next_bit::<_, LSB>(input) {
+            Some(bit) if bit == terminator => return Ok(count),
+            Some(_) => count += 1,
+            None => {
+                *input = start;
+                return Err(end_of_input(input, 1));
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn huffman_<I, E, const LSB: bool>(input: &mut (I, usize), table: &HuffmanTable) -> Result<u16, E>
+where
+    I: Stream<Token = u8> + StreamIsPartial + Clone,
+    E: ParserError<(I, usize)>,
+{
+    let start = input.clone();
+    // Codes of each length are consecutive, starting at `first`, for the symbols at `index`
+    let mut code = 0i32;
+    let mut first = 0i32;
+    let mut index = 0i32;
+    for &count in &table.counts[1..] {
+        let Some(bit) = next_bit::<_, LSB>(input) else {
+            *input = start;
+            return Err(end_of_input(input, 1));
+        };
+        code |= i32::from(bit);
+        let count = i32::from(count);
+        if code - first < count {
+            return Ok(table.symbols[(index + code - first) as usize]);
+        }
+        index += count;
+        first = (first + count) << 1;
+        code <<= 1;
+    }
+    *input = start;
+    Err(ParserError::from_input(input))
+}
+
+/// Read one bit, advancing past fully read bytes
+fn next_bit<I, const LSB: bool>(bit_input: &mut (I, usize)) -> Option<bool>
+where
+    I: Stream<Token = u8>,
+{
+    let (input, offset) = bit_input;
+    let (_, byte) = input.iter_offsets().nth(*offset / BYTE)?;
+    let shift = if LSB {
+        *offset % BYTE
+    } else {
+        BYTE - 1 - *offset % BYTE
+    };
+    *offset += 1;
+    if *offset % BYTE == 0 {
+        let _ = input.next_slice(*offset / BYTE);
+        *offset = 0;
+    }
+    Some(byte >> shift & 1 == 1)
+}
+
+/// Error for running out of input with at least `needed` more bits required
+fn end_of_input<I, E>(input: &(I, usize), needed: usize) -> E
+where
+    I: Stream<Token = u8> + StreamIsPartial + Clone,
+    E: ParserError<(I, usize)>,
+{
+    if input.0.is_partial() {
+        ParserError::incomplete(input, Needed::new(needed))
+    } else {
+        ParserError::from_input(input)
+    }
+}
@@ -202,3 +202,286 @@ fn test_bool_eof_partial() {
         Err(crate::error::ErrMode::Incomplete(Needed::new(1)))
     );
 }
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_exp_golomb_values() {
+    // `1`, `010`, `011`, `00100`, `0001000` and `1`, padded with zeros
+    let input = &[0b1010_0110, 0b0100_0001, 0b0001_0000][..];
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((&[u8], usize), Vec<u64>), InputError<_>> =
+        crate::combinator::repeat(6, exp_golomb).parse_peek((input, 0));
+
+    assert_eq!(result, Ok(((&input[2..], 4), vec![0, 1, 2, 3, 7, 0])));
+}
+
+#[test]
+fn test_signed_exp_golomb_values() {
+    // `011`, `00100` and `00101`
+    let input = &[0b0110_0100, 0b0010_1000][..];
+
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((&[u8], usize), (i64, i64, i64)), InputError<_>> =
+        (signed_exp_golomb, signed_exp_golomb, signed_exp_golomb).parse_peek((input, 0));
+
+    assert_eq!(result, Ok(((&input[1..], 5), (-1, 2, -2))));
+}
+
+#[test]
+fn test_exp_golomb_max() {
+    // 63 zeros, a one and 63 ones
+    let mut input = vec![0u8; 7];
+    input.extend([0b0000_0001]);
+    input.extend([0xff; 8]);
+
+    let result: ModalResult<((&[u8], usize), u64), InputError<_>> =
+        exp_golomb.parse_peek((&input[..], 0));
+    assert_eq!(result, Ok(((&input[15..], 7), u64::MAX - 1)));
+
+    // 64 zeros
+    let input = &[0, 0, 0, 0, 0, 0, 0, 0, 0b1000_0000][..];
+    let result: ModalResult<((&[u8], usize), u64), InputError<_>> =
+        exp_golomb.parse_peek((input, 0));
+    assert_eq!(result, Err(ErrMode::Backtrack(InputError::at((input, 0)))));
+}
+
+#[test]
+fn test_exp_golomb_partial() {
+    // Two zeros of the prefix
+    let input = Partial::new(&[0b1000_0000][..]);
+    let result: ModalResult<((_, usize), u64), InputError<_>> = exp_golomb.parse_peek((input, 6));
+    assert_eq!(result, Err(ErrMode::Incomplete(Needed::new(3))));
+
+    // Prefix of `001` with one of two info bits
+    let input = Partial::new(&[0b0000_0010][..]);
+    let result: ModalResult<((_, usize), u64), InputError<_>> = exp_golomb.parse_peek((input, 4));
+    assert_eq!(result, Err(ErrMode::Incomplete(Needed::new(1))));
+
+    let input = &[0b0000_0010][..];
+    let result: ModalResult<((&[u8], usize), u64), InputError<_>> =
+        exp_golomb.parse_peek((input, 4));
+    assert_eq!(result, Err(ErrMode::Backtrack(InputError::at((input, 4)))));
+}
+
+#[test]
+fn test_unary_across_bytes() {
+    let input = &[0b1111_1111, 0b1100_0000][..];
+
+    let result: ModalResult<((&[u8], usize), usize), InputError<_>> =
+        unary(false).parse_peek((input, 1));
+    assert_eq!(result, Ok(((&input[1..], 3), 9)));
+
+    let input = Partial::new(&[0b1111_1111][..]);
+    let result: ModalResult<((_, usize), usize), InputError<_>> =
+        unary(false).parse_peek((input, 0));
+    assert_eq!(result, Err(ErrMode::Incomplete(Needed::new(1))));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_huffman_table() {
+    // Over-subscribed
+    assert_eq!(HuffmanTable::new(&[1, 1, 1]), None);
+    // Too long
+    assert_eq!(HuffmanTable::new(&[17]), None);
+    // Incomplete codes are allowed
+    assert!(HuffmanTable::new(&[1, 0, 2]).is_some());
+    assert!(HuffmanTable::new(&[]).is_some());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_huffman_complete() {
+    // A: 0, C: 10
+    let table = HuffmanTable::new(&[1, 0, 2]).unwrap();
+
+    let input = &[0b0100_1100][..];
+    #[allow(clippy::type_complexity)]
+    let result: ModalResult<((&[u8], usize), Vec<u16>), InputError<_>> =
+        crate::combinator::repeat(3, huffman(&table)).parse_peek((input, 0));
+    assert_eq!(result, Ok(((input, 4), vec![0, 2, 0])));
+
+    // `11` is unused
+    let result: ModalResult<((&[u8], usize), u16), InputError<_>> =
+        huffman(&table).parse_peek((input, 4));
+    assert_eq!(result, Err(ErrMode::Backtrack(InputError::at((input, 4)))));
+
+    let table = HuffmanTable::new(&[]).unwrap();
+    let result: ModalResult<((&[u8], usize), u16), InputError<_>> =
+        huffman(&table).parse_peek((input, 0));
+    assert_eq!(result, Err(ErrMode::Backtrack(InputError::at((input, 0)))));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_huffman_partial() {
+    // A: 010, B: 011, C: 100, D: 101, E: 110, F: 00, G: 1110, H: 1111
+    let table = HuffmanTable::new(&[3, 3, 3, 3, 3, 2, 4, 4]).unwrap();
+
+    let input = Partial::new(&[0b1111_1111][..]);
+    let result: ModalResult<((_, usize), u16), InputError<_>> =
+        huffman(&table).parse_peek((input, 5));
+    assert_eq!(result, Err(ErrMode::Incomplete(Needed::new(1))));
+
+    let result: ModalResult<((_, usize), u16), InputError<_>> =
+        huffman(&table).parse_peek((input, 0));
+    assert_eq!(result, Ok(((input, 4), 7)));
+}
+
+/// Read `count` bits at `start`, most-significant bit first, one bit at a time
+fn reference_msb(bytes: &[u8], start: usize, count: usize) -> u64 {
+    (start..start + count).fold(0, |acc, i| {
+        (acc << 1) | u64::from(bytes[i / BYTE] >> (BYTE - 1 - i % BYTE) & 1)
+    })
+}
+
+/// Read `count` bits at `start`, least-significant bit first, one bit at a time
+fn reference_lsb(bytes: &[u8], start: usize, count: usize) -> u64 {
+    (start..start + count).enumerate().fold(0, |acc, (n, i)| {
+        acc | u64::from(bytes[i / BYTE] >> (i % BYTE) & 1) << n
+    })
+}
+
+mod lsb {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    use crate::binary::bits::lsb::huffman;
+    use crate::binary::bits::lsb::{bits, bool, bytes, pattern, take, unary};
+    use crate::token::rest;
+
+    #[test]
+    fn test_take_complete_span_over_multiple_bytes() {
+        let input = &[0b00010010, 0b00110100, 0b11111111, 0b11111111][..];
+
+        let result: ModalResult<((&[u8], usize), usize), InputError<_>> =
+            take(24usize).parse_peek((input, 4));
+
+        assert_eq!(
+            result,
+            Ok((([0b11111111].as_ref(), 4), 0b1111_1111_1111_0011_0100_0001))
+        );
+    }
+
+    #[test]
+    fn test_take_complete_eof() {
+        let input = &[0b00010010][..];
+
+        let result: ModalResult<((&[u8], usize), usize), InputError<_>> =
+            take(1usize).parse_peek((input, 8));
+
+        assert_eq!(
+            result,
+            Err(crate::error::ErrMode::Backtrack(
+                InputError::at((input, 8),)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_take_partial_incomplete() {
+        let input = Partial::new(&[0b00010010][..]);
+
+        let result: ModalResult<((_, usize), usize), InputError<_>> =
+            take(12usize).parse_peek((input, 0));
+
+        assert_eq!(
+            result,
+            Err(crate::error::ErrMode::Incomplete(Needed::new(12)))
+        );
+    }
+
+    #[test]
+    fn test_pattern_partial_err() {
+        let input = Partial::new(&[0b00011111][..]);
+
+        let result: ModalResult<((_, usize), usize), InputError<_>> =
+            pattern(0b0001, 4usize).parse_peek((input, 0));
+
+        assert_eq!(
+            result,
+            Err(crate::error::ErrMode::Backtrack(
+                InputError::at((input, 0),)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_bool_complete() {
+        let input = [0b00000010].as_ref();
+
+        let result: ModalResult<((&[u8], usize), bool), InputError<_>> =
+            bool.parse_peek((input, 1));
+
+        assert_eq!(result, Ok(((input, 2), true)));
+    }
+
+    #[test]
+    fn test_bits_bytes() {
+        let input = &[0x12, 0x34, 0x56][..];
+
+        #[allow(clippy::type_complexity)]
+        let result: ModalResult<(&[u8], (u8, u8, &[u8])), InputError<_>> =
+            bits::<_, _, ErrMode<InputError<(&[u8], usize)>>, _, _>((
+                take(4usize),
+                take(8usize),
+                bytes::<_, _, ErrMode<InputError<&[u8]>>, _, _>(rest),
+            ))
+            .parse_peek(input);
+
+        assert_eq!(result, Ok((&[][..], (0x02, 0x41, &[0x56][..]))));
+    }
+
+    #[test]
+    fn test_unary() {
+        let input = &[0b0000_0111, 0b0000_0001][..];
+
+        #[allow(clippy::type_complexity)]
+        let result: ModalResult<((&[u8], usize), (usize, usize)), InputError<_>> =
+            (unary(false), unary(true)).parse_peek((input, 0));
+        assert_eq!(result, Ok(((&input[1..], 1), (3, 4))));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_huffman() {
+        // A: 0, B: 10, C: 11
+        let table = HuffmanTable::new(&[1, 2, 2]).unwrap();
+
+        // `11`, `0` and `10`, each code starting at its least-significant bit
+        let input = &[0b0000_1011][..];
+        #[allow(clippy::type_complexity)]
+        let result: ModalResult<((&[u8], usize), (u16, u16, u16)), InputError<_>> =
+            (huffman(&table), huffman(&table), huffman(&table)).parse_peek((input, 0));
+        assert_eq!(result, Ok(((input, 5), (2, 0, 1))));
+    }
+
+    proptest::proptest! {
+        #[test]
+        #[cfg_attr(miri, ignore)] // See https://github.com/AltSysrq/proptest/issues/253
+        fn reference_readers(
+            input in proptest::collection::vec(proptest::num::u8::ANY, 0..16),
+            counts in proptest::collection::vec(0..=64usize, 0..8),
+        ) {
+            let mut msb = (&input[..], 0);
+            let mut lsb = (&input[..], 0);
+            let mut start = 0;
+            for count in counts {
+                let msb_value: ModalResult<u64, InputError<_>> =
+                    crate::binary::bits::take(count).parse_next(&mut msb);
+                let lsb_value: ModalResult<u64, InputError<_>> = take(count).parse_next(&mut lsb);
+                if input.len() * BYTE < start + count {
+                    proptest::prop_assert!(msb_value.is_err());
+                    proptest::prop_assert!(lsb_value.is_err());
+                    break;
+                }
+                proptest::prop_assert_eq!(msb_value, Ok(reference_msb(&input, start, count)));
+                proptest::prop_assert_eq!(lsb_value, Ok(reference_lsb(&input, start, count)));
+                start += count;
+                proptest::prop_assert_eq!(msb, (&input[start / BYTE..], start % BYTE));
+                proptest::prop_assert_eq!(lsb, msb);
+            }
+        }
+    }
+}
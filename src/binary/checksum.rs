@@ -0,0 +1,310 @@
+//! Checksums for verifying sections of binary formats
+//!
+//! See [`verified`] for parsing a section followed by its checksum.
+
+use crate::error::{FromExternalError, ParserError};
+use crate::lib::std::fmt;
+use crate::stream::{AsBytes, Stream, StreamIsPartial};
+use crate::Parser;
+
+/// Parse a section, followed by a checksum of the bytes it consumed
+///
+/// The checksum is read by the `trailer` parser, like [`le_u32`][super::le_u32], and compared to
+/// the checksum computed by `algorithm`.
+///
+/// Returns a [`ChecksumError`] with both values if they differ.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::checksum::{verified, Crc32};
+/// use winnow::binary::be_u32;
+/// use winnow::token::take;
+///
+/// /// A PNG chunk's type and data
+/// fn chunk<'i>(input: &mut &'i [u8]) -> ModalResult<(&'i [u8], &'i [u8])> {
+///     let len = be_u32.parse_next(input)?;
+///     verified((take(4usize), take(len)), Crc32::new(), be_u32).parse_next(input)
+/// }
+///
+/// let iend = &b"\x00\x00\x00\x00IEND\xae\x42\x60\x82"[..];
+/// assert_eq!(chunk.parse_peek(iend), Ok((&b""[..], (&b"IEND"[..], &b""[..]))));
+///
+/// let corrupt = &b"\x00\x00\x00\x00IEND\x00\x00\x00\x00"[..];
+/// assert!(chunk.parse_peek(corrupt).is_err());
+/// ```
+pub fn verified<Input, Output, Sum, Error, Section, Trailer>(
+    mut section: Section,
+    algorithm: Sum,
+    mut trailer: Trailer,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: Stream + StreamIsPartial,
+    <Input as Stream>::Slice: AsBytes,
+    Sum: Checksum + Clone,
+    Section: Parser<Input, Output, Error>,
+    Trailer: Parser<Input, Sum::Output, Error>,
+    Error: ParserError<Input> + FromExternalError<Input, ChecksumError<Sum::Output>>,
+{
+    crate::combinator::trace("verified", move |input: &mut Input| {
+        let (output, taken) = section.by_ref().with_taken().parse_next(input)?;
+        let mut sum = algorithm.clone();
+        sum.update(taken.as_bytes());
+        let computed = sum.finish();
+
+        let start = input.checkpoint();
+        let expected = trailer.parse_next(input)?;
+        if expected == computed {
+            Ok(output)
+        } else {
+            input.reset(&start);
+            Err(Error::from_external_error(
+                input,
+                ChecksumError { expected, computed },
+            ))
+        }
+    })
+}
+
+/// Checksum algorithm for [`verified`]
+pub trait Checksum {
+    /// Computed checksum
+    type Output: PartialEq;
+
+    /// Add `bytes` to the checksum
+    fn update(&mut self, bytes: &[u8]);
+    /// Checksum of all bytes added so far
+    fn finish(&self) -> Self::Output;
+}
+
+/// Checksum read from the input differs from the one computed, from [`verified`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ChecksumError<T> {
+    /// Checksum read from the input
+    pub expected: T,
+    /// Checksum computed over the section
+    pub computed: T,
+}
+
+impl<T: fmt::LowerHex> fmt::Display for ChecksumError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "checksum mismatch: expected {:#x}, computed {:#x}",
+            self.expected, self.computed
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug + fmt::LowerHex> std::error::Error for ChecksumError<T> {}
+
+/// CRC-32 of Ethernet, zlib and PNG (CRC-32/ISO-HDLC)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Crc32 {
+    crc: u32,
+}
+
+impl Crc32 {
+    /// Start a checksum
+    pub fn new() -> Self {
+        Self { crc: u32::MAX }
+    }
+}
+
+impl Default for Crc32 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Checksum for Crc32 {
+    type Output = u32;
+
+    fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.crc = CRC32_TABLE[usize::from(self.crc as u8 ^ byte)] ^ (self.crc >> 8);
+        }
+    }
+
+    fn finish(&self) -> u32 {
+        !self.crc
+    }
+}
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut index = 0;
+    while index < 256 {
+        let mut crc = index as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[index] = crc;
+        index += 1;
+    }
+    table
+};
+
+/// 16-bit CRC
+///
+/// Common variants have their own constructors, like [`Crc16::modbus`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Crc16 {
+    crc: u16,
+    poly: u16,
+    reflected: bool,
+    xorout: u16,
+}
+
+impl Crc16 {
+    /// Start a checksum with the parameters of a variant
+    ///
+    /// `poly` is the polynomial without its leading term, most-significant bit first, even when
+    /// `reflected`.  Reflected variants process the bits of each byte least-significant first.
+    pub const fn new(poly: u16, init: u16, reflected: bool, xorout: u16) -> Self {
+        let poly = if reflected { poly.reverse_bits() } else { poly };
+        Self {
+            crc: init,
+            poly,
+            reflected,
+            xorout,
+        }
+    }
+
+    /// CRC-16/ARC, also known as CRC-16/IBM
+    pub const fn arc() -> Self {
+        Self::new(0x8005, 0x0000, true, 0x0000)
+    }
+
+    /// CRC-16/MODBUS
+    pub const fn modbus() -> Self {
+        Self::new(0x8005, 0xffff, true, 0x0000)
+    }
+
+    /// CRC-16/IBM-3740, also known as CRC-16/CCITT-FALSE
+    pub const fn ibm_3740() -> Self {
+        Self::new(0x1021, 0xffff, false, 0x0000)
+    }
+
+    /// CRC-16/XMODEM
+    pub const fn xmodem() -> Self {
+        Self::new(0x1021, 0x0000, false, 0x0000)
+    }
+
+    /// CRC-16/KERMIT, also known as CRC-16/CCITT
+    pub const fn kermit() -> Self {
+        Self::new(0x1021, 0x0000, true, 0x0000)
+    }
+}
+
+impl Checksum for Crc16 {
+    type Output = u16;
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            if self.reflected {
+                self.crc ^= u16::from(byte);
+                for _ in 0..8 {
+                    self.crc = if self.crc & 1 == 1 {
+                        (self.crc >> 1) ^ self.poly
+                    } else {
+                        self.crc >> 1
+                    };
+                }
+            } else {
+                self.crc ^= u16::from(byte) << 8;
+                for _ in 0..8 {
+                    self.crc = if self.crc & 0x8000 != 0 {
+                        (self.crc << 1) ^ self.poly
+                    } else {
+                        self.crc << 1
+                    };
+                }
+            }
+        }
+    }
+
+    fn finish(&self) -> u16 {
+        self.crc ^ self.xorout
+    }
+}
+
+/// Adler-32 of zlib
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Adler32 {
+    a: u32,
+    b: u32,
+}
+
+impl Adler32 {
+    const MODULUS: u32 = 65521;
+    /// Most bytes that can be summed before `b` could overflow
+    const CHUNK: usize = 5552;
+
+    /// Start a checksum
+    pub fn new() -> Self {
+        Self { a: 1, b: 0 }
+    }
+}
+
+impl Default for Adler32 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Checksum for Adler32 {
+    type Output = u32;
+
+    fn update(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(Self::CHUNK) {
+            for &byte in chunk {
+                self.a += u32::from(byte);
+                self.b += self.a;
+            }
+            self.a %= Self::MODULUS;
+            self.b %= Self::MODULUS;
+        }
+    }
+
+    fn finish(&self) -> u32 {
+        (self.b << 16) | self.a
+    }
+}
+
+/// Fletcher-16
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Fletcher16 {
+    sum1: u16,
+    sum2: u16,
+}
+
+impl Fletcher16 {
+    /// Start a checksum
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Checksum for Fletcher16 {
+    type Output = u16;
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.sum1 = (self.sum1 + u16::from(byte)) % 255;
+            self.sum2 = (self.sum2 + self.sum1) % 255;
+        }
+    }
+
+    fn finish(&self) -> u16 {
+        (self.sum2 << 8) | self.sum1
+    }
+}
@@ -1,23 +1,42 @@
 //! Parsers recognizing numbers
+//!
+//! With `unstable-print`, the parsers taking an [`Endianness`], like [`u16()`], [`f16()`] and
+//! [`uint`], also implement [`Print`][crate::print::Print].  The fixed-endian functions, like
+//! [`be_u16`], and [`u8()`] and [`i8()`] are plain functions and can't print, use
+//! `u16(Endianness::Big)` or `uint::<1>(Endianness::Big)` in their place.
 
 #![allow(clippy::match_same_arms)]
 
 pub mod bits;
+pub mod checksum;
+mod seek;
+mod text;
+mod varint;
 
 #[cfg(test)]
 mod tests;
 
 use crate::combinator::repeat;
 use crate::combinator::trace;
+use crate::combinator::trace_call;
+use crate::combinator::CallCount;
 use crate::error::Needed;
 use crate::error::ParserError;
 use crate::lib::std::ops::{Add, Shl};
-use crate::stream::Accumulate;
+use crate::stream::TryAccumulate;
 use crate::stream::{Stream, StreamIsPartial};
 use crate::stream::{ToUsize, UpdateSlice};
 use crate::Parser;
 use crate::Result;
 
+pub use seek::{align, at_offset, pad_to, skip_to, SeekError};
+#[cfg(feature = "alloc")]
+pub use seek::{visit, VisitGuard};
+#[cfg(feature = "alloc")]
+pub use text::utf16;
+pub use text::{c_str, padded, terminated_by, utf8, TextError};
+pub use varint::{leb128, zigzag, SignedVarint, Varint, VarintError};
+
 /// Configurable endianness
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Endianness {
@@ -29,6 +48,91 @@ pub enum Endianness {
     Native,
 }
 
+/// [`Parser`] implementation for numbers read with an [`Endianness`], see [`u16()`] and friends
+///
+/// `N` is the width of the number in the input, in bytes.
+pub struct Number<O, I, E, const N: usize> {
+    endian: Endianness,
+    i: core::marker::PhantomData<I>,
+    o: core::marker::PhantomData<O>,
+    e: core::marker::PhantomData<E>,
+}
+
+impl<O, I, E, const N: usize> Number<O, I, E, N> {
+    #[inline(always)]
+    fn new(endian: Endianness) -> Self {
+        Self {
+            endian,
+            i: Default::default(),
+            o: Default::default(),
+            e: Default::default(),
+        }
+    }
+
+    #[cfg(feature = "unstable-print")]
+    #[inline(always)]
+    fn is_big(&self) -> bool {
+        is_big(self.endian)
+    }
+}
+
+macro_rules! impl_number {
+    ($($ty:ty, $width:literal, $be:ident, $le:ident, $fits:expr;)*) => {$(
+        impl<I, E> Parser<I, $ty, E> for Number<$ty, I, E, $width>
+        where
+            I: StreamIsPartial + Stream<Token = u8>,
+            E: ParserError<I>,
+        {
+            #[inline]
+            fn parse_next(&mut self, input: &mut I) -> Result<$ty, E> {
+                match self.endian {
+                    Endianness::Big => $be(input),
+                    Endianness::Little => $le(input),
+                    #[cfg(target_endian = "big")]
+                    Endianness::Native => $be(input),
+                    #[cfg(target_endian = "little")]
+                    Endianness::Native => $le(input),
+                }
+            }
+        }
+
+        #[cfg(feature = "unstable-print")]
+        impl<I, E> crate::print::Print<$ty> for Number<$ty, I, E, $width> {
+            fn print(
+                &self,
+                value: &$ty,
+                sink: &mut dyn crate::print::Sink,
+            ) -> Result<(), crate::print::PrintError> {
+                #[allow(clippy::redundant_closure_call)]
+                if !($fits)(value) {
+                    return Err(crate::print::PrintError::Unprintable);
+                }
+                if self.is_big() {
+                    let bytes = value.to_be_bytes();
+                    sink.write_bytes(&bytes[bytes.len() - $width..])
+                } else {
+                    sink.write_bytes(&value.to_le_bytes()[..$width])
+                }
+            }
+        }
+    )*};
+}
+
+impl_number!(
+    u16, 2, be_u16, le_u16, |_: &u16| true;
+    u32, 3, be_u24, le_u24, |value: &u32| *value < 1 << 24;
+    u32, 4, be_u32, le_u32, |_: &u32| true;
+    u64, 8, be_u64, le_u64, |_: &u64| true;
+    u128, 16, be_u128, le_u128, |_: &u128| true;
+    i16, 2, be_i16, le_i16, |_: &i16| true;
+    i32, 3, be_i24, le_i24, |value: &i32| (-(1 << 23)..1 << 23).contains(value);
+    i32, 4, be_i32, le_i32, |_: &i32| true;
+    i64, 8, be_i64, le_i64, |_: &i64| true;
+    i128, 16, be_i128, le_i128, |_: &i128| true;
+    f32, 4, be_f32, le_f32, |_: &f32| true;
+    f64, 8, be_f64, le_f64, |_: &f64| true;
+);
+
 /// Recognizes an unsigned 1 byte integer.
 ///
 /// *Complete version*: Returns an error if there is not enough input data.
@@ -1332,21 +1436,17 @@ where
 /// assert_eq!(le_u16.parse_peek(Partial::new(&b"\x01"[..])), Err(ErrMode::Incomplete(Needed::new(1))));
 /// ```
 #[inline(always)]
-pub fn u16<Input, Error>(endian: Endianness) -> impl Parser<Input, u16, Error>
+pub fn u16<Input, Error>(
+    endian: Endianness,
+) -> printable!(
+       Number<u16, Input, Error, 2>,
+       impl Parser<Input, u16, Error>
+   )
 where
     Input: StreamIsPartial + Stream<Token = u8>,
     Error: ParserError<Input>,
 {
-    move |input: &mut Input| {
-        match endian {
-            Endianness::Big => be_u16,
-            Endianness::Little => le_u16,
-            #[cfg(target_endian = "big")]
-            Endianness::Native => be_u16,
-            #[cfg(target_endian = "little")]
-            Endianness::Native => le_u16,
-        }
-    }(input)
+    Number::<u16, Input, Error, 2>::new(endian)
 }
 
 /// Recognizes an unsigned 3 byte integer
@@ -1403,21 +1503,17 @@ where
 /// assert_eq!(le_u24.parse_peek(Partial::new(&b"\x01"[..])), Err(ErrMode::Incomplete(Needed::new(2))));
 /// ```
 #[inline(always)]
-pub fn u24<Input, Error>(endian: Endianness) -> impl Parser<Input, u32, Error>
+pub fn u24<Input, Error>(
+    endian: Endianness,
+) -> printable!(
+       Number<u32, Input, Error, 3>,
+       impl Parser<Input, u32, Error>
+   )
 where
     Input: StreamIsPartial + Stream<Token = u8>,
     Error: ParserError<Input>,
 {
-    move |input: &mut Input| {
-        match endian {
-            Endianness::Big => be_u24,
-            Endianness::Little => le_u24,
-            #[cfg(target_endian = "big")]
-            Endianness::Native => be_u24,
-            #[cfg(target_endian = "little")]
-            Endianness::Native => le_u24,
-        }
-    }(input)
+    Number::<u32, Input, Error, 3>::new(endian)
 }
 
 /// Recognizes an unsigned 4 byte integer
@@ -1474,21 +1570,17 @@ where
 /// assert_eq!(le_u32.parse_peek(Partial::new(&b"\x01"[..])), Err(ErrMode::Incomplete(Needed::new(3))));
 /// ```
 #[inline(always)]
-pub fn u32<Input, Error>(endian: Endianness) -> impl Parser<Input, u32, Error>
+pub fn u32<Input, Error>(
+    endian: Endianness,
+) -> printable!(
+       Number<u32, Input, Error, 4>,
+       impl Parser<Input, u32, Error>
+   )
 where
     Input: StreamIsPartial + Stream<Token = u8>,
     Error: ParserError<Input>,
 {
-    move |input: &mut Input| {
-        match endian {
-            Endianness::Big => be_u32,
-            Endianness::Little => le_u32,
-            #[cfg(target_endian = "big")]
-            Endianness::Native => be_u32,
-            #[cfg(target_endian = "little")]
-            Endianness::Native => le_u32,
-        }
-    }(input)
+    Number::<u32, Input, Error, 4>::new(endian)
 }
 
 /// Recognizes an unsigned 8 byte integer
@@ -1545,21 +1637,17 @@ where
 /// assert_eq!(le_u64.parse_peek(Partial::new(&b"\x01"[..])), Err(ErrMode::Incomplete(Needed::new(7))));
 /// ```
 #[inline(always)]
-pub fn u64<Input, Error>(endian: Endianness) -> impl Parser<Input, u64, Error>
+pub fn u64<Input, Error>(
+    endian: Endianness,
+) -> printable!(
+       Number<u64, Input, Error, 8>,
+       impl Parser<Input, u64, Error>
+   )
 where
     Input: StreamIsPartial + Stream<Token = u8>,
     Error: ParserError<Input>,
 {
-    move |input: &mut Input| {
-        match endian {
-            Endianness::Big => be_u64,
-            Endianness::Little => le_u64,
-            #[cfg(target_endian = "big")]
-            Endianness::Native => be_u64,
-            #[cfg(target_endian = "little")]
-            Endianness::Native => le_u64,
-        }
-    }(input)
+    Number::<u64, Input, Error, 8>::new(endian)
 }
 
 /// Recognizes an unsigned 16 byte integer
@@ -1616,21 +1704,17 @@ where
 /// assert_eq!(le_u128.parse_peek(Partial::new(&b"\x01"[..])), Err(ErrMode::Incomplete(Needed::new(15))));
 /// ```
 #[inline(always)]
-pub fn u128<Input, Error>(endian: Endianness) -> impl Parser<Input, u128, Error>
+pub fn u128<Input, Error>(
+    endian: Endianness,
+) -> printable!(
+       Number<u128, Input, Error, 16>,
+       impl Parser<Input, u128, Error>
+   )
 where
     Input: StreamIsPartial + Stream<Token = u8>,
     Error: ParserError<Input>,
 {
-    move |input: &mut Input| {
-        match endian {
-            Endianness::Big => be_u128,
-            Endianness::Little => le_u128,
-            #[cfg(target_endian = "big")]
-            Endianness::Native => be_u128,
-            #[cfg(target_endian = "little")]
-            Endianness::Native => le_u128,
-        }
-    }(input)
+    Number::<u128, Input, Error, 16>::new(endian)
 }
 
 /// Recognizes a signed 1 byte integer
@@ -1746,21 +1830,17 @@ where
 /// assert_eq!(le_i16.parse_peek(Partial::new(&b"\x01"[..])), Err(ErrMode::Incomplete(Needed::new(1))));
 /// ```
 #[inline(always)]
-pub fn i16<Input, Error>(endian: Endianness) -> impl Parser<Input, i16, Error>
+pub fn i16<Input, Error>(
+    endian: Endianness,
+) -> printable!(
+       Number<i16, Input, Error, 2>,
+       impl Parser<Input, i16, Error>
+   )
 where
     Input: StreamIsPartial + Stream<Token = u8>,
     Error: ParserError<Input>,
 {
-    move |input: &mut Input| {
-        match endian {
-            Endianness::Big => be_i16,
-            Endianness::Little => le_i16,
-            #[cfg(target_endian = "big")]
-            Endianness::Native => be_i16,
-            #[cfg(target_endian = "little")]
-            Endianness::Native => le_i16,
-        }
-    }(input)
+    Number::<i16, Input, Error, 2>::new(endian)
 }
 
 /// Recognizes a signed 3 byte integer
@@ -1817,21 +1897,17 @@ where
 /// assert_eq!(le_i24.parse_peek(Partial::new(&b"\x01"[..])), Err(ErrMode::Incomplete(Needed::new(2))));
 /// ```
 #[inline(always)]
-pub fn i24<Input, Error>(endian: Endianness) -> impl Parser<Input, i32, Error>
+pub fn i24<Input, Error>(
+    endian: Endianness,
+) -> printable!(
+       Number<i32, Input, Error, 3>,
+       impl Parser<Input, i32, Error>
+   )
 where
     Input: StreamIsPartial + Stream<Token = u8>,
     Error: ParserError<Input>,
 {
-    move |input: &mut Input| {
-        match endian {
-            Endianness::Big => be_i24,
-            Endianness::Little => le_i24,
-            #[cfg(target_endian = "big")]
-            Endianness::Native => be_i24,
-            #[cfg(target_endian = "little")]
-            Endianness::Native => le_i24,
-        }
-    }(input)
+    Number::<i32, Input, Error, 3>::new(endian)
 }
 
 /// Recognizes a signed 4 byte integer
@@ -1888,21 +1964,17 @@ where
 /// assert_eq!(le_i32.parse_peek(Partial::new(&b"\x01"[..])), Err(ErrMode::Incomplete(Needed::new(3))));
 /// ```
 #[inline(always)]
-pub fn i32<Input, Error>(endian: Endianness) -> impl Parser<Input, i32, Error>
+pub fn i32<Input, Error>(
+    endian: Endianness,
+) -> printable!(
+       Number<i32, Input, Error, 4>,
+       impl Parser<Input, i32, Error>
+   )
 where
     Input: StreamIsPartial + Stream<Token = u8>,
     Error: ParserError<Input>,
 {
-    move |input: &mut Input| {
-        match endian {
-            Endianness::Big => be_i32,
-            Endianness::Little => le_i32,
-            #[cfg(target_endian = "big")]
-            Endianness::Native => be_i32,
-            #[cfg(target_endian = "little")]
-            Endianness::Native => le_i32,
-        }
-    }(input)
+    Number::<i32, Input, Error, 4>::new(endian)
 }
 
 /// Recognizes a signed 8 byte integer
@@ -1959,21 +2031,17 @@ where
 /// assert_eq!(le_i64.parse_peek(Partial::new(&b"\x01"[..])), Err(ErrMode::Incomplete(Needed::new(7))));
 /// ```
 #[inline(always)]
-pub fn i64<Input, Error>(endian: Endianness) -> impl Parser<Input, i64, Error>
+pub fn i64<Input, Error>(
+    endian: Endianness,
+) -> printable!(
+       Number<i64, Input, Error, 8>,
+       impl Parser<Input, i64, Error>
+   )
 where
     Input: StreamIsPartial + Stream<Token = u8>,
     Error: ParserError<Input>,
 {
-    move |input: &mut Input| {
-        match endian {
-            Endianness::Big => be_i64,
-            Endianness::Little => le_i64,
-            #[cfg(target_endian = "big")]
-            Endianness::Native => be_i64,
-            #[cfg(target_endian = "little")]
-            Endianness::Native => le_i64,
-        }
-    }(input)
+    Number::<i64, Input, Error, 8>::new(endian)
 }
 
 /// Recognizes a signed 16 byte integer
@@ -2030,21 +2098,17 @@ where
 /// assert_eq!(le_i128.parse_peek(Partial::new(&b"\x01"[..])), Err(ErrMode::Incomplete(Needed::new(15))));
 /// ```
 #[inline(always)]
-pub fn i128<Input, Error>(endian: Endianness) -> impl Parser<Input, i128, Error>
+pub fn i128<Input, Error>(
+    endian: Endianness,
+) -> printable!(
+       Number<i128, Input, Error, 16>,
+       impl Parser<Input, i128, Error>
+   )
 where
     Input: StreamIsPartial + Stream<Token = u8>,
     Error: ParserError<Input>,
 {
-    move |input: &mut Input| {
-        match endian {
-            Endianness::Big => be_i128,
-            Endianness::Little => le_i128,
-            #[cfg(target_endian = "big")]
-            Endianness::Native => be_i128,
-            #[cfg(target_endian = "little")]
-            Endianness::Native => le_i128,
-        }
-    }(input)
+    Number::<i128, Input, Error, 16>::new(endian)
 }
 
 /// Recognizes a big endian 4 bytes floating point number.
@@ -2290,21 +2354,17 @@ where
 /// assert_eq!(le_f32.parse_peek(Partial::new(&b"abc"[..])), Err(ErrMode::Incomplete(Needed::new(1))));
 /// ```
 #[inline(always)]
-pub fn f32<Input, Error>(endian: Endianness) -> impl Parser<Input, f32, Error>
+pub fn f32<Input, Error>(
+    endian: Endianness,
+) -> printable!(
+       Number<f32, Input, Error, 4>,
+       impl Parser<Input, f32, Error>
+   )
 where
     Input: StreamIsPartial + Stream<Token = u8>,
     Error: ParserError<Input>,
 {
-    move |input: &mut Input| {
-        match endian {
-            Endianness::Big => be_f32,
-            Endianness::Little => le_f32,
-            #[cfg(target_endian = "big")]
-            Endianness::Native => be_f32,
-            #[cfg(target_endian = "little")]
-            Endianness::Native => le_f32,
-        }
-    }(input)
+    Number::<f32, Input, Error, 4>::new(endian)
 }
 
 /// Recognizes an 8 byte floating point number
@@ -2361,21 +2421,669 @@ where
 /// assert_eq!(le_f64.parse_peek(Partial::new(&b"abc"[..])), Err(ErrMode::Incomplete(Needed::new(5))));
 /// ```
 #[inline(always)]
-pub fn f64<Input, Error>(endian: Endianness) -> impl Parser<Input, f64, Error>
+pub fn f64<Input, Error>(
+    endian: Endianness,
+) -> printable!(
+       Number<f64, Input, Error, 8>,
+       impl Parser<Input, f64, Error>
+   )
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input>,
+{
+    Number::<f64, Input, Error, 8>::new(endian)
+}
+
+/// Recognizes a big endian IEEE 754 half-precision (binary16) floating point number
+///
+/// The value is widened to an `f32`, which represents every `f16` exactly.
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// use winnow::binary::be_f16;
+///
+/// fn parser(s: &mut &[u8]) -> ModalResult<f32> {
+///       be_f16.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(&[0x4a, 0x40][..]), Ok((&b""[..], 12.5)));
+/// assert!(parser.parse_peek(&b"a"[..]).is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::be_f16;
+///
+/// fn parser(s: &mut Partial<&[u8]>) -> ModalResult<f32> {
+///       be_f16.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(Partial::new(&[0x4a, 0x40][..])), Ok((Partial::new(&b""[..]), 12.5)));
+/// assert_eq!(parser.parse_peek(Partial::new(&[0x01][..])), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+#[inline(always)]
+#[doc(alias = "half")]
+pub fn be_f16<Input, Error>(input: &mut Input) -> Result<f32, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input>,
+{
+    trace("be_f16", move |input: &mut Input| {
+        be_uint::<_, u16, _>(input, 2).map(f16_to_f32)
+    })
+    .parse_next(input)
+}
+
+/// Recognizes a little endian IEEE 754 half-precision (binary16) floating point number
+///
+/// The value is widened to an `f32`, which represents every `f16` exactly.
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// use winnow::binary::le_f16;
+///
+/// fn parser(s: &mut &[u8]) -> ModalResult<f32> {
+///       le_f16.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(&[0x40, 0x4a][..]), Ok((&b""[..], 12.5)));
+/// assert!(parser.parse_peek(&b"a"[..]).is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::le_f16;
+///
+/// fn parser(s: &mut Partial<&[u8]>) -> ModalResult<f32> {
+///       le_f16.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(Partial::new(&[0x40, 0x4a][..])), Ok((Partial::new(&b""[..]), 12.5)));
+/// assert_eq!(parser.parse_peek(Partial::new(&[0x01][..])), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+#[inline(always)]
+#[doc(alias = "half")]
+pub fn le_f16<Input, Error>(input: &mut Input) -> Result<f32, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input>,
+{
+    trace("le_f16", move |input: &mut Input| {
+        le_uint::<_, u16, _>(input, 2).map(f16_to_f32)
+    })
+    .parse_next(input)
+}
+
+/// Recognizes an IEEE 754 half-precision (binary16) floating point number
+///
+/// If the parameter is `winnow::binary::Endianness::Big`, parse a big endian f16 float,
+/// otherwise if `winnow::binary::Endianness::Little` parse a little endian f16 float.
+///
+/// The value is widened to an `f32`, which represents every `f16` exactly.
+///
+/// *Complete version*: returns an error if there is not enough input data
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// use winnow::binary::f16;
+///
+/// fn be_f16(input: &mut &[u8]) -> ModalResult<f32> {
+///     f16(winnow::binary::Endianness::Big).parse_next(input)
+/// };
+///
+/// assert_eq!(be_f16.parse_peek(&[0x4a, 0x40][..]), Ok((&b""[..], 12.5)));
+/// assert!(be_f16.parse_peek(&b"a"[..]).is_err());
+///
+/// fn le_f16(input: &mut &[u8]) -> ModalResult<f32> {
+///     f16(winnow::binary::Endianness::Little).parse_next(input)
+/// };
+///
+/// assert_eq!(le_f16.parse_peek(&[0x40, 0x4a][..]), Ok((&b""[..], 12.5)));
+/// assert!(le_f16.parse_peek(&b"a"[..]).is_err());
+/// ```
+#[inline(always)]
+#[doc(alias = "half")]
+pub fn f16<Input, Error>(endian: Endianness) -> HalfFloat<Input, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input>,
+{
+    HalfFloat::new(endian, false)
+}
+
+/// Recognizes a big endian bfloat16 floating point number
+///
+/// The value is widened to an `f32`, which represents every `bf16` exactly.
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// use winnow::binary::be_bf16;
+///
+/// fn parser(s: &mut &[u8]) -> ModalResult<f32> {
+///       be_bf16.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(&[0x41, 0x48][..]), Ok((&b""[..], 12.5)));
+/// assert!(parser.parse_peek(&b"a"[..]).is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::be_bf16;
+///
+/// fn parser(s: &mut Partial<&[u8]>) -> ModalResult<f32> {
+///       be_bf16.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(Partial::new(&[0x41, 0x48][..])), Ok((Partial::new(&b""[..]), 12.5)));
+/// assert_eq!(parser.parse_peek(Partial::new(&[0x01][..])), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+#[inline(always)]
+#[doc(alias = "bfloat16")]
+pub fn be_bf16<Input, Error>(input: &mut Input) -> Result<f32, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input>,
+{
+    trace("be_bf16", move |input: &mut Input| {
+        be_uint::<_, u16, _>(input, 2).map(bf16_to_f32)
+    })
+    .parse_next(input)
+}
+
+/// Recognizes a little endian bfloat16 floating point number
+///
+/// The value is widened to an `f32`, which represents every `bf16` exactly.
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// use winnow::binary::le_bf16;
+///
+/// fn parser(s: &mut &[u8]) -> ModalResult<f32> {
+///       le_bf16.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(&[0x48, 0x41][..]), Ok((&b""[..], 12.5)));
+/// assert!(parser.parse_peek(&b"a"[..]).is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::le_bf16;
+///
+/// fn parser(s: &mut Partial<&[u8]>) -> ModalResult<f32> {
+///       le_bf16.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(Partial::new(&[0x48, 0x41][..])), Ok((Partial::new(&b""[..]), 12.5)));
+/// assert_eq!(parser.parse_peek(Partial::new(&[0x01][..])), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+#[inline(always)]
+#[doc(alias = "bfloat16")]
+pub fn le_bf16<Input, Error>(input: &mut Input) -> Result<f32, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input>,
+{
+    trace("le_bf16", move |input: &mut Input| {
+        le_uint::<_, u16, _>(input, 2).map(bf16_to_f32)
+    })
+    .parse_next(input)
+}
+
+/// Recognizes a bfloat16 floating point number
+///
+/// If the parameter is `winnow::binary::Endianness::Big`, parse a big endian bf16 float,
+/// otherwise if `winnow::binary::Endianness::Little` parse a little endian bf16 float.
+///
+/// The value is widened to an `f32`, which represents every `bf16` exactly.
+///
+/// *Complete version*: returns an error if there is not enough input data
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// use winnow::binary::bf16;
+///
+/// fn be_bf16(input: &mut &[u8]) -> ModalResult<f32> {
+///     bf16(winnow::binary::Endianness::Big).parse_next(input)
+/// };
+///
+/// assert_eq!(be_bf16.parse_peek(&[0x41, 0x48][..]), Ok((&b""[..], 12.5)));
+/// assert!(be_bf16.parse_peek(&b"a"[..]).is_err());
+///
+/// fn le_bf16(input: &mut &[u8]) -> ModalResult<f32> {
+///     bf16(winnow::binary::Endianness::Little).parse_next(input)
+/// };
+///
+/// assert_eq!(le_bf16.parse_peek(&[0x48, 0x41][..]), Ok((&b""[..], 12.5)));
+/// assert!(le_bf16.parse_peek(&b"a"[..]).is_err());
+/// ```
+#[inline(always)]
+#[doc(alias = "bfloat16")]
+pub fn bf16<Input, Error>(endian: Endianness) -> HalfFloat<Input, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input>,
+{
+    HalfFloat::new(endian, true)
+}
+
+/// [`Parser`] implementation for [`f16()`] and [`bf16()`]
+pub struct HalfFloat<I, E> {
+    endian: Endianness,
+    brain: bool,
+    i: core::marker::PhantomData<I>,
+    e: core::marker::PhantomData<E>,
+}
+
+impl<I, E> HalfFloat<I, E> {
+    #[inline(always)]
+    fn new(endian: Endianness, brain: bool) -> Self {
+        Self {
+            endian,
+            brain,
+            i: Default::default(),
+            e: Default::default(),
+        }
+    }
+}
+
+impl<I, E> Parser<I, f32, E> for HalfFloat<I, E>
+where
+    I: StreamIsPartial + Stream<Token = u8>,
+    E: ParserError<I>,
+{
+    #[inline]
+    fn parse_next(&mut self, input: &mut I) -> Result<f32, E> {
+        match (self.brain, is_big(self.endian)) {
+            (false, true) => be_f16(input),
+            (false, false) => le_f16(input),
+            (true, true) => be_bf16(input),
+            (true, false) => le_bf16(input),
+        }
+    }
+}
+
+#[cfg(feature = "unstable-print")]
+impl<I, E> crate::print::Print<f32> for HalfFloat<I, E> {
+    fn print(
+        &self,
+        value: &f32,
+        sink: &mut dyn crate::print::Sink,
+    ) -> Result<(), crate::print::PrintError> {
+        let bits = if self.brain {
+            f32_to_bf16(*value)
+        } else {
+            f32_to_f16(*value)
+        }
+        .ok_or(crate::print::PrintError::Unprintable)?;
+        print_sized(u128::from(bits), 2, self.endian, sink)
+    }
+}
+
+/// Widen the bits of an IEEE 754 binary16 to an `f32`
+fn f16_to_f32(bits: u16) -> f32 {
+    let sign = u32::from(bits & 0x8000) << 16;
+    let exponent = u32::from(bits >> 10) & 0x1f;
+    let mut mantissa = u32::from(bits & 0x03ff);
+    let bits = match exponent {
+        0 if mantissa == 0 => sign,
+        0 => {
+            // Subnormal, normalized as the `f32` exponent range is larger
+            let mut exponent = 127 - 14;
+            while mantissa & 0x0400 == 0 {
+                mantissa <<= 1;
+                exponent -= 1;
+            }
+            sign | (exponent << 23) | ((mantissa & 0x03ff) << 13)
+        }
+        0x1f => sign | 0x7f80_0000 | (mantissa << 13),
+        _ => sign | ((exponent + 127 - 15) << 23) | (mantissa << 13),
+    };
+    f32::from_bits(bits)
+}
+
+/// Widen the bits of a bfloat16 to an `f32`
+fn bf16_to_f32(bits: u16) -> f32 {
+    f32::from_bits(u32::from(bits) << 16)
+}
+
+/// Narrow an `f32` to the bits of an IEEE 754 binary16, if it is exactly representable
+#[cfg(feature = "unstable-print")]
+fn f32_to_f16(value: f32) -> Option<u16> {
+    let bits = value.to_bits();
+    let sign = (bits >> 16) as u16 & 0x8000;
+    let exponent = (bits >> 23) & 0xff;
+    let mantissa = bits & 0x007f_ffff;
+    let narrowed = match exponent {
+        0 if mantissa == 0 => 0,
+        // `f32` subnormals are too small for binary16
+        0 => return None,
+        0xff if mantissa != 0 && mantissa >> 13 == 0 => return None,
+        0xff => 0x7c00 | (mantissa >> 13),
+        // Too large for binary16
+        143.. => return None,
+        // Subnormal in binary16
+        1..=112 => {
+            let mantissa = mantissa | 0x0080_0000;
+            let shift = 126 - exponent;
+            if 24 < shift || mantissa & ((1 << shift) - 1) != 0 {
+                return None;
+            }
+            mantissa >> shift
+        }
+        _ => {
+            if mantissa & 0x1fff != 0 {
+                return None;
+            }
+            ((exponent - 127 + 15) << 10) | (mantissa >> 13)
+        }
+    };
+    Some(sign | narrowed as u16)
+}
+
+/// Narrow an `f32` to the bits of a bfloat16, if it is exactly representable
+#[cfg(feature = "unstable-print")]
+fn f32_to_bf16(value: f32) -> Option<u16> {
+    let bits = value.to_bits();
+    (bits & 0xffff == 0).then_some((bits >> 16) as u16)
+}
+
+/// Recognizes an unsigned integer of `N` bytes, for any `N` in `1..=16`
+///
+/// The output can be any integer type the value fits in, failing otherwise.  For example,
+/// `uint::<5>` can be parsed into a `u64`.
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::{uint, Endianness};
+///
+/// fn be_u40(input: &mut &[u8]) -> ModalResult<u64> {
+///     uint::<5>(Endianness::Big).parse_next(input)
+/// }
+///
+/// assert_eq!(be_u40.parse_peek(&b"\x01\x02\x03\x04\x05abc"[..]), Ok((&b"abc"[..], 0x01_0203_0405)));
+/// assert!(be_u40.parse_peek(&b"\x01\x02"[..]).is_err());
+///
+/// fn le_u48(input: &mut Partial<&[u8]>) -> ModalResult<u64> {
+///     uint::<6>(Endianness::Little).parse_next(input)
+/// }
+///
+/// assert_eq!(le_u48.parse_peek(Partial::new(&b"\x01\x02\x03\x04\x05\x06"[..])), Ok((Partial::new(&b""[..]), 0x0605_0403_0201)));
+/// assert_eq!(le_u48.parse_peek(Partial::new(&b"\x01\x02"[..])), Err(ErrMode::Incomplete(Needed::new(4))));
+/// ```
+#[inline(always)]
+pub fn uint<const N: usize>(endian: Endianness) -> SizedUint<N> {
+    #[allow(clippy::let_unit_value)]
+    let () = SizedUint::<N>::VALID;
+    SizedUint {
+        endian,
+        call_count: Default::default(),
+    }
+}
+
+/// Recognizes a signed integer of `N` bytes, for any `N` in `1..=16`
+///
+/// The value is sign-extended and the output can be any integer type the value fits in, failing
+/// otherwise.  For example, `int::<5>` can be parsed into an `i64`.
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::InputError, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::{int, Endianness};
+///
+/// fn be_i40(input: &mut &[u8]) -> ModalResult<i64> {
+///     int::<5>(Endianness::Big).parse_next(input)
+/// }
+///
+/// assert_eq!(be_i40.parse_peek(&b"\xff\xff\xff\xff\xfeabc"[..]), Ok((&b"abc"[..], -2)));
+/// assert_eq!(be_i40.parse_peek(&b"\x7f\xff\xff\xff\xff"[..]), Ok((&b""[..], (1 << 39) - 1)));
+/// assert!(be_i40.parse_peek(&b"\x01\x02"[..]).is_err());
+///
+/// fn le_i48(input: &mut Partial<&[u8]>) -> ModalResult<i64> {
+///     int::<6>(Endianness::Little).parse_next(input)
+/// }
+///
+/// assert_eq!(le_i48.parse_peek(Partial::new(&b"\x00\x00\x00\x00\x00\x80"[..])), Ok((Partial::new(&b""[..]), -(1 << 47))));
+/// assert_eq!(le_i48.parse_peek(Partial::new(&b"\x01\x02"[..])), Err(ErrMode::Incomplete(Needed::new(4))));
+/// ```
+#[inline(always)]
+pub fn int<const N: usize>(endian: Endianness) -> SizedInt<N> {
+    #[allow(clippy::let_unit_value)]
+    let () = SizedInt::<N>::VALID;
+    SizedInt {
+        endian,
+        call_count: Default::default(),
+    }
+}
+
+/// [`Parser`] implementation for [`uint`]
+#[derive(Copy, Clone, Debug)]
+pub struct SizedUint<const N: usize> {
+    endian: Endianness,
+    call_count: CallCount,
+}
+
+impl<const N: usize> SizedUint<N> {
+    const VALID: () = assert!(0 < N && N <= 16, "`uint` supports 1 to 16 bytes");
+}
+
+impl<I, O, E, const N: usize> Parser<I, O, E> for SizedUint<N>
+where
+    I: StreamIsPartial + Stream<Token = u8>,
+    O: TryFrom<u128>,
+    E: ParserError<I>,
+{
+    #[inline]
+    fn parse_next(&mut self, input: &mut I) -> Result<O, E> {
+        let endian = self.endian;
+        trace_call(
+            "uint",
+            &mut self.call_count,
+            |input: &mut I| {
+                let start = input.checkpoint();
+                let bits = sized_uint(input, N, endian)?;
+                O::try_from(bits).map_err(|_| {
+                    input.reset(&start);
+                    ParserError::from_input(input)
+                })
+            },
+            input,
+        )
+    }
+}
+
+#[cfg(feature = "unstable-print")]
+impl<O, const N: usize> crate::print::Print<O> for SizedUint<N>
+where
+    O: Copy + TryInto<u128>,
+{
+    fn print(
+        &self,
+        value: &O,
+        sink: &mut dyn crate::print::Sink,
+    ) -> Result<(), crate::print::PrintError> {
+        let value: u128 = (*value)
+            .try_into()
+            .map_err(|_| crate::print::PrintError::Unprintable)?;
+        if N < 16 && value >> (N * 8) != 0 {
+            return Err(crate::print::PrintError::Unprintable);
+        }
+        print_sized(value, N, self.endian, sink)
+    }
+}
+
+/// [`Parser`] implementation for [`int`]
+#[derive(Copy, Clone, Debug)]
+pub struct SizedInt<const N: usize> {
+    endian: Endianness,
+    call_count: CallCount,
+}
+
+impl<const N: usize> SizedInt<N> {
+    const VALID: () = assert!(0 < N && N <= 16, "`int` supports 1 to 16 bytes");
+}
+
+impl<I, O, E, const N: usize> Parser<I, O, E> for SizedInt<N>
+where
+    I: StreamIsPartial + Stream<Token = u8>,
+    O: TryFrom<i128>,
+    E: ParserError<I>,
+{
+    #[inline]
+    fn parse_next(&mut self, input: &mut I) -> Result<O, E> {
+        let endian = self.endian;
+        trace_call(
+            "int",
+            &mut self.call_count,
+            |input: &mut I| {
+                let start = input.checkpoint();
+                let bits = sized_uint(input, N, endian)?;
+                let unused = u128::BITS as usize - N * 8;
+                let value = ((bits << unused) as i128) >> unused;
+                O::try_from(value).map_err(|_| {
+                    input.reset(&start);
+                    ParserError::from_input(input)
+                })
+            },
+            input,
+        )
+    }
+}
+
+#[cfg(feature = "unstable-print")]
+impl<O, const N: usize> crate::print::Print<O> for SizedInt<N>
+where
+    O: Copy + TryInto<i128>,
+{
+    fn print(
+        &self,
+        value: &O,
+        sink: &mut dyn crate::print::Sink,
+    ) -> Result<(), crate::print::PrintError> {
+        let value: i128 = (*value)
+            .try_into()
+            .map_err(|_| crate::print::PrintError::Unprintable)?;
+        let unused = u128::BITS as usize - N * 8;
+        if (value << unused) >> unused != value {
+            return Err(crate::print::PrintError::Unprintable);
+        }
+        print_sized(value as u128, N, self.endian, sink)
+    }
+}
+
+#[cfg(feature = "unstable-print")]
+fn print_sized(
+    value: u128,
+    bound: usize,
+    endian: Endianness,
+    sink: &mut dyn crate::print::Sink,
+) -> Result<(), crate::print::PrintError> {
+    if is_big(endian) {
+        sink.write_bytes(&value.to_be_bytes()[16 - bound..])
+    } else {
+        sink.write_bytes(&value.to_le_bytes()[..bound])
+    }
+}
+
+#[inline(always)]
+fn is_big(endian: Endianness) -> bool {
+    match endian {
+        Endianness::Big => true,
+        Endianness::Little => false,
+        Endianness::Native => cfg!(target_endian = "big"),
+    }
+}
+
+fn sized_uint<Input, Error>(
+    input: &mut Input,
+    bound: usize,
+    endian: Endianness,
+) -> Result<u128, Error>
 where
     Input: StreamIsPartial + Stream<Token = u8>,
     Error: ParserError<Input>,
 {
-    move |input: &mut Input| {
-        match endian {
-            Endianness::Big => be_f64,
-            Endianness::Little => le_f64,
-            #[cfg(target_endian = "big")]
-            Endianness::Native => be_f64,
-            #[cfg(target_endian = "little")]
-            Endianness::Native => le_f64,
+    let big = is_big(endian);
+    match input.offset_at(bound) {
+        Ok(offset) => {
+            let mut res = 0u128;
+            for (index, (_, byte)) in input.iter_offsets().take(offset).enumerate() {
+                if big {
+                    res = (res << 8) | u128::from(byte);
+                } else {
+                    res |= u128::from(byte) << (8 * index);
+                }
+            }
+            input.next_slice(offset);
+            Ok(res)
         }
-    }(input)
+        Err(e) if <Input as StreamIsPartial>::is_partial_supported() && input.is_partial() => {
+            Err(ParserError::incomplete(input, e))
+        }
+        Err(_needed) => Err(ParserError::from_input(input)),
+    }
 }
 
 /// Get a length-prefixed slice ([TLV](https://en.wikipedia.org/wiki/Type-length-value))
@@ -2481,7 +3189,7 @@ where
     })
 }
 
-/// [`Accumulate`] a length-prefixed sequence of values ([TLV](https://en.wikipedia.org/wiki/Type-length-value))
+/// [Accumulate][TryAccumulate] a length-prefixed sequence of values ([TLV](https://en.wikipedia.org/wiki/Type-length-value))
 ///
 /// If the length represents token counts, see instead [`length_take`]
 ///
@@ -2520,7 +3228,7 @@ pub fn length_repeat<Input, Output, Accumulator, Count, Error, CountParser, Pars
 where
     Input: Stream,
     Count: ToUsize,
-    Accumulator: Accumulate<Output>,
+    Accumulator: TryAccumulate<Output>,
     CountParser: Parser<Input, Count, Error>,
     ParseNext: Parser<Input, Output, Error>,
     Error: ParserError<Input>,
@@ -0,0 +1,343 @@
+use crate::combinator::trace;
+use crate::error::{FromExternalError, Needed, ParserError};
+use crate::lib::std::fmt;
+#[cfg(feature = "alloc")]
+use crate::stream::Stateful;
+use crate::stream::{Seek, Stream, StreamIsPartial};
+use crate::Parser;
+use crate::Result;
+
+/// Run a parser at an absolute `offset` of the input, without consuming anything
+///
+/// This is useful for formats, like ELF, ZIP or TrueType, with tables pointing to absolute
+/// offsets.  For offsets relative to the current position, add [`Seek::seek_offset`].
+///
+/// To catch cycles in graphs of offsets, see [`visit`].
+///
+/// *Complete version*: Returns a [`SeekError::OutOfRange`] if `offset` is past the end of the
+/// input.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if `offset` is past the available data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::{at_offset, length_take, u8};
+/// use winnow::stream::LocatingSlice;
+///
+/// type Stream<'i> = LocatingSlice<&'i [u8]>;
+///
+/// /// An offset to a length-prefixed name, followed by a value
+/// fn entry<'i>(input: &mut Stream<'i>) -> ModalResult<(&'i [u8], u8)> {
+///     let offset = u8.parse_next(input)?;
+///     let name = at_offset(offset.into(), length_take(u8)).parse_next(input)?;
+///     let value = u8.parse_next(input)?;
+///     Ok((name, value))
+/// }
+///
+/// let input = LocatingSlice::new(&b"\x03\x2a!\x02hi"[..]);
+/// let (rest, output) = entry.parse_peek(input).unwrap();
+/// assert_eq!(output, (&b"hi"[..], 0x2a));
+/// assert_eq!(*rest, &b"!\x02hi"[..]);
+///
+/// assert!(entry.parse_peek(LocatingSlice::new(&b"\x10\x2a"[..])).is_err());
+/// ```
+#[doc(alias = "seek")]
+#[doc(alias = "fseek")]
+pub fn at_offset<Input, Output, Error, ParseNext>(
+    offset: usize,
+    mut parser: ParseNext,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: Seek + StreamIsPartial,
+    ParseNext: Parser<Input, Output, Error>,
+    Error: ParserError<Input> + FromExternalError<Input, SeekError>,
+{
+    trace("at_offset", move |input: &mut Input| {
+        check_range(input, offset)?;
+        let start = input.checkpoint();
+        input.seek_to(offset);
+        let res = parser.parse_next(input);
+        input.reset(&start);
+        res
+    })
+}
+
+/// Skip to an absolute `offset` of the input, returning the skipped slice
+///
+/// *Complete version*: Returns a [`SeekError::OutOfRange`] if `offset` is past the end of the
+/// input and a [`SeekError::Behind`] if `offset` was already consumed.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if `offset` is past the available data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::{skip_to, u8};
+/// use winnow::stream::LocatingSlice;
+///
+/// fn parser<'i>(input: &mut LocatingSlice<&'i [u8]>) -> ModalResult<(u8, &'i [u8])> {
+///     (u8, skip_to(4)).parse_next(input)
+/// }
+///
+/// let (rest, output) = parser.parse_peek(LocatingSlice::new(&b"\x01abcd"[..])).unwrap();
+/// assert_eq!(output, (1, &b"abc"[..]));
+/// assert_eq!(*rest, &b"d"[..]);
+///
+/// assert!(parser.parse_peek(LocatingSlice::new(&b"\x01ab"[..])).is_err());
+/// ```
+pub fn skip_to<Input, Error>(offset: usize) -> impl Parser<Input, <Input as Stream>::Slice, Error>
+where
+    Input: Seek + StreamIsPartial,
+    Error: ParserError<Input> + FromExternalError<Input, SeekError>,
+{
+    trace("skip_to", move |input: &mut Input| {
+        let current = input.seek_offset();
+        if offset < current {
+            return Err(Error::from_external_error(
+                input,
+                SeekError::Behind { offset, current },
+            ));
+        }
+        check_range(input, offset)?;
+        Ok(input.next_slice(offset - current))
+    })
+}
+
+/// Skip to the next offset of the input that is a multiple of `alignment`, returning the skipped
+/// slice
+///
+/// Offsets are from the start of the input.
+///
+/// *Complete version*: Returns a [`SeekError::OutOfRange`] if the aligned offset is past the end
+/// of the input.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if the aligned offset is past the available data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::{align, be_u32, u8};
+/// use winnow::stream::LocatingSlice;
+///
+/// fn parser(input: &mut LocatingSlice<&[u8]>) -> ModalResult<(u8, u32)> {
+///     (u8, align(4).void(), be_u32).map(|(tag, (), value)| (tag, value)).parse_next(input)
+/// }
+///
+/// let input = LocatingSlice::new(&b"\x01\x00\x00\x00\x00\x00\x00\x2a"[..]);
+/// assert_eq!(parser.parse_peek(input).map(|(_, output)| output), Ok((1, 42)));
+/// ```
+pub fn align<Input, Error>(alignment: usize) -> impl Parser<Input, <Input as Stream>::Slice, Error>
+where
+    Input: Seek + StreamIsPartial,
+    Error: ParserError<Input> + FromExternalError<Input, SeekError>,
+{
+    trace("align", move |input: &mut Input| {
+        if alignment == 0 {
+            return Err(ParserError::assert(
+                input,
+                "alignment should be greater than zero",
+            ));
+        }
+        let current = input.seek_offset();
+        let padding = (alignment - current % alignment) % alignment;
+        check_range(input, current + padding)?;
+        Ok(input.next_slice(padding))
+    })
+}
+
+/// Run a parser and skip past what's left of `len` tokens
+///
+/// This is useful for fixed-size records whose contents may be shorter than the record.
+///
+/// *Complete version*: Returns a [`SeekError::Overrun`] if `parser` consumes more than `len`
+/// tokens and an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::pad_to;
+/// use winnow::token::take_till;
+///
+/// fn name<'i>(input: &mut &'i [u8]) -> ModalResult<&'i [u8]> {
+///     pad_to(8, take_till(0.., 0)).parse_next(input)
+/// }
+///
+/// assert_eq!(name.parse_peek(&b"winnow\0\0rest"[..]), Ok((&b"rest"[..], &b"winnow"[..])));
+/// assert!(name.parse_peek(&b"long name\0"[..]).is_err());
+/// ```
+pub fn pad_to<Input, Output, Error, ParseNext>(
+    len: usize,
+    mut parser: ParseNext,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: Stream + StreamIsPartial,
+    ParseNext: Parser<Input, Output, Error>,
+    Error: ParserError<Input> + FromExternalError<Input, SeekError>,
+{
+    trace("pad_to", move |input: &mut Input| {
+        let start = input.checkpoint();
+        let before = input.eof_offset();
+        let output = parser.parse_next(input)?;
+        let consumed = before - input.eof_offset();
+        if len < consumed {
+            input.reset(&start);
+            return Err(Error::from_external_error(
+                input,
+                SeekError::Overrun { len, consumed },
+            ));
+        }
+        crate::token::take(len - consumed).parse_next(input)?;
+        Ok(output)
+    })
+}
+
+/// Run a parser at an absolute `offset` of the input, like [`at_offset`], failing on cycles
+///
+/// The offsets being parsed are tracked in a [`VisitGuard`] of the [`Stateful`] state, returning
+/// a [`SeekError::Cycle`] when an offset is reached again from within itself.  Offsets can still
+/// be parsed more than once from different places, like in a DAG.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::{u8, visit, VisitGuard};
+/// use winnow::stream::{LocatingSlice, Stateful};
+///
+/// type Stream<'i> = Stateful<LocatingSlice<&'i [u8]>, VisitGuard>;
+///
+/// /// A linked list of values, with an offset of `0` ending the list
+/// fn list(input: &mut Stream<'_>) -> ModalResult<Vec<u8>> {
+///     let value = u8.parse_next(input)?;
+///     let next = u8.parse_next(input)?;
+///     let mut values = vec![value];
+///     if next != 0 {
+///         values.extend(visit(next.into(), list).parse_next(input)?);
+///     }
+///     Ok(values)
+/// }
+///
+/// let input = |bytes| Stateful {
+///     input: LocatingSlice::new(bytes),
+///     state: VisitGuard::new(),
+/// };
+/// let output = list.parse_peek(input(&b"\x01\x04!!\x02\x06\x03\x00"[..])).map(|(_, o)| o);
+/// assert_eq!(output, Ok(vec![1, 2, 3]));
+/// // The second node points back to itself
+/// assert!(list.parse_peek(input(&b"\x01\x02\x02\x02"[..])).is_err());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn visit<I, S, Output, Error, ParseNext>(
+    offset: usize,
+    mut parser: ParseNext,
+) -> impl Parser<Stateful<I, S>, Output, Error>
+where
+    I: Seek + StreamIsPartial,
+    S: crate::lib::std::borrow::BorrowMut<VisitGuard> + fmt::Debug,
+    ParseNext: Parser<Stateful<I, S>, Output, Error>,
+    Error: ParserError<Stateful<I, S>> + FromExternalError<Stateful<I, S>, SeekError>,
+{
+    trace("visit", move |input: &mut Stateful<I, S>| {
+        if input.state.borrow().active.contains(&offset) {
+            return Err(Error::from_external_error(
+                input,
+                SeekError::Cycle { offset },
+            ));
+        }
+        input.state.borrow_mut().active.push(offset);
+        let res = at_offset(offset, parser.by_ref()).parse_next(input);
+        input.state.borrow_mut().active.pop();
+        res
+    })
+}
+
+/// Offsets being parsed by [`visit`], to catch cycles
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VisitGuard {
+    active: crate::lib::std::vec::Vec<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl VisitGuard {
+    /// Start without any offsets being parsed
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Invalid offset, from [`at_offset`] and related parsers
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SeekError {
+    /// The offset is past the end of the input
+    OutOfRange {
+        /// Requested offset
+        offset: usize,
+        /// Length of the input
+        len: usize,
+    },
+    /// The offset was already consumed
+    Behind {
+        /// Requested offset
+        offset: usize,
+        /// Offset of the current position
+        current: usize,
+    },
+    /// The parser consumed more than the padded length
+    Overrun {
+        /// Padded length
+        len: usize,
+        /// Length consumed by the parser
+        consumed: usize,
+    },
+    /// The offset is already being parsed
+    Cycle {
+        /// Requested offset
+        offset: usize,
+    },
+}
+
+impl fmt::Display for SeekError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfRange { offset, len } => {
+                write!(f, "offset {offset} is past the end of the input ({len})")
+            }
+            Self::Behind { offset, current } => {
+                write!(f, "offset {offset} is before the current offset {current}")
+            }
+            Self::Overrun { len, consumed } => {
+                write!(f, "consumed {consumed}, more than the padded length {len}")
+            }
+            Self::Cycle { offset } => write!(f, "offset {offset} is already being parsed"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SeekError {}
+
+fn check_range<Input, Error>(input: &Input, offset: usize) -> Result<(), Error>
+where
+    Input: Seek + StreamIsPartial,
+    Error: ParserError<Input> + FromExternalError<Input, SeekError>,
+{
+    let len = input.seek_len();
+    if offset <= len {
+        Ok(())
+    } else if input.is_partial() {
+        Err(ParserError::incomplete(input, Needed::new(offset - len)))
+    } else {
+        Err(Error::from_external_error(
+            input,
+            SeekError::OutOfRange { offset, len },
+        ))
+    }
+}
@@ -3716,3 +3716,650 @@ Ok(
         );
     }
 }
+
+mod varint {
+    use super::*;
+
+    use crate::error::{ErrMode, ExternalError, Needed};
+    use crate::Partial;
+
+    type Error = ExternalError<VarintError>;
+
+    fn parse<O: Varint>(input: &[u8]) -> Result<(&[u8], O), ErrMode<Error>> {
+        leb128.parse_peek(input)
+    }
+
+    #[test]
+    fn unsigned() {
+        assert_eq!(parse::<u16>(b"\x00"), Ok((&b""[..], 0)));
+        assert_eq!(parse::<u16>(b"\x7f\x01"), Ok((&b"\x01"[..], 127)));
+        assert_eq!(parse::<u16>(b"\x80\x01"), Ok((&b""[..], 128)));
+        assert_eq!(parse::<u16>(b"\xff\xff\x03"), Ok((&b""[..], u16::MAX)));
+        assert_eq!(
+            parse::<u32>(b"\xff\xff\xff\xff\x0f"),
+            Ok((&b""[..], u32::MAX))
+        );
+        assert_eq!(
+            parse::<u64>(b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\x01"),
+            Ok((&b""[..], u64::MAX))
+        );
+        let mut max = [0xff; 19];
+        max[18] = 0x03;
+        assert_eq!(parse::<u128>(&max), Ok((&b""[..], u128::MAX)));
+    }
+
+    #[test]
+    fn signed() {
+        assert_eq!(parse::<i16>(b"\x00"), Ok((&b""[..], 0)));
+        assert_eq!(parse::<i16>(b"\x7f"), Ok((&b""[..], -1)));
+        assert_eq!(parse::<i16>(b"\x3f"), Ok((&b""[..], 63)));
+        assert_eq!(parse::<i16>(b"\xc0\x00"), Ok((&b""[..], 64)));
+        assert_eq!(parse::<i16>(b"\x40"), Ok((&b""[..], -64)));
+        assert_eq!(parse::<i16>(b"\x80\x80\x7e"), Ok((&b""[..], i16::MIN)));
+        assert_eq!(parse::<i16>(b"\xff\xff\x01"), Ok((&b""[..], i16::MAX)));
+        assert_eq!(
+            parse::<i32>(b"\x80\x80\x80\x80\x78"),
+            Ok((&b""[..], i32::MIN))
+        );
+        assert_eq!(
+            parse::<i64>(b"\x80\x80\x80\x80\x80\x80\x80\x80\x80\x7f"),
+            Ok((&b""[..], i64::MIN))
+        );
+        let mut min = [0x80; 19];
+        min[18] = 0x7e;
+        assert_eq!(parse::<i128>(&min), Ok((&b""[..], i128::MIN)));
+    }
+
+    #[test]
+    fn overflow() {
+        let overflow = ErrMode::Backtrack(ExternalError(Some(VarintError::Overflow)));
+        assert_eq!(parse::<u16>(b"\xff\xff\x04").unwrap_err(), overflow);
+        assert_eq!(parse::<u32>(b"\xff\xff\xff\xff\x1f").unwrap_err(), overflow);
+        assert_eq!(parse::<i16>(b"\xff\xff\x02").unwrap_err(), overflow);
+        assert_eq!(parse::<i16>(b"\x80\x80\x7d").unwrap_err(), overflow);
+        assert_eq!(
+            parse::<u64>(b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\x02").unwrap_err(),
+            overflow
+        );
+    }
+
+    #[test]
+    fn overlong() {
+        let overlong = ErrMode::Backtrack(ExternalError(Some(VarintError::Overlong)));
+        assert_eq!(parse::<u32>(b"\x80\x00").unwrap_err(), overlong);
+        assert_eq!(parse::<u32>(b"\x81\x80\x00").unwrap_err(), overlong);
+        assert_eq!(parse::<i32>(b"\xff\x7f").unwrap_err(), overlong);
+        assert_eq!(parse::<i32>(b"\x80\x00").unwrap_err(), overlong);
+        assert_eq!(parse::<u16>(b"\x80\x80\x80\x00").unwrap_err(), overlong);
+        // Needed sign extension isn't redundant
+        assert_eq!(parse::<i32>(b"\xc0\x00"), Ok((&b""[..], 64)));
+        assert_eq!(parse::<i32>(b"\xbf\x7f"), Ok((&b""[..], -65)));
+    }
+
+    #[test]
+    fn zigzag_values() {
+        fn parse(input: &[u8]) -> Result<(&[u8], i32), ErrMode<Error>> {
+            zigzag.parse_peek(input)
+        }
+        assert_eq!(parse(b"\x00"), Ok((&b""[..], 0)));
+        assert_eq!(parse(b"\x01"), Ok((&b""[..], -1)));
+        assert_eq!(parse(b"\x02"), Ok((&b""[..], 1)));
+        assert_eq!(parse(b"\x03"), Ok((&b""[..], -2)));
+        assert_eq!(parse(b"\xfe\xff\xff\xff\x0f"), Ok((&b""[..], i32::MAX)));
+        assert_eq!(parse(b"\xff\xff\xff\xff\x0f"), Ok((&b""[..], i32::MIN)));
+        assert_eq!(
+            parse(b"\xff\xff\xff\xff\x1f"),
+            Err(ErrMode::Backtrack(ExternalError(Some(
+                VarintError::Overflow
+            ))))
+        );
+    }
+
+    #[test]
+    fn partial() {
+        let input = Partial::new(&b"\x80\x80"[..]);
+        assert_eq!(
+            leb128::<_, u32, ErrMode<Error>>.parse_peek(input),
+            Err(ErrMode::Incomplete(Needed::new(1)))
+        );
+        assert_eq!(
+            zigzag::<_, i64, ErrMode<Error>>.parse_peek(Partial::new(&b""[..])),
+            Err(ErrMode::Incomplete(Needed::new(1)))
+        );
+        assert_eq!(
+            parse::<u32>(b"\x80\x80"),
+            Err(ErrMode::Backtrack(ExternalError(None)))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn length() {
+        let input = &b"\x02\x01\x7f"[..];
+        let result: Result<_, ErrMode<Error>> =
+            length_repeat(leb128::<_, usize, _>, leb128::<_, i16, _>).parse_peek(input);
+        assert_eq!(result, Ok((&b""[..], crate::lib::std::vec![1, -1])));
+    }
+
+    #[cfg(feature = "alloc")]
+    proptest::proptest! {
+        #[test]
+        #[cfg_attr(miri, ignore)] // See https://github.com/AltSysrq/proptest/issues/253
+        fn round_trip(value: i64) {
+            let mut sleb = crate::lib::std::vec::Vec::new();
+            let mut rest = value;
+            loop {
+                let byte = (rest & 0x7f) as u8;
+                rest >>= 7;
+                if (rest == 0 && byte & 0x40 == 0) || (rest == -1 && byte & 0x40 != 0) {
+                    sleb.push(byte);
+                    break;
+                }
+                sleb.push(byte | 0x80);
+            }
+            proptest::prop_assert_eq!(parse::<i64>(&sleb), Ok((&b""[..], value)));
+
+            let mut uleb = crate::lib::std::vec::Vec::new();
+            let mut rest = ((value << 1) ^ (value >> 63)) as u64;
+            while 0x80 <= rest {
+                uleb.push(rest as u8 | 0x80);
+                rest >>= 7;
+            }
+            uleb.push(rest as u8);
+            proptest::prop_assert_eq!(zigzag::<_, i64, ErrMode<Error>>.parse_peek(&uleb[..]), Ok((&b""[..], value)));
+        }
+    }
+}
+
+mod seek {
+    use super::*;
+
+    use crate::error::{ErrMode, ExternalError, Needed};
+    use crate::stream::{LocatingSlice, Stream};
+    use crate::Partial;
+
+    type Error = ExternalError<SeekError>;
+
+    fn seek_error(e: SeekError) -> ErrMode<Error> {
+        ErrMode::Backtrack(ExternalError(Some(e)))
+    }
+
+    #[test]
+    fn at_offset_restores_position() {
+        let mut input = LocatingSlice::new(&b"abcdef"[..]);
+        let _ = input.next_slice(2);
+
+        let output = at_offset::<_, _, ErrMode<Error>, _>(4, be_u16).parse_next(&mut input);
+        assert_eq!(output, Ok(0x6566));
+        assert_eq!(*input, &b"cdef"[..]);
+
+        let output = at_offset::<_, _, ErrMode<Error>, _>(0, be_u8).parse_next(&mut input);
+        assert_eq!(output, Ok(b'a'));
+        assert_eq!(*input, &b"cdef"[..]);
+    }
+
+    #[test]
+    fn at_offset_wrapped() {
+        use crate::stream::Trivia;
+
+        fn space(input: &mut LocatingSlice<&[u8]>) -> crate::ModalResult<()> {
+            crate::token::take_while(0.., b' ').void().parse_next(input)
+        }
+
+        let input = Trivia::new(LocatingSlice::new(&b"\x03\x2a"[..]), space);
+        assert_eq!(
+            at_offset::<_, _, ErrMode<Error>, _>(1, be_u8)
+                .parse_peek(input)
+                .map(|(_, o)| o),
+            Ok(b'*')
+        );
+
+        #[cfg(feature = "unstable-recover")]
+        #[cfg(feature = "std")]
+        {
+            use crate::stream::Recoverable;
+
+            let input = Recoverable::<_, Error>::new(LocatingSlice::new(&b"\x03\x2a"[..]));
+            assert_eq!(
+                at_offset::<_, _, ErrMode<Error>, _>(1, be_u8)
+                    .parse_peek(input)
+                    .map(|(_, o)| o),
+                Ok(b'*')
+            );
+        }
+    }
+
+    #[test]
+    fn at_offset_out_of_range() {
+        let input = LocatingSlice::new(&b"abc"[..]);
+        assert_eq!(
+            at_offset::<_, _, ErrMode<Error>, _>(4, be_u8)
+                .parse_peek(input)
+                .unwrap_err(),
+            seek_error(SeekError::OutOfRange { offset: 4, len: 3 })
+        );
+        // The end of the input is in range
+        assert_eq!(
+            at_offset::<_, _, ErrMode<Error>, _>(3, be_u8)
+                .parse_peek(input)
+                .unwrap_err(),
+            ErrMode::Backtrack(ExternalError(None))
+        );
+
+        let input = Partial::new(LocatingSlice::new(&b"abc"[..]));
+        assert_eq!(
+            at_offset::<_, _, ErrMode<Error>, _>(5, be_u8)
+                .parse_peek(input)
+                .unwrap_err(),
+            ErrMode::Incomplete(Needed::new(2))
+        );
+    }
+
+    #[test]
+    fn skip_to_offsets() {
+        let mut input = LocatingSlice::new(&b"abcdef"[..]);
+        assert_eq!(
+            skip_to::<_, ErrMode<Error>>(2).parse_next(&mut input),
+            Ok(&b"ab"[..])
+        );
+        assert_eq!(
+            skip_to::<_, ErrMode<Error>>(2).parse_next(&mut input),
+            Ok(&b""[..])
+        );
+        assert_eq!(
+            skip_to::<_, ErrMode<Error>>(1)
+                .parse_next(&mut input)
+                .unwrap_err(),
+            seek_error(SeekError::Behind {
+                offset: 1,
+                current: 2
+            })
+        );
+        assert_eq!(
+            skip_to::<_, ErrMode<Error>>(7)
+                .parse_next(&mut input)
+                .unwrap_err(),
+            seek_error(SeekError::OutOfRange { offset: 7, len: 6 })
+        );
+        assert_eq!(*input, &b"cdef"[..]);
+    }
+
+    #[test]
+    fn align_offsets() {
+        let mut input = LocatingSlice::new(&b"abcdefgh"[..]);
+        assert_eq!(
+            align::<_, ErrMode<Error>>(4).parse_next(&mut input),
+            Ok(&b""[..])
+        );
+        let _ = input.next_slice(1);
+        assert_eq!(
+            align::<_, ErrMode<Error>>(4).parse_next(&mut input),
+            Ok(&b"bcd"[..])
+        );
+        let _ = input.next_slice(1);
+        assert_eq!(
+            align::<_, ErrMode<Error>>(8).parse_next(&mut input),
+            Ok(&b"fgh"[..])
+        );
+
+        let mut input = Partial::new(LocatingSlice::new(&b"abcde"[..]));
+        let _ = input.next_slice(1);
+        assert_eq!(
+            align::<_, ErrMode<Error>>(8)
+                .parse_next(&mut input)
+                .unwrap_err(),
+            ErrMode::Incomplete(Needed::new(3))
+        );
+    }
+
+    #[test]
+    fn pad_to_record() {
+        assert_eq!(
+            pad_to::<_, _, ErrMode<Error>, _>(4, be_u16).parse_peek(&b"abcdef"[..]),
+            Ok((&b"ef"[..], 0x6162))
+        );
+        assert_eq!(
+            pad_to::<_, _, ErrMode<Error>, _>(1, be_u16)
+                .parse_peek(&b"abcdef"[..])
+                .unwrap_err(),
+            seek_error(SeekError::Overrun {
+                len: 1,
+                consumed: 2
+            })
+        );
+        assert_eq!(
+            pad_to::<_, _, ErrMode<Error>, _>(4, be_u16)
+                .parse_peek(Partial::new(&b"abc"[..]))
+                .unwrap_err(),
+            ErrMode::Incomplete(Needed::new(1))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn visit_cycles() {
+        use crate::lib::std::vec::Vec;
+        use crate::stream::Stateful;
+
+        type Input<'i> = Stateful<LocatingSlice<&'i [u8]>, VisitGuard>;
+
+        /// A node with a count of children, followed by their offsets
+        fn node(input: &mut Input<'_>) -> Result<usize, ErrMode<Error>> {
+            let children: Vec<u8> = length_repeat(be_u8, be_u8).parse_next(input)?;
+            let mut count = 1;
+            for child in children {
+                count += visit(child.into(), node).parse_next(input)?;
+            }
+            Ok(count)
+        }
+
+        let input = |bytes| Stateful {
+            input: LocatingSlice::new(bytes),
+            state: VisitGuard::new(),
+        };
+
+        // Both children of the root point to the same leaf
+        let (rest, count) = node.parse_peek(input(&b"\x02\x03\x03\x00"[..])).unwrap();
+        assert_eq!(count, 3);
+        assert_eq!(rest.state, VisitGuard::new());
+
+        // The leaf points back to the root's child
+        assert_eq!(
+            node.parse_peek(input(&b"\x01\x02\x01\x02"[..]))
+                .unwrap_err(),
+            seek_error(SeekError::Cycle { offset: 2 })
+        );
+    }
+}
+
+mod checksum {
+    use super::*;
+
+    use crate::binary::checksum::*;
+    use crate::error::{ErrMode, ExternalError};
+    use crate::token::take;
+    use crate::Partial;
+
+    type Error = ExternalError<ChecksumError<u32>>;
+
+    fn sum<C: Checksum>(mut algorithm: C, bytes: &[u8]) -> C::Output {
+        algorithm.update(bytes);
+        algorithm.finish()
+    }
+
+    #[test]
+    fn check_values() {
+        let check = b"123456789";
+        assert_eq!(sum(Crc32::new(), check), 0xcbf4_3926);
+        assert_eq!(sum(Crc16::arc(), check), 0xbb3d);
+        assert_eq!(sum(Crc16::modbus(), check), 0x4b37);
+        assert_eq!(sum(Crc16::ibm_3740(), check), 0x29b1);
+        assert_eq!(sum(Crc16::xmodem(), check), 0x31c3);
+        assert_eq!(sum(Crc16::kermit(), check), 0x2189);
+        assert_eq!(sum(Adler32::new(), b"Wikipedia"), 0x11e6_0398);
+        assert_eq!(sum(Fletcher16::new(), b"abcde"), 0xc8f0);
+        assert_eq!(sum(Fletcher16::new(), b"abcdef"), 0x2057);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn incremental() {
+        let bytes = (0..20_000u32).map(|i| (i * 7) as u8).collect::<Vec<_>>();
+        let mut split = Adler32::new();
+        split.update(&bytes[..6000]);
+        split.update(&bytes[6000..]);
+        assert_eq!(split.finish(), sum(Adler32::new(), &bytes));
+
+        let (a, b) = bytes.iter().fold((1u32, 0u32), |(a, b), &byte| {
+            let a = (a + u32::from(byte)) % 65521;
+            (a, (b + a) % 65521)
+        });
+        assert_eq!(sum(Adler32::new(), &bytes), (b << 16) | a);
+    }
+
+    #[test]
+    fn verified_section() {
+        let mut parser =
+            verified::<_, _, _, ErrMode<Error>, _, _>(take(3usize), Crc32::new(), le_u32);
+
+        let input = &b"abc\xc2\x41\x24\x35rest"[..];
+        assert_eq!(parser.parse_peek(input), Ok((&b"rest"[..], &b"abc"[..])));
+
+        let input = &b"abc\x00\x00\x00\x00rest"[..];
+        assert_eq!(
+            parser.parse_peek(input).unwrap_err(),
+            ErrMode::Backtrack(ExternalError(Some(ChecksumError {
+                expected: 0,
+                computed: 0x3524_41c2
+            })))
+        );
+
+        let input = Partial::new(&b"abc\xc2\x41"[..]);
+        assert!(
+            verified::<_, _, _, ErrMode<Error>, _, _>(take(3usize), Crc32::new(), le_u32)
+                .parse_peek(input)
+                .unwrap_err()
+                .is_incomplete()
+        );
+    }
+}
+
+mod sized {
+    use super::*;
+
+    use crate::error::{ErrMode, InputError, Needed};
+    use crate::Partial;
+
+    type Error<'i> = ErrMode<InputError<&'i [u8]>>;
+
+    #[test]
+    fn f16_values() {
+        let parse = |bytes: [u8; 2]| be_f16::<_, Error<'_>>.parse_peek(&bytes[..]).unwrap().1;
+        assert_eq!(parse([0x3c, 0x00]), 1.0);
+        assert_eq!(parse([0xc0, 0x00]), -2.0);
+        assert_eq!(parse([0x7b, 0xff]), 65504.0);
+        // 2^-24 and 2^-14
+        let (smallest, normal) = (f32::from_bits(0x3380_0000), f32::from_bits(0x3880_0000));
+        assert_eq!(parse([0x00, 0x01]), smallest);
+        assert_eq!(parse([0x03, 0xff]), normal - smallest);
+        assert_eq!(parse([0x04, 0x00]), normal);
+        assert_eq!(parse([0x7c, 0x00]), f32::INFINITY);
+        assert_eq!(parse([0xfc, 0x00]), f32::NEG_INFINITY);
+        assert!(parse([0x7e, 0x00]).is_nan());
+        assert_eq!(parse([0x80, 0x00]).to_bits(), (-0f32).to_bits());
+
+        assert_eq!(
+            f16::<_, Error<'_>>(Endianness::Little).parse_peek(&[0x00, 0x3c][..]),
+            Ok((&b""[..], 1.0))
+        );
+    }
+
+    #[test]
+    fn bf16_values() {
+        let parse = |bytes: [u8; 2]| le_bf16::<_, Error<'_>>.parse_peek(&bytes[..]).unwrap().1;
+        assert_eq!(parse([0x80, 0x3f]), 1.0);
+        assert_eq!(parse([0x80, 0x7f]), f32::INFINITY);
+        assert_eq!(parse([0x7f, 0x7f]), f32::from_bits(0x7f7f_0000));
+        assert!(parse([0xc0, 0x7f]).is_nan());
+
+        assert_eq!(
+            bf16::<Partial<&[u8]>, ErrMode<InputError<_>>>(Endianness::Big)
+                .parse_peek(Partial::new(&[0x3f][..])),
+            Err(ErrMode::Incomplete(Needed::new(1)))
+        );
+    }
+
+    #[test]
+    fn uint_widths() {
+        let input = &b"\x01\x02\x03\x04\x05\x06\x07\x08\x09\x0a\x0b\x0c\x0d\x0e\x0f\x10"[..];
+        assert_eq!(
+            uint::<1>(Endianness::Big).parse_peek(input),
+            Ok::<_, Error<'_>>((&input[1..], 1u8))
+        );
+        assert_eq!(
+            uint::<3>(Endianness::Little).parse_peek(input),
+            Ok::<_, Error<'_>>((&input[3..], 0x03_0201u32))
+        );
+        assert_eq!(
+            uint::<16>(Endianness::Big).parse_peek(input),
+            Ok::<_, Error<'_>>((&b""[..], 0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10u128))
+        );
+        // Doesn't fit the output
+        assert_eq!(
+            uint::<2>(Endianness::Big).parse_peek(input),
+            Err::<(_, u8), Error<'_>>(ErrMode::Backtrack(InputError::at(input)))
+        );
+        assert_eq!(
+            uint::<2>(Endianness::Big).parse_peek(&b"\x00\xff"[..]),
+            Ok::<_, Error<'_>>((&b""[..], 0xffu8))
+        );
+        assert_eq!(
+            uint::<7>(Endianness::Big).parse_peek(Partial::new(&input[..4])),
+            Err::<(_, u64), ErrMode<InputError<_>>>(ErrMode::Incomplete(Needed::new(3)))
+        );
+    }
+
+    #[test]
+    fn int_sign_extension() {
+        let input = &b"\xff\xff\x80"[..];
+        assert_eq!(
+            int::<3>(Endianness::Big).parse_peek(input),
+            Ok::<_, Error<'_>>((&b""[..], -128i32))
+        );
+        assert_eq!(
+            int::<3>(Endianness::Big).parse_peek(input),
+            Ok::<_, Error<'_>>((&b""[..], -128i8))
+        );
+        assert_eq!(
+            int::<3>(Endianness::Little).parse_peek(input),
+            Ok::<_, Error<'_>>((&b""[..], -(1i64 << 23) + 0xffff))
+        );
+        assert_eq!(
+            int::<1>(Endianness::Big).parse_peek(&b"\x80"[..]),
+            Ok::<_, Error<'_>>((&b""[..], i8::MIN))
+        );
+        let mut min = [0u8; 16];
+        min[0] = 0x80;
+        assert_eq!(
+            int::<16>(Endianness::Big).parse_peek(&min[..]),
+            Ok::<_, Error<'_>>((&b""[..], i128::MIN))
+        );
+        // Doesn't fit the output
+        assert_eq!(
+            int::<3>(Endianness::Little).parse_peek(input),
+            Err::<(_, i16), Error<'_>>(ErrMode::Backtrack(InputError::at(input)))
+        );
+        assert_eq!(
+            int::<3>(Endianness::Big).parse_peek(input),
+            Err::<(_, u32), Error<'_>>(ErrMode::Backtrack(InputError::at(input)))
+        );
+    }
+}
+
+mod text {
+    use super::*;
+
+    use crate::error::{ErrMode, ExternalError, Needed};
+    #[cfg(feature = "alloc")]
+    use crate::token::take;
+    use crate::Partial;
+
+    type Error = ExternalError<TextError>;
+
+    fn text_error(e: TextError) -> ErrMode<Error> {
+        ErrMode::Backtrack(ExternalError(Some(e)))
+    }
+
+    #[test]
+    fn c_str_terminators() {
+        assert_eq!(
+            c_str::<_, ErrMode<Error>>.parse_peek(&b"\0rest"[..]),
+            Ok((&b"rest"[..], &b""[..]))
+        );
+        assert_eq!(
+            terminated_by::<_, ErrMode<Error>>(b';').parse_peek(&b"a\0b;c"[..]),
+            Ok((&b"c"[..], &b"a\0b"[..]))
+        );
+        assert_eq!(
+            c_str::<_, ErrMode<Error>>
+                .parse_peek(&b"abc"[..])
+                .unwrap_err(),
+            ErrMode::Backtrack(ExternalError(None))
+        );
+        assert_eq!(
+            c_str::<_, ErrMode<Error>>.parse_peek(Partial::new(&b"abc"[..])),
+            Err(ErrMode::Incomplete(Needed::new(1)))
+        );
+        assert_eq!(
+            c_str::<_, ErrMode<Error>>.parse_peek(Partial::new(&b"abc\0"[..])),
+            Ok((Partial::new(&b""[..]), &b"abc"[..]))
+        );
+    }
+
+    #[test]
+    fn padded_fields() {
+        // tar-style NUL-padded name
+        assert_eq!(
+            padded::<_, ErrMode<Error>>(8, 0).parse_peek(&b"file\0\0\0\0rest"[..]),
+            Ok((&b"rest"[..], &b"file"[..]))
+        );
+        // Only trailing padding is removed
+        assert_eq!(
+            padded::<_, ErrMode<Error>>(6, b' ').parse_peek(&b" a b  "[..]),
+            Ok((&b""[..], &b" a b"[..]))
+        );
+        assert_eq!(
+            padded::<_, ErrMode<Error>>(4, b' ').parse_peek(&b"full"[..]),
+            Ok((&b""[..], &b"full"[..]))
+        );
+        assert_eq!(
+            padded::<_, ErrMode<Error>>(4, b' ').parse_peek(Partial::new(&b"ab"[..])),
+            Err(ErrMode::Incomplete(Needed::new(2)))
+        );
+    }
+
+    #[test]
+    fn utf8_offset() {
+        assert_eq!(
+            utf8::<_, ErrMode<Error>, _>(c_str)
+                .parse_peek(&b"ab\xc3\xa9\xff\0"[..])
+                .unwrap_err(),
+            text_error(TextError::Utf8 { offset: 4 })
+        );
+        assert_eq!(
+            utf8::<_, ErrMode<Error>, _>(length_take(be_u8)).parse_peek(&b"\x03abc"[..]),
+            Ok((&b""[..], "abc"))
+        );
+        // Truncated sequence
+        assert_eq!(
+            utf8::<_, ErrMode<Error>, _>(padded(3, b' '))
+                .parse_peek(&b"a\xe2\x82"[..])
+                .unwrap_err(),
+            text_error(TextError::Utf8 { offset: 1 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn utf16_decoding() {
+        assert_eq!(
+            utf16::<_, ErrMode<Error>, _>(Endianness::Big, take(4usize))
+                .parse_peek(&b"\xd8\x3d\xde\x00\x00\x00"[..]),
+            Ok((&b"\x00\x00"[..], "\u{1f600}".to_owned()))
+        );
+        // Unpaired low surrogate after a character
+        assert_eq!(
+            utf16::<_, ErrMode<Error>, _>(Endianness::Little, take(4usize))
+                .parse_peek(&b"a\x00\x00\xdc"[..])
+                .unwrap_err(),
+            text_error(TextError::Utf16 { offset: 2 })
+        );
+        // Odd length
+        assert_eq!(
+            utf16::<_, ErrMode<Error>, _>(Endianness::Little, take(3usize))
+                .parse_peek(&b"a\x00b"[..])
+                .unwrap_err(),
+            text_error(TextError::Utf16 { offset: 2 })
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use crate::combinator::trace;
+use crate::error::{FromExternalError, ParserError};
+use crate::lib::std::fmt;
+use crate::lib::std::str;
+#[cfg(feature = "alloc")]
+use crate::lib::std::string::String;
+use crate::stream::{Stream, StreamIsPartial};
+use crate::Parser;
+use crate::Result;
+
+#[cfg(feature = "alloc")]
+use super::Endianness;
+
+/// Recognizes a NUL-terminated C string, returning the bytes before the NUL
+///
+/// The NUL is consumed.  For other terminators, see [`terminated_by`].  To get a `&str`, see
+/// [`utf8`].
+///
+/// *Complete version*: Returns an error if there is no NUL.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is no NUL in the available data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::c_str;
+///
+/// fn parser<'i>(s: &mut &'i [u8]) -> ModalResult<&'i [u8]> {
+///     c_str.parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(&b"hello\0world"[..]), Ok((&b"world"[..], &b"hello"[..])));
+/// assert!(parser.parse_peek(&b"hello"[..]).is_err());
+/// ```
+#[inline(always)]
+#[doc(alias = "cstr")]
+#[doc(alias = "nul_terminated")]
+pub fn c_str<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input>,
+{
+    trace("c_str", terminated_by(0)).parse_next(input)
+}
+
+/// Recognizes the bytes up to a `terminator` byte, consuming the terminator
+///
+/// *Complete version*: Returns an error if there is no `terminator`.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is no `terminator` in the available data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::terminated_by;
+///
+/// fn line<'i>(s: &mut &'i [u8]) -> ModalResult<&'i [u8]> {
+///     terminated_by(b'\n').parse_next(s)
+/// }
+///
+/// assert_eq!(line.parse_peek(&b"one\ntwo"[..]), Ok((&b"two"[..], &b"one"[..])));
+/// assert!(line.parse_peek(&b"two"[..]).is_err());
+/// ```
+#[inline(always)]
+pub fn terminated_by<Input, Error>(
+    terminator: u8,
+) -> impl Parser<Input, <Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input>,
+{
+    trace("terminated_by", move |input: &mut Input| {
+        let bytes = crate::token::take_till(0.., terminator).parse_next(input)?;
+        crate::token::any.parse_next(input)?;
+        Ok(bytes)
+    })
+}
+
+/// Recognizes a fixed-width field of `len` bytes, returning it without its trailing `padding`
+///
+/// This is for fields like those of tar headers and ID3 tags, padded with NULs or spaces.
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::padded;
+///
+/// fn title<'i>(s: &mut &'i [u8]) -> ModalResult<&'i [u8]> {
+///     padded(8, b' ').parse_next(s)
+/// }
+///
+/// assert_eq!(title.parse_peek(&b"A B     rest"[..]), Ok((&b"rest"[..], &b"A B"[..])));
+/// assert_eq!(title.parse_peek(&b"        rest"[..]), Ok((&b"rest"[..], &b""[..])));
+/// assert!(title.parse_peek(&b"short"[..]).is_err());
+/// ```
+#[inline(always)]
+#[doc(alias = "fixed_width")]
+pub fn padded<Input, Error>(
+    len: usize,
+    padding: u8,
+) -> impl Parser<Input, <Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input>,
+{
+    trace("padded", move |input: &mut Input| {
+        let start = input.checkpoint();
+        crate::token::take(len).void().parse_next(input)?;
+        input.reset(&start);
+        let end = input
+            .iter_offsets()
+            .take(len)
+            .filter(|(_, byte)| *byte != padding)
+            .last()
+            .map(|(offset, _)| offset + 1)
+            .unwrap_or(0);
+        let field = input.next_slice(end);
+        let _ = input.next_slice(len - end);
+        Ok(field)
+    })
+}
+
+/// Validate the bytes returned by `parser` as UTF-8, returning them as a `&str` without copying
+///
+/// Returns a [`TextError::Utf8`] with the offset of the invalid byte within the field.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::{be_u16, c_str, length_take, utf8};
+///
+/// fn c_string<'i>(s: &mut &'i [u8]) -> ModalResult<&'i str> {
+///     utf8(c_str).parse_next(s)
+/// }
+///
+/// assert_eq!(c_string.parse_peek(&b"caf\xc3\xa9\0"[..]), Ok((&b""[..], "café")));
+/// assert!(c_string.parse_peek(&b"caf\xe9\0"[..]).is_err());
+///
+/// fn pascal_string<'i>(s: &mut &'i [u8]) -> ModalResult<&'i str> {
+///     utf8(length_take(be_u16)).parse_next(s)
+/// }
+///
+/// assert_eq!(pascal_string.parse_peek(&b"\x00\x02hi!"[..]), Ok((&b"!"[..], "hi")));
+/// ```
+#[inline(always)]
+pub fn utf8<'i, Input, Error, ParseNext>(
+    mut parser: ParseNext,
+) -> impl Parser<Input, &'i str, Error>
+where
+    Input: Stream,
+    ParseNext: Parser<Input, &'i [u8], Error>,
+    Error: ParserError<Input> + FromExternalError<Input, TextError>,
+{
+    trace("utf8", move |input: &mut Input| {
+        let start = input.checkpoint();
+        let bytes = parser.parse_next(input)?;
+        str::from_utf8(bytes).map_err(|err| {
+            input.reset(&start);
+            Error::from_external_error(
+                input,
+                TextError::Utf8 {
+                    offset: err.valid_up_to(),
+                },
+            )
+        })
+    })
+}
+
+/// Decode the bytes returned by `parser` as UTF-16 of `endian` byte order
+///
+/// Returns a [`TextError::Utf16`] with the offset of the invalid code unit within the field.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::{be_u8, length_take, utf16, Endianness};
+///
+/// /// A string with its length in code units
+/// fn string(s: &mut &[u8]) -> ModalResult<String> {
+///     utf16(Endianness::Little, length_take(be_u8.map(|len| usize::from(len) * 2))).parse_next(s)
+/// }
+///
+/// assert_eq!(string.parse_peek(&b"\x02h\x00i\x00!"[..]), Ok((&b"!"[..], "hi".to_owned())));
+/// // Unpaired surrogate
+/// assert!(string.parse_peek(&b"\x01\x00\xd8"[..]).is_err());
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn utf16<'i, Input, Error, ParseNext>(
+    endian: Endianness,
+    mut parser: ParseNext,
+) -> impl Parser<Input, String, Error>
+where
+    Input: Stream,
+    ParseNext: Parser<Input, &'i [u8], Error>,
+    Error: ParserError<Input> + FromExternalError<Input, TextError>,
+{
+    trace("utf16", move |input: &mut Input| {
+        let start = input.checkpoint();
+        let bytes = parser.parse_next(input)?;
+        decode_utf16(bytes, super::is_big(endian)).map_err(|offset| {
+            input.reset(&start);
+            Error::from_external_error(input, TextError::Utf16 { offset })
+        })
+    })
+}
+
+/// Decode UTF-16, returning the offset of the first invalid code unit on error
+#[cfg(feature = "alloc")]
+fn decode_utf16(bytes: &[u8], big: bool) -> Result<String, usize> {
+    let units = bytes.chunks_exact(2).map(|unit| {
+        let unit = [unit[0], unit[1]];
+        if big {
+            u16::from_be_bytes(unit)
+        } else {
+            u16::from_le_bytes(unit)
+        }
+    });
+    let mut decoded = String::with_capacity(bytes.len() / 2);
+    let mut offset = 0;
+    for c in char::decode_utf16(units) {
+        let c = c.map_err(|_| offset)?;
+        decoded.push(c);
+        offset += c.len_utf16() * 2;
+    }
+    if bytes.len() % 2 == 0 {
+        Ok(decoded)
+    } else {
+        Err(offset)
+    }
+}
+
+/// Invalid text, from [`utf8`] and [`utf16`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TextError {
+    /// Invalid UTF-8
+    Utf8 {
+        /// Offset of the first invalid byte within the field
+        offset: usize,
+    },
+    /// Invalid UTF-16, either an unpaired surrogate or an odd number of bytes
+    Utf16 {
+        /// Offset of the first invalid code unit within the field
+        offset: usize,
+    },
+}
+
+impl fmt::Display for TextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Utf8 { offset } => write!(f, "invalid UTF-8 at offset {offset}"),
+            Self::Utf16 { offset } => write!(f, "invalid UTF-16 at offset {offset}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TextError {}
@@ -0,0 +1,225 @@
+use crate::combinator::trace;
+use crate::error::{FromExternalError, Needed, ParserError};
+use crate::lib::std::fmt;
+use crate::stream::{Stream, StreamIsPartial};
+use crate::Parser;
+use crate::Result;
+
+/// Recognizes an [LEB128](https://en.wikipedia.org/wiki/LEB128) variable-length integer
+///
+/// Each byte holds 7 bits of the value, least-significant group first, with the high bit set on
+/// every byte but the last.  Unsigned outputs read unsigned LEB128 (ULEB128), like in
+/// Protocol Buffers, while signed outputs read signed LEB128 (SLEB128), like in WebAssembly and
+/// DWARF.
+///
+/// Encodings are rejected with a [`VarintError`]:
+/// - [`VarintError::Overflow`] if the value doesn't fit in `Output`
+/// - [`VarintError::Overlong`] if the encoding has redundant trailing bytes or is longer than any
+///   value of `Output` needs
+///
+/// Unsigned outputs that implement [`ToUsize`][crate::stream::ToUsize], like `u32` and `usize`,
+/// let `leb128` be the length of [`length_take`][super::length_take] and
+/// [`length_repeat`][super::length_repeat].  `u128` doesn't, as its values may not fit in a `usize`.
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::leb128;
+///
+/// fn parser(s: &mut &[u8]) -> ModalResult<(u32, i32)> {
+///     (leb128, leb128).parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(&b"\xe5\x8e\x26\xc0\xbb\x78abc"[..]), Ok((&b"abc"[..], (624485, -123456))));
+/// // Overflow
+/// assert!(parser.parse_peek(&b"\xff\xff\xff\xff\x1f\x00"[..]).is_err());
+/// // Overlong
+/// assert!(parser.parse_peek(&b"\x80\x00\x00"[..]).is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::Needed};
+/// # use winnow::prelude::*;
+/// # use winnow::Partial;
+/// use winnow::binary::{leb128, length_take};
+///
+/// fn parser<'i>(s: &mut Partial<&'i [u8]>) -> ModalResult<&'i [u8]> {
+///     length_take(leb128::<_, u64, _>).parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(Partial::new(&b"\x03abcd"[..])), Ok((Partial::new(&b"d"[..]), &b"abc"[..])));
+/// assert_eq!(parser.parse_peek(Partial::new(&b"\x80"[..])), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+#[inline(always)]
+#[doc(alias = "varint")]
+#[doc(alias = "uleb128")]
+#[doc(alias = "sleb128")]
+pub fn leb128<Input, Output, Error>(input: &mut Input) -> Result<Output, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Output: Varint,
+    Error: ParserError<Input> + FromExternalError<Input, VarintError>,
+{
+    trace("leb128", move |input: &mut Input| {
+        varint(input, Output::BITS, Output::SIGNED).map(Output::from_bits)
+    })
+    .parse_next(input)
+}
+
+/// Recognizes a zigzag-encoded variable-length integer, like `sint32` of Protocol Buffers
+///
+/// The value is mapped to an unsigned [`leb128`] as `0, -1, 1, -2, 2, ...` so small negative
+/// values stay short.
+///
+/// Encodings are rejected with a [`VarintError`], like for [`leb128`].
+///
+/// *Complete version*: Returns an error if there is not enough input data.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there is not enough data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::binary::zigzag;
+///
+/// fn parser(s: &mut &[u8]) -> ModalResult<(i32, i32, i64)> {
+///     (zigzag, zigzag, zigzag).parse_next(s)
+/// }
+///
+/// assert_eq!(parser.parse_peek(&b"\x01\x04\xff\xff\xff\xff\x0fabc"[..]), Ok((&b"abc"[..], (-1, 2, i64::from(i32::MIN)))));
+/// ```
+#[inline(always)]
+#[doc(alias = "varint")]
+#[doc(alias = "sint32")]
+#[doc(alias = "sint64")]
+pub fn zigzag<Input, Output, Error>(input: &mut Input) -> Result<Output, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Output: SignedVarint,
+    Error: ParserError<Input> + FromExternalError<Input, VarintError>,
+{
+    trace("zigzag", move |input: &mut Input| {
+        varint(input, Output::BITS, false)
+            .map(|bits| Output::from_bits((bits >> 1) ^ 0u128.wrapping_sub(bits & 1)))
+    })
+    .parse_next(input)
+}
+
+/// Integer types for [`leb128`]
+pub trait Varint: Sized {
+    #[doc(hidden)]
+    const BITS: u32;
+    #[doc(hidden)]
+    const SIGNED: bool;
+    #[doc(hidden)]
+    fn from_bits(bits: u128) -> Self;
+}
+
+/// Signed integer types for [`zigzag`]
+pub trait SignedVarint: Varint {}
+
+macro_rules! impl_varint {
+    ($($t:ty => $signed:literal),*) => {
+        $(
+            impl Varint for $t {
+                const BITS: u32 = <$t>::BITS;
+                const SIGNED: bool = $signed;
+                #[inline(always)]
+                fn from_bits(bits: u128) -> Self {
+                    bits as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_varint!(
+    u16 => false, u32 => false, u64 => false, u128 => false, usize => false,
+    i16 => true, i32 => true, i64 => true, i128 => true, isize => true
+);
+
+impl SignedVarint for i16 {}
+impl SignedVarint for i32 {}
+impl SignedVarint for i64 {}
+impl SignedVarint for i128 {}
+impl SignedVarint for isize {}
+
+/// Invalid variable-length integer, from [`leb128`] or [`zigzag`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum VarintError {
+    /// The value doesn't fit in the output type
+    Overflow,
+    /// The encoding is longer than needed
+    Overlong,
+}
+
+impl fmt::Display for VarintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => "varint overflows its type".fmt(f),
+            Self::Overlong => "varint is longer than needed".fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VarintError {}
+
+/// Read the bits of a LEB128 value of `bits` width, sign-extended if `signed`
+fn varint<Input, Error>(input: &mut Input, bits: u32, signed: bool) -> Result<u128, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8>,
+    Error: ParserError<Input> + FromExternalError<Input, VarintError>,
+{
+    let max_len = (bits + 6) / 7;
+    let mut value = 0u128;
+    let mut previous = 0u8;
+    for (index, (offset, byte)) in (0..max_len).zip(input.iter_offsets()) {
+        let shift = index * 7;
+        let payload = byte & 0x7f;
+        if index + 1 == max_len {
+            // Bits from the sign bit (signed) or past the width (unsigned) must be redundant
+            let remaining = bits - shift;
+            let check = if signed { remaining - 1 } else { remaining };
+            let extra = payload >> check;
+            if extra != 0 && !(signed && extra == 0x7f >> check) {
+                return Err(Error::from_external_error(input, VarintError::Overflow));
+            }
+        }
+        value |= u128::from(payload) << shift;
+
+        if byte & 0x80 == 0 {
+            let redundant = if signed {
+                (byte == 0x00 && previous & 0x40 == 0) || (byte == 0x7f && previous & 0x40 != 0)
+            } else {
+                byte == 0x00
+            };
+            if index != 0 && redundant {
+                return Err(Error::from_external_error(input, VarintError::Overlong));
+            }
+            let end = shift + 7;
+            if signed && byte & 0x40 != 0 && end < u128::BITS {
+                value |= u128::MAX << end;
+            }
+            let _ = input.next_slice(offset + 1);
+            return Ok(value);
+        }
+        previous = byte;
+    }
+
+    if input.eof_offset() < max_len as usize {
+        if <Input as StreamIsPartial>::is_partial_supported() && input.is_partial() {
+            Err(ParserError::incomplete(input, Needed::new(1)))
+        } else {
+            Err(ParserError::from_input(input))
+        }
+    } else {
+        Err(Error::from_external_error(input, VarintError::Overlong))
+    }
+}
@@ -1,4 +1,6 @@
 use crate::combinator::trace;
+use crate::combinator::trace_call;
+use crate::combinator::CallCount;
 use crate::error::ParserError;
 use crate::stream::Stream;
 use crate::*;
@@ -50,13 +52,62 @@ pub trait Alt<I, O, E> {
 #[doc(alias = "choice")]
 #[inline(always)]
 pub fn alt<Input: Stream, Output, Error, Alternatives>(
-    mut alternatives: Alternatives,
-) -> impl Parser<Input, Output, Error>
+    alternatives: Alternatives,
+) -> introspectable!(Choice<Alternatives, Input, Output, Error>, impl Parser<Input, Output, Error>)
 where
     Alternatives: Alt<Input, Output, Error>,
     Error: ParserError<Input>,
 {
-    trace("alt", move |i: &mut Input| alternatives.choice(i))
+    Choice {
+        alternatives,
+        call_count: Default::default(),
+        i: Default::default(),
+        o: Default::default(),
+        e: Default::default(),
+    }
+}
+
+/// [`Parser`] implementation for [`alt`]
+pub struct Choice<A, I, O, E>
+where
+    A: Alt<I, O, E>,
+    I: Stream,
+    E: ParserError<I>,
+{
+    alternatives: A,
+    call_count: CallCount,
+    i: core::marker::PhantomData<I>,
+    o: core::marker::PhantomData<O>,
+    e: core::marker::PhantomData<E>,
+}
+
+impl<A, I, O, E> Parser<I, O, E> for Choice<A, I, O, E>
+where
+    A: Alt<I, O, E>,
+    I: Stream,
+    E: ParserError<I>,
+{
+    #[inline(always)]
+    fn parse_next(&mut self, input: &mut I) -> Result<O, E> {
+        trace_call(
+            "alt",
+            &mut self.call_count,
+            |i: &mut I| self.alternatives.choice(i),
+            input,
+        )
+    }
+}
+
+#[cfg(feature = "unstable-grammar")]
+impl<A, I, O, E> crate::describe::Describe for Choice<A, I, O, E>
+where
+    A: Alt<I, O, E> + crate::describe::DescribeAlt,
+    I: Stream,
+    E: ParserError<I>,
+{
+    fn describe(&self, grammar: &mut crate::describe::Grammar) -> crate::describe::Expr {
+        crate::describe::Expr::choice(self.alternatives.describe_alt(grammar))
+    }
 }
 
 /// Helper trait for the [`permutation()`] combinator.
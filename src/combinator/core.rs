@@ -1,5 +1,7 @@
 use crate::combinator::trace;
-use crate::error::{ModalError, ParserError};
+use crate::combinator::trace_call;
+use crate::combinator::CallCount;
+use crate::error::{ModalError, Needed, ParserError};
 use crate::stream::Stream;
 use crate::*;
 
@@ -24,23 +26,94 @@ use crate::*;
 /// # }
 /// ```
 pub fn opt<Input: Stream, Output, Error, ParseNext>(
-    mut parser: ParseNext,
-) -> impl Parser<Input, Option<Output>, Error>
+    parser: ParseNext,
+) -> introspectable!(
+       Opt<ParseNext, Input, Output, Error>,
+       impl Parser<Input, Option<Output>, Error>
+   )
 where
     ParseNext: Parser<Input, Output, Error>,
     Error: ParserError<Input>,
 {
-    trace("opt", move |input: &mut Input| {
-        let start = input.checkpoint();
-        match parser.parse_next(input) {
-            Ok(o) => Ok(Some(o)),
-            Err(e) if e.is_backtrack() => {
-                input.reset(&start);
-                Ok(None)
-            }
-            Err(e) => Err(e),
+    Opt {
+        parser,
+        call_count: Default::default(),
+        i: Default::default(),
+        o: Default::default(),
+        e: Default::default(),
+    }
+}
+
+/// [`Parser`] implementation for [`opt`]
+pub struct Opt<P, I, O, E>
+where
+    P: Parser<I, O, E>,
+    I: Stream,
+    E: ParserError<I>,
+{
+    parser: P,
+    call_count: CallCount,
+    i: core::marker::PhantomData<I>,
+    o: core::marker::PhantomData<O>,
+    e: core::marker::PhantomData<E>,
+}
+
+impl<P, I, O, E> Parser<I, Option<O>, E> for Opt<P, I, O, E>
+where
+    P: Parser<I, O, E>,
+    I: Stream,
+    E: ParserError<I>,
+{
+    #[inline(always)]
+    fn parse_next(&mut self, input: &mut I) -> Result<Option<O>, E> {
+        trace_call(
+            "opt",
+            &mut self.call_count,
+            |input: &mut I| {
+                let start = input.checkpoint();
+                match self.parser.parse_next(input) {
+                    Ok(o) => Ok(Some(o)),
+                    Err(e) if e.is_backtrack() => {
+                        input.reset(&start);
+                        Ok(None)
+                    }
+                    Err(e) => Err(e),
+                }
+            },
+            input,
+        )
+    }
+}
+
+#[cfg(feature = "unstable-grammar")]
+impl<P, I, O, E> crate::describe::Describe for Opt<P, I, O, E>
+where
+    P: Parser<I, O, E> + crate::describe::Describe,
+    I: Stream,
+    E: ParserError<I>,
+{
+    fn describe(&self, grammar: &mut crate::describe::Grammar) -> crate::describe::Expr {
+        crate::describe::Expr::optional(self.parser.describe(grammar))
+    }
+}
+
+#[cfg(feature = "unstable-print")]
+impl<P, I, O, E, V> crate::print::Print<Option<V>> for Opt<P, I, O, E>
+where
+    P: Parser<I, O, E> + crate::print::Print<V>,
+    I: Stream,
+    E: ParserError<I>,
+{
+    fn print(
+        &self,
+        value: &Option<V>,
+        sink: &mut dyn crate::print::Sink,
+    ) -> Result<(), crate::print::PrintError> {
+        match value {
+            Some(value) => self.parser.print(value, sink),
+            None => Ok(()),
         }
-    })
+    }
 }
 
 /// Calls the parser if the condition is met.
@@ -158,6 +231,7 @@ where
     Error: ParserError<Input>,
 {
     trace("eof", move |input: &mut Input| {
+        crate::stream::skip_trivia(input)?;
         if input.eof_offset() == 0 {
             Ok(input.next_slice(0))
         } else {
@@ -167,6 +241,58 @@ where
     .parse_next(input)
 }
 
+/// Parse a token made of several parsers, skipping [`Trivia`][crate::stream::Trivia] before it but
+/// not within it
+///
+/// This is also the escape hatch for tokens where trivia is significant, like string literals.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::{alpha1, alphanumeric0, multispace0};
+/// use winnow::combinator::lexeme;
+/// use winnow::stream::Trivia;
+///
+/// fn trivia(input: &mut &str) -> ModalResult<()> {
+///     multispace0.void().parse_next(input)
+/// }
+///
+/// fn ident<'i>(input: &mut Trivia<&'i str>) -> ModalResult<&'i str> {
+///     lexeme((alpha1, alphanumeric0).take()).parse_next(input)
+/// }
+///
+/// assert_eq!(ident.parse_peek(Trivia::new("  ab1 c", trivia)).unwrap().1, "ab1");
+/// ```
+pub fn lexeme<Input, Output, Error, ParseNext>(
+    mut parser: ParseNext,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: Stream,
+    Error: ParserError<Input>,
+    ParseNext: Parser<Input, Output, Error>,
+{
+    trace("lexeme", move |input: &mut Input| {
+        lexeme_(input, |input| parser.parse_next(input))
+    })
+}
+
+#[inline(always)]
+pub(crate) fn lexeme_<I, O, E>(
+    input: &mut I,
+    parse: impl FnOnce(&mut I) -> Result<O, E>,
+) -> Result<O, E>
+where
+    I: Stream,
+    E: ParserError<I>,
+{
+    crate::stream::skip_trivia(input)?;
+    let trivia = crate::stream::set_trivia(input, false);
+    let result = parse(input);
+    crate::stream::set_trivia(input, trivia);
+    result
+}
+
 /// Succeeds if the child parser returns an error.
 ///
 /// <div class="warning">
@@ -333,6 +459,10 @@ where
 ///
 /// On [`ErrMode::Backtrack`][crate::error::ErrMode::Backtrack], iteration will stop. To instead chain an error up, see [`cut_err`].
 ///
+/// On [`ErrMode::Incomplete`][crate::error::ErrMode::Incomplete], iteration will pause before the
+/// partially parsed item.  To continue once more data is available, see
+/// [`ParserIterator::suspend`].
+///
 /// # Example
 ///
 /// ```rust
@@ -362,6 +492,7 @@ where
         parser,
         input,
         state: State::Running,
+        offset: 0,
         o: Default::default(),
     }
 }
@@ -375,6 +506,7 @@ where
     parser: F,
     input: I,
     state: State<E>,
+    offset: usize,
     o: core::marker::PhantomData<O>,
 }
 
@@ -388,6 +520,79 @@ where
     pub fn finish(self) -> Result<(I, ()), E> {
         match self.state {
             State::Running | State::Done => Ok((self.input, ())),
+            State::Incomplete(e) | State::Cut(e) => Err(e),
+        }
+    }
+
+    /// The input after the last fully parsed item
+    pub fn remaining(&self) -> &I {
+        &self.input
+    }
+
+    /// Offset of the end of the last fully parsed item
+    ///
+    /// This counts from the start of the first input, across [`ParserIterator::suspend`]s.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// How much more data is needed when paused on
+    /// [`ErrMode::Incomplete`][crate::error::ErrMode::Incomplete]
+    pub fn needed(&self) -> Option<Needed> {
+        match &self.state {
+            State::Incomplete(e) => e.needed(),
+            State::Running | State::Done | State::Cut(_) => None,
+        }
+    }
+
+    /// Release the input so it can be refilled, keeping the parser and offset
+    ///
+    /// Returns the error if we encountered one other than
+    /// [`ErrMode::Incomplete`][crate::error::ErrMode::Incomplete].
+    ///
+    /// Resume with input that starts with the data from [`ParserIterator::remaining`].
+    ///
+    /// To refill a buffer that the input borrows from, the parser must work with any lifetime of
+    /// the input, like a `fn` does.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::prelude::*;
+    /// use winnow::{combinator::iterator, ascii::alpha1, combinator::terminated};
+    /// use winnow::Partial;
+    ///
+    /// fn item<'i>(input: &mut Partial<&'i str>) -> ModalResult<&'i str> {
+    ///     terminated(alpha1, "|").parse_next(input)
+    /// }
+    ///
+    /// let mut buffer = String::from("abc|de");
+    /// let mut it = iterator(Partial::new(buffer.as_str()), item);
+    /// assert_eq!((&mut it).collect::<Vec<_>>(), ["abc"]);
+    /// assert!(it.needed().is_some());
+    ///
+    /// // Drop the parsed data and refill the buffer
+    /// let consumed = buffer.len() - it.remaining().len();
+    /// let it = it.suspend().unwrap();
+    /// buffer.drain(..consumed);
+    /// buffer.push_str("fg|hij|");
+    ///
+    /// let mut it = it.resume(Partial::new(buffer.as_str()));
+    /// assert_eq!((&mut it).collect::<Vec<_>>(), ["defg", "hij"]);
+    /// assert_eq!(it.offset(), 13);
+    /// ```
+    pub fn suspend(self) -> Result<SuspendedParserIterator<F>, E> {
+        match self.state {
+            State::Running | State::Incomplete(_) => Ok(SuspendedParserIterator {
+                parser: self.parser,
+                offset: self.offset,
+                done: false,
+            }),
+            State::Done => Ok(SuspendedParserIterator {
+                parser: self.parser,
+                offset: self.offset,
+                done: true,
+            }),
             State::Cut(e) => Err(e),
         }
     }
@@ -408,6 +613,7 @@ where
             match self.parser.parse_next(&mut self.input) {
                 Ok(o) => {
                     self.state = State::Running;
+                    self.offset += self.input.offset_from(&start);
                     Some(o)
                 }
                 Err(e) if e.is_backtrack() => {
@@ -415,6 +621,11 @@ where
                     self.state = State::Done;
                     None
                 }
+                Err(e) if e.is_incomplete() => {
+                    self.input.reset(&start);
+                    self.state = State::Incomplete(e);
+                    None
+                }
                 Err(e) => {
                     self.state = State::Cut(e);
                     None
@@ -429,9 +640,44 @@ where
 enum State<E> {
     Running,
     Done,
+    Incomplete(E),
     Cut(E),
 }
 
+/// A [`ParserIterator`] waiting for more input, see [`ParserIterator::suspend`]
+pub struct SuspendedParserIterator<F> {
+    parser: F,
+    offset: usize,
+    done: bool,
+}
+
+impl<F> SuspendedParserIterator<F> {
+    /// Offset of the end of the last fully parsed item
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Continue iterating over `input`
+    pub fn resume<I, O, E>(self, input: I) -> ParserIterator<F, I, O, E>
+    where
+        F: Parser<I, O, E>,
+        I: Stream,
+        E: ParserError<I>,
+    {
+        ParserIterator {
+            parser: self.parser,
+            input,
+            state: if self.done {
+                State::Done
+            } else {
+                State::Running
+            },
+            offset: self.offset,
+            o: Default::default(),
+        }
+    }
+}
+
 /// Succeed, consuming no input
 ///
 /// For example, it can be used as the last alternative in `alt` to
@@ -0,0 +1,525 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::sink::{Severity, TraceEvent, TraceSink};
+
+/// Which [`TraceEvent`]s to keep, to make the trace of large grammars readable
+///
+/// By default, every event is kept.  Restrict them with the builder methods, and apply the
+/// filter with [`TraceFilter::sink`].
+///
+/// The trace on stderr is filtered by the `WINNOW_TRACE` environment variable, see
+/// [`TraceFilter::from_env`].
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::{alpha1, digit1};
+/// use winnow::combinator::{alt, trace, with_trace_sink, TraceEvent, TraceFilter, TraceSink};
+///
+/// /// Names of the parsers that returned
+/// #[derive(Default)]
+/// struct Names(Vec<String>);
+///
+/// impl TraceSink for Names {
+///     fn event(&mut self, event: &TraceEvent<'_>) {
+///         if let TraceEvent::Exit { name, .. } = event {
+///             self.0.push(name.to_string());
+///         }
+///     }
+/// }
+///
+/// let (names, _) = with_trace_sink(TraceFilter::new().failures().sink(Names::default()), || {
+///     trace("word", alt((digit1::<_, ()>, alpha1))).parse_peek("abc")
+/// });
+/// // `alpha1` succeeded, so only the `digit1` branch is traced, within its callers
+/// assert_eq!(names.into_inner().0, ["take_while", "digit1", "alt", "word"]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TraceFilter {
+    names: Vec<String>,
+    max_depth: Option<usize>,
+    failures: bool,
+    final_error: bool,
+}
+
+impl TraceFilter {
+    /// Keep every event
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read the filter from the `WINNOW_TRACE` environment variable
+    ///
+    /// The variable is a comma separated list of:
+    /// - rule names, where `*` matches any text, to only trace within the matching parsers, see
+    ///   [`TraceFilter::name`]
+    /// - `depth<=N`, see [`TraceFilter::max_depth`]
+    /// - `failures`, see [`TraceFilter::failures`]
+    /// - `final-error`, see [`TraceFilter::final_error`]
+    ///
+    /// For example, `WINNOW_TRACE=json_value,depth<=5`.
+    ///
+    /// Every event is kept when the variable isn't set.
+    pub fn from_env() -> Result<Self, TraceFilterError> {
+        match std::env::var("WINNOW_TRACE") {
+            Ok(spec) => spec.parse(),
+            Err(_) => Ok(Self::new()),
+        }
+    }
+
+    /// Only trace within parsers whose name matches `pattern`, where `*` matches any text
+    ///
+    /// Depths are then counted from the outermost matching parser.  Calling this more than once
+    /// matches any of the patterns.
+    pub fn name(mut self, pattern: impl Into<String>) -> Self {
+        self.names.push(pattern.into());
+        self
+    }
+
+    /// Only trace parsers up to `depth` levels below the outermost one
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Only trace parsers that failed, within the parsers that called them
+    pub fn failures(mut self) -> Self {
+        self.failures = true;
+        self
+    }
+
+    /// Only trace the parsers leading to the error of a failed parse
+    ///
+    /// These are the parsers that failed, from the outermost one, each into the last of them it
+    /// called, up to the innermost one, which is traced with all of the parsers it called.
+    /// Successful parses aren't traced.
+    ///
+    /// The events of the outermost parser are held until it returns.
+    pub fn final_error(mut self) -> Self {
+        self.final_error = true;
+        self
+    }
+
+    /// Send the events kept by this filter to `sink`
+    pub fn sink<S: TraceSink>(self, sink: S) -> FilteredSink<S> {
+        FilteredSink {
+            filter: self,
+            sink,
+            root: None,
+            pending: Vec::new(),
+            tree: Vec::new(),
+        }
+    }
+
+    fn matches(&self, name: &dyn fmt::Display) -> bool {
+        let name = name.to_string();
+        self.names.iter().any(|pattern| glob(pattern, &name))
+    }
+}
+
+impl FromStr for TraceFilter {
+    type Err = TraceFilterError;
+
+    /// Parse the filter, like for [`TraceFilter::from_env`]
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let mut filter = Self::new();
+        for item in spec
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+        {
+            let invalid = || TraceFilterError {
+                item: item.to_owned(),
+            };
+            filter = match item {
+                "failures" => filter.failures(),
+                "final-error" => filter.final_error(),
+                _ => {
+                    if let Some(depth) = item
+                        .strip_prefix("depth")
+                        .and_then(|depth| depth.trim_start().strip_prefix("<="))
+                    {
+                        filter.max_depth(depth.trim().parse().map_err(|_| invalid())?)
+                    } else if item.contains(|c: char| c.is_whitespace() || "<=>".contains(c)) {
+                        return Err(invalid());
+                    } else {
+                        filter.name(item)
+                    }
+                }
+            };
+        }
+        Ok(filter)
+    }
+}
+
+/// Invalid item of a [`TraceFilter`], see [`TraceFilter::from_env`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceFilterError {
+    item: String,
+}
+
+impl TraceFilterError {
+    /// The invalid item
+    pub fn item(&self) -> &str {
+        &self.item
+    }
+}
+
+impl fmt::Display for TraceFilterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid trace filter `{}`", self.item)
+    }
+}
+
+impl std::error::Error for TraceFilterError {}
+
+/// [`TraceSink`] for the events kept by a [`TraceFilter`], from [`TraceFilter::sink`]
+///
+/// Depths are relative to the outermost kept parser.
+#[derive(Clone, Debug)]
+pub struct FilteredSink<S> {
+    filter: TraceFilter,
+    sink: S,
+    /// Depth of the outermost parser matching [`TraceFilter::name`] that is running
+    root: Option<usize>,
+    /// Parsers running, for [`TraceFilter::failures`], and whether they were sent
+    pending: Vec<(OwnedEvent, bool)>,
+    /// Events of the outermost parser, for [`TraceFilter::final_error`]
+    tree: Vec<OwnedEvent>,
+}
+
+impl<S: TraceSink> FilteredSink<S> {
+    /// The sink, to get what was sent
+    pub fn into_inner(self) -> S {
+        self.sink
+    }
+
+    /// Depth of the event relative to the outermost kept parser, if it is kept
+    fn depth(&mut self, event: &TraceEvent<'_>) -> Option<usize> {
+        let depth = event.depth();
+        let root = match self.root {
+            _ if self.filter.names.is_empty() => 0,
+            Some(root) if root <= depth => {
+                if matches!(event, TraceEvent::Exit { .. }) && depth == root {
+                    self.root = None;
+                }
+                root
+            }
+            _ => {
+                self.root = None;
+                match event {
+                    TraceEvent::Enter { name, .. } if self.filter.matches(name) => {
+                        self.root = Some(depth);
+                        depth
+                    }
+                    _ => return None,
+                }
+            }
+        };
+        let depth = depth - root;
+        self.filter
+            .max_depth
+            .map(|max_depth| depth <= max_depth)
+            .unwrap_or(true)
+            .then_some(depth)
+    }
+
+    fn final_error(&mut self, event: OwnedEvent) {
+        if event.depth() == 0 {
+            match event {
+                OwnedEvent::Enter { .. } => self.tree.clear(),
+                OwnedEvent::Result { .. } => {
+                    // A check outside of traced parsers
+                    if event.failed() {
+                        self.failures(event);
+                    }
+                    return;
+                }
+                OwnedEvent::Exit { .. } => {}
+            }
+        }
+        let done = event.depth() == 0 && matches!(event, OwnedEvent::Exit { .. });
+        self.tree.push(event);
+        if done {
+            let tree = std::mem::take(&mut self.tree);
+            if tree.last().map(OwnedEvent::failed).unwrap_or(false) {
+                let keep = final_error_path(&tree);
+                for (event, keep) in tree.into_iter().zip(keep) {
+                    if keep {
+                        self.failures(event);
+                    }
+                }
+            }
+        }
+    }
+
+    fn failures(&mut self, event: OwnedEvent) {
+        if !self.filter.failures {
+            self.sink.event(&event.as_event());
+            return;
+        }
+        match event {
+            OwnedEvent::Enter { depth, .. } => {
+                self.pending.truncate(depth);
+                self.pending.push((event, false));
+            }
+            OwnedEvent::Exit { .. } => {
+                if event.failed() {
+                    self.send_pending();
+                }
+                if let Some((_, true)) = self.pending.pop() {
+                    self.sink.event(&event.as_event());
+                }
+            }
+            OwnedEvent::Result { .. } => {
+                if event.failed() {
+                    self.send_pending();
+                    self.sink.event(&event.as_event());
+                }
+            }
+        }
+    }
+
+    fn send_pending(&mut self) {
+        for (event, sent) in &mut self.pending {
+            if !*sent {
+                *sent = true;
+                self.sink.event(&event.as_event());
+            }
+        }
+    }
+}
+
+impl<S: TraceSink> TraceSink for FilteredSink<S> {
+    fn event(&mut self, event: &TraceEvent<'_>) {
+        let Some(depth) = self.depth(event) else {
+            return;
+        };
+        let event = event.with_depth(depth);
+        if self.filter.final_error {
+            self.final_error(OwnedEvent::new(&event));
+        } else if self.filter.failures {
+            self.failures(OwnedEvent::new(&event));
+        } else {
+            self.sink.event(&event);
+        }
+    }
+}
+
+/// Mark the events leading to the error, see [`TraceFilter::final_error`]
+///
+/// `tree` holds the events of a parser that failed.
+fn final_error_path(tree: &[OwnedEvent]) -> Vec<bool> {
+    let mut keep = vec![false; tree.len()];
+    let (mut start, mut end) = (0, tree.len() - 1);
+    while start < end {
+        let depth = tree[start].depth() + 1;
+        let Some(child_end) =
+            (start + 1..end).rfind(|&i| tree[i].depth() == depth && tree[i].failed())
+        else {
+            break;
+        };
+        keep[start] = true;
+        keep[end] = true;
+        start = match tree[child_end] {
+            OwnedEvent::Result { .. } => child_end,
+            _ => (start + 1..child_end)
+                .rfind(|&i| tree[i].depth() == depth && matches!(tree[i], OwnedEvent::Enter { .. }))
+                .unwrap_or(child_end),
+        };
+        end = child_end;
+    }
+    keep[start..=end].fill(true);
+    keep
+}
+
+/// Whether `text` matches `pattern`, where `*` matches any text
+fn glob(pattern: &str, text: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == text,
+        Some((prefix, pattern)) => text
+            .strip_prefix(prefix)
+            .map(|text| {
+                (0..=text.len())
+                    .filter(|&i| text.is_char_boundary(i))
+                    .any(|i| glob(pattern, &text[i..]))
+            })
+            .unwrap_or(false),
+    }
+}
+
+/// [`TraceEvent`] held by a [`FilteredSink`]
+#[derive(Clone, Debug)]
+enum OwnedEvent {
+    Enter {
+        name: String,
+        depth: usize,
+        call_count: usize,
+        position: usize,
+        input: Rendered,
+    },
+    Exit {
+        name: String,
+        depth: usize,
+        call_count: usize,
+        position: usize,
+        consumed: usize,
+        severity: Severity,
+    },
+    Result {
+        name: String,
+        depth: usize,
+        severity: Severity,
+    },
+}
+
+impl OwnedEvent {
+    fn new(event: &TraceEvent<'_>) -> Self {
+        match *event {
+            TraceEvent::Enter {
+                name,
+                depth,
+                call_count,
+                position,
+                input,
+            } => Self::Enter {
+                name: name.to_string(),
+                depth,
+                call_count,
+                position,
+                input: Rendered::new(input),
+            },
+            TraceEvent::Exit {
+                name,
+                depth,
+                call_count,
+                position,
+                consumed,
+                severity,
+            } => Self::Exit {
+                name: name.to_string(),
+                depth,
+                call_count,
+                position,
+                consumed,
+                severity,
+            },
+            TraceEvent::Result {
+                name,
+                depth,
+                severity,
+            } => Self::Result {
+                name: name.to_string(),
+                depth,
+                severity,
+            },
+        }
+    }
+
+    fn as_event(&self) -> TraceEvent<'_> {
+        match self {
+            Self::Enter {
+                name,
+                depth,
+                call_count,
+                position,
+                input,
+            } => TraceEvent::Enter {
+                name,
+                depth: *depth,
+                call_count: *call_count,
+                position: *position,
+                input,
+            },
+            Self::Exit {
+                name,
+                depth,
+                call_count,
+                position,
+                consumed,
+                severity,
+            } => TraceEvent::Exit {
+                name,
+                depth: *depth,
+                call_count: *call_count,
+                position: *position,
+                consumed: *consumed,
+                severity: *severity,
+            },
+            Self::Result {
+                name,
+                depth,
+                severity,
+            } => TraceEvent::Result {
+                name,
+                depth: *depth,
+                severity: *severity,
+            },
+        }
+    }
+
+    fn depth(&self) -> usize {
+        match *self {
+            Self::Enter { depth, .. } | Self::Exit { depth, .. } | Self::Result { depth, .. } => {
+                depth
+            }
+        }
+    }
+
+    fn failed(&self) -> bool {
+        match *self {
+            Self::Enter { .. } => false,
+            Self::Exit { severity, .. } | Self::Result { severity, .. } => {
+                severity != Severity::Success
+            }
+        }
+    }
+}
+
+/// Input of an [`OwnedEvent::Enter`], already rendered with `{:#?}`
+///
+/// Only the start is kept, as held events would otherwise each hold the rest of the input.
+#[derive(Clone)]
+struct Rendered(String);
+
+impl Rendered {
+    /// Wider than a terminal, so the input looks the same as without a filter
+    const MAX_CHARS: usize = 1024;
+
+    fn new(input: &dyn fmt::Debug) -> Self {
+        use std::fmt::Write as _;
+
+        let mut rendered = Truncated::default();
+        // Stops with an error once full
+        let _ = write!(rendered, "{input:#?}");
+        Self(rendered.text)
+    }
+}
+
+/// [`fmt::Write`] keeping the first [`Rendered::MAX_CHARS`] characters
+#[derive(Default)]
+struct Truncated {
+    text: String,
+    chars: usize,
+}
+
+impl fmt::Write for Truncated {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if Rendered::MAX_CHARS <= self.chars {
+                return Err(fmt::Error);
+            }
+            self.text.push(c);
+            self.chars += 1;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Rendered {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
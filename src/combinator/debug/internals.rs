@@ -1,68 +1,51 @@
 #![cfg(feature = "std")]
 
+use std::cell::Cell;
 use std::io::Write;
 
+use super::sink::{emit, Severity, TraceEvent};
 use crate::error::ParserError;
 use crate::stream::Stream;
 use crate::*;
 
-pub(crate) struct Trace<P, D, I, O, E>
+pub(crate) fn trace<P, D, I, O, E>(
+    parser: &mut P,
+    name: &D,
+    call_count: &mut usize,
+    i: &mut I,
+) -> Result<O, E>
 where
     P: Parser<I, O, E>,
     I: Stream,
     D: std::fmt::Display,
     E: ParserError<I>,
 {
-    parser: P,
-    name: D,
-    call_count: usize,
-    i: core::marker::PhantomData<I>,
-    o: core::marker::PhantomData<O>,
-    e: core::marker::PhantomData<E>,
-}
-
-impl<P, D, I, O, E> Trace<P, D, I, O, E>
-where
-    P: Parser<I, O, E>,
-    I: Stream,
-    D: std::fmt::Display,
-    E: ParserError<I>,
-{
-    #[inline(always)]
-    pub(crate) fn new(parser: P, name: D) -> Self {
-        Self {
-            parser,
-            name,
-            call_count: 0,
-            i: Default::default(),
-            o: Default::default(),
-            e: Default::default(),
-        }
-    }
-}
-
-impl<P, D, I, O, E> Parser<I, O, E> for Trace<P, D, I, O, E>
-where
-    P: Parser<I, O, E>,
-    I: Stream,
-    D: std::fmt::Display,
-    E: ParserError<I>,
-{
-    #[inline]
-    fn parse_next(&mut self, i: &mut I) -> Result<O, E> {
-        let depth = Depth::new();
-        let original = i.checkpoint();
-        start(*depth, &self.name, self.call_count, i);
-
-        let res = self.parser.parse_next(i);
-
-        let consumed = i.offset_from(&original);
-        let severity = Severity::with_result(&res);
-        end(*depth, &self.name, self.call_count, consumed, severity);
-        self.call_count += 1;
-
-        res
-    }
+    let depth = Depth::new();
+    let original = i.checkpoint();
+    let position = Origin::position(*depth, i.eof_offset());
+    emit(&TraceEvent::Enter {
+        name,
+        depth: *depth,
+        call_count: *call_count,
+        position,
+        input: i.raw(),
+    });
+
+    let res = parser.parse_next(i);
+
+    let consumed = i.offset_from(&original);
+    let severity = Severity::with_result(&res);
+    emit(&TraceEvent::Exit {
+        name,
+        depth: *depth,
+        call_count: *call_count,
+        position,
+        consumed,
+        severity,
+    });
+    *call_count += 1;
+
+    res
 }
 
 pub(crate) struct Depth {
@@ -72,13 +55,13 @@ pub(crate) struct Depth {
 
 impl Depth {
     pub(crate) fn new() -> Self {
-        let depth = DEPTH.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        let depth = DEPTH.with(|depth| depth.replace(depth.get() + 1));
         let inc = true;
         Self { depth, inc }
     }
 
     pub(crate) fn existing() -> Self {
-        let depth = DEPTH.load(std::sync::atomic::Ordering::SeqCst);
+        let depth = DEPTH.with(Cell::get);
         let inc = false;
         Self { depth, inc }
     }
@@ -87,7 +70,7 @@ impl Depth {
 impl Drop for Depth {
     fn drop(&mut self) {
         if self.inc {
-            let _ = DEPTH.fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
+            DEPTH.with(|depth| depth.set(depth.get() - 1));
         }
     }
 }
@@ -108,31 +91,30 @@ impl crate::lib::std::ops::Deref for Depth {
     }
 }
 
-static DEPTH: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
-
-pub(crate) enum Severity {
-    Success,
-    Backtrack,
-    Cut,
-    Incomplete,
+/// Length of the remaining input when the outermost traced parser started
+struct Origin;
+
+impl Origin {
+    fn position(depth: usize, remaining: usize) -> usize {
+        ORIGIN.with(|origin| {
+            if depth == 0 {
+                origin.set(remaining);
+            }
+            origin.get().saturating_sub(remaining)
+        })
+    }
 }
 
-impl Severity {
-    pub(crate) fn with_result<T, I: Stream, E: ParserError<I>>(result: &Result<T, E>) -> Self {
-        match result {
-            Ok(_) => Self::Success,
-            Err(e) if e.is_backtrack() => Self::Backtrack,
-            Err(e) if e.is_incomplete() => Self::Incomplete,
-            _ => Self::Cut,
-        }
-    }
+thread_local! {
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+    static ORIGIN: Cell<usize> = const { Cell::new(0) };
 }
 
-pub(crate) fn start<I: Stream>(
+pub(crate) fn start(
     depth: usize,
     name: &dyn crate::lib::std::fmt::Display,
     count: usize,
-    input: &I,
+    input: &dyn crate::lib::std::fmt::Debug,
 ) {
     let gutter_style = anstyle::Style::new().bold();
     let input_style = anstyle::Style::new().underline();
@@ -149,7 +131,7 @@ pub(crate) fn start<I: Stream>(
 
     // The debug version of `slice` might be wider, either due to rendering one byte as two nibbles or
     // escaping in strings.
-    let mut debug_slice = format!("{:#?}", input.raw());
+    let mut debug_slice = format!("{input:#?}");
     let (debug_slice, eof) = if let Some(debug_offset) = debug_slice
         .char_indices()
         .enumerate()
@@ -1,12 +1,24 @@
 #![cfg_attr(feature = "debug", allow(clippy::std_instead_of_core))]
 
+#[cfg(feature = "debug")]
+mod filter;
 #[cfg(feature = "debug")]
 mod internals;
+#[cfg(feature = "debug")]
+mod sink;
 
 use crate::error::ParserError;
 use crate::stream::Stream;
 use crate::Parser;
 
+#[cfg(feature = "debug")]
+pub use filter::{FilteredSink, TraceFilter, TraceFilterError};
+#[cfg(feature = "debug")]
+pub use sink::{
+    reset_trace_sink, set_trace_sink, with_trace_sink, JsonLinesSink, Severity, StderrSink,
+    TraceEvent, TraceSink,
+};
+
 /// Trace the execution of the parser
 ///
 /// Note that [`Parser::context`] also provides high level trace information.
@@ -34,20 +46,199 @@ use crate::Parser;
 /// assert!(short_alpha.parse_peek(b"ed").is_err());
 /// assert!(short_alpha.parse_peek(b"12345").is_err());
 /// ```
-#[cfg_attr(not(feature = "debug"), allow(unused_variables))]
-#[cfg_attr(not(feature = "debug"), allow(unused_mut))]
+#[cfg(not(any(feature = "unstable-grammar", feature = "unstable-print")))]
 #[cfg_attr(not(feature = "debug"), inline(always))]
 pub fn trace<I: Stream, O, E: ParserError<I>>(
     name: impl crate::lib::std::fmt::Display,
     parser: impl Parser<I, O, E>,
 ) -> impl Parser<I, O, E> {
+    Trace::new(name, parser)
+}
+
+/// Trace the execution of the parser
+///
+/// Note that [`Parser::context`] also provides high level trace information.
+///
+/// See [tutorial][crate::_tutorial::chapter_8] for more details.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::{error::ErrMode, error::Needed};
+/// # use winnow::token::take_while;
+/// # use winnow::stream::AsChar;
+/// # use winnow::prelude::*;
+/// use winnow::combinator::trace;
+///
+/// fn short_alpha<'s>(s: &mut &'s [u8]) -> ModalResult<&'s [u8]> {
+///   trace("short_alpha",
+///     take_while(3..=6, AsChar::is_alpha)
+///   ).parse_next(s)
+/// }
+///
+/// assert_eq!(short_alpha.parse_peek(b"latin123"), Ok((&b"123"[..], &b"latin"[..])));
+/// assert_eq!(short_alpha.parse_peek(b"lengthy"), Ok((&b"y"[..], &b"length"[..])));
+/// assert_eq!(short_alpha.parse_peek(b"latin"), Ok((&b""[..], &b"latin"[..])));
+/// assert!(short_alpha.parse_peek(b"ed").is_err());
+/// assert!(short_alpha.parse_peek(b"12345").is_err());
+/// ```
+#[cfg(any(feature = "unstable-grammar", feature = "unstable-print"))]
+#[cfg_attr(not(feature = "debug"), inline(always))]
+pub fn trace<I, O, E, P, D>(name: D, parser: P) -> Trace<P, D, I, O, E>
+where
+    I: Stream,
+    E: ParserError<I>,
+    P: Parser<I, O, E>,
+    D: crate::lib::std::fmt::Display,
+{
+    Trace::new(name, parser)
+}
+
+/// [`Parser`] implementation for [`trace`]
+pub struct Trace<P, D, I, O, E>
+where
+    P: Parser<I, O, E>,
+    I: Stream,
+    D: crate::lib::std::fmt::Display,
+    E: ParserError<I>,
+{
+    parser: P,
+    #[cfg(any(feature = "debug", feature = "unstable-grammar"))]
+    name: D,
+    #[cfg(not(any(feature = "debug", feature = "unstable-grammar")))]
+    name: core::marker::PhantomData<D>,
+    #[cfg(feature = "debug")]
+    call_count: usize,
+    i: core::marker::PhantomData<I>,
+    o: core::marker::PhantomData<O>,
+    e: core::marker::PhantomData<E>,
+}
+
+impl<P, D, I, O, E> Trace<P, D, I, O, E>
+where
+    P: Parser<I, O, E>,
+    I: Stream,
+    D: crate::lib::std::fmt::Display,
+    E: ParserError<I>,
+{
+    #[inline(always)]
+    fn new(name: D, parser: P) -> Self {
+        #[cfg(not(any(feature = "debug", feature = "unstable-grammar")))]
+        let name = {
+            let _ = name;
+            core::marker::PhantomData
+        };
+        Self {
+            parser,
+            name,
+            #[cfg(feature = "debug")]
+            call_count: 0,
+            i: Default::default(),
+            o: Default::default(),
+            e: Default::default(),
+        }
+    }
+}
+
+impl<P, D, I, O, E> Parser<I, O, E> for Trace<P, D, I, O, E>
+where
+    P: Parser<I, O, E>,
+    I: Stream,
+    D: crate::lib::std::fmt::Display,
+    E: ParserError<I>,
+{
+    #[cfg_attr(not(feature = "debug"), inline(always))]
+    fn parse_next(&mut self, input: &mut I) -> Result<O, E> {
+        #[cfg(feature = "debug")]
+        {
+            internals::trace(&mut self.parser, &self.name, &mut self.call_count, input)
+        }
+        #[cfg(not(feature = "debug"))]
+        {
+            self.parser.parse_next(input)
+        }
+    }
+}
+
+#[cfg(feature = "unstable-grammar")]
+impl<P, D, I, O, E> crate::describe::Describe for Trace<P, D, I, O, E>
+where
+    P: Parser<I, O, E> + crate::describe::Describe,
+    I: Stream,
+    D: crate::lib::std::fmt::Display,
+    E: ParserError<I>,
+{
+    fn describe(&self, grammar: &mut crate::describe::Grammar) -> crate::describe::Expr {
+        grammar.rule(&self.name, &self.parser)
+    }
+}
+
+#[cfg(feature = "unstable-print")]
+impl<P, D, I, O, E, V> crate::print::Print<V> for Trace<P, D, I, O, E>
+where
+    P: Parser<I, O, E> + crate::print::Print<V>,
+    I: Stream,
+    D: crate::lib::std::fmt::Display,
+    E: ParserError<I>,
+    V: ?Sized,
+{
+    fn print(
+        &self,
+        value: &V,
+        sink: &mut dyn crate::print::Sink,
+    ) -> Result<(), crate::print::PrintError> {
+        self.parser.print(value, sink)
+    }
+}
+
+#[cfg(feature = "unstable-print")]
+impl<P, D, I, O, E> crate::print::PrintConst for Trace<P, D, I, O, E>
+where
+    P: Parser<I, O, E> + crate::print::PrintConst,
+    I: Stream,
+    D: crate::lib::std::fmt::Display,
+    E: ParserError<I>,
+{
+    fn print_const(
+        &self,
+        sink: &mut dyn crate::print::Sink,
+    ) -> Result<(), crate::print::PrintError> {
+        self.parser.print_const(sink)
+    }
+}
+
+/// Number of times a [`Parser`] has been called, for tracing it like [`Trace`]
+///
+/// Built-in parsers hold this rather than building a [`trace`] on each call, which would always
+/// start counting from `0`.
+#[derive(Copy, Clone, Debug, Default)]
+pub(crate) struct CallCount {
+    #[cfg(feature = "debug")]
+    count: usize,
+}
+
+/// Trace one call of `parser`, see [`trace`] and [`CallCount`]
+#[cfg_attr(not(feature = "debug"), inline(always))]
+#[cfg_attr(not(feature = "debug"), allow(unused_variables))]
+pub(crate) fn trace_call<I, O, E, P, D>(
+    name: D,
+    call_count: &mut CallCount,
+    mut parser: P,
+    input: &mut I,
+) -> Result<O, E>
+where
+    I: Stream,
+    E: ParserError<I>,
+    P: Parser<I, O, E>,
+    D: crate::lib::std::fmt::Display,
+{
     #[cfg(feature = "debug")]
     {
-        internals::Trace::new(parser, name)
+        internals::trace(&mut parser, &name, &mut call_count.count, input)
     }
     #[cfg(not(feature = "debug"))]
     {
-        parser
+        parser.parse_next(input)
     }
 }
 
@@ -59,8 +250,12 @@ pub(crate) fn trace_result<T, I: Stream, E: ParserError<I>>(
     #[cfg(feature = "debug")]
     {
         let depth = internals::Depth::existing();
-        let severity = internals::Severity::with_result(res);
-        internals::result(*depth, &name, severity);
+        let severity = Severity::with_result(res);
+        sink::emit(&TraceEvent::Result {
+            name: &name,
+            depth: *depth,
+            severity,
+        });
     }
 }
 
@@ -0,0 +1,365 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::fmt;
+use std::io::Write;
+
+use super::filter::TraceFilter;
+use crate::error::ParserError;
+use crate::stream::Stream;
+
+/// Event of a [`trace`][super::trace]d parser, sent to the [`TraceSink`] of the thread
+#[derive(Copy, Clone)]
+pub enum TraceEvent<'a> {
+    /// A parser is about to run
+    Enter {
+        /// Name given to [`trace`][super::trace]
+        name: &'a dyn fmt::Display,
+        /// Number of traced parsers running around this one
+        depth: usize,
+        /// Number of times this parser ran before
+        call_count: usize,
+        /// Offset of the input from where the outermost traced parser started
+        position: usize,
+        /// Input, to render it
+        input: &'a dyn fmt::Debug,
+    },
+    /// A parser returned
+    Exit {
+        /// Name given to [`trace`][super::trace]
+        name: &'a dyn fmt::Display,
+        /// Number of traced parsers running around this one
+        depth: usize,
+        /// Number of times this parser ran before
+        call_count: usize,
+        /// Offset of the input when the parser started, like for [`TraceEvent::Enter`]
+        position: usize,
+        /// Length of the input consumed
+        consumed: usize,
+        /// How the parser returned
+        severity: Severity,
+    },
+    /// A combinator checked a result, like [`Parser::verify`][crate::Parser::verify]
+    Result {
+        /// Name of the check
+        name: &'a dyn fmt::Display,
+        /// Number of traced parsers running around this check
+        depth: usize,
+        /// Outcome of the check
+        severity: Severity,
+    },
+}
+
+impl TraceEvent<'_> {
+    /// Name given to [`trace`][super::trace], or of the check
+    pub fn name(&self) -> &dyn fmt::Display {
+        match *self {
+            Self::Enter { name, .. } | Self::Exit { name, .. } | Self::Result { name, .. } => name,
+        }
+    }
+
+    /// Number of traced parsers running around this one
+    pub fn depth(&self) -> usize {
+        match *self {
+            Self::Enter { depth, .. } | Self::Exit { depth, .. } | Self::Result { depth, .. } => {
+                depth
+            }
+        }
+    }
+
+    pub(crate) fn with_depth(mut self, depth: usize) -> Self {
+        match &mut self {
+            Self::Enter { depth: d, .. }
+            | Self::Exit { depth: d, .. }
+            | Self::Result { depth: d, .. } => *d = depth,
+        }
+        self
+    }
+}
+
+/// How a parser returned
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Severity {
+    /// The parser succeeded
+    Success,
+    /// The parser failed, allowing alternatives, see [`ErrMode::Backtrack`][crate::error::ErrMode::Backtrack]
+    Backtrack,
+    /// The parser failed, without alternatives, see [`ErrMode::Cut`][crate::error::ErrMode::Cut]
+    Cut,
+    /// The parser needs more input, see [`ErrMode::Incomplete`][crate::error::ErrMode::Incomplete]
+    Incomplete,
+}
+
+impl Severity {
+    pub(crate) fn with_result<T, I: Stream, E: ParserError<I>>(result: &Result<T, E>) -> Self {
+        match result {
+            Ok(_) => Self::Success,
+            Err(e) if e.is_backtrack() => Self::Backtrack,
+            Err(e) if e.is_incomplete() => Self::Incomplete,
+            _ => Self::Cut,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Backtrack => "backtrack",
+            Self::Cut => "cut",
+            Self::Incomplete => "incomplete",
+        }
+    }
+}
+
+/// Destination of [`TraceEvent`]s
+///
+/// Events go to [`StderrSink`], filtered by [`TraceFilter::from_env`], unless another sink is installed for the thread with
+/// [`set_trace_sink`], or for a parse with [`with_trace_sink`].
+///
+/// Closures are sinks too.
+pub trait TraceSink {
+    /// Handle an event
+    fn event(&mut self, event: &TraceEvent<'_>);
+}
+
+impl<F: FnMut(&TraceEvent<'_>)> TraceSink for F {
+    fn event(&mut self, event: &TraceEvent<'_>) {
+        self(event);
+    }
+}
+
+/// Render [`TraceEvent`]s as a colored tree on stderr, the default [`TraceSink`]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct StderrSink;
+
+impl TraceSink for StderrSink {
+    fn event(&mut self, event: &TraceEvent<'_>) {
+        match *event {
+            TraceEvent::Enter {
+                name,
+                depth,
+                call_count,
+                input,
+                ..
+            } => super::internals::start(depth, name, call_count, input),
+            TraceEvent::Exit {
+                name,
+                depth,
+                call_count,
+                consumed,
+                severity,
+                ..
+            } => super::internals::end(depth, name, call_count, consumed, severity),
+            TraceEvent::Result {
+                name,
+                depth,
+                severity,
+            } => super::internals::result(depth, name, severity),
+        }
+    }
+}
+
+/// Write [`TraceEvent`]s as [JSON lines](https://jsonlines.org/), for tools
+///
+/// Each event is an object with the fields of its [`TraceEvent`] variant, besides `input`, and an
+/// `event` of `"enter"`, `"exit"` or `"result"`:
+/// ```text
+/// {"event":"enter","name":"alpha1","depth":0,"call_count":0,"position":0}
+/// {"event":"exit","name":"alpha1","depth":0,"call_count":0,"position":0,"consumed":3,"severity":"success"}
+/// ```
+///
+/// Errors writing are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::alpha1;
+/// use winnow::combinator::{with_trace_sink, JsonLinesSink};
+///
+/// let (sink, _) = with_trace_sink(JsonLinesSink::new(Vec::new()), || {
+///     alpha1::<_, ()>.parse_peek("abc")
+/// });
+/// let json = String::from_utf8(sink.into_inner()).unwrap();
+/// assert_eq!(json.lines().next(), Some(r#"{"event":"enter","name":"alpha1","depth":0,"call_count":0,"position":0}"#));
+/// ```
+#[derive(Clone, Debug)]
+pub struct JsonLinesSink<W> {
+    writer: W,
+}
+
+impl<W: Write> JsonLinesSink<W> {
+    /// Write events to `writer`
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// The writer, to get what was written
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn write(&mut self, event: &TraceEvent<'_>) -> std::io::Result<()> {
+        let kind = match event {
+            TraceEvent::Enter { .. } => "enter",
+            TraceEvent::Exit { .. } => "exit",
+            TraceEvent::Result { .. } => "result",
+        };
+        let mut line = format!(r#"{{"event":"{kind}","name":"#);
+        write_json_string(&mut line, &event.name().to_string());
+        line.push_str(&format!(r#","depth":{}"#, event.depth()));
+        match *event {
+            TraceEvent::Enter {
+                call_count,
+                position,
+                ..
+            } => {
+                line.push_str(&format!(
+                    r#","call_count":{call_count},"position":{position}"#
+                ));
+            }
+            TraceEvent::Exit {
+                call_count,
+                position,
+                consumed,
+                severity,
+                ..
+            } => {
+                line.push_str(&format!(
+                    r#","call_count":{call_count},"position":{position},"consumed":{consumed},"severity":"{}""#,
+                    severity.as_str()
+                ));
+            }
+            TraceEvent::Result { severity, .. } => {
+                line.push_str(&format!(r#","severity":"{}""#, severity.as_str()));
+            }
+        }
+        line.push_str("}\n");
+        self.writer.write_all(line.as_bytes())
+    }
+}
+
+impl<W: Write> TraceSink for JsonLinesSink<W> {
+    fn event(&mut self, event: &TraceEvent<'_>) {
+        let _ = self.write(event);
+    }
+}
+
+fn write_json_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c < '\u{20}' => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Send the [`TraceEvent`]s of this thread to `sink`, instead of stderr
+///
+/// See also [`with_trace_sink`] for a single parse.
+pub fn set_trace_sink(sink: impl TraceSink + 'static) {
+    SINK.with(|slot| *slot.borrow_mut() = Some(Box::new(sink)));
+}
+
+/// Send the [`TraceEvent`]s of this thread back to [`StderrSink`], filtered by
+/// [`TraceFilter::from_env`]
+pub fn reset_trace_sink() {
+    SINK.with(|slot| *slot.borrow_mut() = Some(default_sink()));
+}
+
+/// Send the [`TraceEvent`]s of `f` to `sink`, returning `sink` with the result of `f`
+///
+/// The previous sink of the thread is restored after `f`.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::alpha1;
+/// use winnow::combinator::{with_trace_sink, TraceEvent, TraceSink};
+///
+/// /// Names of the parsers that ran
+/// struct Names(Vec<String>);
+///
+/// impl TraceSink for Names {
+///     fn event(&mut self, event: &TraceEvent<'_>) {
+///         if let TraceEvent::Enter { name, .. } = event {
+///             self.0.push(name.to_string());
+///         }
+///     }
+/// }
+///
+/// let (names, result) = with_trace_sink(Names(Vec::new()), || {
+///     alpha1::<_, ()>.parse_peek("abc")
+/// });
+/// assert_eq!(result, Ok(("", "abc")));
+/// assert_eq!(names.0, ["alpha1", "take_while"]);
+/// ```
+pub fn with_trace_sink<S, R>(sink: S, f: impl FnOnce() -> R) -> (S, R)
+where
+    S: TraceSink + 'static,
+{
+    let previous = SINK.with(|slot| slot.replace(Some(Box::new(sink))));
+    let _restore = Restore(previous);
+    let output = f();
+    let sink = SINK
+        .with(|slot| slot.borrow_mut().take())
+        .and_then(|sink| sink.into_any().downcast::<S>().ok())
+        .expect("trace sink was replaced during `with_trace_sink`");
+    (*sink, output)
+}
+
+/// Send an event to the sink of the thread
+///
+/// Events while the sink is busy, from parsers it runs, are dropped.
+pub(crate) fn emit(event: &TraceEvent<'_>) {
+    SINK.with(|slot| {
+        if let Ok(mut sink) = slot.try_borrow_mut() {
+            match sink.as_mut() {
+                Some(sink) => sink.event(event),
+                None => StderrSink.event(event),
+            }
+        }
+    });
+}
+
+thread_local! {
+    static SINK: RefCell<Option<Box<dyn AnySink>>> = RefCell::new(Some(default_sink()));
+}
+
+fn default_sink() -> Box<dyn AnySink> {
+    match TraceFilter::from_env() {
+        Ok(filter) if filter == TraceFilter::new() => Box::new(StderrSink),
+        Ok(filter) => Box::new(filter.sink(StderrSink)),
+        Err(err) => {
+            let _ = writeln!(anstream::stderr(), "{err}, ignoring `WINNOW_TRACE`");
+            Box::new(StderrSink)
+        }
+    }
+}
+
+/// Restores the previous sink of [`with_trace_sink`], even on panic
+struct Restore(Option<Box<dyn AnySink>>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        SINK.with(|slot| *slot.borrow_mut() = previous);
+    }
+}
+
+/// [`TraceSink`] that can be taken back by [`with_trace_sink`]
+trait AnySink: TraceSink {
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl<S: TraceSink + 'static> AnySink for S {
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
@@ -13,7 +13,7 @@ use crate::lib::std::ops::Range;
 #[cfg(feature = "std")]
 use crate::stream::Recover;
 use crate::stream::StreamIsPartial;
-use crate::stream::{Location, Stream};
+use crate::stream::{Location, NodeSink, Stream};
 use crate::*;
 
 /// [`Parser`] implementation for [`Parser::by_ref`]
@@ -397,9 +397,7 @@ where
         let checkpoint = input.checkpoint();
         match (self.parser).parse_next(input) {
             Ok(_) => {
-                let offset = input.offset_from(&checkpoint);
-                input.reset(&checkpoint);
-                let taken = input.next_slice(offset);
+                let taken = input.taken_since(&checkpoint);
                 Ok(taken)
             }
             Err(e) => Err(e),
@@ -429,9 +427,7 @@ where
         let checkpoint = input.checkpoint();
         match (self.parser).parse_next(input) {
             Ok(result) => {
-                let offset = input.offset_from(&checkpoint);
-                input.reset(&checkpoint);
-                let taken = input.next_slice(offset);
+                let taken = input.taken_since(&checkpoint);
                 Ok((result, taken))
             }
             Err(e) => Err(e),
@@ -493,6 +489,44 @@ where
     }
 }
 
+/// [`Parser`] implementation for [`Parser::node`]
+pub struct Node<F, I, O, E, K>
+where
+    F: Parser<I, O, E>,
+    I: Stream + NodeSink<K>,
+{
+    pub(crate) parser: F,
+    pub(crate) kind: K,
+    pub(crate) i: core::marker::PhantomData<I>,
+    pub(crate) o: core::marker::PhantomData<O>,
+    pub(crate) e: core::marker::PhantomData<E>,
+}
+
+impl<F, I, O, E, K> Parser<I, O, E> for Node<F, I, O, E, K>
+where
+    F: Parser<I, O, E>,
+    I: Stream + NodeSink<K>,
+    K: Clone,
+    E: ParserError<I>,
+{
+    #[inline]
+    fn parse_next(&mut self, input: &mut I) -> Result<O, E> {
+        crate::stream::skip_trivia(input)?;
+        let start = input.checkpoint();
+        input.start_node(self.kind.clone());
+        match self.parser.parse_next(input) {
+            Ok(output) => {
+                input.finish_node();
+                Ok(output)
+            }
+            Err(err) => {
+                input.reset(&start);
+                Err(err)
+            }
+        }
+    }
+}
+
 /// [`Parser`] implementation for [`Parser::output_into`]
 pub struct OutputInto<F, I, O, O2, E>
 where
@@ -727,3 +761,415 @@ where
     err = FromRecoverableError::from_recoverable_error(&token_start, &err_start, i, err);
     Err(err)
 }
+
+#[cfg(feature = "unstable-grammar")]
+mod describe {
+    use super::*;
+    use crate::describe::{Describe, Expr, Grammar};
+
+    impl<P, I, O, E> Describe for ByRef<'_, P, I, O, E>
+    where
+        P: Parser<I, O, E> + Describe,
+    {
+        fn describe(&self, grammar: &mut Grammar) -> Expr {
+            self.p.describe(grammar)
+        }
+    }
+
+    impl<F, G, I, O, O2, E> Describe for Map<F, G, I, O, O2, E>
+    where
+        F: Parser<I, O, E> + Describe,
+        G: FnMut(O) -> O2,
+    {
+        fn describe(&self, grammar: &mut Grammar) -> Expr {
+            self.parser.describe(grammar)
+        }
+    }
+
+    impl<F, G, I, O, O2, E, E2> Describe for TryMap<F, G, I, O, O2, E, E2>
+    where
+        F: Parser<I, O, E> + Describe,
+        G: FnMut(O) -> Result<O2, E2>,
+        I: Stream,
+        E: FromExternalError<I, E2>,
+        E: ParserError<I>,
+    {
+        fn describe(&self, grammar: &mut Grammar) -> Expr {
+            self.parser.describe(grammar)
+        }
+    }
+
+    impl<F, G, I, O, O2, E> Describe for VerifyMap<F, G, I, O, O2, E>
+    where
+        F: Parser<I, O, E> + Describe,
+        G: FnMut(O) -> Option<O2>,
+        I: Stream,
+        E: ParserError<I>,
+    {
+        fn describe(&self, grammar: &mut Grammar) -> Expr {
+            self.parser.describe(grammar)
+        }
+    }
+
+    impl<F, G, I, O, O2, E> Describe for AndThen<F, G, I, O, O2, E>
+    where
+        F: Parser<I, O, E> + Describe,
+        G: Parser<O, O2, E>,
+        O: StreamIsPartial,
+        I: Stream,
+    {
+        fn describe(&self, grammar: &mut Grammar) -> Expr {
+            self.outer.describe(grammar)
+        }
+    }
+
+    impl<P, I, O, O2, E> Describe for ParseTo<P, I, O, O2, E>
+    where
+        P: Parser<I, O, E> + Describe,
+        I: Stream,
+        O: crate::stream::ParseSlice<O2>,
+        E: ParserError<I>,
+    {
+        fn describe(&self, grammar: &mut Grammar) -> Expr {
+            self.p.describe(grammar)
+        }
+    }
+
+    impl<P, I, O, E> Describe for CompleteErr<P, I, O, E>
+    where
+        P: Parser<I, O, E> + Describe,
+    {
+        fn describe(&self, grammar: &mut Grammar) -> Expr {
+            self.p.describe(grammar)
+        }
+    }
+
+    impl<F, G, I, O, O2, E> Describe for Verify<F, G, I, O, O2, E>
+    where
+        F: Parser<I, O, E> + Describe,
+        G: FnMut(&O2) -> bool,
+        I: Stream,
+        O: Borrow<O2>,
+        O2: ?Sized,
+        E: ParserError<I>,
+    {
+        fn describe(&self, grammar: &mut Grammar) -> Expr {
+            self.parser.describe(grammar)
+        }
+    }
+
+    impl<F, I, O, O2, E> Describe for Value<F, I, O, O2, E>
+    where
+        F: Parser<I, O, E> + Describe,
+        O2: Clone,
+    {
+        fn describe(&self, grammar: &mut Grammar) -> Expr {
+            self.parser.describe(grammar)
+        }
+    }
+
+    impl<F, I, O, O2, E> Describe for DefaultValue<F, I, O, O2, E>
+    where
+        F: Parser<I, O, E> + Describe,
+        O2: core::default::Default,
+    {
+        fn describe(&self, grammar: &mut Grammar) -> Expr {
+            self.parser.describe(grammar)
+        }
+    }
+
+    impl<F, I, O, E> Describe for Void<F, I, O, E>
+    where
+        F: Parser<I, O, E> + Describe,
+    {
+        fn describe(&self, grammar: &mut Grammar) -> Expr {
+            self.parser.describe(grammar)
+        }
+    }
+
+    impl<F, I, O, E> Describe for Take<F, I, O, E>
+    where
+        F: Parser<I, O, E> + Describe,
+        I: Stream,
+    {
+        fn describe(&self, grammar: &mut Grammar) -> Expr {
+            self.parser.describe(grammar)
+        }
+    }
+
+    impl<F, I, O, E> Describe for WithTaken<F, I, O, E>
+    where
+        F: Parser<I, O, E> + Describe,
+        I: Stream,
+    {
+        fn describe(&self, grammar: &mut Grammar) -> Expr {
+            self.parser.describe(grammar)
+        }
+    }
+
+    impl<F, I, O, E> Describe for Span<F, I, O, E>
+    where
+        F: Parser<I, O, E> + Describe,
+        I: Stream + Location,
+    {
+        fn describe(&self, grammar: &mut Grammar) -> Expr {
+            self.parser.describe(grammar)
+        }
+    }
+
+    impl<F, I, O, E> Describe for WithSpan<F, I, O, E>
+    where
+        F: Parser<I, O, E> + Describe,
+        I: Stream + Location,
+    {
+        fn describe(&self, grammar: &mut Grammar) -> Expr {
+            self.parser.describe(grammar)
+        }
+    }
+
+    impl<F, I, O, E, K> Describe for Node<F, I, O, E, K>
+    where
+        F: Parser<I, O, E> + Describe,
+        I: Stream + NodeSink<K>,
+    {
+        fn describe(&self, grammar: &mut Grammar) -> Expr {
+            self.parser.describe(grammar)
+        }
+    }
+
+    impl<F, I, O, O2, E> Describe for OutputInto<F, I, O, O2, E>
+    where
+        F: Parser<I, O, E> + Describe,
+        O: Into<O2>,
+    {
+        fn describe(&self, grammar: &mut Grammar) -> Expr {
+            self.parser.describe(grammar)
+        }
+    }
+
+    impl<F, I, O, E, E2> Describe for ErrInto<F, I, O, E, E2>
+    where
+        F: Parser<I, O, E> + Describe,
+        E: Into<E2>,
+    {
+        fn describe(&self, grammar: &mut Grammar) -> Expr {
+            self.parser.describe(grammar)
+        }
+    }
+
+    impl<F, I, O, E, C> Describe for Context<F, I, O, E, C>
+    where
+        F: Parser<I, O, E> + Describe,
+        I: Stream,
+        E: AddContext<I, C>,
+        E: ParserError<I>,
+        C: Clone + crate::lib::std::fmt::Debug,
+    {
+        fn describe(&self, grammar: &mut Grammar) -> Expr {
+            self.parser.describe(grammar)
+        }
+    }
+}
+
+#[cfg(feature = "unstable-print")]
+mod print {
+    use super::*;
+    use crate::print::{Print, PrintConst, PrintError, Sink};
+
+    impl<P, I, O, E, V> Print<V> for ByRef<'_, P, I, O, E>
+    where
+        P: Parser<I, O, E> + Print<V>,
+        V: ?Sized,
+    {
+        fn print(&self, value: &V, sink: &mut dyn Sink) -> Result<(), PrintError> {
+            self.p.print(value, sink)
+        }
+    }
+
+    impl<P, I, O, E> PrintConst for ByRef<'_, P, I, O, E>
+    where
+        P: Parser<I, O, E> + PrintConst,
+    {
+        fn print_const(&self, sink: &mut dyn Sink) -> Result<(), PrintError> {
+            self.p.print_const(sink)
+        }
+    }
+
+    /// Prints the value with [`Display`][crate::lib::std::fmt::Display]
+    impl<P, I, O, O2, E> Print<O2> for ParseTo<P, I, O, O2, E>
+    where
+        P: Parser<I, O, E>,
+        I: Stream,
+        O: crate::stream::ParseSlice<O2>,
+        O2: crate::lib::std::fmt::Display,
+        E: ParserError<I>,
+    {
+        fn print(&self, value: &O2, sink: &mut dyn Sink) -> Result<(), PrintError> {
+            use crate::lib::std::fmt::Write as _;
+            write!(crate::print::SinkWriter(sink), "{value}").map_err(|_| PrintError::Sink)
+        }
+    }
+
+    impl<P, I, O, E, V> Print<V> for CompleteErr<P, I, O, E>
+    where
+        P: Parser<I, O, E> + Print<V>,
+        V: ?Sized,
+    {
+        fn print(&self, value: &V, sink: &mut dyn Sink) -> Result<(), PrintError> {
+            self.p.print(value, sink)
+        }
+    }
+
+    impl<P, I, O, E> PrintConst for CompleteErr<P, I, O, E>
+    where
+        P: Parser<I, O, E> + PrintConst,
+    {
+        fn print_const(&self, sink: &mut dyn Sink) -> Result<(), PrintError> {
+            self.p.print_const(sink)
+        }
+    }
+
+    impl<F, G, I, O, O2, E, V> Print<V> for Verify<F, G, I, O, O2, E>
+    where
+        F: Parser<I, O, E> + Print<V>,
+        G: FnMut(&O2) -> bool,
+        I: Stream,
+        O: Borrow<O2>,
+        O2: ?Sized,
+        E: ParserError<I>,
+        V: ?Sized,
+    {
+        fn print(&self, value: &V, sink: &mut dyn Sink) -> Result<(), PrintError> {
+            self.parser.print(value, sink)
+        }
+    }
+
+    impl<F, I, O, O2, E> Print<O2> for Value<F, I, O, O2, E>
+    where
+        F: Parser<I, O, E> + PrintConst,
+        O2: Clone,
+    {
+        fn print(&self, _value: &O2, sink: &mut dyn Sink) -> Result<(), PrintError> {
+            self.parser.print_const(sink)
+        }
+    }
+
+    impl<F, I, O, O2, E> PrintConst for Value<F, I, O, O2, E>
+    where
+        F: Parser<I, O, E> + PrintConst,
+        O2: Clone,
+    {
+        fn print_const(&self, sink: &mut dyn Sink) -> Result<(), PrintError> {
+            self.parser.print_const(sink)
+        }
+    }
+
+    impl<F, I, O, O2, E> Print<O2> for DefaultValue<F, I, O, O2, E>
+    where
+        F: Parser<I, O, E> + PrintConst,
+        O2: core::default::Default,
+    {
+        fn print(&self, _value: &O2, sink: &mut dyn Sink) -> Result<(), PrintError> {
+            self.parser.print_const(sink)
+        }
+    }
+
+    impl<F, I, O, O2, E> PrintConst for DefaultValue<F, I, O, O2, E>
+    where
+        F: Parser<I, O, E> + PrintConst,
+        O2: core::default::Default,
+    {
+        fn print_const(&self, sink: &mut dyn Sink) -> Result<(), PrintError> {
+            self.parser.print_const(sink)
+        }
+    }
+
+    impl<F, I, O, E> Print<()> for Void<F, I, O, E>
+    where
+        F: Parser<I, O, E> + PrintConst,
+    {
+        fn print(&self, _value: &(), sink: &mut dyn Sink) -> Result<(), PrintError> {
+            self.parser.print_const(sink)
+        }
+    }
+
+    impl<F, I, O, E> PrintConst for Void<F, I, O, E>
+    where
+        F: Parser<I, O, E> + PrintConst,
+    {
+        fn print_const(&self, sink: &mut dyn Sink) -> Result<(), PrintError> {
+            self.parser.print_const(sink)
+        }
+    }
+
+    /// Prints the value as-is
+    impl<F, I, O, E, V> Print<V> for Take<F, I, O, E>
+    where
+        F: Parser<I, O, E>,
+        I: Stream,
+        V: PrintConst + ?Sized,
+    {
+        fn print(&self, value: &V, sink: &mut dyn Sink) -> Result<(), PrintError> {
+            value.print_const(sink)
+        }
+    }
+
+    impl<F, I, O, E, K, V> Print<V> for Node<F, I, O, E, K>
+    where
+        F: Parser<I, O, E> + Print<V>,
+        I: Stream + NodeSink<K>,
+        V: ?Sized,
+    {
+        fn print(&self, value: &V, sink: &mut dyn Sink) -> Result<(), PrintError> {
+            self.parser.print(value, sink)
+        }
+    }
+
+    impl<F, I, O, O2, E> Print<O2> for OutputInto<F, I, O, O2, E>
+    where
+        F: Parser<I, O, E> + Print<O2>,
+        O: Into<O2>,
+    {
+        fn print(&self, value: &O2, sink: &mut dyn Sink) -> Result<(), PrintError> {
+            self.parser.print(value, sink)
+        }
+    }
+
+    impl<F, I, O, E, E2, V> Print<V> for ErrInto<F, I, O, E, E2>
+    where
+        F: Parser<I, O, E> + Print<V>,
+        E: Into<E2>,
+        V: ?Sized,
+    {
+        fn print(&self, value: &V, sink: &mut dyn Sink) -> Result<(), PrintError> {
+            self.parser.print(value, sink)
+        }
+    }
+
+    impl<F, I, O, E, C, V> Print<V> for Context<F, I, O, E, C>
+    where
+        F: Parser<I, O, E> + Print<V>,
+        I: Stream,
+        E: AddContext<I, C>,
+        E: ParserError<I>,
+        C: Clone + crate::lib::std::fmt::Debug,
+        V: ?Sized,
+    {
+        fn print(&self, value: &V, sink: &mut dyn Sink) -> Result<(), PrintError> {
+            self.parser.print(value, sink)
+        }
+    }
+
+    impl<F, I, O, E, C> PrintConst for Context<F, I, O, E, C>
+    where
+        F: Parser<I, O, E> + PrintConst,
+        I: Stream,
+        E: AddContext<I, C>,
+        E: ParserError<I>,
+        C: Clone + crate::lib::std::fmt::Debug,
+    {
+        fn print_const(&self, sink: &mut dyn Sink) -> Result<(), PrintError> {
+            self.parser.print_const(sink)
+        }
+    }
+}
@@ -38,6 +38,7 @@
 //! | [`preceded`] | `preceded("ab", "XY")` | `"abXYZ"` | `"Z"` | `Ok("XY")` |Parse two values, discarding the first value|
 //! | [`terminated`] | `terminated("ab", "XY")` | `"abXYZ"` | `"Z"` | `Ok("ab")` |Parse two values, discarding the second value|
 //! | [`separated_pair`] | `separated_pair("hello", ',', "world")` | `"hello,world!"` | `"!"` | `Ok(("hello", "world"))` | Parse three values, discarding the middle value|
+//! | [`grammar!`] | \- | \- | \- | \- | Define parser functions from PEG-style rules |
 //!
 //! ## Applying a parser multiple times
 //!
@@ -72,7 +73,9 @@
 //! - [`Parser::with_taken`]: If the child parser was successful, return a tuple of the consumed input and the produced output.
 //! - [`Parser::span`]: If the child parser was successful, return the location of the consumed input as the produced value
 //! - [`Parser::with_span`]: If the child parser was successful, return a tuple of the location of the consumed input and the produced output.
+//! - [`Parser::node`]: Record the consumed input as a node of a [lossless syntax tree][crate::cst]
 //! - [`Parser::verify`]: Returns the result of the child parser if it satisfies a verification function
+//! - [`lexeme`]: Skip [`Trivia`][crate::stream::Trivia] before, but not within, the child parser
 //!
 //! ## Error management and debugging
 //!
@@ -176,5 +179,7 @@ pub use self::debug::*;
 pub use self::multi::*;
 pub use self::sequence::*;
 
+pub use crate::grammar;
+
 #[allow(unused_imports)]
 use crate::Parser;
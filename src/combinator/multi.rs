@@ -1,20 +1,22 @@
 //! Combinators applying their child parser multiple times
 
 use crate::combinator::trace;
+use crate::combinator::trace_call;
+use crate::combinator::CallCount;
 use crate::error::FromExternalError;
 use crate::error::ParserError;
-use crate::stream::Accumulate;
 use crate::stream::Range;
 use crate::stream::Stream;
+use crate::stream::TryAccumulate;
 use crate::Parser;
 use crate::Result;
 
-/// [`Accumulate`] the output of a parser into a container, like `Vec`
+/// [Accumulate][TryAccumulate] the output of a parser into a container, like `Vec`
 ///
 /// This stops before `n` when the parser returns [`ErrMode::Backtrack`][crate::error::ErrMode::Backtrack]. To instead chain an error up, see
 /// [`cut_err`][crate::combinator::cut_err].
 ///
-/// To take a series of tokens, [`Accumulate`] into a `()`
+/// To take a series of tokens, [Accumulate][TryAccumulate] into a `()`
 /// (e.g. with [`.map(|()| ())`][Parser::map])
 /// and then [`Parser::take`].
 ///
@@ -117,7 +119,7 @@ pub fn repeat<Input, Output, Accumulator, Error, ParseNext>(
 ) -> Repeat<ParseNext, Input, Output, Accumulator, Error>
 where
     Input: Stream,
-    Accumulator: Accumulate<Output>,
+    Accumulator: TryAccumulate<Output>,
     ParseNext: Parser<Input, Output, Error>,
     Error: ParserError<Input>,
 {
@@ -136,7 +138,7 @@ pub struct Repeat<P, I, O, C, E>
 where
     P: Parser<I, O, E>,
     I: Stream,
-    C: Accumulate<O>,
+    C: TryAccumulate<O>,
     E: ParserError<I>,
 {
     occurrences: Range,
@@ -436,7 +438,7 @@ impl<P, I, O, C, E> Parser<I, C, E> for Repeat<P, I, O, C, E>
 where
     P: Parser<I, O, E>,
     I: Stream,
-    C: Accumulate<O>,
+    C: TryAccumulate<O>,
     E: ParserError<I>,
 {
     #[inline(always)]
@@ -457,21 +459,65 @@ where
     }
 }
 
+#[cfg(feature = "unstable-grammar")]
+impl<P, I, O, C, E> crate::describe::Describe for Repeat<P, I, O, C, E>
+where
+    P: Parser<I, O, E> + crate::describe::Describe,
+    I: Stream,
+    C: TryAccumulate<O>,
+    E: ParserError<I>,
+{
+    fn describe(&self, grammar: &mut crate::describe::Grammar) -> crate::describe::Expr {
+        crate::describe::Expr::repeat(
+            self.parser.describe(grammar),
+            self.occurrences.start_inclusive,
+            self.occurrences.end_inclusive,
+        )
+    }
+}
+
+#[cfg(feature = "unstable-print")]
+impl<P, I, O, C, E> crate::print::Print<C> for Repeat<P, I, O, C, E>
+where
+    P: Parser<I, O, E> + crate::print::Print<O>,
+    I: Stream,
+    C: TryAccumulate<O>,
+    for<'c> &'c C: IntoIterator<Item = &'c O>,
+    E: ParserError<I>,
+{
+    fn print(
+        &self,
+        value: &C,
+        sink: &mut dyn crate::print::Sink,
+    ) -> Result<(), crate::print::PrintError> {
+        let mut count = 0;
+        for item in value {
+            self.parser.print(item, sink)?;
+            count += 1;
+        }
+        if crate::lib::std::ops::RangeBounds::contains(&self.occurrences, &count) {
+            Ok(())
+        } else {
+            Err(crate::print::PrintError::Unprintable)
+        }
+    }
+}
+
 fn repeat0_<I, O, C, E, F>(f: &mut F, i: &mut I) -> Result<C, E>
 where
     I: Stream,
-    C: Accumulate<O>,
+    C: TryAccumulate<O>,
     F: Parser<I, O, E>,
     E: ParserError<I>,
 {
-    let mut acc = C::initial(None);
+    let mut acc = Accumulator::<C, O>::initial(None);
     loop {
         let start = i.checkpoint();
         let len = i.eof_offset();
         match f.parse_next(i) {
             Err(e) if e.is_backtrack() => {
                 i.reset(&start);
-                return Ok(acc);
+                return acc.finish(i);
             }
             Err(e) => return Err(e),
             Ok(o) => {
@@ -483,7 +529,7 @@ where
                     ));
                 }
 
-                acc.accumulate(o);
+                acc.accumulate(o, i, &start)?;
             }
         }
     }
@@ -492,7 +538,7 @@ where
 fn repeat1_<I, O, C, E, F>(f: &mut F, i: &mut I) -> Result<C, E>
 where
     I: Stream,
-    C: Accumulate<O>,
+    C: TryAccumulate<O>,
     F: Parser<I, O, E>,
     E: ParserError<I>,
 {
@@ -500,8 +546,8 @@ where
     match f.parse_next(i) {
         Err(e) => Err(e.append(i, &start)),
         Ok(o) => {
-            let mut acc = C::initial(None);
-            acc.accumulate(o);
+            let mut acc = Accumulator::<C, O>::initial(None);
+            acc.accumulate(o, i, &start)?;
 
             loop {
                 let start = i.checkpoint();
@@ -509,7 +555,7 @@ where
                 match f.parse_next(i) {
                     Err(e) if e.is_backtrack() => {
                         i.reset(&start);
-                        return Ok(acc);
+                        return acc.finish(i);
                     }
                     Err(e) => return Err(e),
                     Ok(o) => {
@@ -521,7 +567,7 @@ where
                             ));
                         }
 
-                        acc.accumulate(o);
+                        acc.accumulate(o, i, &start)?;
                     }
                 }
             }
@@ -532,11 +578,11 @@ where
 fn repeat_n_<I, O, C, E, F>(count: usize, f: &mut F, i: &mut I) -> Result<C, E>
 where
     I: Stream,
-    C: Accumulate<O>,
+    C: TryAccumulate<O>,
     F: Parser<I, O, E>,
     E: ParserError<I>,
 {
-    let mut res = C::initial(Some(count));
+    let mut res = Accumulator::<C, O>::initial(Some(count));
 
     for _ in 0..count {
         let start = i.checkpoint();
@@ -551,7 +597,7 @@ where
                     ));
                 }
 
-                res.accumulate(o);
+                res.accumulate(o, i, &start)?;
             }
             Err(e) => {
                 return Err(e.append(i, &start));
@@ -559,13 +605,13 @@ where
         }
     }
 
-    Ok(res)
+    res.finish(i)
 }
 
 fn repeat_m_n_<I, O, C, E, F>(min: usize, max: usize, parse: &mut F, input: &mut I) -> Result<C, E>
 where
     I: Stream,
-    C: Accumulate<O>,
+    C: TryAccumulate<O>,
     F: Parser<I, O, E>,
     E: ParserError<I>,
 {
@@ -576,7 +622,7 @@ where
         ));
     }
 
-    let mut res = C::initial(Some(min));
+    let mut res = Accumulator::<C, O>::initial(Some(min));
     for count in 0..max {
         let start = input.checkpoint();
         let len = input.eof_offset();
@@ -590,14 +636,14 @@ where
                     ));
                 }
 
-                res.accumulate(value);
+                res.accumulate(value, input, &start)?;
             }
             Err(e) if e.is_backtrack() => {
                 if count < min {
                     return Err(e.append(input, &start));
                 } else {
                     input.reset(&start);
-                    return Ok(res);
+                    return res.finish(input);
                 }
             }
             Err(e) => {
@@ -606,17 +652,17 @@ where
         }
     }
 
-    Ok(res)
+    res.finish(input)
 }
 
-/// [`Accumulate`] the output of parser `f` into a container, like `Vec`, until the parser `g`
+/// [Accumulate][TryAccumulate] the output of parser `f` into a container, like `Vec`, until the parser `g`
 /// produces a result.
 ///
 /// Returns a tuple of the results of `f` in a `Vec` and the result of `g`.
 ///
 /// `f` keeps going so long as `g` produces [`ErrMode::Backtrack`][crate::error::ErrMode::Backtrack]. To instead chain an error up, see [`cut_err`][crate::combinator::cut_err].
 ///
-/// To take a series of tokens, [`Accumulate`] into a `()`
+/// To take a series of tokens, [Accumulate][TryAccumulate] into a `()`
 /// (e.g. with [`.map(|((), _)| ())`][Parser::map])
 /// and then [`Parser::take`].
 ///
@@ -651,7 +697,7 @@ pub fn repeat_till<Input, Output, Accumulator, Terminator, Error, ParseNext, Ter
 ) -> impl Parser<Input, (Accumulator, Terminator), Error>
 where
     Input: Stream,
-    Accumulator: Accumulate<Output>,
+    Accumulator: TryAccumulate<Output>,
     ParseNext: Parser<Input, Output, Error>,
     TerminatorParser: Parser<Input, Terminator, Error>,
     Error: ParserError<Input>,
@@ -677,17 +723,17 @@ where
 fn repeat_till0_<I, O, C, P, E, F, G>(f: &mut F, g: &mut G, i: &mut I) -> Result<(C, P), E>
 where
     I: Stream,
-    C: Accumulate<O>,
+    C: TryAccumulate<O>,
     F: Parser<I, O, E>,
     G: Parser<I, P, E>,
     E: ParserError<I>,
 {
-    let mut res = C::initial(None);
+    let mut res = Accumulator::<C, O>::initial(None);
     loop {
         let start = i.checkpoint();
         let len = i.eof_offset();
         match g.parse_next(i) {
-            Ok(o) => return Ok((res, o)),
+            Ok(o) => return Ok((res.finish(i)?, o)),
             Err(e) if e.is_backtrack() => {
                 i.reset(&start);
                 match f.parse_next(i) {
@@ -701,7 +747,7 @@ where
                             ));
                         }
 
-                        res.accumulate(o);
+                        res.accumulate(o, i, &start)?;
                     }
                 }
             }
@@ -719,7 +765,7 @@ fn repeat_till_m_n_<I, O, C, P, E, F, G>(
 ) -> Result<(C, P), E>
 where
     I: Stream,
-    C: Accumulate<O>,
+    C: TryAccumulate<O>,
     F: Parser<I, O, E>,
     G: Parser<I, P, E>,
     E: ParserError<I>,
@@ -731,13 +777,13 @@ where
         ));
     }
 
-    let mut res = C::initial(Some(min));
+    let mut res = Accumulator::<C, O>::initial(Some(min));
 
     let start = i.checkpoint();
     for _ in 0..min {
         match f.parse_next(i) {
             Ok(o) => {
-                res.accumulate(o);
+                res.accumulate(o, i, &start)?;
             }
             Err(e) => {
                 return Err(e.append(i, &start));
@@ -748,7 +794,7 @@ where
         let start = i.checkpoint();
         let len = i.eof_offset();
         match g.parse_next(i) {
-            Ok(o) => return Ok((res, o)),
+            Ok(o) => return Ok((res.finish(i)?, o)),
             Err(err) if err.is_backtrack() => {
                 if count == max {
                     return Err(err);
@@ -767,7 +813,7 @@ where
                             ));
                         }
 
-                        res.accumulate(o);
+                        res.accumulate(o, i, &start)?;
                     }
                 }
             }
@@ -777,12 +823,12 @@ where
     unreachable!()
 }
 
-/// [`Accumulate`] the output of a parser, interleaved with `sep`
+/// [Accumulate][TryAccumulate] the output of a parser, interleaved with `sep`
 ///
 /// This stops when either parser returns [`ErrMode::Backtrack`][crate::error::ErrMode::Backtrack]. To instead chain an error up, see
 /// [`cut_err`][crate::combinator::cut_err].
 ///
-/// To take a series of tokens, [`Accumulate`] into a `()`
+/// To take a series of tokens, [Accumulate][TryAccumulate] into a `()`
 /// (e.g. with [`.map(|()| ())`][Parser::map])
 /// and then [`Parser::take`].
 ///
@@ -879,36 +925,136 @@ where
 #[inline(always)]
 pub fn separated<Input, Output, Accumulator, Sep, Error, ParseNext, SepParser>(
     occurrences: impl Into<Range>,
-    mut parser: ParseNext,
-    mut separator: SepParser,
-) -> impl Parser<Input, Accumulator, Error>
+    parser: ParseNext,
+    separator: SepParser,
+) -> introspectable!(
+       Separated<ParseNext, SepParser, Input, Output, Sep, Accumulator, Error>,
+       impl Parser<Input, Accumulator, Error>
+   )
 where
     Input: Stream,
-    Accumulator: Accumulate<Output>,
+    Accumulator: TryAccumulate<Output>,
     ParseNext: Parser<Input, Output, Error>,
     SepParser: Parser<Input, Sep, Error>,
     Error: ParserError<Input>,
 {
-    let Range {
-        start_inclusive,
-        end_inclusive,
-    } = occurrences.into();
-    trace("separated", move |input: &mut Input| {
-        match (start_inclusive, end_inclusive) {
-            (0, None) => separated0_(&mut parser, &mut separator, input),
-            (1, None) => separated1_(&mut parser, &mut separator, input),
-            (start, end) if Some(start) == end => {
-                separated_n_(start, &mut parser, &mut separator, input)
+    Separated {
+        occurrences: occurrences.into(),
+        parser,
+        separator,
+        call_count: Default::default(),
+        i: Default::default(),
+        o: Default::default(),
+        o2: Default::default(),
+        c: Default::default(),
+        e: Default::default(),
+    }
+}
+
+/// [`Parser`] implementation for [`separated`]
+pub struct Separated<P, S, I, O, O2, C, E>
+where
+    P: Parser<I, O, E>,
+    S: Parser<I, O2, E>,
+    I: Stream,
+    C: TryAccumulate<O>,
+    E: ParserError<I>,
+{
+    occurrences: Range,
+    parser: P,
+    separator: S,
+    call_count: CallCount,
+    i: core::marker::PhantomData<I>,
+    o: core::marker::PhantomData<O>,
+    o2: core::marker::PhantomData<O2>,
+    c: core::marker::PhantomData<C>,
+    e: core::marker::PhantomData<E>,
+}
+
+impl<P, S, I, O, O2, C, E> Parser<I, C, E> for Separated<P, S, I, O, O2, C, E>
+where
+    P: Parser<I, O, E>,
+    S: Parser<I, O2, E>,
+    I: Stream,
+    C: TryAccumulate<O>,
+    E: ParserError<I>,
+{
+    #[inline(always)]
+    fn parse_next(&mut self, input: &mut I) -> Result<C, E> {
+        let Range {
+            start_inclusive,
+            end_inclusive,
+        } = self.occurrences;
+        trace_call(
+            "separated",
+            &mut self.call_count,
+            |input: &mut I| match (start_inclusive, end_inclusive) {
+                (0, None) => separated0_(&mut self.parser, &mut self.separator, input),
+                (1, None) => separated1_(&mut self.parser, &mut self.separator, input),
+                (start, end) if Some(start) == end => {
+                    separated_n_(start, &mut self.parser, &mut self.separator, input)
+                }
+                (start, end) => separated_m_n_(
+                    start,
+                    end.unwrap_or(usize::MAX),
+                    &mut self.parser,
+                    &mut self.separator,
+                    input,
+                ),
+            },
+            input,
+        )
+    }
+}
+
+#[cfg(feature = "unstable-grammar")]
+impl<P, S, I, O, O2, C, E> crate::describe::Describe for Separated<P, S, I, O, O2, C, E>
+where
+    P: Parser<I, O, E> + crate::describe::Describe,
+    S: Parser<I, O2, E> + crate::describe::Describe,
+    I: Stream,
+    C: TryAccumulate<O>,
+    E: ParserError<I>,
+{
+    fn describe(&self, grammar: &mut crate::describe::Grammar) -> crate::describe::Expr {
+        crate::describe::Expr::separated(
+            self.parser.describe(grammar),
+            self.separator.describe(grammar),
+            self.occurrences.start_inclusive,
+            self.occurrences.end_inclusive,
+        )
+    }
+}
+
+#[cfg(feature = "unstable-print")]
+impl<P, S, I, O, O2, C, E> crate::print::Print<C> for Separated<P, S, I, O, O2, C, E>
+where
+    P: Parser<I, O, E> + crate::print::Print<O>,
+    S: Parser<I, O2, E> + crate::print::PrintConst,
+    I: Stream,
+    C: TryAccumulate<O>,
+    for<'c> &'c C: IntoIterator<Item = &'c O>,
+    E: ParserError<I>,
+{
+    fn print(
+        &self,
+        value: &C,
+        sink: &mut dyn crate::print::Sink,
+    ) -> Result<(), crate::print::PrintError> {
+        let mut count = 0;
+        for item in value {
+            if 0 < count {
+                crate::print::PrintConst::print_const(&self.separator, sink)?;
             }
-            (start, end) => separated_m_n_(
-                start,
-                end.unwrap_or(usize::MAX),
-                &mut parser,
-                &mut separator,
-                input,
-            ),
+            self.parser.print(item, sink)?;
+            count += 1;
         }
-    })
+        if crate::lib::std::ops::RangeBounds::contains(&self.occurrences, &count) {
+            Ok(())
+        } else {
+            Err(crate::print::PrintError::Unprintable)
+        }
+    }
 }
 
 fn separated0_<I, O, C, O2, E, P, S>(
@@ -918,22 +1064,22 @@ fn separated0_<I, O, C, O2, E, P, S>(
 ) -> Result<C, E>
 where
     I: Stream,
-    C: Accumulate<O>,
+    C: TryAccumulate<O>,
     P: Parser<I, O, E>,
     S: Parser<I, O2, E>,
     E: ParserError<I>,
 {
-    let mut acc = C::initial(None);
+    let mut acc = Accumulator::<C, O>::initial(None);
 
     let start = input.checkpoint();
     match parser.parse_next(input) {
         Err(e) if e.is_backtrack() => {
             input.reset(&start);
-            return Ok(acc);
+            return acc.finish(input);
         }
         Err(e) => return Err(e),
         Ok(o) => {
-            acc.accumulate(o);
+            acc.accumulate(o, input, &start)?;
         }
     }
 
@@ -943,7 +1089,7 @@ where
         match separator.parse_next(input) {
             Err(e) if e.is_backtrack() => {
                 input.reset(&start);
-                return Ok(acc);
+                return acc.finish(input);
             }
             Err(e) => return Err(e),
             Ok(_) => {
@@ -958,11 +1104,11 @@ where
                 match parser.parse_next(input) {
                     Err(e) if e.is_backtrack() => {
                         input.reset(&start);
-                        return Ok(acc);
+                        return acc.finish(input);
                     }
                     Err(e) => return Err(e),
                     Ok(o) => {
-                        acc.accumulate(o);
+                        acc.accumulate(o, input, &start)?;
                     }
                 }
             }
@@ -977,18 +1123,19 @@ fn separated1_<I, O, C, O2, E, P, S>(
 ) -> Result<C, E>
 where
     I: Stream,
-    C: Accumulate<O>,
+    C: TryAccumulate<O>,
     P: Parser<I, O, E>,
     S: Parser<I, O2, E>,
     E: ParserError<I>,
 {
-    let mut acc = C::initial(None);
+    let mut acc = Accumulator::<C, O>::initial(None);
 
     // Parse the first element
+    let start = input.checkpoint();
     match parser.parse_next(input) {
         Err(e) => return Err(e),
         Ok(o) => {
-            acc.accumulate(o);
+            acc.accumulate(o, input, &start)?;
         }
     }
 
@@ -998,7 +1145,7 @@ where
         match separator.parse_next(input) {
             Err(e) if e.is_backtrack() => {
                 input.reset(&start);
-                return Ok(acc);
+                return acc.finish(input);
             }
             Err(e) => return Err(e),
             Ok(_) => {
@@ -1013,11 +1160,11 @@ where
                 match parser.parse_next(input) {
                     Err(e) if e.is_backtrack() => {
                         input.reset(&start);
-                        return Ok(acc);
+                        return acc.finish(input);
                     }
                     Err(e) => return Err(e),
                     Ok(o) => {
-                        acc.accumulate(o);
+                        acc.accumulate(o, input, &start)?;
                     }
                 }
             }
@@ -1033,15 +1180,15 @@ fn separated_n_<I, O, C, O2, E, P, S>(
 ) -> Result<C, E>
 where
     I: Stream,
-    C: Accumulate<O>,
+    C: TryAccumulate<O>,
     P: Parser<I, O, E>,
     S: Parser<I, O2, E>,
     E: ParserError<I>,
 {
-    let mut acc = C::initial(Some(count));
+    let mut acc = Accumulator::<C, O>::initial(Some(count));
 
     if count == 0 {
-        return Ok(acc);
+        return acc.finish(input);
     }
 
     let start = input.checkpoint();
@@ -1050,7 +1197,7 @@ where
             return Err(e.append(input, &start));
         }
         Ok(o) => {
-            acc.accumulate(o);
+            acc.accumulate(o, input, &start)?;
         }
     }
 
@@ -1075,14 +1222,14 @@ where
                         return Err(e.append(input, &start));
                     }
                     Ok(o) => {
-                        acc.accumulate(o);
+                        acc.accumulate(o, input, &start)?;
                     }
                 }
             }
         }
     }
 
-    Ok(acc)
+    acc.finish(input)
 }
 
 fn separated_m_n_<I, O, C, O2, E, P, S>(
@@ -1094,7 +1241,7 @@ fn separated_m_n_<I, O, C, O2, E, P, S>(
 ) -> Result<C, E>
 where
     I: Stream,
-    C: Accumulate<O>,
+    C: TryAccumulate<O>,
     P: Parser<I, O, E>,
     S: Parser<I, O2, E>,
     E: ParserError<I>,
@@ -1106,21 +1253,21 @@ where
         ));
     }
 
-    let mut acc = C::initial(Some(min));
+    let mut acc = Accumulator::<C, O>::initial(Some(min));
 
     let start = input.checkpoint();
     match parser.parse_next(input) {
         Err(e) if e.is_backtrack() => {
             if min == 0 {
                 input.reset(&start);
-                return Ok(acc);
+                return acc.finish(input);
             } else {
                 return Err(e.append(input, &start));
             }
         }
         Err(e) => return Err(e),
         Ok(o) => {
-            acc.accumulate(o);
+            acc.accumulate(o, input, &start)?;
         }
     }
 
@@ -1133,7 +1280,7 @@ where
                     return Err(e.append(input, &start));
                 } else {
                     input.reset(&start);
-                    return Ok(acc);
+                    return acc.finish(input);
                 }
             }
             Err(e) => {
@@ -1154,21 +1301,21 @@ where
                             return Err(e.append(input, &start));
                         } else {
                             input.reset(&start);
-                            return Ok(acc);
+                            return acc.finish(input);
                         }
                     }
                     Err(e) => {
                         return Err(e);
                     }
                     Ok(o) => {
-                        acc.accumulate(o);
+                        acc.accumulate(o, input, &start)?;
                     }
                 }
             }
         }
     }
 
-    Ok(acc)
+    acc.finish(input)
 }
 
 /// Alternates between two parsers, merging the results (left associative)
@@ -1608,3 +1755,54 @@ where
 
     Ok(acc)
 }
+
+/// [Accumulate][TryAccumulate] into `C`, turning a full or short accumulator into an error
+struct Accumulator<C, O>
+where
+    C: TryAccumulate<O>,
+{
+    partial: C::Partial,
+    o: core::marker::PhantomData<O>,
+}
+
+impl<C, O> Accumulator<C, O>
+where
+    C: TryAccumulate<O>,
+{
+    #[inline(always)]
+    fn initial(capacity: Option<usize>) -> Self {
+        Self {
+            partial: C::initial(capacity),
+            o: Default::default(),
+        }
+    }
+
+    /// Backtracks to `start` if `partial` is full
+    #[inline(always)]
+    fn accumulate<I, E>(
+        &mut self,
+        value: O,
+        input: &mut I,
+        start: &<I as Stream>::Checkpoint,
+    ) -> Result<(), E>
+    where
+        I: Stream,
+        E: ParserError<I>,
+    {
+        if C::try_accumulate(&mut self.partial, value).is_err() {
+            input.reset(start);
+            return Err(ParserError::from_input(input));
+        }
+        Ok(())
+    }
+
+    /// Errors if `partial` needs more values
+    #[inline(always)]
+    fn finish<I, E>(self, input: &I) -> Result<C, E>
+    where
+        I: Stream,
+        E: ParserError<I>,
+    {
+        C::finish(self.partial).ok_or_else(|| ParserError::from_input(input))
+    }
+}
@@ -1,4 +1,6 @@
 use crate::combinator::trace;
+use crate::combinator::trace_call;
+use crate::combinator::CallCount;
 use crate::error::ParserError;
 use crate::stream::Stream;
 use crate::*;
@@ -6,6 +8,68 @@ use crate::*;
 #[doc(inline)]
 pub use crate::seq;
 
+/// [`Parser`] implementation for [`seq!`]
+#[doc(hidden)]
+pub struct Seq<P, I, O, E>
+where
+    P: Parser<I, O, E>,
+    I: Stream,
+    E: ParserError<I>,
+{
+    name: &'static str,
+    parser: P,
+    call_count: CallCount,
+    i: core::marker::PhantomData<I>,
+    o: core::marker::PhantomData<O>,
+    e: core::marker::PhantomData<E>,
+}
+
+impl<P, I, O, E> Seq<P, I, O, E>
+where
+    P: Parser<I, O, E>,
+    I: Stream,
+    E: ParserError<I>,
+{
+    #[doc(hidden)]
+    #[inline(always)]
+    pub fn new(name: &'static str, parser: P) -> Self {
+        Self {
+            name,
+            parser,
+            call_count: Default::default(),
+            i: Default::default(),
+            o: Default::default(),
+            e: Default::default(),
+        }
+    }
+}
+
+impl<P, I, O, E> Parser<I, O, E> for Seq<P, I, O, E>
+where
+    P: Parser<I, O, E>,
+    I: Stream,
+    E: ParserError<I>,
+{
+    #[inline(always)]
+    fn parse_next(&mut self, input: &mut I) -> Result<O, E> {
+        trace_call(self.name, &mut self.call_count, self.parser.by_ref(), input)
+    }
+}
+
+/// The fields' parsers are only created while parsing, so this refers to the rule by name, like
+/// [`opaque`][crate::describe::opaque]
+#[cfg(feature = "unstable-grammar")]
+impl<P, I, O, E> crate::describe::Describe for Seq<P, I, O, E>
+where
+    P: Parser<I, O, E>,
+    I: Stream,
+    E: ParserError<I>,
+{
+    fn describe(&self, _grammar: &mut crate::describe::Grammar) -> crate::describe::Expr {
+        crate::describe::Expr::Rule(self.name.into())
+    }
+}
+
 /// Sequence two parsers, only returning the output from the second.
 ///
 /// See also [`seq`] to generalize this across any number of fields.
@@ -29,19 +93,100 @@ pub use crate::seq;
 /// ```
 #[doc(alias = "ignore_then")]
 pub fn preceded<Input, Ignored, Output, Error, IgnoredParser, ParseNext>(
-    mut ignored: IgnoredParser,
-    mut parser: ParseNext,
-) -> impl Parser<Input, Output, Error>
+    ignored: IgnoredParser,
+    parser: ParseNext,
+) -> introspectable!(
+       Preceded<IgnoredParser, ParseNext, Input, Ignored, Output, Error>,
+       impl Parser<Input, Output, Error>
+   )
 where
     Input: Stream,
     Error: ParserError<Input>,
     IgnoredParser: Parser<Input, Ignored, Error>,
     ParseNext: Parser<Input, Output, Error>,
 {
-    trace("preceded", move |input: &mut Input| {
-        let _ = ignored.parse_next(input)?;
-        parser.parse_next(input)
-    })
+    Preceded {
+        ignored,
+        parser,
+        call_count: Default::default(),
+        i: Default::default(),
+        o1: Default::default(),
+        o: Default::default(),
+        e: Default::default(),
+    }
+}
+
+/// [`Parser`] implementation for [`preceded`]
+pub struct Preceded<P1, P, I, O1, O, E>
+where
+    I: Stream,
+    E: ParserError<I>,
+    P1: Parser<I, O1, E>,
+    P: Parser<I, O, E>,
+{
+    ignored: P1,
+    parser: P,
+    call_count: CallCount,
+    i: core::marker::PhantomData<I>,
+    o1: core::marker::PhantomData<O1>,
+    o: core::marker::PhantomData<O>,
+    e: core::marker::PhantomData<E>,
+}
+
+impl<P1, P, I, O1, O, E> Parser<I, O, E> for Preceded<P1, P, I, O1, O, E>
+where
+    I: Stream,
+    E: ParserError<I>,
+    P1: Parser<I, O1, E>,
+    P: Parser<I, O, E>,
+{
+    #[inline(always)]
+    fn parse_next(&mut self, input: &mut I) -> Result<O, E> {
+        trace_call(
+            "preceded",
+            &mut self.call_count,
+            |input: &mut I| {
+                let _ = self.ignored.parse_next(input)?;
+                self.parser.parse_next(input)
+            },
+            input,
+        )
+    }
+}
+
+#[cfg(feature = "unstable-grammar")]
+impl<P1, P, I, O1, O, E> crate::describe::Describe for Preceded<P1, P, I, O1, O, E>
+where
+    I: Stream,
+    E: ParserError<I>,
+    P1: Parser<I, O1, E> + crate::describe::Describe,
+    P: Parser<I, O, E> + crate::describe::Describe,
+{
+    fn describe(&self, grammar: &mut crate::describe::Grammar) -> crate::describe::Expr {
+        crate::describe::Expr::sequence([
+            self.ignored.describe(grammar),
+            self.parser.describe(grammar),
+        ])
+    }
+}
+
+#[cfg(feature = "unstable-print")]
+impl<P1, P, I, O1, O, E, V> crate::print::Print<V> for Preceded<P1, P, I, O1, O, E>
+where
+    I: Stream,
+    E: ParserError<I>,
+    P1: Parser<I, O1, E> + crate::print::PrintConst,
+    P: Parser<I, O, E> + crate::print::Print<V>,
+    V: ?Sized,
+{
+    fn print(
+        &self,
+        value: &V,
+        sink: &mut dyn crate::print::Sink,
+    ) -> Result<(), crate::print::PrintError> {
+        crate::print::PrintConst::print_const(&self.ignored, sink)?;
+        self.parser.print(value, sink)
+    }
 }
 
 /// Sequence two parsers, only returning the output of the first.
@@ -67,19 +212,100 @@ where
 /// ```
 #[doc(alias = "then_ignore")]
 pub fn terminated<Input, Output, Ignored, Error, ParseNext, IgnoredParser>(
-    mut parser: ParseNext,
-    mut ignored: IgnoredParser,
-) -> impl Parser<Input, Output, Error>
+    parser: ParseNext,
+    ignored: IgnoredParser,
+) -> introspectable!(
+       Terminated<ParseNext, IgnoredParser, Input, Output, Ignored, Error>,
+       impl Parser<Input, Output, Error>
+   )
 where
     Input: Stream,
     Error: ParserError<Input>,
     ParseNext: Parser<Input, Output, Error>,
     IgnoredParser: Parser<Input, Ignored, Error>,
 {
-    trace("terminated", move |input: &mut Input| {
-        let o = parser.parse_next(input)?;
-        ignored.parse_next(input).map(|_| o)
-    })
+    Terminated {
+        parser,
+        ignored,
+        call_count: Default::default(),
+        i: Default::default(),
+        o: Default::default(),
+        o2: Default::default(),
+        e: Default::default(),
+    }
+}
+
+/// [`Parser`] implementation for [`terminated`]
+pub struct Terminated<P, P2, I, O, O2, E>
+where
+    I: Stream,
+    E: ParserError<I>,
+    P: Parser<I, O, E>,
+    P2: Parser<I, O2, E>,
+{
+    parser: P,
+    ignored: P2,
+    call_count: CallCount,
+    i: core::marker::PhantomData<I>,
+    o: core::marker::PhantomData<O>,
+    o2: core::marker::PhantomData<O2>,
+    e: core::marker::PhantomData<E>,
+}
+
+impl<P, P2, I, O, O2, E> Parser<I, O, E> for Terminated<P, P2, I, O, O2, E>
+where
+    I: Stream,
+    E: ParserError<I>,
+    P: Parser<I, O, E>,
+    P2: Parser<I, O2, E>,
+{
+    #[inline(always)]
+    fn parse_next(&mut self, input: &mut I) -> Result<O, E> {
+        trace_call(
+            "terminated",
+            &mut self.call_count,
+            |input: &mut I| {
+                let o = self.parser.parse_next(input)?;
+                self.ignored.parse_next(input).map(|_| o)
+            },
+            input,
+        )
+    }
+}
+
+#[cfg(feature = "unstable-grammar")]
+impl<P, P2, I, O, O2, E> crate::describe::Describe for Terminated<P, P2, I, O, O2, E>
+where
+    I: Stream,
+    E: ParserError<I>,
+    P: Parser<I, O, E> + crate::describe::Describe,
+    P2: Parser<I, O2, E> + crate::describe::Describe,
+{
+    fn describe(&self, grammar: &mut crate::describe::Grammar) -> crate::describe::Expr {
+        crate::describe::Expr::sequence([
+            self.parser.describe(grammar),
+            self.ignored.describe(grammar),
+        ])
+    }
+}
+
+#[cfg(feature = "unstable-print")]
+impl<P, P2, I, O, O2, E, V> crate::print::Print<V> for Terminated<P, P2, I, O, O2, E>
+where
+    I: Stream,
+    E: ParserError<I>,
+    P: Parser<I, O, E> + crate::print::Print<V>,
+    P2: Parser<I, O2, E> + crate::print::PrintConst,
+    V: ?Sized,
+{
+    fn print(
+        &self,
+        value: &V,
+        sink: &mut dyn crate::print::Sink,
+    ) -> Result<(), crate::print::PrintError> {
+        self.parser.print(value, sink)?;
+        crate::print::PrintConst::print_const(&self.ignored, sink)
+    }
 }
 
 /// Sequence three parsers, only returning the values of the first and third.
@@ -155,10 +381,13 @@ pub fn delimited<
     ParseNext,
     IgnoredParser2,
 >(
-    mut ignored1: IgnoredParser1,
-    mut parser: ParseNext,
-    mut ignored2: IgnoredParser2,
-) -> impl Parser<Input, Output, Error>
+    ignored1: IgnoredParser1,
+    parser: ParseNext,
+    ignored2: IgnoredParser2,
+) -> introspectable!(
+       Delimited<IgnoredParser1, ParseNext, IgnoredParser2, Input, Ignored1, Output, Ignored2, Error>,
+       impl Parser<Input, Output, Error>
+   )
 where
     Input: Stream,
     Error: ParserError<Input>,
@@ -166,9 +395,97 @@ where
     ParseNext: Parser<Input, Output, Error>,
     IgnoredParser2: Parser<Input, Ignored2, Error>,
 {
-    trace("delimited", move |input: &mut Input| {
-        let _ = ignored1.parse_next(input)?;
-        let o2 = parser.parse_next(input)?;
-        ignored2.parse_next(input).map(|_| o2)
-    })
+    Delimited {
+        ignored1,
+        parser,
+        ignored2,
+        call_count: Default::default(),
+        i: Default::default(),
+        o1: Default::default(),
+        o: Default::default(),
+        o2: Default::default(),
+        e: Default::default(),
+    }
+}
+
+/// [`Parser`] implementation for [`delimited`]
+pub struct Delimited<P1, P, P2, I, O1, O, O2, E>
+where
+    I: Stream,
+    E: ParserError<I>,
+    P1: Parser<I, O1, E>,
+    P: Parser<I, O, E>,
+    P2: Parser<I, O2, E>,
+{
+    ignored1: P1,
+    parser: P,
+    ignored2: P2,
+    call_count: CallCount,
+    i: core::marker::PhantomData<I>,
+    o1: core::marker::PhantomData<O1>,
+    o: core::marker::PhantomData<O>,
+    o2: core::marker::PhantomData<O2>,
+    e: core::marker::PhantomData<E>,
+}
+
+impl<P1, P, P2, I, O1, O, O2, E> Parser<I, O, E> for Delimited<P1, P, P2, I, O1, O, O2, E>
+where
+    I: Stream,
+    E: ParserError<I>,
+    P1: Parser<I, O1, E>,
+    P: Parser<I, O, E>,
+    P2: Parser<I, O2, E>,
+{
+    #[inline(always)]
+    fn parse_next(&mut self, input: &mut I) -> Result<O, E> {
+        trace_call(
+            "delimited",
+            &mut self.call_count,
+            |input: &mut I| {
+                let _ = self.ignored1.parse_next(input)?;
+                let o2 = self.parser.parse_next(input)?;
+                self.ignored2.parse_next(input).map(|_| o2)
+            },
+            input,
+        )
+    }
+}
+
+#[cfg(feature = "unstable-grammar")]
+impl<P1, P, P2, I, O1, O, O2, E> crate::describe::Describe for Delimited<P1, P, P2, I, O1, O, O2, E>
+where
+    I: Stream,
+    E: ParserError<I>,
+    P1: Parser<I, O1, E> + crate::describe::Describe,
+    P: Parser<I, O, E> + crate::describe::Describe,
+    P2: Parser<I, O2, E> + crate::describe::Describe,
+{
+    fn describe(&self, grammar: &mut crate::describe::Grammar) -> crate::describe::Expr {
+        crate::describe::Expr::sequence([
+            self.ignored1.describe(grammar),
+            self.parser.describe(grammar),
+            self.ignored2.describe(grammar),
+        ])
+    }
+}
+
+#[cfg(feature = "unstable-print")]
+impl<P1, P, P2, I, O1, O, O2, E, V> crate::print::Print<V> for Delimited<P1, P, P2, I, O1, O, O2, E>
+where
+    I: Stream,
+    E: ParserError<I>,
+    P1: Parser<I, O1, E> + crate::print::PrintConst,
+    P: Parser<I, O, E> + crate::print::Print<V>,
+    P2: Parser<I, O2, E> + crate::print::PrintConst,
+    V: ?Sized,
+{
+    fn print(
+        &self,
+        value: &V,
+        sink: &mut dyn crate::print::Sink,
+    ) -> Result<(), crate::print::PrintError> {
+        crate::print::PrintConst::print_const(&self.ignored1, sink)?;
+        self.parser.print(value, sink)?;
+        crate::print::PrintConst::print_const(&self.ignored2, sink)
+    }
 }
@@ -8,6 +8,8 @@ use crate::binary::u16;
 use crate::binary::u8;
 use crate::binary::Endianness;
 use crate::error::ErrMode;
+#[cfg(feature = "alloc")]
+use crate::error::Needed;
 use crate::error::ParserError;
 #[cfg(feature = "alloc")]
 use crate::lib::std::borrow::ToOwned;
@@ -4032,3 +4034,550 @@ Err(
         .raw()
     );
 }
+
+#[test]
+fn repeat_fixed_vec_test() {
+    use crate::stream::FixedVec;
+
+    fn multi<'i>(i: &mut &'i str) -> TestResult<&'i str, FixedVec<&'i str, 2>> {
+        repeat(0.., "ab").parse_next(i)
+    }
+
+    assert_parse!(
+        multi.parse_peek("abab;"),
+        str![[r#"
+Ok(
+    (
+        ";",
+        [
+            "ab",
+            "ab",
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        multi.parse_peek("ababab;"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "ab;",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn repeat_array_test() {
+    fn multi<'i>(i: &mut &'i str) -> TestResult<&'i str, [&'i str; 2]> {
+        repeat(0.., "ab").parse_next(i)
+    }
+
+    assert_parse!(
+        multi.parse_peek("ab;"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: ";",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        multi.parse_peek("abab;"),
+        str![[r#"
+Ok(
+    (
+        ";",
+        [
+            "ab",
+            "ab",
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        multi.parse_peek("ababab;"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "ab;",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn repeat_array_without_default() {
+    use crate::token::one_of;
+
+    #[derive(Debug, PartialEq)]
+    struct Digit(char);
+
+    fn multi<'i>(i: &mut &'i str) -> TestResult<&'i str, [Digit; 2]> {
+        repeat(0.., one_of('0'..='9').map(Digit)).parse_next(i)
+    }
+
+    assert_eq!(multi.parse_peek("12;"), Ok((";", [Digit('1'), Digit('2')])));
+    assert!(multi.parse_peek("1;").is_err());
+    assert!(multi.parse_peek("123;").is_err());
+}
+
+#[test]
+fn repeat_till_fixed_vec_test() {
+    use crate::stream::FixedVec;
+
+    #[allow(clippy::type_complexity)]
+    fn multi<'i>(i: &mut &'i str) -> TestResult<&'i str, (FixedVec<&'i str, 2>, &'i str)> {
+        repeat_till(0.., "ab", "cd").parse_next(i)
+    }
+
+    assert_parse!(
+        multi.parse_peek("ababcd"),
+        str![[r#"
+Ok(
+    (
+        "",
+        (
+            [
+                "ab",
+                "ab",
+            ],
+            "cd",
+        ),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        multi.parse_peek("abababcd"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "abcd",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+fn separated_fixed_vec_test() {
+    use crate::stream::FixedVec;
+
+    fn multi<'i>(i: &mut &'i str) -> TestResult<&'i str, FixedVec<&'i str, 2>> {
+        separated(1.., "ab", ",").parse_next(i)
+    }
+
+    assert_parse!(
+        multi.parse_peek("ab,ab;"),
+        str![[r#"
+Ok(
+    (
+        ";",
+        [
+            "ab",
+            "ab",
+        ],
+    ),
+)
+
+"#]]
+        .raw()
+    );
+    assert_parse!(
+        multi.parse_peek("ab,ab,ab;"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: ",ab;",
+        },
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn iterator_resume_test() {
+    fn item<'i>(i: &mut Partial<&'i [u8]>) -> TestResult<Partial<&'i [u8]>, &'i [u8]> {
+        terminated(digit, ",").parse_next(i)
+    }
+
+    let mut buffer = b"12,3".to_vec();
+    let mut it = iterator(Partial::new(&buffer[..]), item);
+    let parsed = (&mut it).map(|o| o.to_owned()).collect::<Vec<_>>();
+    assert_eq!(parsed, [b"12".to_vec()]);
+    assert_eq!(it.offset(), 3);
+    assert_eq!(**it.remaining(), &b"3"[..]);
+    assert_eq!(it.needed(), Some(Needed::new(1)));
+
+    let consumed = buffer.len() - it.remaining().len();
+    let it = it.suspend().unwrap();
+    buffer.drain(..consumed);
+    buffer.extend_from_slice(b"4,56,x");
+
+    let mut it = it.resume(Partial::new(&buffer[..]));
+    let parsed = (&mut it).map(|o| o.to_owned()).collect::<Vec<_>>();
+    assert_eq!(parsed, [b"34".to_vec(), b"56".to_vec()]);
+    assert_eq!(it.offset(), 9);
+    assert_eq!(it.needed(), None);
+    assert_parse!(
+        it.finish(),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: [
+                120,
+            ],
+            partial: true,
+        },
+        (),
+    ),
+)
+
+"#]]
+        .raw()
+    );
+}
+
+#[cfg(feature = "debug")]
+mod trace_sink {
+    use super::*;
+
+    use crate::error::EmptyError;
+    use crate::lib::std::string::{String, ToString};
+
+    /// Events, as text
+    #[derive(Default)]
+    struct Events(Vec<String>);
+
+    impl TraceSink for Events {
+        fn event(&mut self, event: &TraceEvent<'_>) {
+            let event = match *event {
+                TraceEvent::Enter {
+                    name,
+                    depth,
+                    call_count,
+                    position,
+                    input,
+                } => format!("{depth} > {name}:{call_count} @{position} {input:?}"),
+                TraceEvent::Exit {
+                    name,
+                    depth,
+                    call_count,
+                    position,
+                    consumed,
+                    severity,
+                } => format!("{depth} < {name}:{call_count} @{position} +{consumed} {severity:?}"),
+                TraceEvent::Result {
+                    name,
+                    depth,
+                    severity,
+                } => format!("{depth} | {name} {severity:?}"),
+            };
+            self.0.push(event);
+        }
+    }
+
+    /// Untraced literal, to only see the traces of the test
+    fn lit<'i>(
+        literal: &'static str,
+    ) -> impl FnMut(&mut &'i str) -> ModalResult<&'i str, EmptyError> {
+        move |input: &mut &'i str| {
+            if input.starts_with(literal) {
+                Ok(input.next_slice(literal.len()))
+            } else {
+                Err(ParserError::from_input(input))
+            }
+        }
+    }
+
+    fn pair<'i>(input: &mut &'i str) -> ModalResult<(&'i str, &'i str), EmptyError> {
+        trace(
+            "pair",
+            (
+                trace("first", lit("ab")),
+                repeat::<_, _, (), _, _>(0.., trace("second", lit("c"))).take(),
+            ),
+        )
+        .parse_next(input)
+    }
+
+    #[test]
+    fn events() {
+        let (events, result) = with_trace_sink(Events::default(), || pair.parse_peek("xabccd"));
+        assert!(result.is_err());
+        assert_eq!(
+            events.0,
+            [
+                r#"0 > pair:0 @0 "xabccd""#,
+                r#"1 > first:0 @0 "xabccd""#,
+                "1 < first:0 @0 +0 Backtrack",
+                "0 < pair:0 @0 +0 Backtrack",
+            ]
+        );
+
+        let (events, result) = with_trace_sink(Events::default(), || pair.parse_peek("abccd"));
+        assert_eq!(result, Ok(("d", ("ab", "cc"))));
+        assert_eq!(
+            events.0,
+            [
+                r#"0 > pair:0 @0 "abccd""#,
+                r#"1 > first:0 @0 "abccd""#,
+                "1 < first:0 @0 +2 Success",
+                r#"1 > repeat:0 @2 "ccd""#,
+                r#"2 > second:0 @2 "ccd""#,
+                "2 < second:0 @2 +1 Success",
+                r#"2 > second:1 @3 "cd""#,
+                "2 < second:1 @3 +1 Success",
+                r#"2 > second:2 @4 "d""#,
+                "2 < second:2 @4 +0 Backtrack",
+                "1 < repeat:0 @2 +2 Success",
+                "0 < pair:0 @0 +4 Success",
+            ]
+        );
+    }
+
+    #[test]
+    fn built_in_call_counts() {
+        let (events, _) = with_trace_sink(Events::default(), || {
+            repeat::<_, _, (), _, _>(0.., alt((lit("a"), lit("b")))).parse_peek("abc")
+        });
+        let alts = events
+            .0
+            .iter()
+            .filter(|event| event.contains(" < alt:"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            alts,
+            [
+                "1 < alt:0 @0 +1 Success",
+                "1 < alt:1 @1 +1 Success",
+                "1 < alt:2 @2 +0 Backtrack",
+            ]
+        );
+    }
+
+    #[test]
+    fn sized_call_counts() {
+        use crate::binary::{uint, Endianness};
+
+        let (events, _) = with_trace_sink(Events::default(), || {
+            repeat::<_, u16, (), ErrMode<EmptyError>, _>(0.., uint::<2>(Endianness::Big))
+                .parse_peek(&b"\x01\x02\x03\x04\x05"[..])
+        });
+        let uints = events
+            .0
+            .iter()
+            .filter(|event| event.contains(" < uint:"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            uints,
+            [
+                "1 < uint:0 @0 +2 Success",
+                "1 < uint:1 @2 +2 Success",
+                "1 < uint:2 @4 +0 Backtrack",
+            ]
+        );
+    }
+
+    #[test]
+    fn result_events() {
+        let (events, _) = with_trace_sink(Events::default(), || {
+            lit("a").verify(|_: &str| false).parse_peek("ab")
+        });
+        assert!(events.0.contains(&"0 | verify Backtrack".to_owned()));
+    }
+
+    #[test]
+    fn json_lines() {
+        let (sink, _) = with_trace_sink(JsonLinesSink::new(Vec::new()), || {
+            trace("say \"a\"", lit("a")).parse_peek("ab")
+        });
+        assert_eq!(
+            String::from_utf8(sink.into_inner()).unwrap(),
+            r#"{"event":"enter","name":"say \"a\"","depth":0,"call_count":0,"position":0}
+{"event":"exit","name":"say \"a\"","depth":0,"call_count":0,"position":0,"consumed":1,"severity":"success"}
+"#
+        );
+    }
+
+    #[test]
+    fn per_thread() {
+        use std::sync::mpsc;
+
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            set_trace_sink(move |event: &TraceEvent<'_>| {
+                let _ = sender.send(event.name().to_string());
+            });
+            let _ = trace("on_thread", lit("a")).parse_peek("a");
+            reset_trace_sink();
+        })
+        .join()
+        .unwrap();
+        // Other threads keep their sinks
+        let (events, _) = with_trace_sink(Events::default(), || {
+            trace("here", lit("a")).parse_peek("a")
+        });
+
+        assert_eq!(
+            receiver.iter().collect::<Vec<_>>(),
+            ["on_thread", "on_thread"]
+        );
+        assert_eq!(events.0.len(), 2);
+    }
+
+    #[test]
+    fn filter_spec() {
+        assert_eq!(
+            "json_value, depth <= 5,failures,".parse::<TraceFilter>(),
+            Ok(TraceFilter::new()
+                .name("json_value")
+                .max_depth(5)
+                .failures())
+        );
+        assert_eq!(
+            "value_*,final-error".parse::<TraceFilter>(),
+            Ok(TraceFilter::new().name("value_*").final_error())
+        );
+        assert_eq!("".parse::<TraceFilter>(), Ok(TraceFilter::new()));
+        for spec in ["depth<5", "depth<=x", "json value"] {
+            assert_eq!(spec.parse::<TraceFilter>().unwrap_err().item(), spec);
+        }
+    }
+
+    #[test]
+    fn filter_name_depth() {
+        let filter = TraceFilter::new().name("rep*").max_depth(1);
+        let (sink, _) =
+            with_trace_sink(filter.sink(Events::default()), || pair.parse_peek("abccd"));
+        assert_eq!(
+            sink.into_inner().0,
+            [
+                r#"0 > repeat:0 @2 "ccd""#,
+                r#"1 > second:0 @2 "ccd""#,
+                "1 < second:0 @2 +1 Success",
+                r#"1 > second:1 @3 "cd""#,
+                "1 < second:1 @3 +1 Success",
+                r#"1 > second:2 @4 "d""#,
+                "1 < second:2 @4 +0 Backtrack",
+                "0 < repeat:0 @2 +2 Success",
+            ]
+        );
+
+        let filter = TraceFilter::new().max_depth(0);
+        let (sink, _) =
+            with_trace_sink(filter.sink(Events::default()), || pair.parse_peek("abccd"));
+        assert_eq!(
+            sink.into_inner().0,
+            [r#"0 > pair:0 @0 "abccd""#, "0 < pair:0 @0 +4 Success"]
+        );
+    }
+
+    #[test]
+    fn filter_failures() {
+        let (sink, _) = with_trace_sink(
+            TraceFilter::new().failures().sink(Events::default()),
+            || pair.parse_peek("abccd"),
+        );
+        assert_eq!(
+            sink.into_inner().0,
+            [
+                r#"0 > pair:0 @0 "abccd""#,
+                r#"1 > repeat:0 @2 "ccd""#,
+                r#"2 > second:2 @4 "d""#,
+                "2 < second:2 @4 +0 Backtrack",
+                "1 < repeat:0 @2 +2 Success",
+                "0 < pair:0 @0 +4 Success",
+            ]
+        );
+    }
+
+    #[test]
+    fn filter_final_error() {
+        fn pair_or<'i>(input: &mut &'i str) -> ModalResult<(&'i str, &'i str), EmptyError> {
+            trace(
+                "pair_or",
+                (
+                    trace("first", lit("ab")),
+                    trace(
+                        "last",
+                        alt((
+                            trace("c", lit("c")),
+                            trace("d", (lit("d"), lit("e")).take()),
+                        )),
+                    ),
+                ),
+            )
+            .parse_next(input)
+        }
+
+        let filter = TraceFilter::new().final_error();
+        let (sink, _) = with_trace_sink(filter.clone().sink(Events::default()), || {
+            pair_or.parse_peek("abc")
+        });
+        assert_eq!(sink.into_inner().0, Vec::<String>::new());
+
+        let (sink, _) = with_trace_sink(filter.sink(Events::default()), || {
+            pair_or.parse_peek("abdf")
+        });
+        assert_eq!(
+            sink.into_inner().0,
+            [
+                r#"0 > pair_or:0 @0 "abdf""#,
+                r#"1 > last:0 @2 "df""#,
+                r#"2 > alt:0 @2 "df""#,
+                r#"3 > d:0 @2 "df""#,
+                "3 < d:0 @2 +1 Backtrack",
+                "2 < alt:0 @2 +1 Backtrack",
+                "1 < last:0 @2 +1 Backtrack",
+                "0 < pair_or:0 @0 +3 Backtrack",
+            ]
+        );
+    }
+
+    #[test]
+    fn filter_long_input() {
+        let input = "a".repeat(10_000);
+        let (sink, _) = with_trace_sink(
+            TraceFilter::new().final_error().sink(Events::default()),
+            || trace("b", lit("b")).parse_peek(input.as_str()),
+        );
+        let events = sink.into_inner().0;
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0], format!("0 > b:0 @0 \"{}", "a".repeat(1023)));
+    }
+}
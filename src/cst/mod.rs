@@ -0,0 +1,417 @@
+//! # Lossless concrete syntax trees
+//!
+//! Parse a [`Recording`] stream, marking the structure with [`Parser::node`], and turn the
+//! recorded [`Event`]s into a tree that keeps every token of the input, including whitespace and
+//! comments:
+//! - [`GreenNode`]: an immutable, position-independent tree, built with [`GreenNodeBuilder`]
+//! - [`SyntaxNode`]: a view of a [`GreenNode`] with parent pointers and absolute spans
+//!
+//! After an [`Edit`] to the input, a [`Reparser`] updates the tree by reparsing only the
+//! smallest [`reusable`] node that contains the edit.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use winnow::prelude::*;
+//! use winnow::ascii::{alpha1, dec_uint, multispace0};
+//! use winnow::combinator::{delimited, separated};
+//! use winnow::cst::{GreenNode, SyntaxNode};
+//! use winnow::stream::Recording;
+//!
+//! #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+//! enum Kind {
+//!     Root,
+//!     List,
+//!     Number,
+//! }
+//!
+//! type Stream<'i> = Recording<&'i str, Kind>;
+//!
+//! fn list(input: &mut Stream<'_>) -> ModalResult<()> {
+//!     delimited(
+//!         ('[', multispace0),
+//!         separated(0.., number, (multispace0, ',', multispace0)),
+//!         (multispace0, ']'),
+//!     )
+//!     .node(Kind::List)
+//!     .parse_next(input)
+//! }
+//!
+//! fn number(input: &mut Stream<'_>) -> ModalResult<u32> {
+//!     dec_uint.node(Kind::Number).parse_next(input)
+//! }
+//!
+//! let source = "[1 , 20 ]";
+//! let mut input = Recording::new(source);
+//! list.parse_next(&mut input).unwrap();
+//! let (_, events) = input.into_parts();
+//!
+//! let root = SyntaxNode::new_root(GreenNode::from_events(Kind::Root, events));
+//! assert_eq!(root.to_string(), source);
+//!
+//! let list = root.children().next().unwrap();
+//! assert_eq!(*list.kind(), Kind::List);
+//! let numbers = list.children().map(|n| n.span()).collect::<Vec<_>>();
+//! assert_eq!(numbers, [1..2, 5..7]);
+//! ```
+
+use crate::lib::std::fmt;
+use crate::lib::std::ops::Range;
+use crate::lib::std::rc::Rc;
+use crate::lib::std::vec::Vec;
+
+use crate::stream::SliceLen;
+#[allow(unused_imports)] // Here for intra-doc links
+use crate::stream::{NodeSink, Recording};
+#[allow(unused_imports)] // Here for intra-doc links
+use crate::Parser;
+
+mod reparse;
+
+#[cfg(test)]
+mod tests;
+
+pub use reparse::*;
+
+/// Parse event, recorded by [`Recording`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Event<K, S> {
+    /// Start a node of the given kind, see [`NodeSink::start_node`]
+    Start(K),
+    /// A slice of consumed input
+    Token(S),
+    /// Finish the most recently started node, see [`NodeSink::finish_node`]
+    Finish,
+}
+
+/// Immutable, position-independent syntax tree node
+///
+/// Tokens are stored as slices of the input, so the tree can be written back out losslessly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GreenNode<K, S> {
+    kind: K,
+    len: usize,
+    children: Vec<GreenElement<K, S>>,
+}
+
+impl<K, S> GreenNode<K, S>
+where
+    S: SliceLen,
+{
+    /// Create a node from its children
+    pub fn new(kind: K, children: Vec<GreenElement<K, S>>) -> Self {
+        let len = children.iter().map(|child| child.len()).sum();
+        Self {
+            kind,
+            len,
+            children,
+        }
+    }
+
+    /// Build a tree from [`Event`]s, wrapped in a root node of `kind`
+    ///
+    /// The root node holds any tokens recorded outside of a node.  Unfinished nodes are finished at
+    /// the end.
+    pub fn from_events(kind: K, events: impl IntoIterator<Item = Event<K, S>>) -> Self {
+        let mut builder = GreenNodeBuilder::new(kind);
+        for event in events {
+            match event {
+                Event::Start(kind) => builder.start_node(kind),
+                Event::Token(token) => builder.token(token),
+                Event::Finish => builder.finish_node(),
+            }
+        }
+        builder.finish()
+    }
+}
+
+impl<K, S> GreenNode<K, S> {
+    /// The kind of node
+    #[inline(always)]
+    pub fn kind(&self) -> &K {
+        &self.kind
+    }
+
+    /// Length of the input covered by this node
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether this node covers no input
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Nodes and tokens directly within this node
+    #[inline(always)]
+    pub fn children(&self) -> &[GreenElement<K, S>] {
+        &self.children
+    }
+}
+
+/// Write out the input covered by this node
+impl<K, S: fmt::Display> fmt::Display for GreenNode<K, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for child in &self.children {
+            match child {
+                GreenElement::Node(node) => node.fmt(f)?,
+                GreenElement::Token(token) => token.fmt(f)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Child of a [`GreenNode`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GreenElement<K, S> {
+    /// Nested node
+    Node(Rc<GreenNode<K, S>>),
+    /// Slice of the input
+    Token(S),
+}
+
+impl<K, S> GreenElement<K, S>
+where
+    S: SliceLen,
+{
+    /// Length of the input covered by this element
+    #[inline]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Node(node) => node.len(),
+            Self::Token(token) => token.slice_len(),
+        }
+    }
+
+    /// Whether this element covers no input
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Incrementally build a [`GreenNode`]
+///
+/// See also [`GreenNode::from_events`].
+#[derive(Clone, Debug)]
+pub struct GreenNodeBuilder<K, S> {
+    parents: Vec<(K, usize)>,
+    children: Vec<GreenElement<K, S>>,
+}
+
+impl<K, S> GreenNodeBuilder<K, S>
+where
+    S: SliceLen,
+{
+    /// Start building a tree, with a root node of `kind`
+    pub fn new(kind: K) -> Self {
+        Self {
+            parents: crate::lib::std::vec![(kind, 0)],
+            children: Vec::new(),
+        }
+    }
+
+    /// Start a child node of the current node
+    pub fn start_node(&mut self, kind: K) {
+        self.parents.push((kind, self.children.len()));
+    }
+
+    /// Add a token to the current node
+    pub fn token(&mut self, token: S) {
+        self.children.push(GreenElement::Token(token));
+    }
+
+    /// Finish the current node
+    ///
+    /// The root node is only finished by [`GreenNodeBuilder::finish`].
+    pub fn finish_node(&mut self) {
+        if 1 < self.parents.len() {
+            let node = self.pop_node();
+            self.children.push(GreenElement::Node(Rc::new(node)));
+        }
+    }
+
+    /// Finish all nodes, returning the root
+    pub fn finish(mut self) -> GreenNode<K, S> {
+        while 1 < self.parents.len() {
+            self.finish_node();
+        }
+        self.pop_node()
+    }
+
+    fn pop_node(&mut self) -> GreenNode<K, S> {
+        let (kind, first_child) = self.parents.pop().expect("root is only popped by `finish`");
+        let children = self.children.split_off(first_child);
+        GreenNode::new(kind, children)
+    }
+}
+
+/// View of a [`GreenNode`] within the tree, with access to its parent and its span of the input
+pub struct SyntaxNode<K, S>(Rc<NodeData<K, S>>);
+
+struct NodeData<K, S> {
+    green: Rc<GreenNode<K, S>>,
+    parent: Option<SyntaxNode<K, S>>,
+    offset: usize,
+}
+
+impl<K, S> SyntaxNode<K, S> {
+    /// View `green` as the root of the tree
+    pub fn new_root(green: GreenNode<K, S>) -> Self {
+        Self(Rc::new(NodeData {
+            green: Rc::new(green),
+            parent: None,
+            offset: 0,
+        }))
+    }
+
+    /// The underlying [`GreenNode`]
+    #[inline(always)]
+    pub fn green(&self) -> &GreenNode<K, S> {
+        &self.0.green
+    }
+
+    /// The kind of node
+    #[inline(always)]
+    pub fn kind(&self) -> &K {
+        self.green().kind()
+    }
+
+    /// Location of this node within the input
+    #[inline]
+    pub fn span(&self) -> Range<usize> {
+        self.0.offset..self.0.offset + self.green().len()
+    }
+
+    /// The node containing this one
+    #[inline]
+    pub fn parent(&self) -> Option<Self> {
+        self.0.parent.clone()
+    }
+
+    /// Nodes and tokens directly within this node
+    pub fn children_with_tokens(&self) -> impl Iterator<Item = SyntaxElement<K, S>> + '_
+    where
+        S: SliceLen,
+    {
+        let mut offset = self.0.offset;
+        self.green()
+            .children()
+            .iter()
+            .enumerate()
+            .map(move |(index, child)| {
+                let start = offset;
+                offset += child.len();
+                match child {
+                    GreenElement::Node(green) => SyntaxElement::Node(Self(Rc::new(NodeData {
+                        green: green.clone(),
+                        parent: Some(self.clone()),
+                        offset: start,
+                    }))),
+                    GreenElement::Token(_) => SyntaxElement::Token(SyntaxToken {
+                        parent: self.clone(),
+                        index,
+                        offset: start,
+                    }),
+                }
+            })
+    }
+
+    /// Nodes directly within this node
+    pub fn children(&self) -> impl Iterator<Item = SyntaxNode<K, S>> + '_
+    where
+        S: SliceLen,
+    {
+        self.children_with_tokens().filter_map(|child| match child {
+            SyntaxElement::Node(node) => Some(node),
+            SyntaxElement::Token(_) => None,
+        })
+    }
+}
+
+impl<K, S> Clone for SyntaxNode<K, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<K: fmt::Debug, S> fmt::Debug for SyntaxNode<K, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SyntaxNode")
+            .field("kind", self.kind())
+            .field("span", &self.span())
+            .finish()
+    }
+}
+
+/// Write out the input covered by this node
+impl<K, S: fmt::Display> fmt::Display for SyntaxNode<K, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.green().fmt(f)
+    }
+}
+
+/// View of a token within the tree, see [`SyntaxNode`]
+pub struct SyntaxToken<K, S> {
+    parent: SyntaxNode<K, S>,
+    index: usize,
+    offset: usize,
+}
+
+impl<K, S> SyntaxToken<K, S> {
+    /// The slice of input
+    pub fn text(&self) -> &S {
+        match &self.parent.green().children()[self.index] {
+            GreenElement::Token(token) => token,
+            GreenElement::Node(_) => unreachable!("`SyntaxToken` is only created for tokens"),
+        }
+    }
+
+    /// Location of this token within the input
+    #[inline]
+    pub fn span(&self) -> Range<usize>
+    where
+        S: SliceLen,
+    {
+        self.offset..self.offset + self.text().slice_len()
+    }
+
+    /// The node containing this token
+    #[inline]
+    pub fn parent(&self) -> SyntaxNode<K, S> {
+        self.parent.clone()
+    }
+}
+
+impl<K, S> Clone for SyntaxToken<K, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            parent: self.parent.clone(),
+            index: self.index,
+            offset: self.offset,
+        }
+    }
+}
+
+impl<K, S: fmt::Debug> fmt::Debug for SyntaxToken<K, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SyntaxToken")
+            .field("text", self.text())
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+/// Child of a [`SyntaxNode`]
+#[derive(Clone, Debug)]
+pub enum SyntaxElement<K, S> {
+    /// Nested node
+    Node(SyntaxNode<K, S>),
+    /// Slice of the input
+    Token(SyntaxToken<K, S>),
+}
@@ -0,0 +1,288 @@
+use crate::combinator::eof;
+use crate::combinator::trace;
+use crate::error::ErrMode;
+use crate::error::ParserError;
+use crate::lib::std::ops::Range;
+use crate::lib::std::rc::Rc;
+use crate::lib::std::string::String;
+use crate::lib::std::vec::Vec;
+use crate::stream::LocatingSlice;
+use crate::stream::NodeSink;
+use crate::stream::Recording;
+use crate::stream::Stream;
+use crate::Parser;
+
+use super::GreenElement;
+use super::GreenNode;
+
+/// Input for [`Rule`]s of a [`Reparser`]
+pub type ReparseInput<'i, K> = Recording<LocatingSlice<&'i str>, K>;
+
+/// Parser for a node that can be reparsed on its own, see [`Reparser`]
+pub type Rule<'i, K, E = ErrMode<crate::error::ContextError>> =
+    fn(&mut ReparseInput<'i, K>) -> Result<(), E>;
+
+/// Change to the text of a document
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Edit<'e> {
+    range: (usize, usize),
+    replacement: &'e str,
+}
+
+impl<'e> Edit<'e> {
+    /// Replace `range` of the old text with `replacement`
+    #[inline]
+    pub fn new(range: Range<usize>, replacement: &'e str) -> Self {
+        Self {
+            range: (range.start, range.end),
+            replacement,
+        }
+    }
+
+    /// The replaced part of the old text
+    #[inline(always)]
+    pub fn range(&self) -> Range<usize> {
+        self.range.0..self.range.1
+    }
+
+    /// The text that was inserted
+    #[inline(always)]
+    pub fn replacement(&self) -> &'e str {
+        self.replacement
+    }
+
+    /// Apply the edit to the old text
+    ///
+    /// # Panic
+    ///
+    /// If the range is out of bounds or not on a `char` boundary
+    pub fn apply(&self, text: &str) -> String {
+        let mut new =
+            String::with_capacity(text.len() - self.range().len() + self.replacement.len());
+        new.push_str(&text[..self.range.0]);
+        new.push_str(self.replacement);
+        new.push_str(&text[self.range.1..]);
+        new
+    }
+}
+
+/// Mark the boundary of a [`Rule`] that can be reparsed on its own, see [`Reparser`]
+///
+/// This records the consumed input as a node of `kind`, like [`Parser::node`].
+///
+/// A rule can only be reused if it parses the same regardless of what came before it, and what
+/// comes after it only depends on where it ended.
+#[inline(always)]
+pub fn reusable<Input, Output, Error, ParseNext, K>(
+    kind: K,
+    parser: ParseNext,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: Stream + NodeSink<K>,
+    Error: ParserError<Input>,
+    ParseNext: Parser<Input, Output, Error>,
+    K: Clone,
+{
+    trace("reusable", parser.node(kind))
+}
+
+/// Incrementally reparse a document after an [`Edit`]
+///
+/// On an edit, the smallest node with a registered [`Rule`] that strictly contains the edit is
+/// parsed again, in the context of the new text.  If the rule fails or doesn't end where the node
+/// is expected to end, the next enclosing rule is tried, falling back to parsing the whole
+/// document.  All nodes outside of the reparsed node are reused.
+///
+/// The result is the same as parsing the new text from scratch as long as the rules are marked
+/// with [`reusable`].
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::{alpha1, multispace0};
+/// use winnow::combinator::{alt, delimited, repeat, terminated};
+/// use winnow::cst::{reusable, Edit, ReparseInput, Reparser};
+///
+/// #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// enum Kind {
+///     Root,
+///     List,
+///     Word,
+/// }
+///
+/// type Input<'i> = ReparseInput<'i, Kind>;
+///
+/// fn items(input: &mut Input<'_>) -> ModalResult<()> {
+///     repeat(0.., terminated(alt((list, alpha1.void().node(Kind::Word))), multispace0))
+///         .parse_next(input)
+/// }
+///
+/// fn list(input: &mut Input<'_>) -> ModalResult<()> {
+///     reusable(Kind::List, delimited(('[', multispace0), items, ']')).parse_next(input)
+/// }
+///
+/// let reparser = Reparser::new(Kind::Root, items).rule(Kind::List, list);
+///
+/// let old = "[a [b c]] d";
+/// let tree = reparser.parse(old).unwrap();
+///
+/// let edit = Edit::new(4..5, "bb");
+/// let new = edit.apply(old);
+/// let tree = reparser.reparse(&tree, &edit, &new).unwrap();
+/// assert_eq!(tree.to_string(), "[a [bb c]] d");
+/// assert_eq!(tree, reparser.parse(&new).unwrap());
+/// ```
+pub struct Reparser<'i, K, E = ErrMode<crate::error::ContextError>> {
+    root: (K, Rule<'i, K, E>),
+    rules: Vec<(K, Rule<'i, K, E>)>,
+}
+
+impl<'i, K, E> Reparser<'i, K, E>
+where
+    K: Clone + PartialEq + crate::lib::std::fmt::Debug,
+    E: ParserError<ReparseInput<'i, K>>,
+    <E as ParserError<ReparseInput<'i, K>>>::Inner: ParserError<ReparseInput<'i, K>>,
+{
+    /// Parse documents with `root`, wrapping them in a node of `kind`
+    ///
+    /// `root` must consume the whole document.
+    #[inline]
+    pub fn new(kind: K, root: Rule<'i, K, E>) -> Self {
+        Self {
+            root: (kind, root),
+            rules: Vec::new(),
+        }
+    }
+
+    /// Reparse nodes of `kind` with `rule`
+    ///
+    /// `rule` must record exactly one node of `kind`, see [`reusable`].
+    #[inline]
+    pub fn rule(mut self, kind: K, rule: Rule<'i, K, E>) -> Self {
+        self.rules.push((kind, rule));
+        self
+    }
+
+    /// Parse the whole document
+    pub fn parse(
+        &self,
+        text: &'i str,
+    ) -> Result<GreenNode<K, &'i str>, <E as ParserError<ReparseInput<'i, K>>>::Inner> {
+        let mut input = Recording::new(LocatingSlice::new(text));
+        (self.root.1, eof).parse_next(&mut input).map_err(|e| {
+            e.into_inner().unwrap_or_else(|_err| {
+                panic!("complete parsers should not report `ErrMode::Incomplete(_)`")
+            })
+        })?;
+        let (_, events) = input.into_parts();
+        Ok(GreenNode::from_events(self.root.0.clone(), events))
+    }
+
+    /// Update the tree for the document `old` was parsed from after `edit`
+    ///
+    /// `text` is the document after the edit.
+    pub fn reparse(
+        &self,
+        old: &GreenNode<K, &'i str>,
+        edit: &Edit<'_>,
+        text: &'i str,
+    ) -> Result<GreenNode<K, &'i str>, <E as ParserError<ReparseInput<'i, K>>>::Inner> {
+        let range = edit.range();
+        debug_assert_eq!(
+            text.len(),
+            old.len() - range.len() + edit.replacement().len(),
+            "`text` is `old` with `edit` applied"
+        );
+
+        // Nodes strictly containing the edit, with their parent and their index in it
+        let mut path = Vec::new();
+        let mut parent = old;
+        let mut offset = 0;
+        'descend: loop {
+            for (index, child) in parent.children().iter().enumerate() {
+                let end = offset + child.len();
+                if range.end < end {
+                    match child {
+                        GreenElement::Node(node) if offset < range.start => {
+                            path.push((parent, index, offset));
+                            parent = node;
+                            continue 'descend;
+                        }
+                        _ => break 'descend,
+                    }
+                }
+                offset = end;
+            }
+            break;
+        }
+
+        for depth in (0..path.len()).rev() {
+            let (parent, index, offset) = path[depth];
+            let GreenElement::Node(node) = &parent.children()[index] else {
+                unreachable!("only nodes are descended into")
+            };
+            let Some(rule) = self.find_rule(node.kind()) else {
+                continue;
+            };
+            let len = node.len() - range.len() + edit.replacement().len();
+            if let Some(mut green) = reparse_node(rule, node.kind(), text, offset, len) {
+                for &(parent, index, _) in path[..=depth].iter().rev() {
+                    let mut children = parent.children().to_vec();
+                    children[index] = GreenElement::Node(Rc::new(green));
+                    green = GreenNode::new(parent.kind().clone(), children);
+                }
+                return Ok(green);
+            }
+        }
+
+        self.parse(text)
+    }
+
+    fn find_rule(&self, kind: &K) -> Option<Rule<'i, K, E>> {
+        self.rules
+            .iter()
+            .find(|(rule_kind, _)| rule_kind == kind)
+            .map(|(_, rule)| *rule)
+    }
+}
+
+impl<K: crate::lib::std::fmt::Debug, E> crate::lib::std::fmt::Debug for Reparser<'_, K, E> {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        f.debug_struct("Reparser")
+            .field("root", &self.root.0)
+            .field(
+                "rules",
+                &self.rules.iter().map(|(kind, _)| kind).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// Parse `rule` at `offset` in `text`, expecting a single node of `kind` covering `len`
+fn reparse_node<'i, K, E>(
+    rule: Rule<'i, K, E>,
+    kind: &K,
+    text: &'i str,
+    offset: usize,
+    len: usize,
+) -> Option<GreenNode<K, &'i str>>
+where
+    K: Clone + PartialEq + crate::lib::std::fmt::Debug,
+{
+    let mut input = LocatingSlice::new(text);
+    input.next_slice(offset);
+    let mut input = Recording::new(input);
+    rule(&mut input).ok()?;
+    let (_, events) = input.into_parts();
+
+    let mut root = GreenNode::from_events(kind.clone(), events);
+    match root.children.pop() {
+        Some(GreenElement::Node(node))
+            if root.children.is_empty() && node.kind() == kind && node.len() == len =>
+        {
+            Some(Rc::try_unwrap(node).unwrap_or_else(|node| (*node).clone()))
+        }
+        _ => None,
+    }
+}
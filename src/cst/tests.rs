@@ -0,0 +1,311 @@
+use super::*;
+
+use crate::ascii::{alpha1, dec_uint, digit1, multispace1};
+use crate::combinator::{alt, eof, repeat};
+use crate::stream::{Recording, Trivia};
+use crate::token::take_till;
+use crate::{ModalResult, Parser};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Kind {
+    Root,
+    List,
+    Word,
+    Number,
+}
+
+#[test]
+fn builder_unbalanced() {
+    let mut builder = GreenNodeBuilder::new(Kind::Root);
+    builder.token("a");
+    builder.finish_node();
+    builder.start_node(Kind::Word);
+    builder.token("bc");
+    builder.start_node(Kind::Number);
+    builder.token("1");
+    let root = builder.finish();
+
+    assert_eq!(root.len(), 4);
+    assert_eq!(root.to_string(), "abc1");
+    assert_eq!(root.children().len(), 2);
+    match &root.children()[1] {
+        GreenElement::Node(word) => {
+            assert_eq!(*word.kind(), Kind::Word);
+            assert_eq!(word.len(), 3);
+        }
+        GreenElement::Token(_) => panic!("expected node"),
+    }
+}
+
+#[test]
+fn recording_backtracks() {
+    let mut input = Recording::new("abc 12");
+    let item = alt((
+        (alpha1, ' ', alpha1).void().node(Kind::Word),
+        (alpha1, ' ').void().node(Kind::Word),
+    ));
+    let parsed: ModalResult<_> = (item, digit1.node(Kind::Number)).parse_next(&mut input);
+    parsed.unwrap();
+    let (_, events) = input.into_parts();
+    assert_eq!(
+        events,
+        [
+            Event::Start(Kind::Word),
+            Event::Token("abc"),
+            Event::Token(" "),
+            Event::Finish,
+            Event::Start(Kind::Number),
+            Event::Token("12"),
+            Event::Finish,
+        ]
+    );
+}
+
+#[test]
+fn recording_backtracks_nodes() {
+    let mut input = Recording::new("aby");
+    let parsed: ModalResult<_> =
+        alt((("ab".node(Kind::Word), "x").take(), "ab")).parse_next(&mut input);
+    assert_eq!(parsed, Ok("ab"));
+    let (_, events) = input.into_parts();
+    assert_eq!(events, [Event::Token("ab")]);
+
+    let mut input = Recording::new("abc");
+    let parsed: ModalResult<_> = alt((
+        ("ab", digit1).void().node(Kind::Word),
+        alpha1.void().node(Kind::Number),
+    ))
+    .parse_next(&mut input);
+    parsed.unwrap();
+    let (_, events) = input.into_parts();
+    assert_eq!(
+        events,
+        [
+            Event::Start(Kind::Number),
+            Event::Token("abc"),
+            Event::Finish
+        ]
+    );
+
+    let mut input = Recording::new("abc");
+    let parsed: ModalResult<_> = ("ab", 'x').node(Kind::Word).parse_next(&mut input);
+    assert!(parsed.is_err());
+    let (rest, events) = input.into_parts();
+    assert_eq!(rest, "abc");
+    assert_eq!(events, []);
+}
+
+#[test]
+fn recording_take() {
+    let mut input = Recording::new("abc 12");
+    let parsed: ModalResult<_> = (alpha1.node(Kind::Word), ' ', digit1.node(Kind::Number))
+        .take()
+        .node(Kind::List)
+        .parse_next(&mut input);
+    assert_eq!(parsed, Ok("abc 12"));
+    let (_, events) = input.into_parts();
+    assert_eq!(
+        events,
+        [
+            Event::Start(Kind::List),
+            Event::Start(Kind::Word),
+            Event::Token("abc"),
+            Event::Finish,
+            Event::Token(" "),
+            Event::Start(Kind::Number),
+            Event::Token("12"),
+            Event::Finish,
+            Event::Finish,
+        ]
+    );
+}
+
+#[test]
+#[cfg(feature = "unstable-recover")]
+#[cfg(feature = "std")]
+fn recoverable_recording() {
+    use crate::error::ContextError;
+    use crate::stream::Recoverable;
+
+    let mut input = Recoverable::<_, ContextError>::new(Recording::new("abc"));
+    let parsed: ModalResult<_> = alpha1.node(Kind::Word).parse_next(&mut input);
+    assert_eq!(parsed, Ok("abc"));
+    let (input, _) = input.into_parts();
+    let (_, events) = input.into_parts();
+    assert_eq!(
+        events,
+        [Event::Start(Kind::Word), Event::Token("abc"), Event::Finish,]
+    );
+}
+
+#[test]
+fn recording_trivia() {
+    fn trivia(input: &mut Recording<&str, Kind>) -> ModalResult<()> {
+        repeat(
+            0..,
+            alt((multispace1.void(), ("#", take_till(0.., '\n')).void())),
+        )
+        .parse_next(input)
+    }
+
+    let source = " abc # comment\n 12 ";
+    let mut input = Trivia::new(Recording::new(source), trivia);
+    let parsed: ModalResult<_> = (
+        alpha1.node(Kind::Word),
+        dec_uint::<_, u32, _>.node(Kind::Number),
+        eof,
+    )
+        .parse_next(&mut input);
+    parsed.unwrap();
+    let (_, events) = input.into_inner().into_parts();
+
+    let root = SyntaxNode::new_root(GreenNode::from_events(Kind::Root, events));
+    assert_eq!(root.to_string(), source);
+    assert_eq!(root.span(), 0..source.len());
+
+    let nodes = root
+        .children()
+        .map(|node| (*node.kind(), node.span(), node.to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        nodes,
+        [
+            (Kind::Word, 1..4, "abc".to_owned()),
+            (Kind::Number, 16..18, "12".to_owned()),
+        ]
+    );
+
+    // Trivia belongs to the enclosing node
+    let tokens = root
+        .children_with_tokens()
+        .filter_map(|child| match child {
+            SyntaxElement::Token(token) => Some((*token.text(), token.span())),
+            SyntaxElement::Node(_) => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tokens,
+        [
+            (" ", 0..1),
+            (" ", 4..5),
+            ("#", 5..6),
+            (" comment", 6..14),
+            ("\n ", 14..16),
+            (" ", 18..19)
+        ]
+    );
+
+    let number = root.children().nth(1).unwrap();
+    assert_eq!(*number.parent().unwrap().kind(), Kind::Root);
+    assert!(root.parent().is_none());
+}
+
+mod reparse {
+    use super::*;
+
+    use crate::ascii::space0;
+    use crate::combinator::{delimited, terminated};
+    use crate::cst::{reusable, Edit, ReparseInput, Reparser};
+
+    #[cfg(feature = "std")]
+    use proptest::prelude::*;
+
+    type Input<'i> = ReparseInput<'i, Kind>;
+
+    fn items(input: &mut Input<'_>) -> ModalResult<()> {
+        repeat(
+            0..,
+            terminated(
+                alt((
+                    list,
+                    alpha1.void().node(Kind::Word),
+                    digit1.void().node(Kind::Number),
+                )),
+                space0,
+            ),
+        )
+        .parse_next(input)
+    }
+
+    fn list(input: &mut Input<'_>) -> ModalResult<()> {
+        reusable(Kind::List, delimited(('[', space0), items, ']')).parse_next(input)
+    }
+
+    fn reparser<'i>() -> Reparser<'i, Kind> {
+        Reparser::new(Kind::Root, items).rule(Kind::List, list)
+    }
+
+    #[test]
+    fn reuses_siblings() {
+        let reparser = reparser();
+        let old = "[a 1] [b [c]] [d]";
+        let tree = reparser.parse(old).unwrap();
+
+        let edit = Edit::new(10..11, "cc 2");
+        let new = edit.apply(old);
+        let reparsed = reparser.reparse(&tree, &edit, &new).unwrap();
+        assert_eq!(reparsed.to_string(), "[a 1] [b [cc 2]] [d]");
+        assert_eq!(reparsed, reparser.parse(&new).unwrap());
+
+        let (GreenElement::Node(old_first), GreenElement::Node(new_first)) =
+            (&tree.children()[0], &reparsed.children()[0])
+        else {
+            panic!("expected nodes");
+        };
+        assert!(Rc::ptr_eq(old_first, new_first));
+        let (GreenElement::Node(old_last), GreenElement::Node(new_last)) =
+            (&tree.children()[4], &reparsed.children()[4])
+        else {
+            panic!("expected nodes");
+        };
+        assert!(Rc::ptr_eq(old_last, new_last));
+    }
+
+    #[test]
+    fn falls_back_to_enclosing_rule() {
+        let reparser = reparser();
+        let old = "[a [b] c]";
+        let tree = reparser.parse(old).unwrap();
+
+        // Closing the inner list early moves its end, so the outer list is reparsed
+        let edit = Edit::new(5..5, "] [y");
+        let new = edit.apply(old);
+        let reparsed = reparser.reparse(&tree, &edit, &new).unwrap();
+        assert_eq!(reparsed.to_string(), "[a [b] [y] c]");
+        assert_eq!(reparsed, reparser.parse(&new).unwrap());
+
+        // Unbalanced brackets fail the whole document
+        let edit = Edit::new(4..5, "]");
+        let new = edit.apply(old);
+        assert!(reparser.reparse(&tree, &edit, &new).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    fn document() -> impl Strategy<Value = String> {
+        let leaf = prop_oneof!["[a-c]{1,2}", "[0-9]{1,2}"];
+        let item = leaf.prop_recursive(4, 24, 4, |item| {
+            prop::collection::vec(item, 0..4).prop_map(|items| format!("[{}]", items.join(" ")))
+        });
+        prop::collection::vec(item, 0..4).prop_map(|items| items.join(" "))
+    }
+
+    #[cfg(feature = "std")]
+    proptest! {
+      #[test]
+      #[cfg_attr(miri, ignore)]  // See https://github.com/AltSysrq/proptest/issues/253
+      fn reparse_matches_parse(
+          (old, start, end) in document().prop_flat_map(|old| {
+              let len = old.len();
+              (Just(old), 0..=len, 0..=len)
+          }),
+          replacement in "[\\[\\] a1]{0,3}",
+      ) {
+          let reparser = reparser();
+          let tree = reparser.parse(&old).unwrap();
+          let edit = Edit::new(start.min(end)..start.max(end), &replacement);
+          let new = edit.apply(&old);
+          let reparsed = reparser.reparse(&tree, &edit, &new);
+          prop_assert_eq!(reparsed, reparser.parse(&new));
+      }
+    }
+}
@@ -0,0 +1,161 @@
+use crate::lib::std::fmt::Write as _;
+use crate::lib::std::string::String;
+
+use super::{ClassItem, Expr, Grammar};
+
+pub(crate) fn grammar(grammar: &Grammar) -> String {
+    let mut out = String::new();
+    for (name, expr) in grammar.rules() {
+        out.push_str(name);
+        out.push_str(" ::= ");
+        self::expr(expr, Precedence::Choice, &mut out);
+        out.push('\n');
+    }
+    out
+}
+
+/// How tightly an expression binds, to know when to add parentheses
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Precedence {
+    Choice,
+    Sequence,
+    Postfix,
+}
+
+/// Render a single expression
+pub(crate) fn to_string(expr: &Expr) -> String {
+    let mut out = String::new();
+    self::expr(expr, Precedence::Choice, &mut out);
+    out
+}
+
+fn expr(expr: &Expr, context: Precedence, out: &mut String) {
+    let precedence = match expr {
+        Expr::Choice(_) => Precedence::Choice,
+        Expr::Sequence(_) => Precedence::Sequence,
+        Expr::Literal(text) if 1 < text.chars().count() && !text.chars().all(is_plain) => {
+            Precedence::Sequence
+        }
+        Expr::CaselessLiteral(text) if 1 < text.chars().count() => Precedence::Sequence,
+        _ => Precedence::Postfix,
+    };
+    let parenthesize = precedence < context;
+    if parenthesize {
+        out.push_str("( ");
+    }
+    match expr {
+        Expr::Empty => out.push_str("()"),
+        Expr::Literal(text) => literal(text, out),
+        Expr::CaselessLiteral(text) => {
+            for (i, c) in text.chars().enumerate() {
+                if 0 < i {
+                    out.push(' ');
+                }
+                if c.is_ascii_alphabetic() {
+                    out.push('[');
+                    out.push(c.to_ascii_lowercase());
+                    out.push(c.to_ascii_uppercase());
+                    out.push(']');
+                } else {
+                    literal(c.encode_utf8(&mut [0; 4]), out);
+                }
+            }
+        }
+        Expr::Class(items) => class(items, out),
+        Expr::Rule(name) => out.push_str(name),
+        Expr::Sequence(exprs) => {
+            for (i, item) in exprs.iter().enumerate() {
+                if 0 < i {
+                    out.push(' ');
+                }
+                self::expr(item, Precedence::Sequence, out);
+            }
+        }
+        Expr::Choice(exprs) => {
+            for (i, item) in exprs.iter().enumerate() {
+                if 0 < i {
+                    out.push_str(" | ");
+                }
+                self::expr(item, Precedence::Sequence, out);
+            }
+        }
+        Expr::Repeat { expr, min, max } => {
+            self::expr(expr, Precedence::Postfix, out);
+            out.push_str(&repetition(*min, *max));
+        }
+    }
+    if parenthesize {
+        out.push_str(" )");
+    }
+}
+
+pub(crate) fn repetition(min: usize, max: Option<usize>) -> String {
+    match (min, max) {
+        (0, Some(1)) => "?".into(),
+        (0, None) => "*".into(),
+        (1, None) => "+".into(),
+        (min, None) => alloc::format!("{{{min},}}"),
+        (min, Some(max)) if min == max => alloc::format!("{{{min}}}"),
+        (min, Some(max)) => alloc::format!("{{{min},{max}}}"),
+    }
+}
+
+fn literal(text: &str, out: &mut String) {
+    if text.is_empty() {
+        out.push_str("\"\"");
+        return;
+    }
+    let mut quoted = false;
+    for (i, c) in text.chars().enumerate() {
+        if is_plain(c) {
+            if !quoted {
+                if 0 < i {
+                    out.push(' ');
+                }
+                out.push('"');
+                quoted = true;
+            }
+            out.push(c);
+        } else {
+            if quoted {
+                out.push('"');
+                quoted = false;
+            }
+            if 0 < i {
+                out.push(' ');
+            }
+            let _ = write!(out, "#x{:X}", c as u32);
+        }
+    }
+    if quoted {
+        out.push('"');
+    }
+}
+
+fn is_plain(c: char) -> bool {
+    (c.is_ascii_graphic() || c == ' ') && c != '"'
+}
+
+fn class(items: &[ClassItem], out: &mut String) {
+    out.push('[');
+    for item in items {
+        match *item {
+            ClassItem::Char(c) => class_char(c, out),
+            ClassItem::Range(start, end) => {
+                class_char(start, out);
+                out.push('-');
+                class_char(end, out);
+            }
+            ClassItem::Any => out.push_str("#x0-#x10FFFF"),
+        }
+    }
+    out.push(']');
+}
+
+fn class_char(c: char, out: &mut String) {
+    if c.is_ascii_graphic() && !matches!(c, '[' | ']' | '-' | '^' | '\\' | '#') {
+        out.push(c);
+    } else {
+        let _ = write!(out, "#x{:X}", c as u32);
+    }
+}
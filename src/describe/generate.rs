@@ -0,0 +1,357 @@
+use crate::lib::std::borrow::Cow;
+use crate::lib::std::collections::HashMap;
+use crate::lib::std::ops::RangeInclusive;
+
+use proptest::collection::vec;
+use proptest::sample::{select, Index};
+use proptest::strategy::{BoxedStrategy, Just, Strategy, Union};
+
+use super::{ClassItem, Expr, Grammar};
+
+/// Generate inputs for a rule of a [`Grammar`], for property testing with [`proptest`]
+///
+/// Literals are emitted verbatim, [classes][Expr::Class] are sampled, repetitions stay within
+/// their bounds and each case of a [choice][Expr::Choice] is picked from.  Once
+/// [`Generator::max_depth`] rules are nested, rules expand to their shortest input, keeping
+/// recursive grammars finite.
+///
+/// As inputs are derived from the [description][super::Describe] of a parser, they are only as
+/// valid as that description: [`opaque`][super::opaque] rules must be added to the grammar and
+/// the [first case][crate::combinator::alt] of a choice that matches wins, so a generated input
+/// may still be parsed differently than expected.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use proptest::prelude::*;
+/// use winnow::combinator::{alt, delimited, separated, trace};
+/// use winnow::describe::{opaque, Describe, Generator, Grammar};
+/// use winnow::error::{ContextError, ErrMode};
+/// use winnow::token::take_while;
+///
+/// type Error = ErrMode<ContextError>;
+///
+/// fn number<'i>() -> impl Parser<&'i str, &'i str, Error> + Describe {
+///     trace("number", take_while(1.., '0'..='9'))
+/// }
+///
+/// fn list<'i>() -> impl Parser<&'i str, Vec<()>, Error> + Describe {
+///     trace(
+///         "list",
+///         delimited('[', separated(0.., opaque("value", value), ','), ']'),
+///     )
+/// }
+///
+/// fn value(input: &mut &str) -> ModalResult<()> {
+///     alt((number().void(), list().void())).parse_next(input)
+/// }
+///
+/// let mut grammar = Grammar::new();
+/// grammar.rule("value", &alt((number().void(), list().void())));
+///
+/// proptest!(|(input in Generator::new(&grammar, "value").strings())| {
+///     prop_assert!(value.parse(&input).is_ok());
+/// });
+/// ```
+#[derive(Clone, Debug)]
+pub struct Generator<'g> {
+    grammar: &'g Grammar,
+    start: Expr,
+    max_depth: usize,
+    max_repeat: usize,
+    near_valid: bool,
+}
+
+impl<'g> Generator<'g> {
+    /// Generate inputs for `rule` of `grammar`
+    #[inline]
+    pub fn new(grammar: &'g Grammar, rule: &str) -> Self {
+        Self {
+            grammar,
+            start: Expr::Rule(rule.to_owned()),
+            max_depth: 8,
+            max_repeat: 4,
+            near_valid: false,
+        }
+    }
+
+    /// How many rules may be nested before they expand to their shortest input
+    ///
+    /// Default: 8
+    #[inline]
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// How many repetitions beyond the minimum may be generated
+    ///
+    /// Default: 4
+    #[inline]
+    pub fn max_repeat(mut self, max_repeat: usize) -> Self {
+        self.max_repeat = max_repeat;
+        self
+    }
+
+    /// Whether to make a random edit to each input
+    ///
+    /// A token is removed, inserted or replaced, using tokens from the grammar, to exercise error
+    /// paths with inputs that are usually just barely invalid.
+    ///
+    /// Default: `false`
+    #[inline]
+    pub fn near_valid(mut self, near_valid: bool) -> Self {
+        self.near_valid = near_valid;
+        self
+    }
+
+    /// Generate `&str` inputs
+    ///
+    /// # Panic
+    ///
+    /// If a rule isn't in the grammar or can't be expanded to a finite input
+    pub fn strings(&self) -> BoxedStrategy<String> {
+        self.tokens(false)
+            .prop_map(|tokens| tokens.into_iter().collect())
+            .boxed()
+    }
+
+    /// Generate `&[u8]` inputs
+    ///
+    /// Tokens are described as the `char` of the same value, so [classes][Expr::Class] are
+    /// limited to `'\0'..='\u{ff}'` and larger `char`s in literals are encoded as UTF-8.
+    ///
+    /// # Panic
+    ///
+    /// If a rule isn't in the grammar or can't be expanded to a finite input
+    pub fn bytes(&self) -> BoxedStrategy<Vec<u8>> {
+        self.tokens(true)
+            .prop_map(|tokens| {
+                let mut bytes = Vec::with_capacity(tokens.len());
+                for c in tokens {
+                    match u8::try_from(c) {
+                        Ok(byte) => bytes.push(byte),
+                        Err(_) => bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+                    }
+                }
+                bytes
+            })
+            .boxed()
+    }
+
+    fn tokens(&self, bytes: bool) -> BoxedStrategy<Vec<char>> {
+        let mut builder = Builder {
+            grammar: self.grammar,
+            max_repeat: self.max_repeat,
+            bytes,
+            shortest: shortest(self.grammar, bytes),
+            rules: HashMap::new(),
+        };
+        let valid = builder.expr(&self.start, self.max_depth);
+        if !self.near_valid {
+            return valid;
+        }
+
+        let mut alphabet = Vec::new();
+        for (_, expr) in self.grammar.rules() {
+            collect_alphabet(expr, bytes, &mut alphabet);
+        }
+        alphabet.sort_unstable();
+        alphabet.dedup();
+        if alphabet.is_empty() {
+            alphabet.push('\0');
+        }
+        (
+            valid,
+            0..3u8,
+            proptest::arbitrary::any::<Index>(),
+            select(alphabet),
+        )
+            .prop_map(|(mut tokens, edit, index, token)| {
+                match edit {
+                    0 if !tokens.is_empty() => {
+                        tokens.remove(index.index(tokens.len()));
+                    }
+                    1 if !tokens.is_empty() => {
+                        let i = index.index(tokens.len());
+                        tokens[i] = token;
+                    }
+                    _ => tokens.insert(index.index(tokens.len() + 1), token),
+                }
+                tokens
+            })
+            .boxed()
+    }
+}
+
+struct Builder<'g> {
+    grammar: &'g Grammar,
+    max_repeat: usize,
+    bytes: bool,
+    shortest: HashMap<&'g str, Vec<char>>,
+    rules: HashMap<(&'g str, usize), BoxedStrategy<Vec<char>>>,
+}
+
+impl<'g> Builder<'g> {
+    fn expr(&mut self, expr: &Expr, depth: usize) -> BoxedStrategy<Vec<char>> {
+        match expr {
+            Expr::Empty => Just(Vec::new()).boxed(),
+            Expr::Literal(text) => Just(text.chars().collect()).boxed(),
+            Expr::CaselessLiteral(text) => text
+                .chars()
+                .map(|c| {
+                    if c.is_ascii_alphabetic() {
+                        select(vec![c.to_ascii_lowercase(), c.to_ascii_uppercase()]).boxed()
+                    } else {
+                        Just(c).boxed()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .boxed(),
+            Expr::Class(items) => {
+                let ranges = class_ranges(items, self.bytes);
+                assert!(!ranges.is_empty(), "class {items:?} has no tokens");
+                proptest::char::ranges(Cow::Owned(ranges))
+                    .prop_map(|c| vec![c])
+                    .boxed()
+            }
+            Expr::Rule(name) => self.rule(name, depth),
+            Expr::Sequence(exprs) => exprs
+                .iter()
+                .map(|expr| self.expr(expr, depth))
+                .collect::<Vec<_>>()
+                .prop_map(|parts| parts.concat())
+                .boxed(),
+            Expr::Choice(exprs) => {
+                Union::new(exprs.iter().map(|expr| self.expr(expr, depth))).boxed()
+            }
+            Expr::Repeat { expr, min, max } => {
+                let limit = min.saturating_add(self.max_repeat);
+                let max = max.map(|max| max.min(limit)).unwrap_or(limit);
+                vec(self.expr(expr, depth), *min..=max)
+                    .prop_map(|parts| parts.concat())
+                    .boxed()
+            }
+        }
+    }
+
+    fn rule(&mut self, name: &str, depth: usize) -> BoxedStrategy<Vec<char>> {
+        let Some((name, expr)) = self.grammar.rules().find(|(rule, _)| *rule == name) else {
+            panic!("rule `{name}` is not in the grammar")
+        };
+        if depth == 0 {
+            let Some(shortest) = self.shortest.get(name) else {
+                panic!("rule `{name}` can't be expanded to a finite input")
+            };
+            return Just(shortest.clone()).boxed();
+        }
+        if let Some(strategy) = self.rules.get(&(name, depth)) {
+            return strategy.clone();
+        }
+        let strategy = self.expr(expr, depth - 1);
+        self.rules.insert((name, depth), strategy.clone());
+        strategy
+    }
+}
+
+/// Find the shortest input for each rule that has one
+fn shortest(grammar: &Grammar, bytes: bool) -> HashMap<&str, Vec<char>> {
+    let mut rules = HashMap::new();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (name, expr) in grammar.rules() {
+            let Some(tokens) = shortest_expr(expr, bytes, &rules) else {
+                continue;
+            };
+            if rules
+                .get(name)
+                .map(|old: &Vec<char>| tokens.len() < old.len())
+                .unwrap_or(true)
+            {
+                rules.insert(name, tokens);
+                changed = true;
+            }
+        }
+    }
+    rules
+}
+
+fn shortest_expr(expr: &Expr, bytes: bool, rules: &HashMap<&str, Vec<char>>) -> Option<Vec<char>> {
+    match expr {
+        Expr::Empty => Some(Vec::new()),
+        Expr::Literal(text) | Expr::CaselessLiteral(text) => Some(text.chars().collect()),
+        Expr::Class(items) => {
+            let first = class_ranges(items, bytes).first()?.clone();
+            Some(vec![*first.start()])
+        }
+        Expr::Rule(name) => rules.get(name.as_str()).cloned(),
+        Expr::Sequence(exprs) => {
+            let mut tokens = Vec::new();
+            for expr in exprs {
+                tokens.extend(shortest_expr(expr, bytes, rules)?);
+            }
+            Some(tokens)
+        }
+        Expr::Choice(exprs) => exprs
+            .iter()
+            .filter_map(|expr| shortest_expr(expr, bytes, rules))
+            .min_by_key(|tokens| tokens.len()),
+        Expr::Repeat { expr, min, .. } => {
+            if *min == 0 {
+                return Some(Vec::new());
+            }
+            Some(shortest_expr(expr, bytes, rules)?.repeat(*min))
+        }
+    }
+}
+
+/// Ranges of `char`s that are valid tokens, skipping surrogates
+fn class_ranges(items: &[ClassItem], bytes: bool) -> Vec<RangeInclusive<char>> {
+    let last = if bytes { '\u{ff}' } else { char::MAX };
+    let mut ranges = Vec::new();
+    for item in items {
+        let (start, end) = match *item {
+            ClassItem::Char(c) => (c, c),
+            ClassItem::Range(start, end) => (start, end),
+            ClassItem::Any => ('\0', last),
+        };
+        let end = end.min(last);
+        if end < start {
+            continue;
+        }
+        if start < '\u{e000}' && '\u{d7ff}' < end {
+            ranges.push(start..='\u{d7ff}');
+            ranges.push('\u{e000}'..=end);
+        } else {
+            ranges.push(start..=end);
+        }
+    }
+    ranges
+}
+
+fn collect_alphabet(expr: &Expr, bytes: bool, alphabet: &mut Vec<char>) {
+    match expr {
+        Expr::Empty | Expr::Rule(_) => {}
+        Expr::Literal(text) => alphabet.extend(text.chars()),
+        Expr::CaselessLiteral(text) => {
+            for c in text.chars() {
+                alphabet.push(c.to_ascii_lowercase());
+                alphabet.push(c.to_ascii_uppercase());
+            }
+        }
+        Expr::Class(items) => {
+            for range in class_ranges(items, bytes) {
+                alphabet.push(*range.start());
+                alphabet.push(*range.end());
+            }
+        }
+        Expr::Sequence(exprs) | Expr::Choice(exprs) => {
+            for expr in exprs {
+                collect_alphabet(expr, bytes, alphabet);
+            }
+        }
+        Expr::Repeat { expr, .. } => collect_alphabet(expr, bytes, alphabet),
+    }
+}
@@ -0,0 +1,554 @@
+//! # Grammar introspection
+//!
+//! Parsers built from combinators like [`alt`][crate::combinator::alt],
+//! [`repeat`][crate::combinator::repeat] and [`literal`][crate::token::literal] implement
+//! [`Describe`], reporting the syntax they accept as an [`Expr`].  Named rules, from
+//! [`trace`][crate::combinator::trace], are collected into a [`Grammar`] which can be exported
+//! as [EBNF][Grammar::to_ebnf] or as a [railroad diagram][Grammar::to_railroad], keeping
+//! syntax documentation in sync with the parser.
+//!
+//! With the `unstable-generate` feature, a [`Generator`] derives [`proptest`] strategies from a
+//! grammar, producing valid and near-valid inputs for property testing and fuzzing.
+//!
+//! Parsers that can't be inspected, like closures and `fn`s, can be described by name with
+//! [`opaque`], which is also how recursive rules refer to each other.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use winnow::prelude::*;
+//! use winnow::combinator::{alt, delimited, separated, trace};
+//! use winnow::describe::{opaque, Describe, Grammar};
+//! use winnow::error::{ContextError, ErrMode};
+//! use winnow::token::take_while;
+//!
+//! type Error = ErrMode<ContextError>;
+//!
+//! fn number<'i>() -> impl Parser<&'i str, &'i str, Error> + Describe {
+//!     trace("number", take_while(1.., '0'..='9'))
+//! }
+//!
+//! fn list<'i>() -> impl Parser<&'i str, Vec<()>, Error> + Describe {
+//!     trace(
+//!         "list",
+//!         delimited('[', separated(0.., opaque("value", value), ','), ']'),
+//!     )
+//! }
+//!
+//! fn value(input: &mut &str) -> ModalResult<()> {
+//!     alt((number().void(), list().void())).parse_next(input)
+//! }
+//!
+//! let mut grammar = Grammar::new();
+//! grammar.rule("value", &alt((number().void(), list().void())));
+//! assert_eq!(
+//!     grammar.to_ebnf(),
+//!     r#"value ::= number | list
+//! number ::= [0-9]+
+//! list ::= "[" ( value ( "," value )* )? "]"
+//! "#
+//! );
+//! assert!(grammar.to_railroad().starts_with("<svg"));
+//! ```
+
+mod ebnf;
+#[cfg(feature = "unstable-generate")]
+mod generate;
+mod railroad;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "unstable-generate")]
+pub use generate::*;
+
+use crate::ascii::Caseless;
+use crate::error::ParserError;
+use crate::lib::std::borrow::ToOwned;
+use crate::lib::std::boxed::Box;
+use crate::lib::std::fmt::Display;
+use crate::lib::std::string::{String, ToString};
+use crate::lib::std::vec::Vec;
+use crate::stream::AsChar;
+use crate::stream::Stream;
+use crate::Parser;
+
+/// Report the syntax accepted by a [`Parser`]
+pub trait Describe {
+    /// Describe the accepted syntax, adding any named rules to `grammar`
+    fn describe(&self, grammar: &mut Grammar) -> Expr;
+}
+
+/// Report the cases of [`alt`][crate::combinator::alt]
+pub trait DescribeAlt {
+    /// Describe each case, adding any named rules to `grammar`
+    fn describe_alt(&self, grammar: &mut Grammar) -> Vec<Expr>;
+}
+
+/// Report the tokens in a [set of tokens][crate::stream::ContainsToken]
+pub trait DescribeTokens {
+    /// Add the tokens to `class`
+    fn describe_tokens(&self, class: &mut Vec<ClassItem>);
+}
+
+/// Syntax accepted by a parser
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Expr {
+    /// Matches nothing, always succeeding
+    Empty,
+    /// Matches exactly this text
+    Literal(String),
+    /// Matches this text, ignoring ASCII case
+    CaselessLiteral(String),
+    /// Matches one token out of a set
+    Class(Vec<ClassItem>),
+    /// Matches a named rule
+    Rule(String),
+    /// Matches each expression in order
+    Sequence(Vec<Expr>),
+    /// Matches the first expression that succeeds
+    Choice(Vec<Expr>),
+    /// Matches an expression repeatedly
+    Repeat {
+        /// The repeated expression
+        expr: Box<Expr>,
+        /// The minimum number of repetitions
+        min: usize,
+        /// The maximum number of repetitions, if any
+        max: Option<usize>,
+    },
+}
+
+impl Expr {
+    /// Match each expression in order, flattening nested sequences
+    pub fn sequence(exprs: impl IntoIterator<Item = Expr>) -> Self {
+        let mut items = Vec::new();
+        for expr in exprs {
+            match expr {
+                Self::Empty => {}
+                Self::Sequence(nested) => items.extend(nested),
+                expr => items.push(expr),
+            }
+        }
+        match items.len() {
+            0 => Self::Empty,
+            1 => items.pop().expect("checked length"),
+            _ => Self::Sequence(items),
+        }
+    }
+
+    /// Match the first expression that succeeds, flattening nested choices
+    pub fn choice(exprs: impl IntoIterator<Item = Expr>) -> Self {
+        let mut items = Vec::new();
+        for expr in exprs {
+            match expr {
+                Self::Choice(nested) => items.extend(nested),
+                expr => items.push(expr),
+            }
+        }
+        match items.len() {
+            0 => Self::Empty,
+            1 => items.pop().expect("checked length"),
+            _ => Self::Choice(items),
+        }
+    }
+
+    /// Match `expr` between `min` and `max` times
+    pub fn repeat(expr: Expr, min: usize, max: Option<usize>) -> Self {
+        match (expr, min, max) {
+            (Self::Empty, _, _) | (_, 0, Some(0)) => Self::Empty,
+            (expr, 1, Some(1)) => expr,
+            (expr, min, max) => Self::Repeat {
+                expr: Box::new(expr),
+                min,
+                max,
+            },
+        }
+    }
+
+    /// Optionally match `expr`
+    pub fn optional(expr: Expr) -> Self {
+        Self::repeat(expr, 0, Some(1))
+    }
+
+    /// Match `expr` between `min` and `max` times, separated by `sep`
+    pub fn separated(expr: Expr, sep: Expr, min: usize, max: Option<usize>) -> Self {
+        if max == Some(0) {
+            return Self::Empty;
+        }
+        let rest = Self::repeat(
+            Self::sequence([sep, expr.clone()]),
+            min.saturating_sub(1),
+            max.map(|max| max - 1),
+        );
+        let list = Self::sequence([expr, rest]);
+        if min == 0 {
+            Self::optional(list)
+        } else {
+            list
+        }
+    }
+}
+
+/// Member of [`Expr::Class`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ClassItem {
+    /// A single token
+    Char(char),
+    /// An inclusive range of tokens
+    Range(char, char),
+    /// Any token
+    Any,
+}
+
+/// Named rules, see [`Describe`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Grammar {
+    // Rules are `None` while being described, to stop on recursion
+    rules: Vec<(String, Option<Expr>)>,
+    // Rule about to be described, so a `trace` of the same name describes the rule rather than
+    // referring to it
+    defining: Option<usize>,
+}
+
+impl Grammar {
+    /// Create an empty grammar
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a rule for `parser`, returning a reference to it
+    ///
+    /// If the rule already exists, it is left as-is.  When `parser` is a
+    /// [`trace`][crate::combinator::trace] of the same name, the rule is what it traces.
+    pub fn rule(&mut self, name: impl Display, parser: &(impl Describe + ?Sized)) -> Expr {
+        let name = name.to_string();
+        let defining = self.defining.take();
+        match self.rules.iter().position(|(rule, _)| *rule == name) {
+            Some(index) if defining == Some(index) => {
+                let expr = parser.describe(self);
+                self.rules[index].1 = Some(expr);
+            }
+            Some(_) => {}
+            None => {
+                let index = self.rules.len();
+                self.rules.push((name.clone(), None));
+                self.defining = Some(index);
+                let expr = parser.describe(self);
+                self.defining = None;
+                self.rules[index].1.get_or_insert(expr);
+            }
+        }
+        Expr::Rule(name)
+    }
+
+    /// Look up a rule's definition
+    pub fn get(&self, name: &str) -> Option<&Expr> {
+        self.rules
+            .iter()
+            .find(|(rule, _)| rule == name)
+            .and_then(|(_, expr)| expr.as_ref())
+    }
+
+    /// Rules, in the order they were added
+    pub fn rules(&self) -> impl Iterator<Item = (&str, &Expr)> + '_ {
+        self.rules
+            .iter()
+            .filter_map(|(name, expr)| Some((name.as_str(), expr.as_ref()?)))
+    }
+
+    /// Render as [W3C-style EBNF](https://www.w3.org/TR/xml/#sec-notation), one rule per line
+    pub fn to_ebnf(&self) -> String {
+        ebnf::grammar(self)
+    }
+
+    /// Render as an SVG railroad diagram, with one diagram per rule
+    pub fn to_railroad(&self) -> String {
+        railroad::grammar(self)
+    }
+}
+
+/// Describe `parser` as a reference to the rule `name`, without inspecting it
+///
+/// This allows describing parsers that don't implement [`Describe`], like closures and `fn`s, and
+/// referring to recursive rules.
+#[inline(always)]
+pub fn opaque<Input, Output, Error, ParseNext>(
+    name: &'static str,
+    parser: ParseNext,
+) -> Opaque<ParseNext, Input, Output, Error>
+where
+    Input: Stream,
+    ParseNext: Parser<Input, Output, Error>,
+    Error: ParserError<Input>,
+{
+    Opaque {
+        name,
+        parser,
+        i: Default::default(),
+        o: Default::default(),
+        e: Default::default(),
+    }
+}
+
+/// [`Parser`] implementation for [`opaque`]
+pub struct Opaque<P, I, O, E>
+where
+    P: Parser<I, O, E>,
+{
+    name: &'static str,
+    parser: P,
+    i: core::marker::PhantomData<I>,
+    o: core::marker::PhantomData<O>,
+    e: core::marker::PhantomData<E>,
+}
+
+impl<P, I, O, E> Parser<I, O, E> for Opaque<P, I, O, E>
+where
+    P: Parser<I, O, E>,
+{
+    #[inline(always)]
+    fn parse_next(&mut self, input: &mut I) -> Result<O, E> {
+        self.parser.parse_next(input)
+    }
+}
+
+impl<P, I, O, E> Describe for Opaque<P, I, O, E>
+where
+    P: Parser<I, O, E>,
+{
+    fn describe(&self, _grammar: &mut Grammar) -> Expr {
+        Expr::Rule(self.name.to_owned())
+    }
+}
+
+impl<P: Describe + ?Sized> Describe for &P {
+    fn describe(&self, grammar: &mut Grammar) -> Expr {
+        (**self).describe(grammar)
+    }
+}
+
+impl<P: Describe + ?Sized> Describe for &mut P {
+    fn describe(&self, grammar: &mut Grammar) -> Expr {
+        (**self).describe(grammar)
+    }
+}
+
+impl<P: Describe + ?Sized> Describe for Box<P> {
+    fn describe(&self, grammar: &mut Grammar) -> Expr {
+        (**self).describe(grammar)
+    }
+}
+
+impl Describe for u8 {
+    fn describe(&self, _grammar: &mut Grammar) -> Expr {
+        Expr::Literal(self.as_char().to_string())
+    }
+}
+
+impl Describe for char {
+    fn describe(&self, _grammar: &mut Grammar) -> Expr {
+        Expr::Literal(self.to_string())
+    }
+}
+
+impl Describe for str {
+    fn describe(&self, _grammar: &mut Grammar) -> Expr {
+        Expr::Literal(self.to_owned())
+    }
+}
+
+impl Describe for [u8] {
+    fn describe(&self, _grammar: &mut Grammar) -> Expr {
+        Expr::Literal(self.iter().map(|b| b.as_char()).collect())
+    }
+}
+
+impl<const N: usize> Describe for [u8; N] {
+    fn describe(&self, grammar: &mut Grammar) -> Expr {
+        self[..].describe(grammar)
+    }
+}
+
+impl<T: Describe> Describe for Caseless<T> {
+    fn describe(&self, grammar: &mut Grammar) -> Expr {
+        match self.0.describe(grammar) {
+            Expr::Literal(text) => Expr::CaselessLiteral(text),
+            expr => expr,
+        }
+    }
+}
+
+macro_rules! impl_describe_for_tuple {
+  ($($index:tt $parser:ident),+) => (
+    impl<$($parser),+> Describe for ($($parser),+,)
+    where
+      $($parser: Describe),+
+    {
+      fn describe(&self, grammar: &mut Grammar) -> Expr {
+        Expr::sequence([$(self.$index.describe(grammar)),+])
+      }
+    }
+
+    impl<$($parser),+> DescribeAlt for ($($parser),+,)
+    where
+      $($parser: Describe),+
+    {
+      fn describe_alt(&self, grammar: &mut Grammar) -> Vec<Expr> {
+        crate::lib::std::vec![$(self.$index.describe(grammar)),+]
+      }
+    }
+
+    impl<$($parser),+> DescribeTokens for ($($parser),+,)
+    where
+      $($parser: DescribeTokens),+
+    {
+      fn describe_tokens(&self, class: &mut Vec<ClassItem>) {
+        $(self.$index.describe_tokens(class);)+
+      }
+    }
+  )
+}
+
+macro_rules! impl_describe_for_tuples {
+    ($index1:tt $parser1:ident, $($index:tt $parser:ident),+) => {
+        impl_describe_for_tuples!(__impl $index1 $parser1; $($index $parser),+);
+    };
+    (__impl $($index:tt $parser:ident),+; $index1:tt $parser1:ident $(,$index2:tt $parser2:ident)*) => {
+        impl_describe_for_tuple!($($index $parser),+);
+        impl_describe_for_tuples!(__impl $($index $parser),+, $index1 $parser1; $($index2 $parser2),*);
+    };
+    (__impl $($index:tt $parser:ident),+;) => {
+        impl_describe_for_tuple!($($index $parser),+);
+    }
+}
+
+impl_describe_for_tuples!(
+  0 P0,
+  1 P1,
+  2 P2,
+  3 P3,
+  4 P4,
+  5 P5,
+  6 P6,
+  7 P7,
+  8 P8,
+  9 P9,
+  10 P10,
+  11 P11,
+  12 P12,
+  13 P13,
+  14 P14,
+  15 P15,
+  16 P16,
+  17 P17,
+  18 P18,
+  19 P19,
+  20 P20,
+  21 P21
+);
+
+impl<const N: usize, P: Describe> DescribeAlt for [P; N] {
+    fn describe_alt(&self, grammar: &mut Grammar) -> Vec<Expr> {
+        self.iter().map(|case| case.describe(grammar)).collect()
+    }
+}
+
+impl<P: Describe> DescribeAlt for &mut [P] {
+    fn describe_alt(&self, grammar: &mut Grammar) -> Vec<Expr> {
+        self.iter().map(|case| case.describe(grammar)).collect()
+    }
+}
+
+impl DescribeTokens for u8 {
+    fn describe_tokens(&self, class: &mut Vec<ClassItem>) {
+        class.push(ClassItem::Char(self.as_char()));
+    }
+}
+
+impl DescribeTokens for char {
+    fn describe_tokens(&self, class: &mut Vec<ClassItem>) {
+        class.push(ClassItem::Char(*self));
+    }
+}
+
+impl<C: AsChar + Clone> DescribeTokens for crate::lib::std::ops::Range<C> {
+    fn describe_tokens(&self, class: &mut Vec<ClassItem>) {
+        let start = self.start.clone().as_char();
+        let end = self.end.clone().as_char();
+        if let Some(end) = prev_char(end).filter(|end| start <= *end) {
+            class.push(ClassItem::Range(start, end));
+        }
+    }
+}
+
+impl<C: AsChar + Clone> DescribeTokens for crate::lib::std::ops::RangeInclusive<C> {
+    fn describe_tokens(&self, class: &mut Vec<ClassItem>) {
+        let start = self.start().clone().as_char();
+        let end = self.end().clone().as_char();
+        if start <= end {
+            class.push(ClassItem::Range(start, end));
+        }
+    }
+}
+
+impl<C: AsChar + Clone> DescribeTokens for crate::lib::std::ops::RangeFrom<C> {
+    fn describe_tokens(&self, class: &mut Vec<ClassItem>) {
+        class.push(ClassItem::Range(self.start.clone().as_char(), char::MAX));
+    }
+}
+
+impl<C: AsChar + Clone> DescribeTokens for crate::lib::std::ops::RangeTo<C> {
+    fn describe_tokens(&self, class: &mut Vec<ClassItem>) {
+        if let Some(end) = prev_char(self.end.clone().as_char()) {
+            class.push(ClassItem::Range('\0', end));
+        }
+    }
+}
+
+impl<C: AsChar + Clone> DescribeTokens for crate::lib::std::ops::RangeToInclusive<C> {
+    fn describe_tokens(&self, class: &mut Vec<ClassItem>) {
+        class.push(ClassItem::Range('\0', self.end.clone().as_char()));
+    }
+}
+
+impl DescribeTokens for crate::lib::std::ops::RangeFull {
+    fn describe_tokens(&self, class: &mut Vec<ClassItem>) {
+        class.push(ClassItem::Any);
+    }
+}
+
+impl DescribeTokens for () {
+    fn describe_tokens(&self, _class: &mut Vec<ClassItem>) {}
+}
+
+impl<T: DescribeTokens> DescribeTokens for [T] {
+    fn describe_tokens(&self, class: &mut Vec<ClassItem>) {
+        for token in self {
+            token.describe_tokens(class);
+        }
+    }
+}
+
+impl<const LEN: usize, T: DescribeTokens> DescribeTokens for [T; LEN] {
+    fn describe_tokens(&self, class: &mut Vec<ClassItem>) {
+        self[..].describe_tokens(class);
+    }
+}
+
+impl<T: DescribeTokens + ?Sized> DescribeTokens for &T {
+    fn describe_tokens(&self, class: &mut Vec<ClassItem>) {
+        (**self).describe_tokens(class);
+    }
+}
+
+fn prev_char(c: char) -> Option<char> {
+    match c {
+        '\0' => None,
+        '\u{E000}' => Some('\u{D7FF}'),
+        c => char::from_u32(c as u32 - 1),
+    }
+}
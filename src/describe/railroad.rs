@@ -0,0 +1,289 @@
+use crate::lib::std::boxed::Box;
+use crate::lib::std::fmt::Write as _;
+use crate::lib::std::string::String;
+use crate::lib::std::vec::Vec;
+
+use super::{Expr, Grammar};
+
+/// Radius of the curves joining rails
+const ARC: i64 = 10;
+const BOX_HEIGHT: i64 = 22;
+const CHAR_WIDTH: i64 = 8;
+/// Horizontal space between items in a sequence
+const GAP: i64 = 10;
+/// Vertical space between stacked rails
+const ROW_GAP: i64 = 10;
+const LABEL_HEIGHT: i64 = 14;
+const TITLE_HEIGHT: i64 = 24;
+const MARGIN: i64 = 20;
+
+const STYLE: &str = "path { stroke-width: 2; stroke: black; fill: none; } \
+rect { stroke-width: 2; stroke: black; fill: white; } \
+text { font: 14px monospace; text-anchor: middle; } \
+text.rule { font-weight: bold; text-anchor: start; } \
+text.label { font-size: 12px; }";
+
+pub(crate) fn grammar(grammar: &Grammar) -> String {
+    let mut body = String::new();
+    let mut width = 0;
+    let mut y = MARGIN;
+    for (name, expr) in grammar.rules() {
+        let node = layout(expr);
+        let _ = write!(
+            body,
+            r#"<text class="rule" x="{MARGIN}" y="{}">{}:</text>"#,
+            y + LABEL_HEIGHT,
+            Escape(name)
+        );
+        let baseline = y + TITLE_HEIGHT + node.up;
+        let start = MARGIN;
+        let end = MARGIN + GAP + node.width + GAP;
+        let _ = write!(
+            body,
+            r#"<path d="M{start} {} v16 m0 -8 h{GAP}"/>"#,
+            baseline - 8,
+        );
+        node.draw(start + GAP, baseline, &mut body);
+        let _ = write!(
+            body,
+            r#"<path d="M{} {baseline} h{GAP} m0 -8 v16"/>"#,
+            end - GAP,
+        );
+        width = width.max(end + MARGIN);
+        y = baseline + node.down + MARGIN;
+    }
+
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{y}" viewBox="0 0 {width} {y}" class="railroad-diagram"><style>{STYLE}</style>{body}</svg>"#,
+    );
+    out
+}
+
+/// Laid out [`Expr`], drawn along a horizontal rail
+struct Node {
+    kind: Kind,
+    width: i64,
+    /// Extent above the rail
+    up: i64,
+    /// Extent below the rail
+    down: i64,
+}
+
+enum Kind {
+    Skip,
+    Terminal(String),
+    NonTerminal(String),
+    Sequence(Vec<Node>),
+    /// Cases with their offset below the rail
+    Choice(Vec<(i64, Node)>),
+    Loop {
+        item: Box<Node>,
+        label: Option<String>,
+        /// Offset of the returning rail below the main rail
+        depth: i64,
+    },
+}
+
+fn layout(expr: &Expr) -> Node {
+    match expr {
+        Expr::Empty => Node::skip(),
+        Expr::Literal(_) | Expr::CaselessLiteral(_) | Expr::Class(_) => {
+            let text = super::ebnf::to_string(expr);
+            Node::boxed(Kind::Terminal(text))
+        }
+        Expr::Rule(name) => Node::boxed(Kind::NonTerminal(name.clone())),
+        Expr::Sequence(exprs) => sequence(exprs.iter().map(layout).collect()),
+        Expr::Choice(exprs) => choice(exprs.iter().map(layout).collect()),
+        Expr::Repeat { expr, min, max } => {
+            let item = layout(expr);
+            match (*min, *max) {
+                (_, Some(1)) => choice([Node::skip(), item].into()),
+                (0, None) => choice([Node::skip(), repeat(item, None)].into()),
+                (1, None) => repeat(item, None),
+                (min, max) => repeat(item, Some(super::ebnf::repetition(min, max))),
+            }
+        }
+    }
+}
+
+fn sequence(items: Vec<Node>) -> Node {
+    let width =
+        items.iter().map(|item| item.width).sum::<i64>() + GAP * (items.len() as i64 - 1).max(0);
+    let up = items.iter().map(|item| item.up).max().unwrap_or(0);
+    let down = items.iter().map(|item| item.down).max().unwrap_or(0);
+    Node {
+        kind: Kind::Sequence(items),
+        width,
+        up,
+        down,
+    }
+}
+
+fn choice(items: Vec<Node>) -> Node {
+    let width = items.iter().map(|item| item.width).max().unwrap_or(0) + 4 * ARC;
+    let up = items.first().map(|item| item.up).unwrap_or(0);
+    let mut cases = Vec::with_capacity(items.len());
+    let mut down = 0;
+    for item in items {
+        let offset = match cases.last() {
+            None => 0,
+            Some((offset, previous)) => {
+                let previous: &Node = previous;
+                (offset + previous.down + ROW_GAP + item.up).max(2 * ARC)
+            }
+        };
+        down = offset + item.down;
+        cases.push((offset, item));
+    }
+    Node {
+        kind: Kind::Choice(cases),
+        width,
+        up,
+        down,
+    }
+}
+
+fn repeat(item: Node, label: Option<String>) -> Node {
+    let depth = (item.down + ROW_GAP).max(2 * ARC);
+    let label_height = if label.is_some() { LABEL_HEIGHT } else { 0 };
+    Node {
+        width: item.width + 2 * ARC,
+        up: item.up,
+        down: depth + label_height,
+        kind: Kind::Loop {
+            item: Box::new(item),
+            label,
+            depth,
+        },
+    }
+}
+
+impl Node {
+    fn skip() -> Self {
+        Self {
+            kind: Kind::Skip,
+            width: 0,
+            up: 0,
+            down: 0,
+        }
+    }
+
+    fn boxed(kind: Kind) -> Self {
+        let text = match &kind {
+            Kind::Terminal(text) | Kind::NonTerminal(text) => text,
+            _ => unreachable!("only terminals and non-terminals are boxed"),
+        };
+        Self {
+            width: text.chars().count() as i64 * CHAR_WIDTH + 2 * ARC,
+            up: BOX_HEIGHT / 2,
+            down: BOX_HEIGHT / 2,
+            kind,
+        }
+    }
+
+    /// Draw with the rail entering at `(x, y)`
+    fn draw(&self, x: i64, y: i64, out: &mut String) {
+        match &self.kind {
+            Kind::Skip => {}
+            Kind::Terminal(text) | Kind::NonTerminal(text) => {
+                let radius = if matches!(self.kind, Kind::Terminal(_)) {
+                    ARC
+                } else {
+                    0
+                };
+                let _ = write!(
+                    out,
+                    r#"<rect x="{x}" y="{}" width="{}" height="{BOX_HEIGHT}" rx="{radius}"/><text x="{}" y="{}">{}</text>"#,
+                    y - self.up,
+                    self.width,
+                    x + self.width / 2,
+                    y + 5,
+                    Escape(text),
+                );
+            }
+            Kind::Sequence(items) => {
+                let mut x = x;
+                for (i, item) in items.iter().enumerate() {
+                    if 0 < i {
+                        let _ = write!(out, r#"<path d="M{x} {y} h{GAP}"/>"#);
+                        x += GAP;
+                    }
+                    item.draw(x, y, out);
+                    x += item.width;
+                }
+            }
+            Kind::Choice(cases) => {
+                let inner = self.width - 4 * ARC;
+                for (offset, item) in cases {
+                    let offset = *offset;
+                    let item_x = x + 2 * ARC;
+                    let item_end = item_x + item.width;
+                    let fill = inner - item.width;
+                    if offset == 0 {
+                        let _ = write!(
+                            out,
+                            r#"<path d="M{x} {y} h{}"/><path d="M{item_end} {y} h{}"/>"#,
+                            2 * ARC,
+                            fill + 2 * ARC,
+                        );
+                    } else {
+                        let drop = offset - 2 * ARC;
+                        let _ = write!(
+                            out,
+                            r#"<path d="M{x} {y} a{ARC} {ARC} 0 0 1 {ARC} {ARC} v{drop} a{ARC} {ARC} 0 0 0 {ARC} {ARC}"/>"#,
+                        );
+                        let _ = write!(
+                            out,
+                            r#"<path d="M{item_end} {} h{fill} a{ARC} {ARC} 0 0 0 {ARC} -{ARC} v-{drop} a{ARC} {ARC} 0 0 1 {ARC} -{ARC}"/>"#,
+                            y + offset,
+                        );
+                    }
+                    item.draw(item_x, y + offset, out);
+                }
+            }
+            Kind::Loop { item, label, depth } => {
+                let item_end = x + ARC + item.width;
+                let rise = depth - 2 * ARC;
+                let _ = write!(
+                    out,
+                    r#"<path d="M{x} {y} h{ARC}"/><path d="M{item_end} {y} h{ARC}"/>"#,
+                );
+                let _ = write!(
+                    out,
+                    r#"<path d="M{item_end} {y} a{ARC} {ARC} 0 0 1 {ARC} {ARC} v{rise} a{ARC} {ARC} 0 0 1 -{ARC} {ARC} h-{} a{ARC} {ARC} 0 0 1 -{ARC} -{ARC} v-{rise} a{ARC} {ARC} 0 0 1 {ARC} -{ARC}"/>"#,
+                    item.width,
+                );
+                if let Some(label) = label {
+                    let _ = write!(
+                        out,
+                        r#"<text class="label" x="{}" y="{}">{}</text>"#,
+                        x + ARC + item.width / 2,
+                        y + depth + LABEL_HEIGHT,
+                        Escape(label),
+                    );
+                }
+                item.draw(x + ARC, y, out);
+            }
+        }
+    }
+}
+
+/// Escape text for XML
+struct Escape<'s>(&'s str);
+
+impl crate::lib::std::fmt::Display for Escape<'_> {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '&' => f.write_str("&amp;")?,
+                '<' => f.write_str("&lt;")?,
+                '>' => f.write_str("&gt;")?,
+                '"' => f.write_str("&quot;")?,
+                c => f.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
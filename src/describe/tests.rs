@@ -0,0 +1,241 @@
+use super::*;
+
+use crate::ascii::Caseless;
+use crate::combinator::{alt, opt, preceded, repeat, separated, terminated, trace};
+use crate::error::{ContextError, ErrMode};
+use crate::stream::Stream;
+use crate::token::{literal, one_of, take_while};
+
+type Error = ErrMode<ContextError>;
+
+fn ebnf<I: Stream, O>(parser: impl Parser<I, O, Error> + Describe) -> String {
+    let mut grammar = Grammar::new();
+    grammar.rule("start", &parser);
+    grammar.to_ebnf()
+}
+
+#[test]
+fn combinators() {
+    let parser = (
+        opt('-'),
+        alt(("0x", literal("0o"), "0b")),
+        repeat::<_, _, (), _, _>(2..=4, one_of(('0'..='9', 'a'..'g', '_'))),
+    );
+    assert_eq!(
+        ebnf::<&str, _>(parser),
+        r#"start ::= "-"? ( "0x" | "0o" | "0b" ) [0-9a-f_]{2,4}
+"#
+    );
+
+    let parser = terminated(
+        preceded(
+            "(",
+            separated::<_, _, (), _, _, _, _>(1..=3, take_while(0.., ..), ","),
+        ),
+        ")",
+    );
+    assert_eq!(
+        ebnf::<&str, _>(parser),
+        r#"start ::= "(" [#x0-#x10FFFF]* ( "," [#x0-#x10FFFF]* ){0,2} ")"
+"#
+    );
+}
+
+#[test]
+fn literals() {
+    let parser = (
+        literal(Caseless("Ab-")),
+        &b"\"\t\x80"[..],
+        one_of([b'[', b']', b'-']),
+    );
+    assert_eq!(
+        ebnf::<&[u8], _>(parser),
+        r##"start ::= [aA] [bB] "-" #x22 #x9 #x80 [#x5B#x5D#x2D]
+"##
+    );
+}
+
+#[test]
+fn rules() {
+    fn digits<'i>() -> impl Parser<&'i str, &'i str, Error> + Describe {
+        trace("digits", take_while(1.., '0'..='9'))
+    }
+
+    fn expr(input: &mut &str) -> Result<(), Error> {
+        alt((digits().void(), ('(', opaque("expr", expr), ')').void())).parse_next(input)
+    }
+
+    let parser = trace(
+        "expr",
+        alt((
+            digits().void(),
+            trace("group", ('(', opaque("expr", expr), ')')).void(),
+        )),
+    );
+    let mut grammar = Grammar::new();
+    assert_eq!(
+        grammar.rule("root", &(&parser, digits())),
+        Expr::Rule("root".into())
+    );
+    assert_eq!(
+        grammar.to_ebnf(),
+        r#"root ::= expr digits
+expr ::= digits | group
+digits ::= [0-9]+
+group ::= "(" expr ")"
+"#
+    );
+    assert_eq!(
+        grammar.get("group"),
+        grammar.rules().nth(3).map(|(_, expr)| expr)
+    );
+    assert_eq!(grammar.get("missing"), None);
+}
+
+#[test]
+fn railroad() {
+    let parser = trace(
+        "list",
+        (
+            '[',
+            opt::<&str, _, Error, _>(separated::<_, _, (), _, _, _, _>(0.., alt(("a", "b")), ",")),
+            repeat::<_, _, (), _, _>(2..=3, ']'),
+        ),
+    );
+    let mut grammar = Grammar::new();
+    grammar.rule("list", &parser);
+    let svg = grammar.to_railroad();
+    assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg""#));
+    assert!(svg.ends_with("</svg>"));
+    assert!(svg.contains(r#"<text class="rule" x="20" y="34">list:</text>"#));
+    // "[", "a", "b", ",", "a", "b", "]"
+    assert_eq!(svg.matches("<rect").count(), 7);
+    assert!(svg.contains("&quot;a&quot;"));
+    assert!(svg.contains(r#"<text class="label""#));
+    assert!(svg.contains(">{2,3}</text>"));
+}
+
+#[cfg(feature = "unstable-generate")]
+mod generate {
+    use super::*;
+
+    use proptest::prelude::*;
+
+    use crate::combinator::delimited;
+
+    fn value(input: &mut &str) -> Result<(), Error> {
+        alt((
+            take_while(1.., '0'..='9').void(),
+            delimited('[', separated(0.., opaque("value", value), ','), ']'),
+        ))
+        .parse_next(input)
+    }
+
+    fn grammar() -> Grammar {
+        let mut grammar = Grammar::new();
+        grammar.rule(
+            "value",
+            &trace(
+                "value",
+                alt((
+                    take_while(1.., '0'..='9').void(),
+                    delimited(
+                        '[',
+                        separated::<_, _, (), _, _, _, _>(0.., opaque("value", value), ','),
+                        ']',
+                    ),
+                )),
+            ),
+        );
+        grammar
+    }
+
+    proptest! {
+        #[test]
+        #[cfg_attr(miri, ignore)] // See https://github.com/AltSysrq/proptest/issues/253
+        fn strings_parse(input in Generator::new(&grammar(), "value").strings()) {
+            prop_assert_eq!(value.parse(&input), Ok(()));
+        }
+
+        #[test]
+        #[cfg_attr(miri, ignore)] // See https://github.com/AltSysrq/proptest/issues/253
+        fn shallow_strings_parse(
+            input in Generator::new(&grammar(), "value").max_depth(1).strings()
+        ) {
+            prop_assert_eq!(value.parse(&input), Ok(()));
+            prop_assert!(input.matches('[').count() <= 1, "{input:?}");
+        }
+
+        #[test]
+        #[cfg_attr(miri, ignore)] // See https://github.com/AltSysrq/proptest/issues/253
+        fn bytes_parse(input in {
+            let mut grammar = Grammar::new();
+            grammar.rule("start", &(
+                literal::<_, &[u8], Error>(Caseless("ok")),
+                repeat::<_, _, (), _, _>(2..=3, one_of::<&[u8], _, Error>((0x80..=0xff, b'-'))),
+                one_of::<&[u8], _, Error>(..),
+            ));
+            Generator::new(&grammar, "start").bytes()
+        }) {
+            prop_assert!(input[..2].eq_ignore_ascii_case(b"ok"), "{input:?}");
+            prop_assert!((5..=6).contains(&input.len()), "{input:?}");
+            prop_assert!(
+                input[2..input.len() - 1].iter().all(|b| *b == b'-' || 0x80 <= *b),
+                "{input:?}"
+            );
+        }
+
+        #[test]
+        #[cfg_attr(miri, ignore)] // See https://github.com/AltSysrq/proptest/issues/253
+        fn near_valid_strings(input in {
+            let mut grammar = Grammar::new();
+            grammar.rule("start", &repeat::<&str, _, (), Error, _>(3, one_of('a'..='c')));
+            Generator::new(&grammar, "start").near_valid(true).strings()
+        }) {
+            prop_assert!((2..=4).contains(&input.len()), "{input:?}");
+            prop_assert!(input.chars().all(|c| ('a'..='c').contains(&c)), "{input:?}");
+        }
+    }
+
+    #[test]
+    #[should_panic = "rule `value` is not in the grammar"]
+    fn missing_rule() {
+        let mut grammar = Grammar::new();
+        grammar.rule("start", &opaque("value", value));
+        let _ = Generator::new(&grammar, "start").strings();
+    }
+}
+
+#[test]
+fn seq_by_name() {
+    #[derive(Debug)]
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    fn point<'i>() -> impl Parser<&'i str, Point, Error> + Describe {
+        crate::combinator::seq! {Point {
+            x: crate::ascii::dec_uint,
+            _: ',',
+            y: crate::ascii::dec_uint,
+        }}
+    }
+
+    let parsed = point().parse("1,2").unwrap();
+    assert_eq!((parsed.x, parsed.y), (1, 2));
+
+    let mut grammar = Grammar::new();
+    grammar.rule(
+        "points",
+        &separated::<_, _, Vec<_>, _, _, _, _>(1.., point(), ';'),
+    );
+    let digits = || take_while::<_, &str, Error>(1.., '0'..='9');
+    grammar.rule("Point", &(digits(), ',', digits()));
+    assert_eq!(
+        grammar.to_ebnf(),
+        r#"points ::= Point ( ";" Point )*
+Point ::= [0-9]+ "," [0-9]+
+"#
+    );
+}
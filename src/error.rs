@@ -51,6 +51,31 @@ pub type ModalResult<O, E = ContextError> = Result<O, ErrMode<E>>;
 #[cfg(test)]
 pub(crate) type TestResult<I, O> = ModalResult<O, InputError<I>>;
 
+/// Keeps the error passed to [`FromExternalError`], if any, for testing parsers that report one
+#[cfg(test)]
+#[derive(Debug, PartialEq)]
+pub(crate) struct ExternalError<E>(pub(crate) Option<E>);
+
+#[cfg(test)]
+impl<I: Stream, E> ParserError<I> for ExternalError<E> {
+    type Inner = Self;
+
+    fn from_input(_input: &I) -> Self {
+        Self(None)
+    }
+
+    fn into_inner(self) -> Result<Self::Inner, Self> {
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+impl<I, E> FromExternalError<I, E> for ExternalError<E> {
+    fn from_external_error(_input: &I, e: E) -> Self {
+        Self(Some(e))
+    }
+}
+
 /// Contains information on needed data if a parser returned `Incomplete`
 ///
 /// <div class="warning">
@@ -204,6 +229,11 @@ impl<I: Stream, E: ParserError<I>> ParserError<I> for ErrMode<E> {
         ErrMode::Incomplete(needed)
     }
 
+    #[inline(always)]
+    fn cut_from_input(input: &I) -> Self {
+        ErrMode::Cut(E::from_input(input))
+    }
+
     #[inline]
     fn append(self, input: &I, token_start: &<I as Stream>::Checkpoint) -> Self {
         match self {
@@ -370,6 +400,14 @@ pub trait ParserError<I: Stream>: Sized {
         Self::from_input(input)
     }
 
+    /// Like [`ParserError::from_input`] but without backtracking, see [`ModalError::cut`]
+    ///
+    /// Errors that always allow backtracking return [`ParserError::from_input`].
+    #[inline(always)]
+    fn cut_from_input(input: &I) -> Self {
+        Self::from_input(input)
+    }
+
     /// Like [`ParserError::from_input`] but merges it with the existing error.
     ///
     /// This is useful when backtracking through a parse tree, accumulating error context on the
@@ -81,7 +81,7 @@ pub(crate) mod lib {
 
         #[cfg(feature = "alloc")]
         #[doc(hidden)]
-        pub(crate) use alloc::{borrow, boxed, collections, string, vec};
+        pub(crate) use alloc::{borrow, boxed, collections, rc, string, vec};
 
         #[doc(hidden)]
         pub(crate) use core::{
@@ -95,7 +95,7 @@ pub(crate) mod lib {
         #![allow(clippy::std_instead_of_core)]
         #[doc(hidden)]
         pub(crate) use std::{
-            borrow, boxed, cmp, collections, convert, fmt, hash, iter, mem, ops, result, slice,
+            borrow, boxed, cmp, collections, convert, fmt, hash, iter, mem, ops, rc, result, slice,
             str, string, vec,
         };
     }
@@ -111,10 +111,18 @@ mod parser;
 
 pub mod stream;
 
+pub mod abnf;
 pub mod ascii;
 pub mod binary;
 pub mod combinator;
+#[cfg(feature = "alloc")]
+pub mod cst;
+#[cfg(feature = "unstable-grammar")]
+pub mod describe;
+#[cfg(feature = "unstable-print")]
+pub mod print;
 pub mod token;
+pub mod unicode;
 
 #[cfg(feature = "unstable-doc")]
 pub mod _topic;
@@ -169,6 +177,8 @@ pub use stream::LocatingSlice;
 pub use stream::Partial;
 pub use stream::Stateful;
 pub use stream::Str;
+#[cfg(feature = "unstable-derive")]
+pub use winnow_derive::Parse;
 
 #[cfg(test)]
 pub(crate) use error::TestResult;
@@ -0,0 +1,301 @@
+/// Define parser functions from PEG-style rules
+///
+/// Each rule is written as a function signature whose body is a parsing expression.  The body is
+/// expanded into the existing combinators, wrapped in a [`trace`][crate::combinator::trace] named
+/// after the rule, so the generated functions work like hand-written ones for any
+/// [`Stream`][crate::stream::Stream] and [`ParserError`][crate::error::ParserError] the signature
+/// allows.
+///
+/// | Expression      | Meaning                                | Expands to |
+/// |-----------------|----------------------------------------|------------|
+/// | `"lit"`, `'c'`  | literal                                | [`literal`][crate::token::literal] |
+/// | `rule`, `a::b`  | call a rule or any other parser        | the parser |
+/// | `{ expr }`      | any Rust expression that is a parser   | `expr` |
+/// | `a b`           | sequence, outputting a tuple           | `(a, b)` |
+/// | `a / b`         | ordered choice                         | [`alt`][crate::combinator::alt] |
+/// | `( a b / c )`   | grouping                               | |
+/// | `a*`, `a+`      | zero or more, one or more              | [`repeat`][crate::combinator::repeat] |
+/// | `a?`            | optional                               | [`opt`][crate::combinator::opt] |
+/// | `&a`            | positive lookahead                     | [`peek`][crate::combinator::peek] |
+/// | `!a`            | negative lookahead                     | [`not`][crate::combinator::not] |
+/// | `name:a`        | named capture, for use in an action    | |
+/// | `a b => expr`   | action computing the output            | |
+///
+/// When a sequence has an action, only the named captures are kept; everything else is parsed
+/// and discarded (repetitions of it are not collected).  An action extends to the next `/`, so
+/// put divisions inside parentheses.
+///
+/// Repetitions collect into whatever [`Accumulate`][crate::stream::Accumulate] the surrounding
+/// code asks for, like [`repeat`][crate::combinator::repeat].  For other parser expressions, like
+/// those needing turbofish, use `{ expr }`.
+///
+/// Rule signatures take one input parameter.  Generic parameters may have one bound each, with
+/// further bounds in a `where` clause using one bound per predicate.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::{alpha1, dec_uint, space0};
+/// use winnow::combinator::grammar;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Value<'s> {
+///     Number(u32),
+///     Name(&'s str),
+///     List(Vec<Value<'s>>),
+/// }
+///
+/// grammar! {
+///     /// A value, like `[1, foo, [2]]`
+///     fn value<'s>(input: &mut &'s str) -> ModalResult<Value<'s>> {
+///         n:{ dec_uint } => Value::Number(n)
+///         / n:alpha1 => Value::Name(n)
+///         / "[" space0 items:list? space0 "]" => Value::List(items.unwrap_or_default())
+///     }
+///
+///     fn list<'s>(input: &mut &'s str) -> ModalResult<Vec<Value<'s>>> {
+///         first:value rest:(space0 "," space0 v:value => v)* => {
+///             let mut rest: Vec<_> = rest;
+///             rest.insert(0, first);
+///             rest
+///         }
+///     }
+/// }
+///
+/// assert_eq!(
+///     value.parse("[1, foo, [2]]"),
+///     Ok(Value::List(vec![
+///         Value::Number(1),
+///         Value::Name("foo"),
+///         Value::List(vec![Value::Number(2)]),
+///     ]))
+/// );
+/// ```
+///
+/// Rules can be generic over the input and error:
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::combinator::grammar;
+/// use winnow::error::ParserError;
+/// use winnow::stream::{Compare, Stream, StreamIsPartial};
+///
+/// grammar! {
+///     fn boolean<I, E: ParserError<I>>(input: &mut I) -> Result<bool, E>
+///     where
+///         I: Stream,
+///         I: StreamIsPartial,
+///         I: Compare<&'static str>,
+///     {
+///         "true" => true / "false" => false
+///     }
+/// }
+///
+/// assert_eq!(boolean::<_, winnow::error::ContextError>.parse_peek("true!"), Ok(("!", true)));
+/// assert_eq!(boolean::<_, winnow::error::ContextError>.parse_peek(&b"false"[..]), Ok((&b""[..], false)));
+/// ```
+#[macro_export]
+#[doc(hidden)] // forced to be visible in intended location
+macro_rules! grammar {
+    ($(
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident
+        $(<
+            $($lifetime:lifetime),* $(,)?
+            $($param:ident $(: $param_bound:path)?),* $(,)?
+        >)?
+        ($input:ident : $input_ty:ty) -> $output:ty
+        $(where $($bounded:ty : $bound:path),+ $(,)?)?
+        { $($body:tt)* }
+    )*) => {$(
+        $(#[$attr])*
+        $vis fn $name $(<$($lifetime,)* $($param $(: $param_bound)?),*>)? ($input: $input_ty) -> $output
+        $(where $($bounded: $bound),+)?
+        {
+            $crate::Parser::parse_next(
+                &mut $crate::combinator::trace(
+                    stringify!($name),
+                    $crate::grammar_alt!(value; $($body)*),
+                ),
+                $input,
+            )
+        }
+    )*};
+}
+
+/// Split a parsing expression into alternatives of items
+///
+/// `value` mode keeps the output of each item while `void` mode is for items whose output is
+/// discarded.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! grammar_alt {
+    ($mode:ident;) => {
+        ()
+    };
+    ($mode:ident; $($tokens:tt)+) => {
+        $crate::grammar_alt!(@item $mode [] [] $($tokens)+)
+    };
+
+    (@item $mode:ident [$($alts:tt)*] $items:tt / $($rest:tt)+) => {
+        $crate::grammar_alt!(@item $mode [$($alts)* {$items []}] [] $($rest)+)
+    };
+    (@item $mode:ident $alts:tt $items:tt => $($rest:tt)+) => {
+        $crate::grammar_alt!(@action $mode $alts $items [] $($rest)+)
+    };
+    (@item $mode:ident [$($alts:tt)*] $items:tt) => {
+        $crate::grammar_alt!(@emit $mode [$($alts)* {$items []}])
+    };
+    (@item $mode:ident $alts:tt $items:tt $bind:ident : $($rest:tt)+) => {
+        $crate::grammar_alt!(@prefix $mode $alts $items $bind $($rest)+)
+    };
+    (@item $mode:ident $alts:tt $items:tt $($rest:tt)+) => {
+        $crate::grammar_alt!(@prefix $mode $alts $items _ $($rest)+)
+    };
+
+    (@prefix $mode:ident $alts:tt $items:tt $bind:tt & $($rest:tt)+) => {
+        $crate::grammar_alt!(@atom $mode $alts $items $bind peek $($rest)+)
+    };
+    (@prefix $mode:ident $alts:tt $items:tt $bind:tt ! $($rest:tt)+) => {
+        $crate::grammar_alt!(@atom $mode $alts $items $bind not $($rest)+)
+    };
+    (@prefix $mode:ident $alts:tt $items:tt $bind:tt $($rest:tt)+) => {
+        $crate::grammar_alt!(@atom $mode $alts $items $bind none $($rest)+)
+    };
+
+    (@atom $mode:ident $alts:tt $items:tt $bind:tt $prefix:ident $lit:literal $($rest:tt)*) => {
+        $crate::grammar_alt!(@postfix $mode $alts $items $bind $prefix [lit $lit] [] $($rest)*)
+    };
+    (@atom $mode:ident $alts:tt $items:tt $bind:tt $prefix:ident ( $($group:tt)* ) $($rest:tt)*) => {
+        $crate::grammar_alt!(@postfix $mode $alts $items $bind $prefix [group $($group)*] [] $($rest)*)
+    };
+    (@atom $mode:ident $alts:tt $items:tt $bind:tt $prefix:ident { $($block:tt)* } $($rest:tt)*) => {
+        $crate::grammar_alt!(@postfix $mode $alts $items $bind $prefix [block $($block)*] [] $($rest)*)
+    };
+    (@atom $mode:ident $alts:tt $items:tt $bind:tt $prefix:ident $head:ident $($rest:tt)*) => {
+        $crate::grammar_alt!(@path $mode $alts $items $bind $prefix [$head] $($rest)*)
+    };
+
+    (@path $mode:ident $alts:tt $items:tt $bind:tt $prefix:ident [$($path:tt)*] :: $segment:ident $($rest:tt)*) => {
+        $crate::grammar_alt!(@path $mode $alts $items $bind $prefix [$($path)* :: $segment] $($rest)*)
+    };
+    (@path $mode:ident $alts:tt $items:tt $bind:tt $prefix:ident [$($path:tt)*] $($rest:tt)*) => {
+        $crate::grammar_alt!(@postfix $mode $alts $items $bind $prefix [path $($path)*] [] $($rest)*)
+    };
+
+    (@postfix $mode:ident $alts:tt $items:tt $bind:tt $prefix:ident $atom:tt [$($ops:tt)*] * $($rest:tt)*) => {
+        $crate::grammar_alt!(@postfix $mode $alts $items $bind $prefix $atom [$($ops)* *] $($rest)*)
+    };
+    (@postfix $mode:ident $alts:tt $items:tt $bind:tt $prefix:ident $atom:tt [$($ops:tt)*] + $($rest:tt)*) => {
+        $crate::grammar_alt!(@postfix $mode $alts $items $bind $prefix $atom [$($ops)* +] $($rest)*)
+    };
+    (@postfix $mode:ident $alts:tt $items:tt $bind:tt $prefix:ident $atom:tt [$($ops:tt)*] ? $($rest:tt)*) => {
+        $crate::grammar_alt!(@postfix $mode $alts $items $bind $prefix $atom [$($ops)* ?] $($rest)*)
+    };
+    (@postfix $mode:ident $alts:tt [$($items:tt)*] $bind:tt $prefix:ident $atom:tt $ops:tt $($rest:tt)*) => {
+        $crate::grammar_alt!(@item $mode $alts [$($items)* {$bind $prefix $atom $ops}] $($rest)*)
+    };
+
+    (@action $mode:ident [$($alts:tt)*] $items:tt [$($action:tt)*] / $($rest:tt)+) => {
+        $crate::grammar_alt!(@item $mode [$($alts)* {$items [$($action)*]}] [] $($rest)+)
+    };
+    (@action $mode:ident [$($alts:tt)*] $items:tt [$($action:tt)*]) => {
+        $crate::grammar_alt!(@emit $mode [$($alts)* {$items [$($action)*]}])
+    };
+    (@action $mode:ident $alts:tt $items:tt [$($action:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::grammar_alt!(@action $mode $alts $items [$($action)* $next] $($rest)*)
+    };
+
+    (@emit $mode:ident [$alt:tt]) => {
+        $crate::grammar_alt!(@seq $mode $alt)
+    };
+    (@emit $mode:ident [$($alt:tt)+]) => {
+        $crate::combinator::alt(($($crate::grammar_alt!(@seq $mode $alt),)+))
+    };
+
+    (@seq value {[] []}) => {
+        ()
+    };
+    (@seq value {[$item:tt] []}) => {
+        $crate::grammar_item!(value $item)
+    };
+    (@seq value {[$($item:tt)+] []}) => {
+        ($($crate::grammar_item!(value $item),)+)
+    };
+    (@seq void {[$($item:tt)*] []}) => {
+        $crate::Parser::void(($($crate::grammar_item!(void $item),)*))
+    };
+    (@seq value {[$($item:tt)*] [$($action:tt)+]}) => {
+        move |input: &mut _| {
+            $($crate::grammar_item!(@let input $item);)*
+            Ok($($action)+)
+        }
+    };
+    (@seq void {$items:tt $action:tt}) => {
+        $crate::Parser::void($crate::grammar_alt!(@seq value {$items $action}))
+    };
+}
+
+/// Expand one item of a parsing expression into a parser
+#[macro_export]
+#[doc(hidden)]
+macro_rules! grammar_item {
+    (@let $input:ident {_ $prefix:ident $atom:tt $ops:tt}) => {
+        let _ = $crate::Parser::parse_next(
+            &mut $crate::grammar_item!(void {_ $prefix $atom $ops}),
+            $input,
+        )?;
+    };
+    (@let $input:ident {$bind:ident $prefix:ident $atom:tt $ops:tt}) => {
+        let $bind = $crate::Parser::parse_next(
+            &mut $crate::grammar_item!(value {$bind $prefix $atom $ops}),
+            $input,
+        )?;
+    };
+
+    ($mode:ident {$bind:tt peek $atom:tt [$($ops:tt)*]}) => {
+        $crate::combinator::peek($crate::grammar_item!(@ops $mode [$crate::grammar_item!(@atom $mode $atom)] $($ops)*))
+    };
+    ($mode:ident {$bind:tt not $atom:tt [$($ops:tt)*]}) => {
+        $crate::combinator::not($crate::grammar_item!(@ops void [$crate::grammar_item!(@atom void $atom)] $($ops)*))
+    };
+    ($mode:ident {$bind:tt none $atom:tt [$($ops:tt)*]}) => {
+        $crate::grammar_item!(@ops $mode [$crate::grammar_item!(@atom $mode $atom)] $($ops)*)
+    };
+
+    (@ops $mode:ident [$($parser:tt)*]) => {
+        $($parser)*
+    };
+    (@ops value [$($parser:tt)*] * $($ops:tt)*) => {
+        $crate::grammar_item!(@ops value [$crate::combinator::repeat(0.., $($parser)*)] $($ops)*)
+    };
+    (@ops value [$($parser:tt)*] + $($ops:tt)*) => {
+        $crate::grammar_item!(@ops value [$crate::combinator::repeat(1.., $($parser)*)] $($ops)*)
+    };
+    (@ops void [$($parser:tt)*] * $($ops:tt)*) => {
+        $crate::grammar_item!(@ops void [
+            $crate::Parser::map($crate::combinator::repeat(0.., $($parser)*), |(): ()| ())
+        ] $($ops)*)
+    };
+    (@ops void [$($parser:tt)*] + $($ops:tt)*) => {
+        $crate::grammar_item!(@ops void [
+            $crate::Parser::map($crate::combinator::repeat(1.., $($parser)*), |(): ()| ())
+        ] $($ops)*)
+    };
+    (@ops $mode:ident [$($parser:tt)*] ? $($ops:tt)*) => {
+        $crate::grammar_item!(@ops $mode [$crate::combinator::opt($($parser)*)] $($ops)*)
+    };
+
+    (@atom $mode:ident [lit $lit:literal]) => {
+        $lit
+    };
+    (@atom $mode:ident [group $($group:tt)*]) => {
+        $crate::grammar_alt!($mode; $($group)*)
+    };
+    (@atom $mode:ident [block $($block:tt)*]) => {
+        { $($block)* }
+    };
+    (@atom $mode:ident [path $($path:tt)*]) => {
+        $($path)*
+    };
+}
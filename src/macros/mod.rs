@@ -1,4 +1,7 @@
 mod dispatch;
+mod grammar;
+#[cfg(feature = "unstable-print")]
+mod printable_seq;
 mod seq;
 
 #[cfg(test)]
@@ -9,6 +12,44 @@ macro_rules! assert_parse(
   };
 );
 
+/// Return type of a built-in parser
+///
+/// The parser is named when it can be described or printed, so its structure is available to
+/// `Describe` and `Print`, and otherwise stays an `impl Parser`.
+#[cfg(any(feature = "unstable-grammar", feature = "unstable-print"))]
+macro_rules! introspectable {
+    ($parser:ty, impl $($opaque:tt)+) => {
+        $parser
+    };
+}
+
+/// Return type of a built-in parser
+///
+/// The parser is named when it can be described or printed, so its structure is available to
+/// `Describe` and `Print`, and otherwise stays an `impl Parser`.
+#[cfg(not(any(feature = "unstable-grammar", feature = "unstable-print")))]
+macro_rules! introspectable {
+    ($parser:ty, impl $($opaque:tt)+) => {
+        impl $($opaque)+
+    };
+}
+
+/// Return type of a built-in parser that can only be printed, see `introspectable!`
+#[cfg(feature = "unstable-print")]
+macro_rules! printable {
+    ($parser:ty, impl $($opaque:tt)+) => {
+        $parser
+    };
+}
+
+/// Return type of a built-in parser that can only be printed, see `introspectable!`
+#[cfg(not(feature = "unstable-print"))]
+macro_rules! printable {
+    ($parser:ty, impl $($opaque:tt)+) => {
+        impl $($opaque)+
+    };
+}
+
 macro_rules! impl_partial_eq {
     ($lhs:ty, $rhs:ty) => {
         #[allow(unused_lifetimes)]
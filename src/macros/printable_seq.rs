@@ -0,0 +1,261 @@
+/// Initialize a struct or tuple out of a sequences of parsers, printing it back with
+/// [`Print`][crate::print::Print]
+///
+/// This is [`seq!`][crate::combinator::seq] where each field's parser prints that field and
+/// each `_` field's parser is printed without a value, see
+/// [`PrintConst`][crate::print::PrintConst].  Fields set by `..update` aren't printed.
+///
+/// Unlike [`seq!`][crate::combinator::seq], the parsers are created once, up front, so they
+/// can't refer to earlier fields.
+///
+/// # Example
+///
+/// ```
+/// # use winnow::prelude::*;
+/// # use winnow::ascii::digit1;
+/// # use winnow::stream::AsChar;
+/// # use winnow::token::take_while;
+/// # use winnow::error::ContextError;
+/// # use winnow::error::ErrMode;
+/// use winnow::print::{assert_round_trip, printable_seq, Print};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Entry {
+///     key: String,
+///     value: u32,
+/// }
+///
+/// fn entry<'i>() -> impl Parser<&'i str, Entry, ErrMode<ContextError>> + Print<Entry> {
+///     printable_seq! {Entry {
+///         key: take_while(1.., AsChar::is_alpha).output_into(),
+///         _: " = ",
+///         value: digit1.parse_to(),
+///     }}
+/// }
+///
+/// let width = Entry {
+///     key: "width".to_owned(),
+///     value: 80,
+/// };
+/// assert_eq!(entry().print_to_string(&width).unwrap(), "width = 80");
+/// assert_eq!(assert_round_trip(entry(), "height = 25").value, 25);
+///
+/// // Or tuples
+/// fn pair<'i>() -> impl Parser<&'i str, (u32, u32), ErrMode<ContextError>> + Print<(u32, u32)> {
+///     printable_seq!(digit1.parse_to(), _: ',', digit1.parse_to())
+/// }
+///
+/// assert_eq!(pair().print_to_string(&(1, 2)).unwrap(), "1,2");
+/// ```
+#[macro_export]
+#[doc(hidden)] // forced to be visible in intended location
+macro_rules! printable_seq {
+    ($($name: ident)::* { $($fields: tt)* }) => {
+        $crate::printable_seq_struct_fields!(
+            ( $($fields)* );
+            ( 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20 );
+            $($name)::* ;
+            ( parsers, input, value, sink );
+            [] [] [] []
+        )
+    };
+    ($($name: ident)::* ( $($fields: tt)* )) => {
+        $crate::printable_seq_tuple_fields!(
+            ( $($fields)* );
+            ( 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20 );
+            ( _0, _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20 );
+            ( 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20 );
+            ( stringify!($($name)::*), $($name)::* );
+            ( parsers, input, value, sink );
+            [] [] [] []
+        )
+    };
+    (( $($fields: tt)* )) => {
+        $crate::printable_seq_tuple_fields!(
+            ( $($fields)* );
+            ( 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20 );
+            ( _0, _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20 );
+            ( 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20 );
+            ( "tuple", );
+            ( parsers, input, value, sink );
+            [] [] [] []
+        )
+    };
+    ($($fields: tt)*) => {
+        $crate::printable_seq!((
+            $($fields)*
+        ))
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! printable_seq_struct_fields {
+    (
+        ( _ : $head_parser: expr $(, $($fields: tt)*)? );
+        ( $index1: tt, $($index: tt),* );
+        $($name: ident)::* ;
+        ( $parsers: ident, $input: ident, $value: ident, $sink: ident );
+        [ $($built: expr,)* ] [ $($parse: tt)* ] [ $($print: tt)* ] [ $($inits: tt)* ]
+    ) => {
+        $crate::printable_seq_struct_fields!(
+            ( $($($fields)*)? );
+            ( $($index),* );
+            $($name)::* ;
+            ( $parsers, $input, $value, $sink );
+            [ $($built,)* $head_parser, ]
+            [
+                $($parse)*
+                let _ = match $crate::Parser::parse_next(&mut $parsers.$index1, $input) {
+                    Ok(output) => output,
+                    Err(err) => return Err(err),
+                };
+            ]
+            [
+                $($print)*
+                $crate::print::PrintConst::print_const(&$parsers.$index1, $sink)?;
+            ]
+            [ $($inits)* ]
+        )
+    };
+    (
+        ( $head_field: ident : $head_parser: expr $(, $($fields: tt)*)? );
+        ( $index1: tt, $($index: tt),* );
+        $($name: ident)::* ;
+        ( $parsers: ident, $input: ident, $value: ident, $sink: ident );
+        [ $($built: expr,)* ] [ $($parse: tt)* ] [ $($print: tt)* ] [ $($inits: tt)* ]
+    ) => {
+        $crate::printable_seq_struct_fields!(
+            ( $($($fields)*)? );
+            ( $($index),* );
+            $($name)::* ;
+            ( $parsers, $input, $value, $sink );
+            [ $($built,)* $head_parser, ]
+            [
+                $($parse)*
+                let $head_field = match $crate::Parser::parse_next(&mut $parsers.$index1, $input) {
+                    Ok(output) => output,
+                    Err(err) => return Err(err),
+                };
+            ]
+            [
+                $($print)*
+                $crate::print::Print::print(&$parsers.$index1, &$value.$head_field, $sink)?;
+            ]
+            [ $($inits)* $head_field, ]
+        )
+    };
+    (
+        ( $(.. $update: expr)? $(,)? );
+        ( $($index: tt),* );
+        $($name: ident)::* ;
+        ( $parsers: ident, $input: ident, $value: ident, $sink: ident );
+        [ $($built: expr,)* ] [ $($parse: tt)* ] [ $($print: tt)* ] [ $($inits: tt)* ]
+    ) => {
+        $crate::combinator::trace(
+            stringify!($($name)::*),
+            $crate::print::PrintableSeq::new(
+                ( $($built,)* ),
+                |$parsers: &mut _, $input: &mut _| {
+                    $($parse)*
+                    Ok($($name)::* { $($inits)* $(..$update)? })
+                },
+                |$parsers: &_, $value: &_, $sink: &mut dyn $crate::print::Sink| {
+                    $($print)*
+                    Ok(())
+                },
+            ),
+        )
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! printable_seq_tuple_fields {
+    (
+        ( _ : $head_parser: expr $(, $($fields: tt)*)? );
+        ( $index1: tt, $($index: tt),* );
+        ( $unnamed1: ident, $($unnamed: ident),* );
+        ( $($position: tt),* );
+        ( $label: expr, $($name: ident)::* );
+        ( $parsers: ident, $input: ident, $value: ident, $sink: ident );
+        [ $($built: expr,)* ] [ $($parse: tt)* ] [ $($print: tt)* ] [ $($inits: tt)* ]
+    ) => {
+        $crate::printable_seq_tuple_fields!(
+            ( $($($fields)*)? );
+            ( $($index),* );
+            ( $($unnamed),* );
+            ( $($position),* );
+            ( $label, $($name)::* );
+            ( $parsers, $input, $value, $sink );
+            [ $($built,)* $head_parser, ]
+            [
+                $($parse)*
+                let _ = match $crate::Parser::parse_next(&mut $parsers.$index1, $input) {
+                    Ok(output) => output,
+                    Err(err) => return Err(err),
+                };
+            ]
+            [
+                $($print)*
+                $crate::print::PrintConst::print_const(&$parsers.$index1, $sink)?;
+            ]
+            [ $($inits)* ]
+        )
+    };
+    (
+        ( $head_parser: expr $(, $($fields: tt)*)? );
+        ( $index1: tt, $($index: tt),* );
+        ( $unnamed1: ident, $($unnamed: ident),* );
+        ( $position1: tt, $($position: tt),* );
+        ( $label: expr, $($name: ident)::* );
+        ( $parsers: ident, $input: ident, $value: ident, $sink: ident );
+        [ $($built: expr,)* ] [ $($parse: tt)* ] [ $($print: tt)* ] [ $($inits: tt)* ]
+    ) => {
+        $crate::printable_seq_tuple_fields!(
+            ( $($($fields)*)? );
+            ( $($index),* );
+            ( $($unnamed),* );
+            ( $($position),* );
+            ( $label, $($name)::* );
+            ( $parsers, $input, $value, $sink );
+            [ $($built,)* $head_parser, ]
+            [
+                $($parse)*
+                let $unnamed1 = match $crate::Parser::parse_next(&mut $parsers.$index1, $input) {
+                    Ok(output) => output,
+                    Err(err) => return Err(err),
+                };
+            ]
+            [
+                $($print)*
+                $crate::print::Print::print(&$parsers.$index1, &$value.$position1, $sink)?;
+            ]
+            [ $($inits)* $unnamed1, ]
+        )
+    };
+    (
+        ( $(,)? );
+        ( $($index: tt),* );
+        ( $($unnamed: ident),* );
+        ( $($position: tt),* );
+        ( $label: expr, $($name: ident)::* );
+        ( $parsers: ident, $input: ident, $value: ident, $sink: ident );
+        [ $($built: expr,)* ] [ $($parse: tt)* ] [ $($print: tt)* ] [ $($inits: tt)* ]
+    ) => {
+        $crate::combinator::trace(
+            $label,
+            $crate::print::PrintableSeq::new(
+                ( $($built,)* ),
+                |$parsers: &mut _, $input: &mut _| {
+                    $($parse)*
+                    Ok($($name)::* ( $($inits)* ))
+                },
+                |$parsers: &_, $value: &_, $sink: &mut dyn $crate::print::Sink| {
+                    $($print)*
+                    Ok(())
+                },
+            ),
+        )
+    };
+}
@@ -8,6 +8,10 @@
 /// - Struct-style initialization (`{ 0: _, 1: _}`) is not supported
 /// - `_: <parser>` fields can exist to run a parser but ignore the result
 ///
+/// With the `unstable-grammar` feature, the sequence is described as a reference to a rule named
+/// after the struct, as its parsers are only created while parsing.  Add that rule to the
+/// `Grammar` yourself, or use tuples of parsers to describe each one.
+///
 ///# Example
 ///
 /// ```
@@ -74,7 +78,7 @@
 #[doc(hidden)] // forced to be visible in intended location
 macro_rules! seq {
     ($($name: ident)::* { $($fields: tt)* }) => {
-        $crate::combinator::trace(stringify!($($name)::*), move |input: &mut _| {
+        $crate::combinator::Seq::new(stringify!($($name)::*), move |input: &mut _| {
             $crate::seq_parse_struct_fields!(
                 ( $($fields)* );
                 ( _0, _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20 );
@@ -87,7 +91,7 @@ macro_rules! seq {
         })
     };
     ($($name: ident)::* ( $($fields: tt)* )) => {
-        $crate::combinator::trace(stringify!($($name)::*), move |input: &mut _| {
+        $crate::combinator::Seq::new(stringify!($($name)::*), move |input: &mut _| {
             $crate::seq_parse_tuple_fields!(
                 ( $($fields)* );
                 ( _0, _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20 );
@@ -101,7 +105,7 @@ macro_rules! seq {
         })
     };
     (( $($fields: tt)* )) => {
-        $crate::combinator::trace("tuple", move |input: &mut _| {
+        $crate::combinator::Seq::new("tuple", move |input: &mut _| {
             $crate::seq_parse_tuple_fields!(
                 ( $($fields)* );
                 ( _0, _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20 );
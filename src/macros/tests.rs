@@ -6,6 +6,7 @@ use crate::ascii::digit0;
 use crate::combinator::dispatch;
 use crate::combinator::empty;
 use crate::combinator::fail;
+use crate::combinator::grammar;
 use crate::combinator::seq;
 use crate::prelude::*;
 use crate::token::any;
@@ -614,3 +615,214 @@ fn seq_tuple_borrow() {
         .parse_next(input)
     }
 }
+
+#[test]
+fn grammar_sequence_and_choice() {
+    grammar! {
+        fn parser<'i>(input: &mut &'i str) -> TestResult<&'i str, (&'i str, Option<char>)> {
+            ("ab" / "cd") 'x'?
+        }
+    }
+    assert_parse!(
+        parser.parse_peek("abxy"),
+        str![[r#"
+Ok(
+    (
+        "y",
+        (
+            "ab",
+            Some(
+                'x',
+            ),
+        ),
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        parser.parse_peek("cdy"),
+        str![[r#"
+Ok(
+    (
+        "y",
+        (
+            "cd",
+            None,
+        ),
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        parser.parse_peek("ef"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "ef",
+        },
+    ),
+)
+
+"#]]
+    );
+}
+
+#[test]
+fn grammar_actions() {
+    grammar! {
+        fn parser<'i>(input: &mut &'i str) -> TestResult<&'i str, (u32, usize)> {
+            "(" ' '* a:dec_uint ' '* "," ' '* count:'x'+ ' '* ")" => (a, count)
+            / n:crate::ascii::dec_uint => (n, 0)
+        }
+    }
+    assert_parse!(
+        parser.parse_peek("( 10 , xxx )!"),
+        str![[r#"
+Ok(
+    (
+        "!",
+        (
+            10,
+            3,
+        ),
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        parser.parse_peek("42!"),
+        str![[r#"
+Ok(
+    (
+        "!",
+        (
+            42,
+            0,
+        ),
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        parser.parse_peek("(1,)"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "(1,)",
+        },
+    ),
+)
+
+"#]]
+    );
+}
+
+#[test]
+fn grammar_lookahead() {
+    grammar! {
+        fn keyword<'i>(input: &mut &'i str) -> TestResult<&'i str, &'i str> {
+            k:"if" !{ any.verify(|c: &char| c.is_alphanumeric()) } => k
+        }
+
+        fn number_ahead<'i>(input: &mut &'i str) -> TestResult<&'i str, &'i str> {
+            &digit0 rest:{ crate::token::rest } => rest
+        }
+    }
+    assert_parse!(
+        keyword.parse_peek("if x"),
+        str![[r#"
+Ok(
+    (
+        " x",
+        "if",
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        keyword.parse_peek("iffy"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "fy",
+        },
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        number_ahead.parse_peek("12ab"),
+        str![[r#"
+Ok(
+    (
+        "",
+        "12ab",
+    ),
+)
+
+"#]]
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn grammar_recursion() {
+    grammar! {
+        fn nesting<'i>(input: &mut &'i str) -> TestResult<&'i str, usize> {
+            "(" depth:nesting ")" => depth + 1
+            / "" => 0
+        }
+
+        fn all<'i>(input: &mut &'i str) -> TestResult<&'i str, Vec<usize>> {
+            (depth:nesting ';' => depth)+
+        }
+    }
+    assert_parse!(
+        nesting.parse_peek("((()))"),
+        str![[r#"
+Ok(
+    (
+        "",
+        3,
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        nesting.parse_peek("(()"),
+        str![[r#"
+Ok(
+    (
+        "(()",
+        0,
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        all.parse_peek("(());();x"),
+        str![[r#"
+Ok(
+    (
+        "x",
+        [
+            2,
+            1,
+        ],
+    ),
+)
+
+"#]]
+    );
+}
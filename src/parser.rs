@@ -6,7 +6,7 @@ use crate::combinator::impls;
 #[cfg(feature = "std")]
 use crate::error::FromRecoverableError;
 use crate::error::{AddContext, FromExternalError, ParseError, ParserError, Result};
-use crate::stream::{Compare, Location, ParseSlice, Stream, StreamIsPartial};
+use crate::stream::{Compare, Location, NodeSink, ParseSlice, Stream, StreamIsPartial};
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 use crate::stream::{Recover, Recoverable};
@@ -403,6 +403,46 @@ pub trait Parser<I, O, E> {
         }
     }
 
+    /// Record the consumed input as a node of `kind` in a [lossless syntax tree][crate::cst]
+    ///
+    /// Any [`Trivia`][crate::stream::Trivia] before the node is left to the parent node.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use winnow::prelude::*;
+    /// use winnow::ascii::{alpha1, space1};
+    /// use winnow::cst::Event;
+    /// use winnow::stream::Recording;
+    ///
+    /// fn parser<'i>(input: &mut Recording<&'i str, &'static str>) -> ModalResult<&'i str> {
+    ///     (alpha1.node("word"), space1, alpha1.node("word")).take().parse_next(input)
+    /// }
+    ///
+    /// let mut input = Recording::new("ab cd");
+    /// parser.parse_next(&mut input).unwrap();
+    /// assert_eq!(input.events(), [
+    ///     Event::Start("word"), Event::Token("ab"), Event::Finish,
+    ///     Event::Token(" "),
+    ///     Event::Start("word"), Event::Token("cd"), Event::Finish,
+    /// ]);
+    /// ```
+    #[inline(always)]
+    fn node<K>(self, kind: K) -> impls::Node<Self, I, O, E, K>
+    where
+        Self: core::marker::Sized,
+        I: Stream + NodeSink<K>,
+        K: Clone,
+    {
+        impls::Node {
+            parser: self,
+            kind,
+            i: Default::default(),
+            o: Default::default(),
+            e: Default::default(),
+        }
+    }
+
     /// Produce the location of consumed input with the output
     ///
     /// Functions similarly to [`Parser::span`] except it
@@ -1191,6 +1231,16 @@ pub trait ModalParser<I, O, E>: Parser<I, O, crate::error::ErrMode<E>> {}
 
 impl<I, O, E, P> ModalParser<I, O, E> for P where P: Parser<I, O, crate::error::ErrMode<E>> {}
 
+/// A type that can be parsed from the front of `I`
+///
+/// This is usually implemented with [`derive(Parse)`][macro@crate::Parse].  As `parse_next` is a
+/// function, it can be used as a [`Parser`], like `Request::parse_next.parse(input)`.
+#[cfg(feature = "unstable-derive")]
+pub trait Parse<I, E>: Sized {
+    /// Parse `Self`, advancing `input` past it
+    fn parse_next(input: &mut I) -> Result<Self, E>;
+}
+
 /// Collect all errors when parsing the input
 ///
 /// [`Parser`]s will need to use [`Recoverable<I, _>`] for their input.
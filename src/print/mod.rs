@@ -0,0 +1,675 @@
+//! # Printing values back as input
+//!
+//! [`Print`] is the counterpart of [`Parser`]: combinators like
+//! [`literal`][crate::token::literal], [`delimited`][crate::combinator::delimited],
+//! [`separated`][crate::combinator::separated] and [`opt`][crate::combinator::opt] write a value
+//! back into a [`Sink`], so one definition drives both reading and writing a format.
+//!
+//! Parsers that discard their output, like the delimiters of
+//! [`delimited`][crate::combinator::delimited], must be able to print without a value, see
+//! [`PrintConst`].  Other building blocks are:
+//! - [`alt_by`] picks which case of an [`alt`][crate::combinator::alt] to print
+//! - [`bimap`] is a [`Parser::map`] that can be undone when printing
+//! - [`printable_seq!`][crate::printable_seq] is a [`seq!`][crate::combinator::seq] that can
+//!   print the struct it parses
+//! - [`assert_round_trip`] checks that printing gives back what was parsed
+//!
+//! Numbers in [`binary`][crate::binary] print through the parsers taking an
+//! [`Endianness`][crate::binary::Endianness], like [`u16()`][crate::binary::u16()] and
+//! [`uint`][crate::binary::uint], rather than the fixed-endian functions like
+//! [`be_u16`][crate::binary::be_u16].
+//!
+//! # Example
+//!
+//! ```rust
+//! # use winnow::prelude::*;
+//! use winnow::ascii::digit1;
+//! use winnow::combinator::{delimited, separated};
+//! use winnow::error::{ContextError, ErrMode};
+//! use winnow::print::{assert_round_trip, Print};
+//!
+//! fn list<'i>() -> impl Parser<&'i str, Vec<u32>, ErrMode<ContextError>> + Print<Vec<u32>> {
+//!     delimited('[', separated(0.., digit1.parse_to::<u32>(), ','), ']')
+//! }
+//!
+//! assert_eq!(list().print_to_string(&vec![1, 22, 333]).unwrap(), "[1,22,333]");
+//! assert_eq!(assert_round_trip(list(), "[4,5]"), vec![4, 5]);
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+#[doc(inline)]
+pub use crate::printable_seq;
+
+use crate::ascii::Caseless;
+use crate::combinator::Alt;
+use crate::error::ParserError;
+use crate::lib::std::boxed::Box;
+use crate::lib::std::fmt;
+use crate::lib::std::string::String;
+use crate::lib::std::vec::Vec;
+use crate::stream::AsBStr;
+use crate::stream::BStr;
+use crate::stream::Stream;
+use crate::stream::StreamIsPartial;
+use crate::Parser;
+
+/// Write a value back as the input a [`Parser`] would accept
+pub trait Print<V: ?Sized> {
+    /// Write `value` to `sink`
+    fn print(&self, value: &V, sink: &mut dyn Sink) -> Result<(), PrintError>;
+
+    /// Print `value` as text
+    fn print_to_string(&self, value: &V) -> Result<String, PrintError> {
+        let mut sink = String::new();
+        self.print(value, &mut sink)?;
+        Ok(sink)
+    }
+
+    /// Print `value` as bytes
+    fn print_to_vec(&self, value: &V) -> Result<Vec<u8>, PrintError> {
+        let mut sink = Vec::new();
+        self.print(value, &mut sink)?;
+        Ok(sink)
+    }
+}
+
+/// Write the input a [`Parser`] accepts without being given a value
+///
+/// This is implemented for parsers that always match the same input, like literals, so they can
+/// be printed when their output is discarded, like by
+/// [`delimited`][crate::combinator::delimited].  Tokens and slices of them also implement this,
+/// printing themselves.
+pub trait PrintConst {
+    /// Write to `sink`
+    fn print_const(&self, sink: &mut dyn Sink) -> Result<(), PrintError>;
+}
+
+/// Print one of the cases of [`alt_by`]
+///
+/// This trait is implemented for tuples of up to 21 elements
+pub trait PrintAlt<V: ?Sized> {
+    /// Print `value` with the case at `index`
+    fn print_alt(&self, index: usize, value: &V, sink: &mut dyn Sink) -> Result<(), PrintError>;
+}
+
+/// Destination for [`Print`]
+pub trait Sink {
+    /// Write text
+    fn write_str(&mut self, text: &str) -> Result<(), PrintError>;
+
+    /// Write bytes
+    ///
+    /// Sinks that only accept text report [`PrintError::NotText`] when the bytes aren't UTF-8.
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), PrintError>;
+}
+
+impl Sink for String {
+    #[inline]
+    fn write_str(&mut self, text: &str) -> Result<(), PrintError> {
+        self.push_str(text);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), PrintError> {
+        self.push_str(crate::lib::std::str::from_utf8(bytes).map_err(|_| PrintError::NotText)?);
+        Ok(())
+    }
+}
+
+impl Sink for Vec<u8> {
+    #[inline]
+    fn write_str(&mut self, text: &str) -> Result<(), PrintError> {
+        self.extend_from_slice(text.as_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), PrintError> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+impl Sink for fmt::Formatter<'_> {
+    #[inline]
+    fn write_str(&mut self, text: &str) -> Result<(), PrintError> {
+        fmt::Write::write_str(self, text).map_err(|_| PrintError::Sink)
+    }
+
+    #[inline]
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), PrintError> {
+        Sink::write_str(
+            self,
+            crate::lib::std::str::from_utf8(bytes).map_err(|_| PrintError::NotText)?,
+        )
+    }
+}
+
+/// Adapt any [`fmt::Write`] as a [`Sink`]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct FmtSink<W>(pub W);
+
+impl<W: fmt::Write> Sink for FmtSink<W> {
+    #[inline]
+    fn write_str(&mut self, text: &str) -> Result<(), PrintError> {
+        self.0.write_str(text).map_err(|_| PrintError::Sink)
+    }
+
+    #[inline]
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), PrintError> {
+        self.write_str(crate::lib::std::str::from_utf8(bytes).map_err(|_| PrintError::NotText)?)
+    }
+}
+
+/// [`fmt::Write`] into a [`Sink`], for printing with [`fmt::Display`]
+pub(crate) struct SinkWriter<'s>(pub(crate) &'s mut dyn Sink);
+
+impl fmt::Write for SinkWriter<'_> {
+    #[inline]
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        self.0.write_str(text).map_err(|_| fmt::Error)
+    }
+}
+
+/// Failure to [`Print`] a value
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PrintError {
+    /// The [`Sink`] failed to write
+    Sink,
+    /// Bytes that aren't UTF-8 were written to a [`Sink`] that only accepts text
+    NotText,
+    /// The value can't be printed as input the parser would accept
+    Unprintable,
+}
+
+impl fmt::Display for PrintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sink => "failed to write to sink".fmt(f),
+            Self::NotText => "bytes written to a text sink are not UTF-8".fmt(f),
+            Self::Unprintable => "value can't be printed".fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PrintError {}
+
+/// Parse `input` and print the value back, asserting the output is `input`
+///
+/// Returns the parsed value.
+///
+/// # Panic
+///
+/// If parsing or printing fails or if the printed input is different
+#[track_caller]
+pub fn assert_round_trip<Input, Output, Error, ParseNext>(
+    mut parser: ParseNext,
+    input: Input,
+) -> Output
+where
+    Input: Stream + StreamIsPartial + AsBStr + Clone + fmt::Debug,
+    Error: ParserError<Input>,
+    <Error as ParserError<Input>>::Inner: ParserError<Input> + fmt::Debug,
+    ParseNext: Parser<Input, Output, Error> + Print<Output>,
+    Output: fmt::Debug,
+{
+    let value = match parser.parse(input.clone()) {
+        Ok(value) => value,
+        Err(err) => panic!("failed to parse {input:?}: {err:?}"),
+    };
+    let printed = match parser.print_to_vec(&value) {
+        Ok(printed) => printed,
+        Err(err) => panic!("failed to print {value:?}: {err}"),
+    };
+    assert_eq!(
+        BStr::new(&printed),
+        BStr::new(input.as_bstr()),
+        "printing {value:?} gave different input"
+    );
+    value
+}
+
+/// Pick the first successful parser, printing the case selected by `discriminator`
+///
+/// This parses like [`alt`][crate::combinator::alt].  When printing, `discriminator` gives the
+/// index of the case in `alternatives` that should print the value.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::error::{ContextError, ErrMode};
+/// use winnow::print::{alt_by, Print};
+///
+/// fn boolean<'i>() -> impl Parser<&'i str, bool, ErrMode<ContextError>> + Print<bool> {
+///     alt_by(|value: &bool| if *value { 0 } else { 1 }, ("yes".value(true), "no".value(false)))
+/// }
+///
+/// assert_eq!(boolean().parse("no"), Ok(false));
+/// assert_eq!(boolean().print_to_string(&true).unwrap(), "yes");
+/// ```
+#[inline(always)]
+pub fn alt_by<Input, Output, Error, Alternatives, Discriminator>(
+    discriminator: Discriminator,
+    alternatives: Alternatives,
+) -> AltBy<Alternatives, Discriminator, Input, Output, Error>
+where
+    Input: Stream,
+    Alternatives: Alt<Input, Output, Error> + PrintAlt<Output>,
+    Discriminator: Fn(&Output) -> usize,
+    Error: ParserError<Input>,
+{
+    AltBy {
+        alternatives,
+        discriminator,
+        i: Default::default(),
+        o: Default::default(),
+        e: Default::default(),
+    }
+}
+
+/// [`Parser`] implementation for [`alt_by`]
+pub struct AltBy<A, F, I, O, E>
+where
+    I: Stream,
+    A: Alt<I, O, E> + PrintAlt<O>,
+    F: Fn(&O) -> usize,
+    E: ParserError<I>,
+{
+    alternatives: A,
+    discriminator: F,
+    i: core::marker::PhantomData<I>,
+    o: core::marker::PhantomData<O>,
+    e: core::marker::PhantomData<E>,
+}
+
+impl<A, F, I, O, E> Parser<I, O, E> for AltBy<A, F, I, O, E>
+where
+    I: Stream,
+    A: Alt<I, O, E> + PrintAlt<O>,
+    F: Fn(&O) -> usize,
+    E: ParserError<I>,
+{
+    #[inline(always)]
+    fn parse_next(&mut self, input: &mut I) -> Result<O, E> {
+        crate::combinator::trace("alt_by", |i: &mut I| self.alternatives.choice(i))
+            .parse_next(input)
+    }
+}
+
+impl<A, F, I, O, E> Print<O> for AltBy<A, F, I, O, E>
+where
+    I: Stream,
+    A: Alt<I, O, E> + PrintAlt<O>,
+    F: Fn(&O) -> usize,
+    E: ParserError<I>,
+{
+    fn print(&self, value: &O, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        let index = (self.discriminator)(value);
+        self.alternatives.print_alt(index, value, sink)
+    }
+}
+
+/// Maps a function on the result of a parser, with `unmap` to get it back when printing
+///
+/// `unmap` returns `None` when the value didn't come from `map`, like for another variant of an
+/// `enum`.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::digit1;
+/// use winnow::error::{ContextError, ErrMode};
+/// use winnow::print::{alt_by, bimap, Print};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Size {
+///     Auto,
+///     Fixed(u32),
+/// }
+///
+/// fn size<'i>() -> impl Parser<&'i str, Size, ErrMode<ContextError>> + Print<Size> {
+///     alt_by(
+///         |size: &Size| match size {
+///             Size::Auto => 0,
+///             Size::Fixed(_) => 1,
+///         },
+///         (
+///             "auto".value(Size::Auto),
+///             bimap(digit1.parse_to(), Size::Fixed, |size: &Size| match size {
+///                 Size::Fixed(width) => Some(width),
+///                 _ => None,
+///             }),
+///         ),
+///     )
+/// }
+///
+/// assert_eq!(size().parse("42"), Ok(Size::Fixed(42)));
+/// assert_eq!(size().print_to_string(&Size::Fixed(7)).unwrap(), "7");
+/// ```
+#[inline(always)]
+pub fn bimap<Input, Output, Output2, Error, ParseNext, Map, Unmap>(
+    parser: ParseNext,
+    map: Map,
+    unmap: Unmap,
+) -> Bimap<ParseNext, Map, Unmap, Input, Output, Output2, Error>
+where
+    ParseNext: Parser<Input, Output, Error> + Print<Output>,
+    Map: FnMut(Output) -> Output2,
+    Unmap: Fn(&Output2) -> Option<&Output>,
+{
+    Bimap {
+        parser,
+        map,
+        unmap,
+        i: Default::default(),
+        o: Default::default(),
+        o2: Default::default(),
+        e: Default::default(),
+    }
+}
+
+/// [`Parser`] implementation for [`bimap`]
+pub struct Bimap<P, F, G, I, O, O2, E>
+where
+    P: Parser<I, O, E> + Print<O>,
+    F: FnMut(O) -> O2,
+    G: Fn(&O2) -> Option<&O>,
+{
+    parser: P,
+    map: F,
+    unmap: G,
+    i: core::marker::PhantomData<I>,
+    o: core::marker::PhantomData<O>,
+    o2: core::marker::PhantomData<O2>,
+    e: core::marker::PhantomData<E>,
+}
+
+impl<P, F, G, I, O, O2, E> Parser<I, O2, E> for Bimap<P, F, G, I, O, O2, E>
+where
+    P: Parser<I, O, E> + Print<O>,
+    F: FnMut(O) -> O2,
+    G: Fn(&O2) -> Option<&O>,
+{
+    #[inline]
+    fn parse_next(&mut self, input: &mut I) -> Result<O2, E> {
+        let o = self.parser.parse_next(input)?;
+        Ok((self.map)(o))
+    }
+}
+
+impl<P, F, G, I, O, O2, E> Print<O2> for Bimap<P, F, G, I, O, O2, E>
+where
+    P: Parser<I, O, E> + Print<O>,
+    F: FnMut(O) -> O2,
+    G: Fn(&O2) -> Option<&O>,
+{
+    fn print(&self, value: &O2, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        let value = (self.unmap)(value).ok_or(PrintError::Unprintable)?;
+        self.parser.print(value, sink)
+    }
+}
+
+/// [`Parser`] implementation for [`printable_seq!`][crate::printable_seq]
+#[doc(hidden)]
+pub struct PrintableSeq<P, F, G, I, O, E>
+where
+    F: FnMut(&mut P, &mut I) -> Result<O, E>,
+    G: Fn(&P, &O, &mut dyn Sink) -> Result<(), PrintError>,
+{
+    parsers: P,
+    parse: F,
+    print: G,
+    i: core::marker::PhantomData<I>,
+    o: core::marker::PhantomData<O>,
+    e: core::marker::PhantomData<E>,
+}
+
+impl<P, F, G, I, O, E> PrintableSeq<P, F, G, I, O, E>
+where
+    F: FnMut(&mut P, &mut I) -> Result<O, E>,
+    G: Fn(&P, &O, &mut dyn Sink) -> Result<(), PrintError>,
+{
+    #[doc(hidden)]
+    #[inline(always)]
+    pub fn new(parsers: P, parse: F, print: G) -> Self {
+        Self {
+            parsers,
+            parse,
+            print,
+            i: Default::default(),
+            o: Default::default(),
+            e: Default::default(),
+        }
+    }
+}
+
+impl<P, F, G, I, O, E> Parser<I, O, E> for PrintableSeq<P, F, G, I, O, E>
+where
+    F: FnMut(&mut P, &mut I) -> Result<O, E>,
+    G: Fn(&P, &O, &mut dyn Sink) -> Result<(), PrintError>,
+{
+    #[inline(always)]
+    fn parse_next(&mut self, input: &mut I) -> Result<O, E> {
+        (self.parse)(&mut self.parsers, input)
+    }
+}
+
+impl<P, F, G, I, O, E> Print<O> for PrintableSeq<P, F, G, I, O, E>
+where
+    F: FnMut(&mut P, &mut I) -> Result<O, E>,
+    G: Fn(&P, &O, &mut dyn Sink) -> Result<(), PrintError>,
+{
+    #[inline(always)]
+    fn print(&self, value: &O, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        (self.print)(&self.parsers, value, sink)
+    }
+}
+
+impl<V: ?Sized, P: Print<V> + ?Sized> Print<V> for &P {
+    fn print(&self, value: &V, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        (**self).print(value, sink)
+    }
+}
+
+impl<V: ?Sized, P: Print<V> + ?Sized> Print<V> for &mut P {
+    fn print(&self, value: &V, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        (**self).print(value, sink)
+    }
+}
+
+impl<V: ?Sized, P: Print<V> + ?Sized> Print<V> for Box<P> {
+    fn print(&self, value: &V, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        (**self).print(value, sink)
+    }
+}
+
+impl<P: PrintConst + ?Sized> PrintConst for &P {
+    fn print_const(&self, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        (**self).print_const(sink)
+    }
+}
+
+impl<P: PrintConst + ?Sized> PrintConst for &mut P {
+    fn print_const(&self, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        (**self).print_const(sink)
+    }
+}
+
+impl<P: PrintConst + ?Sized> PrintConst for Box<P> {
+    fn print_const(&self, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        (**self).print_const(sink)
+    }
+}
+
+impl PrintConst for u8 {
+    fn print_const(&self, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        sink.write_bytes(&[*self])
+    }
+}
+
+impl PrintConst for char {
+    fn print_const(&self, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        sink.write_str(self.encode_utf8(&mut [0; 4]))
+    }
+}
+
+impl PrintConst for str {
+    fn print_const(&self, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        sink.write_str(self)
+    }
+}
+
+impl PrintConst for [u8] {
+    fn print_const(&self, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        sink.write_bytes(self)
+    }
+}
+
+impl<const N: usize> PrintConst for [u8; N] {
+    fn print_const(&self, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        sink.write_bytes(self)
+    }
+}
+
+impl PrintConst for String {
+    fn print_const(&self, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        sink.write_str(self)
+    }
+}
+
+impl PrintConst for Vec<u8> {
+    fn print_const(&self, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        sink.write_bytes(self)
+    }
+}
+
+impl PrintConst for BStr {
+    fn print_const(&self, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        sink.write_bytes(self)
+    }
+}
+
+impl<T: PrintConst> PrintConst for Caseless<T> {
+    fn print_const(&self, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        self.0.print_const(sink)
+    }
+}
+
+impl PrintConst for () {
+    fn print_const(&self, _sink: &mut dyn Sink) -> Result<(), PrintError> {
+        Ok(())
+    }
+}
+
+macro_rules! impl_print_for_literal {
+    ($($literal:ty),+) => {
+        $(
+            impl<V: ?Sized> Print<V> for $literal {
+                fn print(&self, _value: &V, sink: &mut dyn Sink) -> Result<(), PrintError> {
+                    self.print_const(sink)
+                }
+            }
+        )+
+    };
+}
+
+impl_print_for_literal!(u8, char, str, [u8], (), Caseless<&str>, Caseless<&[u8]>);
+
+impl<V: ?Sized, const N: usize> Print<V> for [u8; N] {
+    fn print(&self, _value: &V, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        self.print_const(sink)
+    }
+}
+
+impl<V: ?Sized, const N: usize> Print<V> for Caseless<[u8; N]> {
+    fn print(&self, _value: &V, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        self.print_const(sink)
+    }
+}
+
+impl<V: ?Sized, const N: usize> Print<V> for Caseless<&[u8; N]> {
+    fn print(&self, _value: &V, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        self.print_const(sink)
+    }
+}
+
+macro_rules! impl_print_for_tuple {
+  ($($index:tt $parser:ident $value:ident),+) => (
+    impl<$($parser, $value),+> Print<($($value),+,)> for ($($parser),+,)
+    where
+      $($parser: Print<$value>),+
+    {
+      fn print(&self, value: &($($value),+,), sink: &mut dyn Sink) -> Result<(), PrintError> {
+        $(self.$index.print(&value.$index, sink)?;)+
+        Ok(())
+      }
+    }
+
+    impl<$($parser),+> PrintConst for ($($parser),+,)
+    where
+      $($parser: PrintConst),+
+    {
+      fn print_const(&self, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        $(self.$index.print_const(sink)?;)+
+        Ok(())
+      }
+    }
+
+    impl<V: ?Sized, $($parser),+> PrintAlt<V> for ($($parser),+,)
+    where
+      $($parser: Print<V>),+
+    {
+      fn print_alt(&self, index: usize, value: &V, sink: &mut dyn Sink) -> Result<(), PrintError> {
+        match index {
+          $($index => self.$index.print(value, sink),)+
+          _ => Err(PrintError::Unprintable),
+        }
+      }
+    }
+  )
+}
+
+macro_rules! impl_print_for_tuples {
+    ($index1:tt $parser1:ident $value1:ident, $($index:tt $parser:ident $value:ident),+) => {
+        impl_print_for_tuples!(__impl $index1 $parser1 $value1; $($index $parser $value),+);
+    };
+    (__impl $($index:tt $parser:ident $value:ident),+; $index1:tt $parser1:ident $value1:ident $(,$index2:tt $parser2:ident $value2:ident)*) => {
+        impl_print_for_tuple!($($index $parser $value),+);
+        impl_print_for_tuples!(__impl $($index $parser $value),+, $index1 $parser1 $value1; $($index2 $parser2 $value2),*);
+    };
+    (__impl $($index:tt $parser:ident $value:ident),+;) => {
+        impl_print_for_tuple!($($index $parser $value),+);
+    }
+}
+
+impl_print_for_tuples!(
+  0 P0 V0,
+  1 P1 V1,
+  2 P2 V2,
+  3 P3 V3,
+  4 P4 V4,
+  5 P5 V5,
+  6 P6 V6,
+  7 P7 V7,
+  8 P8 V8,
+  9 P9 V9,
+  10 P10 V10,
+  11 P11 V11,
+  12 P12 V12,
+  13 P13 V13,
+  14 P14 V14,
+  15 P15 V15,
+  16 P16 V16,
+  17 P17 V17,
+  18 P18 V18,
+  19 P19 V19,
+  20 P20 V20,
+  21 P21 V21
+);
@@ -0,0 +1,212 @@
+use super::*;
+
+use crate::ascii::digit1;
+use crate::binary::{bf16, f16, int, u16, u24, uint, Endianness};
+use crate::combinator::{delimited, opt, repeat, separated};
+use crate::error::{ContextError, ErrMode};
+use crate::stream::AsChar;
+use crate::token::{one_of, take_while};
+
+type Error = ErrMode<ContextError>;
+
+#[test]
+fn combinators() {
+    let mut parser = delimited::<&str, _, _, _, Error, _, _, _>(
+        '[',
+        separated::<_, _, Vec<_>, _, _, _, _>(
+            0..,
+            (opt('-'), take_while(1.., AsChar::is_dec_digit)),
+            ", ",
+        ),
+        ']',
+    );
+    assert_eq!(
+        assert_round_trip(parser.by_ref(), "[1, -22, 333]"),
+        vec![(None, "1"), (Some('-'), "22"), (None, "333")]
+    );
+    assert_eq!(
+        parser.print_to_string(&vec![(Some('-'), "4")]),
+        Ok("[-4]".to_owned())
+    );
+}
+
+#[test]
+fn repeat_count() {
+    let parser = repeat::<&str, _, Vec<char>, Error, _>(2..=3, one_of('a'..='c'));
+    assert_eq!(parser.print_to_string(&vec!['a', 'c']), Ok("ac".to_owned()));
+    assert_eq!(
+        parser.print_to_string(&vec!['a']),
+        Err(PrintError::Unprintable)
+    );
+    assert_eq!(
+        parser.print_to_string(&vec!['a', 'd']),
+        Err(PrintError::Unprintable)
+    );
+
+    let parser = separated::<&str, _, Vec<char>, _, Error, _, _>(1.., one_of('a'..='c'), ',');
+    assert_eq!(
+        parser.print_to_string(&vec![]),
+        Err(PrintError::Unprintable)
+    );
+}
+
+#[test]
+fn alternatives() {
+    #[derive(Clone, Debug, PartialEq)]
+    enum Value {
+        Null,
+        Number(u32),
+    }
+
+    let mut parser = alt_by::<&str, _, Error, _, _>(
+        |value: &Value| match value {
+            Value::Null => 0,
+            Value::Number(_) => 1,
+        },
+        (
+            "null".value(Value::Null),
+            bimap(
+                digit1.parse_to(),
+                Value::Number,
+                |value: &Value| match value {
+                    Value::Number(n) => Some(n),
+                    Value::Null => None,
+                },
+            ),
+        ),
+    );
+    assert_eq!(assert_round_trip(parser.by_ref(), "null"), Value::Null);
+    assert_eq!(assert_round_trip(parser.by_ref(), "42"), Value::Number(42));
+
+    let parser = alt_by::<&str, _, Error, _, _>(|_: &&str| 2, ("a", "b"));
+    assert_eq!(parser.print_to_string(&"a"), Err(PrintError::Unprintable));
+}
+
+#[test]
+fn seq_struct() {
+    #[derive(Debug, PartialEq)]
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    let mut parser = printable_seq! {Point {
+        _: '(',
+        x: digit1::<&str, Error>.parse_to(),
+        _: ',',
+        y: digit1.parse_to(),
+        _: ')',
+    }};
+    assert_eq!(
+        assert_round_trip(parser.by_ref(), "(1,2)"),
+        Point { x: 1, y: 2 }
+    );
+    assert_eq!(
+        parser.print_to_string(&Point { x: 30, y: 4 }),
+        Ok("(30,4)".to_owned())
+    );
+}
+
+#[test]
+fn seq_tuple() {
+    #[derive(Debug, PartialEq)]
+    struct Range(u32, u32);
+
+    let mut parser =
+        printable_seq!(Range(digit1::<&str, Error>.parse_to(), _: "..", digit1.parse_to()));
+    assert_eq!(assert_round_trip(parser.by_ref(), "1..5"), Range(1, 5));
+
+    let mut parser = printable_seq!((one_of::<&str, _, Error>('a'..='z'), _: '=', take_while(1.., AsChar::is_dec_digit)));
+    assert_eq!(assert_round_trip(parser.by_ref(), "x=10"), ('x', "10"));
+}
+
+#[test]
+fn binary() {
+    let mut parser = (
+        u16::<&[u8], Error>(Endianness::Big),
+        u24(Endianness::Little),
+    );
+    assert_eq!(
+        assert_round_trip(parser.by_ref(), &[0x01, 0x02, 0x03, 0x04, 0x05][..]),
+        (0x0102, 0x050403)
+    );
+    assert_eq!(
+        parser.print_to_vec(&(0x0a0b, 1 << 24)),
+        Err(PrintError::Unprintable)
+    );
+    assert_eq!(
+        parser.print_to_string(&(0xffff, 0)),
+        Err(PrintError::NotText)
+    );
+}
+
+#[test]
+fn sized_integers() {
+    let mut parser = (uint::<5>(Endianness::Big), int::<3>(Endianness::Little));
+    assert_eq!(
+        assert_round_trip::<&[u8], (u64, i32), Error, _>(
+            parser.by_ref(),
+            &[0x01, 0x02, 0x03, 0x04, 0x05, 0xfe, 0xff, 0xff][..]
+        ),
+        (0x01_0203_0405, -2)
+    );
+    assert_eq!(
+        Print::<u64>::print_to_vec(&uint::<5>(Endianness::Big), &(1 << 40)),
+        Err(PrintError::Unprintable)
+    );
+    assert_eq!(
+        Print::<i32>::print_to_vec(&int::<3>(Endianness::Big), &-(1 << 23)),
+        Ok(vec![0x80, 0x00, 0x00])
+    );
+    assert_eq!(
+        Print::<i32>::print_to_vec(&int::<3>(Endianness::Big), &(1 << 23)),
+        Err(PrintError::Unprintable)
+    );
+}
+
+#[test]
+fn single_byte_integers() {
+    let mut parser = (uint::<1>(Endianness::Big), int::<1>(Endianness::Big));
+    assert_eq!(
+        assert_round_trip::<&[u8], (u8, i8), Error, _>(parser.by_ref(), &[0xff, 0xff][..]),
+        (0xff, -1)
+    );
+    assert_eq!(
+        Print::<u16>::print_to_vec(&uint::<1>(Endianness::Little), &0x100),
+        Err(PrintError::Unprintable)
+    );
+}
+
+#[test]
+fn half_floats() {
+    let mut parser = (f16(Endianness::Big), bf16(Endianness::Little));
+    assert_eq!(
+        assert_round_trip::<&[u8], (f32, f32), Error, _>(
+            parser.by_ref(),
+            &[0x4a, 0x40, 0x48, 0x41][..]
+        ),
+        (12.5, 12.5)
+    );
+    // Subnormal, largest subnormal, largest normal, infinity and NaN
+    for bytes in [
+        [0x00, 0x01],
+        [0x83, 0xff],
+        [0x7b, 0xff],
+        [0xfc, 0x00],
+        [0x7e, 0x00],
+    ] {
+        assert_round_trip::<&[u8], f32, Error, _>(f16(Endianness::Big), &bytes[..]);
+    }
+    assert_eq!(
+        Print::<f32>::print_to_vec(&f16::<&[u8], Error>(Endianness::Big), &65536.0),
+        Err(PrintError::Unprintable)
+    );
+    assert_eq!(
+        Print::<f32>::print_to_vec(&f16::<&[u8], Error>(Endianness::Big), &0.1),
+        Err(PrintError::Unprintable)
+    );
+    assert_eq!(
+        Print::<f32>::print_to_vec(&bf16::<&[u8], Error>(Endianness::Big), &0.1),
+        Err(PrintError::Unprintable)
+    );
+}
@@ -0,0 +1,184 @@
+use core::ptr;
+
+use crate::lib::std::fmt;
+use crate::lib::std::mem::{ManuallyDrop, MaybeUninit};
+use crate::lib::std::ops::Deref;
+use crate::lib::std::slice;
+
+use crate::stream::TryAccumulate;
+
+/// Vector with a fixed capacity of `N`, stored inline
+///
+/// This is a [`TryAccumulate`] for `no_std` targets without `alloc`.  Accumulating more than `N`
+/// values into it with [`repeat`][crate::combinator::repeat] and friends is a parser error.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::dec_uint;
+/// use winnow::combinator::separated;
+/// use winnow::stream::FixedVec;
+///
+/// fn numbers(input: &mut &str) -> ModalResult<FixedVec<u8, 4>> {
+///     separated(1.., dec_uint::<_, u8, _>, ',').parse_next(input)
+/// }
+///
+/// assert_eq!(numbers.parse_peek("1,2,3").unwrap().1.as_slice(), &[1, 2, 3]);
+/// assert!(numbers.parse_peek("1,2,3,4,5").is_err());
+/// ```
+pub struct FixedVec<T, const N: usize> {
+    items: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> FixedVec<T, N> {
+    /// Create an empty vector
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            items: [(); N].map(|_| MaybeUninit::uninit()),
+            len: 0,
+        }
+    }
+
+    /// Maximum number of elements
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Whether there is no room for more elements
+    #[inline(always)]
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Append an element, returning it back if the vector is full
+    #[inline]
+    pub fn try_push(&mut self, value: T) -> Result<(), T> {
+        match self.items.get_mut(self.len) {
+            Some(slot) => {
+                slot.write(value);
+                self.len += 1;
+                Ok(())
+            }
+            None => Err(value),
+        }
+    }
+
+    /// Remove the last element
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // SAFETY: `len` elements are initialized and `len` was just decremented so it won't be
+        // read again
+        Some(unsafe { self.items[self.len].assume_init_read() })
+    }
+
+    /// Remove all elements
+    #[inline]
+    pub fn clear(&mut self) {
+        while self.pop().is_some() {}
+    }
+
+    /// The initialized elements
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the first `len` elements are initialized and `MaybeUninit<T>` has the same
+        // layout as `T`
+        unsafe { slice::from_raw_parts(self.items.as_ptr().cast::<T>(), self.len) }
+    }
+
+    /// The initialized elements
+    #[inline(always)]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the first `len` elements are initialized and `MaybeUninit<T>` has the same
+        // layout as `T`
+        unsafe { slice::from_raw_parts_mut(self.items.as_mut_ptr().cast::<T>(), self.len) }
+    }
+
+    /// Convert into an array, if full
+    pub fn into_array(self) -> Result<[T; N], Self> {
+        if !self.is_full() {
+            return Err(self);
+        }
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `this` is never dropped so ownership of the elements moves to `items`
+        let items = unsafe { ptr::read(&this.items) };
+        // SAFETY: all `N` elements are initialized
+        Ok(items.map(|item| unsafe { item.assume_init() }))
+    }
+}
+
+impl<T, const N: usize> Drop for FixedVec<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Default for FixedVec<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for FixedVec<T, N> {
+    fn clone(&self) -> Self {
+        let mut clone = Self::new();
+        for item in self.iter() {
+            let _ = clone.try_push(item.clone());
+        }
+        clone
+    }
+}
+
+impl<T, const N: usize> Deref for FixedVec<T, N> {
+    type Target = [T];
+
+    #[inline(always)]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> crate::lib::std::ops::DerefMut for FixedVec<T, N> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for FixedVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for FixedVec<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for FixedVec<T, N> {}
+
+impl<T, const N: usize> TryAccumulate<T> for FixedVec<T, N> {
+    type Partial = Self;
+    #[inline(always)]
+    fn initial(_capacity: Option<usize>) -> Self {
+        Self::new()
+    }
+    #[inline(always)]
+    fn try_accumulate(partial: &mut Self, acc: T) -> Result<(), T> {
+        partial.try_push(acc)
+    }
+    #[inline(always)]
+    fn finish(partial: Self) -> Option<Self> {
+        Some(partial)
+    }
+}
@@ -1,4 +1,6 @@
 use crate::error::Needed;
+use crate::stream::sealed::SealedMarker;
+use crate::stream::trivia::TriviaError;
 use crate::stream::AsBStr;
 use crate::stream::AsBytes;
 use crate::stream::Checkpoint;
@@ -6,10 +8,12 @@ use crate::stream::Compare;
 use crate::stream::CompareResult;
 use crate::stream::FindSlice;
 use crate::stream::Location;
+use crate::stream::NodeSink;
 use crate::stream::Offset;
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 use crate::stream::Recover;
+use crate::stream::Seek;
 use crate::stream::SliceLen;
 use crate::stream::Stream;
 use crate::stream::StreamIsPartial;
@@ -161,6 +165,19 @@ impl<I: Stream> Stream for LocatingSlice<I> {
     fn reset(&mut self, checkpoint: &Self::Checkpoint) {
         self.input.reset(&checkpoint.inner);
     }
+    #[inline(always)]
+    fn taken_since(&mut self, checkpoint: &Self::Checkpoint) -> Self::Slice {
+        self.input.taken_since(&checkpoint.inner)
+    }
+
+    #[inline(always)]
+    fn skip_trivia(&mut self, marker: SealedMarker) -> Result<(), TriviaError> {
+        self.input.skip_trivia(marker)
+    }
+    #[inline(always)]
+    fn set_trivia(&mut self, enabled: bool, marker: SealedMarker) -> bool {
+        self.input.set_trivia(enabled, marker)
+    }
 
     #[inline(always)]
     fn raw(&self) -> &dyn crate::lib::std::fmt::Debug {
@@ -182,6 +199,40 @@ where
     }
 }
 
+impl<I> Seek for LocatingSlice<I>
+where
+    I: Clone + Stream + Offset,
+{
+    #[inline(always)]
+    fn seek_offset(&self) -> usize {
+        self.input.offset_from(&self.initial)
+    }
+    #[inline(always)]
+    fn seek_len(&self) -> usize {
+        self.initial.eof_offset()
+    }
+    #[inline]
+    fn seek_to(&mut self, offset: usize) {
+        debug_assert!(offset <= self.seek_len(), "offset is out of range");
+        self.input = self.initial.clone();
+        let _ = self.input.next_slice(offset);
+    }
+}
+
+impl<I, K> NodeSink<K> for LocatingSlice<I>
+where
+    I: NodeSink<K>,
+{
+    #[inline(always)]
+    fn start_node(&mut self, kind: K) {
+        self.input.start_node(kind);
+    }
+    #[inline(always)]
+    fn finish_node(&mut self) {
+        self.input.finish_node();
+    }
+}
+
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 impl<I, E> Recover<E> for LocatingSlice<I>
@@ -39,9 +39,12 @@ use crate::lib::std::vec::Vec;
 
 mod bstr;
 mod bytes;
+mod fixed_vec;
 mod locating;
 mod partial;
 mod range;
+#[cfg(feature = "alloc")]
+mod recording;
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 mod recoverable;
@@ -49,17 +52,26 @@ mod stateful;
 #[cfg(test)]
 mod tests;
 mod token;
+mod trivia;
 
 pub use bstr::BStr;
 pub use bytes::Bytes;
+pub use fixed_vec::FixedVec;
 pub use locating::LocatingSlice;
 pub use partial::Partial;
 pub use range::Range;
+#[cfg(feature = "alloc")]
+pub use recording::Recording;
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 pub use recoverable::Recoverable;
 pub use stateful::Stateful;
 pub use token::TokenSlice;
+pub use trivia::Trivia;
+
+pub(crate) use trivia::set_trivia;
+pub(crate) use trivia::skip_trivia;
+use trivia::TriviaError;
 
 /// UTF-8 Stream
 pub type Str<'i> = &'i str;
@@ -213,6 +225,37 @@ pub trait Stream: Offset<<Self as Stream>::Checkpoint> + crate::lib::std::fmt::D
     ///
     /// May panic if an invalid [`Self::Checkpoint`] is provided
     fn reset(&mut self, checkpoint: &Self::Checkpoint);
+    /// Return the input consumed since a prior [`Self::Checkpoint`], see [`Parser::take`][crate::Parser::take]
+    ///
+    /// Unlike [`Stream::reset`] followed by [`Stream::next_slice`], anything recorded while
+    /// consuming the input is kept, like the [`Parser::node`][crate::Parser::node]s of a
+    /// [`Recording`].
+    ///
+    /// # Panic
+    ///
+    /// May panic if an invalid [`Self::Checkpoint`] is provided
+    #[inline(always)]
+    fn taken_since(&mut self, checkpoint: &Self::Checkpoint) -> Self::Slice {
+        let offset = self.offset_from(checkpoint);
+        self.reset(checkpoint);
+        self.next_slice(offset)
+    }
+
+    /// Skip insignificant input, like whitespace and comments, ahead of a token
+    ///
+    /// This does nothing unless the stream is wrapped in [`Trivia`].
+    #[doc(hidden)]
+    #[inline(always)]
+    fn skip_trivia(&mut self, _: sealed::SealedMarker) -> Result<(), TriviaError> {
+        Ok(())
+    }
+    /// Enable or disable skipping [`Trivia`], returning the prior setting
+    #[doc(hidden)]
+    #[inline(always)]
+    fn set_trivia(&mut self, enabled: bool, _: sealed::SealedMarker) -> bool {
+        let _ = enabled;
+        false
+    }
 
     /// Return the inner-most stream
     fn raw(&self) -> &dyn crate::lib::std::fmt::Debug;
@@ -548,6 +591,31 @@ pub trait Location {
     fn current_token_start(&self) -> usize;
 }
 
+/// Move to absolute offsets of the input
+///
+/// See [`LocatingSlice`] for adding seeking to your [`Stream`] and
+/// [`at_offset`][crate::binary::at_offset] for parsing at an offset.
+pub trait Seek: Stream {
+    /// Offset of the current position from the start of the input
+    fn seek_offset(&self) -> usize;
+    /// Length of the input from its start
+    fn seek_len(&self) -> usize;
+    /// Move to `offset` from the start of the input
+    ///
+    /// `offset` must be at most [`Seek::seek_len`].
+    fn seek_to(&mut self, offset: usize);
+}
+
+/// Capture the structure of the parsed input
+///
+/// See [`Recording`] for building a [lossless syntax tree][crate::cst] from your [`Stream`]
+pub trait NodeSink<K> {
+    /// Start a node of `kind`, containing the input consumed until [`NodeSink::finish_node`]
+    fn start_node(&mut self, kind: K);
+    /// Finish the most recently started node
+    fn finish_node(&mut self);
+}
+
 /// Capture top-level errors in the middle of parsing so parsing can resume
 ///
 /// See [`Recoverable`] for adding error recovery tracking to your [`Stream`]
@@ -1458,6 +1526,121 @@ where
     }
 }
 
+/// Fallibly accumulate values, like into a fixed-capacity container
+///
+/// Parsers like [`repeat`][crate::combinator::repeat] collect into this, turning a full
+/// accumulator into a parser error.  Implement it for a custom [`Accumulate`] by forwarding to it,
+/// with `Partial = Self`.
+pub trait TryAccumulate<T>: Sized {
+    /// Values accumulated so far
+    type Partial;
+    /// Create a new [`TryAccumulate::Partial`]
+    fn initial(capacity: Option<usize>) -> Self::Partial;
+    /// Accumulate the input into an accumulator, returning the input if there is no room for it
+    fn try_accumulate(partial: &mut Self::Partial, acc: T) -> Result<(), T>;
+    /// Finish accumulating, if there were enough values
+    fn finish(partial: Self::Partial) -> Option<Self>;
+}
+
+/// Forward [`TryAccumulate`] to [`Accumulate`], which is never full
+macro_rules! try_accumulate_with_accumulate {
+    ($acc:ty) => {
+        type Partial = Self;
+        #[inline(always)]
+        fn initial(capacity: Option<usize>) -> Self {
+            <Self as Accumulate<$acc>>::initial(capacity)
+        }
+        #[inline(always)]
+        fn try_accumulate(partial: &mut Self, acc: $acc) -> Result<(), $acc> {
+            <Self as Accumulate<$acc>>::accumulate(partial, acc);
+            Ok(())
+        }
+        #[inline(always)]
+        fn finish(partial: Self) -> Option<Self> {
+            Some(partial)
+        }
+    };
+}
+
+impl<T> TryAccumulate<T> for () {
+    try_accumulate_with_accumulate!(T);
+}
+
+impl<T> TryAccumulate<T> for usize {
+    try_accumulate_with_accumulate!(T);
+}
+
+#[cfg(feature = "alloc")]
+impl<T> TryAccumulate<T> for Vec<T> {
+    try_accumulate_with_accumulate!(T);
+}
+
+#[cfg(feature = "alloc")]
+impl<'i, T: Clone> TryAccumulate<&'i [T]> for Vec<T> {
+    try_accumulate_with_accumulate!(&'i [T]);
+}
+
+#[cfg(feature = "alloc")]
+impl TryAccumulate<char> for String {
+    try_accumulate_with_accumulate!(char);
+}
+
+#[cfg(feature = "alloc")]
+impl<'i> TryAccumulate<&'i str> for String {
+    try_accumulate_with_accumulate!(&'i str);
+}
+
+#[cfg(feature = "alloc")]
+impl<K, V> TryAccumulate<(K, V)> for BTreeMap<K, V>
+where
+    K: crate::lib::std::cmp::Ord,
+{
+    try_accumulate_with_accumulate!((K, V));
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> TryAccumulate<(K, V)> for HashMap<K, V, S>
+where
+    K: crate::lib::std::cmp::Eq + crate::lib::std::hash::Hash,
+    S: BuildHasher + Default,
+{
+    try_accumulate_with_accumulate!((K, V));
+}
+
+#[cfg(feature = "alloc")]
+impl<K> TryAccumulate<K> for BTreeSet<K>
+where
+    K: crate::lib::std::cmp::Ord,
+{
+    try_accumulate_with_accumulate!(K);
+}
+
+#[cfg(feature = "std")]
+impl<K, S> TryAccumulate<K> for HashSet<K, S>
+where
+    K: crate::lib::std::cmp::Eq + crate::lib::std::hash::Hash,
+    S: BuildHasher + Default,
+{
+    try_accumulate_with_accumulate!(K);
+}
+
+/// Requires exactly `N` values
+impl<T, const N: usize> TryAccumulate<T> for [T; N] {
+    type Partial = FixedVec<T, N>;
+    #[inline(always)]
+    fn initial(_capacity: Option<usize>) -> Self::Partial {
+        FixedVec::new()
+    }
+    #[inline(always)]
+    fn try_accumulate(partial: &mut Self::Partial, acc: T) -> Result<(), T> {
+        partial.try_push(acc)
+    }
+    #[inline(always)]
+    fn finish(partial: Self::Partial) -> Option<Self> {
+        partial.into_array().ok()
+    }
+}
+
 #[cfg(feature = "alloc")]
 #[inline]
 pub(crate) fn clamp_capacity<T>(capacity: usize) -> usize {
@@ -2121,3 +2304,7 @@ fn memmem3_(
     }
     None
 }
+
+mod sealed {
+    pub struct SealedMarker;
+}
@@ -1,4 +1,6 @@
 use crate::error::Needed;
+use crate::stream::sealed::SealedMarker;
+use crate::stream::trivia::TriviaError;
 use crate::stream::AsBStr;
 use crate::stream::AsBytes;
 use crate::stream::Checkpoint;
@@ -6,10 +8,12 @@ use crate::stream::Compare;
 use crate::stream::CompareResult;
 use crate::stream::FindSlice;
 use crate::stream::Location;
+use crate::stream::NodeSink;
 use crate::stream::Offset;
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 use crate::stream::Recover;
+use crate::stream::Seek;
 use crate::stream::SliceLen;
 use crate::stream::Stream;
 use crate::stream::StreamIsPartial;
@@ -198,6 +202,19 @@ impl<I: Stream> Stream for Partial<I> {
     fn reset(&mut self, checkpoint: &Self::Checkpoint) {
         self.input.reset(&checkpoint.inner);
     }
+    #[inline(always)]
+    fn taken_since(&mut self, checkpoint: &Self::Checkpoint) -> Self::Slice {
+        self.input.taken_since(&checkpoint.inner)
+    }
+
+    #[inline(always)]
+    fn skip_trivia(&mut self, marker: SealedMarker) -> Result<(), TriviaError> {
+        self.input.skip_trivia(marker)
+    }
+    #[inline(always)]
+    fn set_trivia(&mut self, enabled: bool, marker: SealedMarker) -> bool {
+        self.input.set_trivia(enabled, marker)
+    }
 
     #[inline(always)]
     fn raw(&self) -> &dyn crate::lib::std::fmt::Debug {
@@ -205,6 +222,24 @@ impl<I: Stream> Stream for Partial<I> {
     }
 }
 
+impl<I> Seek for Partial<I>
+where
+    I: Seek,
+{
+    #[inline(always)]
+    fn seek_offset(&self) -> usize {
+        self.input.seek_offset()
+    }
+    #[inline(always)]
+    fn seek_len(&self) -> usize {
+        self.input.seek_len()
+    }
+    #[inline(always)]
+    fn seek_to(&mut self, offset: usize) {
+        self.input.seek_to(offset);
+    }
+}
+
 impl<I> Location for Partial<I>
 where
     I: Location,
@@ -219,6 +254,20 @@ where
     }
 }
 
+impl<I, K> NodeSink<K> for Partial<I>
+where
+    I: NodeSink<K>,
+{
+    #[inline(always)]
+    fn start_node(&mut self, kind: K) {
+        self.input.start_node(kind);
+    }
+    #[inline(always)]
+    fn finish_node(&mut self) {
+        self.input.finish_node();
+    }
+}
+
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 impl<I, E> Recover<E> for Partial<I>
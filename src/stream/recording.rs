@@ -0,0 +1,394 @@
+use crate::cst::Event;
+use crate::error::Needed;
+use crate::lib::std::vec::Vec;
+use crate::stream::sealed::SealedMarker;
+use crate::stream::trivia::TriviaError;
+use crate::stream::AsBStr;
+use crate::stream::AsBytes;
+use crate::stream::Checkpoint;
+use crate::stream::Compare;
+use crate::stream::CompareResult;
+use crate::stream::FindSlice;
+use crate::stream::Location;
+use crate::stream::NodeSink;
+use crate::stream::Offset;
+#[cfg(feature = "unstable-recover")]
+#[cfg(feature = "std")]
+use crate::stream::Recover;
+use crate::stream::Seek;
+use crate::stream::SliceLen;
+use crate::stream::Stream;
+use crate::stream::StreamIsPartial;
+
+/// Record the consumed input and [`Parser::node`][crate::Parser::node]s as [`Event`]s
+///
+/// Every slice of input that is consumed is recorded, including whitespace and comments, so a
+/// [lossless syntax tree][crate::cst] can be built from the events.  Events from parsers that fail
+/// and are backtracked are discarded on [`Stream::reset`], while [`Stream::taken_since`] keeps the
+/// events of the input it returns, like for [`Parser::take`][crate::Parser::take].
+///
+/// To record skipped [`Trivia`][crate::stream::Trivia], wrap the `Recording` in it, rather than
+/// the other way around.
+#[derive(Debug)]
+pub struct Recording<I, K>
+where
+    I: Stream,
+{
+    input: I,
+    events: Vec<Event<K, <I as Stream>::Slice>>,
+}
+
+impl<I, K> Recording<I, K>
+where
+    I: Stream,
+{
+    /// Wrap another Stream, recording its events
+    #[inline]
+    pub fn new(input: I) -> Self {
+        Self {
+            input,
+            events: Vec::new(),
+        }
+    }
+
+    /// The events recorded so far
+    #[inline(always)]
+    pub fn events(&self) -> &[Event<K, <I as Stream>::Slice>] {
+        &self.events
+    }
+
+    /// Access the current input and events
+    #[inline]
+    pub fn into_parts(self) -> (I, Vec<Event<K, <I as Stream>::Slice>>) {
+        (self.input, self.events)
+    }
+}
+
+impl<I, K> Clone for Recording<I, K>
+where
+    I: Stream + Clone,
+    K: Clone,
+    <I as Stream>::Slice: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            input: self.input.clone(),
+            events: self.events.clone(),
+        }
+    }
+}
+
+impl<I, K> AsRef<I> for Recording<I, K>
+where
+    I: Stream,
+{
+    #[inline(always)]
+    fn as_ref(&self) -> &I {
+        &self.input
+    }
+}
+
+impl<I, K> crate::lib::std::ops::Deref for Recording<I, K>
+where
+    I: Stream,
+{
+    type Target = I;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.input
+    }
+}
+
+impl<I: crate::lib::std::fmt::Display, K> crate::lib::std::fmt::Display for Recording<I, K>
+where
+    I: Stream,
+{
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        crate::lib::std::fmt::Display::fmt(&self.input, f)
+    }
+}
+
+impl<I, K> SliceLen for Recording<I, K>
+where
+    I: SliceLen,
+    I: Stream,
+{
+    #[inline(always)]
+    fn slice_len(&self) -> usize {
+        self.input.slice_len()
+    }
+}
+
+impl<I, K: crate::lib::std::fmt::Debug> Stream for Recording<I, K>
+where
+    I: Stream,
+{
+    type Token = <I as Stream>::Token;
+    type Slice = <I as Stream>::Slice;
+
+    type IterOffsets = <I as Stream>::IterOffsets;
+
+    type Checkpoint = Checkpoint<RecordingCheckpoint<I::Checkpoint>, Self>;
+
+    #[inline(always)]
+    fn iter_offsets(&self) -> Self::IterOffsets {
+        self.input.iter_offsets()
+    }
+    #[inline(always)]
+    fn eof_offset(&self) -> usize {
+        self.input.eof_offset()
+    }
+
+    #[inline]
+    fn next_token(&mut self) -> Option<Self::Token> {
+        let offset = self.input.offset_at(1).ok()?;
+        let slice = self.input.peek_slice(offset);
+        let token = self.input.next_token()?;
+        self.events.push(Event::Token(slice));
+        Some(token)
+    }
+
+    #[inline(always)]
+    fn peek_token(&self) -> Option<Self::Token> {
+        self.input.peek_token()
+    }
+
+    #[inline(always)]
+    fn offset_for<P>(&self, predicate: P) -> Option<usize>
+    where
+        P: Fn(Self::Token) -> bool,
+    {
+        self.input.offset_for(predicate)
+    }
+    #[inline(always)]
+    fn offset_at(&self, tokens: usize) -> Result<usize, Needed> {
+        self.input.offset_at(tokens)
+    }
+    #[inline]
+    fn next_slice(&mut self, offset: usize) -> Self::Slice {
+        if offset != 0 {
+            let slice = self.input.peek_slice(offset);
+            self.events.push(Event::Token(slice));
+        }
+        self.input.next_slice(offset)
+    }
+    #[inline(always)]
+    fn peek_slice(&self, offset: usize) -> Self::Slice {
+        self.input.peek_slice(offset)
+    }
+
+    #[inline(always)]
+    fn checkpoint(&self) -> Self::Checkpoint {
+        Checkpoint::<_, Self>::new(RecordingCheckpoint {
+            input: self.input.checkpoint(),
+            events: self.events.len(),
+        })
+    }
+    #[inline(always)]
+    fn reset(&mut self, checkpoint: &Self::Checkpoint) {
+        self.input.reset(&checkpoint.inner.input);
+        self.events.truncate(checkpoint.inner.events);
+    }
+    #[inline(always)]
+    fn taken_since(&mut self, checkpoint: &Self::Checkpoint) -> Self::Slice {
+        // The consumed input was already recorded
+        self.input.taken_since(&checkpoint.inner.input)
+    }
+
+    #[inline(always)]
+    fn skip_trivia(&mut self, marker: SealedMarker) -> Result<(), TriviaError> {
+        self.input.skip_trivia(marker)
+    }
+    #[inline(always)]
+    fn set_trivia(&mut self, enabled: bool, marker: SealedMarker) -> bool {
+        self.input.set_trivia(enabled, marker)
+    }
+
+    #[inline(always)]
+    fn raw(&self) -> &dyn crate::lib::std::fmt::Debug {
+        &self.input
+    }
+}
+
+/// Position within a [`Recording`], see [`Stream::checkpoint`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RecordingCheckpoint<C> {
+    input: C,
+    events: usize,
+}
+
+impl<C: Offset> Offset for RecordingCheckpoint<C> {
+    #[inline(always)]
+    fn offset_from(&self, start: &Self) -> usize {
+        self.input.offset_from(&start.input)
+    }
+}
+
+impl<I, K> Seek for Recording<I, K>
+where
+    I: Seek,
+    K: crate::lib::std::fmt::Debug,
+{
+    #[inline(always)]
+    fn seek_offset(&self) -> usize {
+        self.input.seek_offset()
+    }
+    #[inline(always)]
+    fn seek_len(&self) -> usize {
+        self.input.seek_len()
+    }
+    #[inline(always)]
+    fn seek_to(&mut self, offset: usize) {
+        self.input.seek_to(offset);
+    }
+}
+
+impl<I, K> NodeSink<K> for Recording<I, K>
+where
+    I: Stream,
+{
+    #[inline]
+    fn start_node(&mut self, kind: K) {
+        self.events.push(Event::Start(kind));
+    }
+    #[inline]
+    fn finish_node(&mut self) {
+        self.events.push(Event::Finish);
+    }
+}
+
+impl<I, K> Location for Recording<I, K>
+where
+    I: Location,
+    I: Stream,
+{
+    #[inline(always)]
+    fn previous_token_end(&self) -> usize {
+        self.input.previous_token_end()
+    }
+    #[inline(always)]
+    fn current_token_start(&self) -> usize {
+        self.input.current_token_start()
+    }
+}
+
+#[cfg(feature = "unstable-recover")]
+#[cfg(feature = "std")]
+impl<I, K, E> Recover<E> for Recording<I, K>
+where
+    I: Recover<E>,
+    I: Stream,
+    K: crate::lib::std::fmt::Debug,
+{
+    #[inline(always)]
+    fn record_err(
+        &mut self,
+        _token_start: &Self::Checkpoint,
+        _err_start: &Self::Checkpoint,
+        err: E,
+    ) -> Result<(), E> {
+        Err(err)
+    }
+
+    /// Report whether the [`Stream`] can save off errors for recovery
+    #[inline(always)]
+    fn is_recovery_supported() -> bool {
+        false
+    }
+}
+
+impl<I, K> StreamIsPartial for Recording<I, K>
+where
+    I: StreamIsPartial,
+    I: Stream,
+{
+    type PartialState = I::PartialState;
+
+    #[inline]
+    fn complete(&mut self) -> Self::PartialState {
+        self.input.complete()
+    }
+
+    #[inline]
+    fn restore_partial(&mut self, state: Self::PartialState) {
+        self.input.restore_partial(state);
+    }
+
+    #[inline(always)]
+    fn is_partial_supported() -> bool {
+        I::is_partial_supported()
+    }
+
+    #[inline(always)]
+    fn is_partial(&self) -> bool {
+        self.input.is_partial()
+    }
+}
+
+impl<I, K> Offset for Recording<I, K>
+where
+    I: Stream,
+    K: crate::lib::std::fmt::Debug,
+{
+    #[inline(always)]
+    fn offset_from(&self, other: &Self) -> usize {
+        self.offset_from(&other.checkpoint())
+    }
+}
+
+impl<I, K> Offset<<Recording<I, K> as Stream>::Checkpoint> for Recording<I, K>
+where
+    I: Stream,
+    K: crate::lib::std::fmt::Debug,
+{
+    #[inline(always)]
+    fn offset_from(&self, other: &<Recording<I, K> as Stream>::Checkpoint) -> usize {
+        self.checkpoint().offset_from(other)
+    }
+}
+
+impl<I, K> AsBytes for Recording<I, K>
+where
+    I: Stream,
+    I: AsBytes,
+{
+    #[inline(always)]
+    fn as_bytes(&self) -> &[u8] {
+        self.input.as_bytes()
+    }
+}
+
+impl<I, K> AsBStr for Recording<I, K>
+where
+    I: Stream,
+    I: AsBStr,
+{
+    #[inline(always)]
+    fn as_bstr(&self) -> &[u8] {
+        self.input.as_bstr()
+    }
+}
+
+impl<I, K, U> Compare<U> for Recording<I, K>
+where
+    I: Stream,
+    I: Compare<U>,
+{
+    #[inline(always)]
+    fn compare(&self, other: U) -> CompareResult {
+        self.input.compare(other)
+    }
+}
+
+impl<I, K, T> FindSlice<T> for Recording<I, K>
+where
+    I: Stream,
+    I: FindSlice<T>,
+{
+    #[inline(always)]
+    fn find_slice(&self, substr: T) -> Option<crate::lib::std::ops::Range<usize>> {
+        self.input.find_slice(substr)
+    }
+}
@@ -1,5 +1,7 @@
 use crate::error::FromRecoverableError;
 use crate::error::Needed;
+use crate::stream::sealed::SealedMarker;
+use crate::stream::trivia::TriviaError;
 use crate::stream::AsBStr;
 use crate::stream::AsBytes;
 use crate::stream::Checkpoint;
@@ -7,10 +9,12 @@ use crate::stream::Compare;
 use crate::stream::CompareResult;
 use crate::stream::FindSlice;
 use crate::stream::Location;
+use crate::stream::NodeSink;
 use crate::stream::Offset;
 #[cfg(feature = "unstable-recover")]
 #[cfg(feature = "std")]
 use crate::stream::Recover;
+use crate::stream::Seek;
 use crate::stream::SliceLen;
 use crate::stream::Stream;
 use crate::stream::StreamIsPartial;
@@ -171,6 +175,19 @@ where
     fn reset(&mut self, checkpoint: &Self::Checkpoint) {
         self.input.reset(&checkpoint.inner);
     }
+    #[inline(always)]
+    fn taken_since(&mut self, checkpoint: &Self::Checkpoint) -> Self::Slice {
+        self.input.taken_since(&checkpoint.inner)
+    }
+
+    #[inline(always)]
+    fn skip_trivia(&mut self, marker: SealedMarker) -> Result<(), TriviaError> {
+        self.input.skip_trivia(marker)
+    }
+    #[inline(always)]
+    fn set_trivia(&mut self, enabled: bool, marker: SealedMarker) -> bool {
+        self.input.set_trivia(enabled, marker)
+    }
 
     #[inline(always)]
     fn raw(&self) -> &dyn crate::lib::std::fmt::Debug {
@@ -178,6 +195,25 @@ where
     }
 }
 
+impl<I, E> Seek for Recoverable<I, E>
+where
+    I: Seek,
+    E: crate::lib::std::fmt::Debug,
+{
+    #[inline(always)]
+    fn seek_offset(&self) -> usize {
+        self.input.seek_offset()
+    }
+    #[inline(always)]
+    fn seek_len(&self) -> usize {
+        self.input.seek_len()
+    }
+    #[inline(always)]
+    fn seek_to(&mut self, offset: usize) {
+        self.input.seek_to(offset);
+    }
+}
+
 impl<I, E> Location for Recoverable<I, E>
 where
     I: Location,
@@ -193,6 +229,21 @@ where
     }
 }
 
+impl<I, E, K> NodeSink<K> for Recoverable<I, E>
+where
+    I: NodeSink<K>,
+    I: Stream,
+{
+    #[inline(always)]
+    fn start_node(&mut self, kind: K) {
+        self.input.start_node(kind);
+    }
+    #[inline(always)]
+    fn finish_node(&mut self) {
+        self.input.finish_node();
+    }
+}
+
 impl<I, E, R> Recover<E> for Recoverable<I, R>
 where
     I: Stream,
@@ -273,3 +273,108 @@ fn tokenslice_location() {
 
     assert_eq!(input.current_token_start(), 29);
 }
+
+#[test]
+fn trivia_tokens() {
+    use crate::ascii::{dec_int, float, multispace1};
+    use crate::combinator::{alt, eof, lexeme, repeat};
+    use crate::token::take_till;
+
+    fn trivia(input: &mut &str) -> ModalResult<()> {
+        repeat(
+            0..,
+            alt((multispace1.void(), ("#", take_till(0.., '\n')).void())),
+        )
+        .parse_next(input)
+    }
+
+    fn string<'i>(input: &mut Trivia<&'i str>) -> ModalResult<&'i str> {
+        lexeme(('"', take_till(0.., '"'), '"').take()).parse_next(input)
+    }
+
+    let input = Trivia::new(" -12 # comment\n 1.5 \" a b \" ", trivia);
+    let parsed = (dec_int::<_, i32, _>, float::<_, f64, _>, string, eof).parse(input);
+    assert_eq!(parsed.unwrap(), (-12, 1.5, "\" a b \"", ""));
+
+    // Composite tokens don't skip trivia within them
+    let input = Trivia::new("- 12", trivia);
+    assert!(dec_int::<_, i32, crate::error::ContextError>
+        .parse_peek(input)
+        .is_err());
+    let input = Trivia::new(" 1. 5", trivia);
+    let (rest, parsed) = float::<_, f64, crate::error::ContextError>
+        .parse_peek(input)
+        .unwrap();
+    assert_eq!(parsed, 1.0);
+    assert_eq!(rest.into_inner(), " 5");
+}
+
+#[test]
+fn trivia_span() {
+    use crate::ascii::{alpha1, multispace0};
+
+    fn trivia(input: &mut LocatingSlice<&str>) -> ModalResult<()> {
+        multispace0.void().parse_next(input)
+    }
+
+    let input = Trivia::new(LocatingSlice::new("  ab  cd"), trivia);
+    let parsed = (alpha1.span(), alpha1.span())
+        .parse_peek(input)
+        .map_err(|_: ErrMode<InputError<_>>| ())
+        .unwrap()
+        .1;
+    assert_eq!(parsed, (2..4, 6..8));
+}
+
+#[test]
+fn trivia_errors() {
+    use crate::ascii::multispace0;
+    use crate::combinator::{cut_err, preceded};
+    use crate::error::{ContextError, Needed};
+
+    fn spaces(input: &mut Partial<&str>) -> Result<(), ErrMode<ContextError>> {
+        multispace0.void().parse_next(input)
+    }
+
+    // Needing more input to finish the trivia is reported
+    let input = Trivia::new(Partial::new("x  "), spaces);
+    assert_eq!(
+        ("x", "y")
+            .parse_peek(input)
+            .map_err(|e: ErrMode<ContextError>| e),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+
+    fn comment(input: &mut &str) -> Result<(), ErrMode<ContextError>> {
+        preceded("/*", cut_err(("*/", multispace0)))
+            .void()
+            .parse_next(input)
+    }
+
+    // Backtracking is having no trivia, but cutting isn't
+    let input = Trivia::new("x", comment);
+    assert!("x"
+        .parse_peek(input)
+        .map_err(|_: ErrMode<ContextError>| ())
+        .is_ok());
+    let input = Trivia::new("/* x", comment);
+    assert!(matches!(
+        "x".parse_peek(input),
+        Err(ErrMode::<ContextError>::Cut(_))
+    ));
+}
+
+#[test]
+fn trivia_checkpoint() {
+    use crate::ascii::multispace0;
+
+    fn trivia(input: &mut &str) -> ModalResult<()> {
+        multispace0.void().parse_next(input)
+    }
+
+    let mut input = Trivia::new(" a", trivia);
+    let start = input.checkpoint();
+    assert!(set_trivia(&mut input, false));
+    input.reset(&start);
+    assert!(set_trivia(&mut input, true));
+}
@@ -0,0 +1,463 @@
+use crate::error::ContextError;
+use crate::error::ErrMode;
+use crate::error::Needed;
+use crate::error::ParserError;
+use crate::stream::sealed::SealedMarker;
+use crate::stream::AsBStr;
+use crate::stream::AsBytes;
+use crate::stream::Checkpoint;
+use crate::stream::Compare;
+use crate::stream::CompareResult;
+use crate::stream::FindSlice;
+use crate::stream::Location;
+use crate::stream::NodeSink;
+use crate::stream::Offset;
+#[cfg(feature = "unstable-recover")]
+#[cfg(feature = "std")]
+use crate::stream::Recover;
+use crate::stream::Seek;
+use crate::stream::SliceLen;
+use crate::stream::Stream;
+use crate::stream::StreamIsPartial;
+use crate::stream::UpdateSlice;
+
+/// Skip insignificant input, like whitespace and comments, before each token
+///
+/// Token parsers, like [`literal`][crate::token::literal], [`one_of`][crate::token::one_of],
+/// [`take_while`][crate::token::take_while] and the [`ascii`][crate::ascii] number parsers, run the
+/// `trivia` parser before matching.  [`Parser::span`][crate::Parser::span] excludes the skipped
+/// trivia when the wrapped stream is a [`Location`], like
+/// [`LocatingSlice`][crate::stream::LocatingSlice].
+///
+/// Parsers built out of several tokens, like identifiers or string literals, need to be wrapped
+/// in [`lexeme`][crate::combinator::lexeme] so trivia is only skipped before them, and not within
+/// them.
+///
+/// `trivia` backtracking is treated as there being nothing to skip.  Otherwise, like when it needs
+/// more of a [`Partial`][crate::Partial] input or [cuts][crate::combinator::cut_err], the token
+/// parser fails the same way.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::ascii::{dec_uint, multispace1, till_line_ending};
+/// use winnow::combinator::{alt, delimited, lexeme, repeat, separated};
+/// use winnow::stream::Trivia;
+/// use winnow::token::take_till;
+///
+/// fn trivia(input: &mut &str) -> ModalResult<()> {
+///     repeat(0.., alt((multispace1.void(), ("//", till_line_ending).void()))).parse_next(input)
+/// }
+///
+/// fn string<'i>(input: &mut Trivia<&'i str>) -> ModalResult<&'i str> {
+///     lexeme(delimited('"', take_till(0.., '"'), '"')).parse_next(input)
+/// }
+///
+/// fn list<'i>(input: &mut Trivia<&'i str>) -> ModalResult<(Vec<u32>, &'i str)> {
+///     delimited('[', (separated(0.., dec_uint::<_, u32, _>, ','), string), ']').parse_next(input)
+/// }
+///
+/// let input = Trivia::new("[ 1, // one\n 2 \" a b \" ]", trivia);
+/// assert_eq!(list.parse(input).unwrap(), (vec![1, 2], " a b "));
+/// ```
+pub struct Trivia<I, E = ErrMode<ContextError>> {
+    input: I,
+    trivia: fn(&mut I) -> Result<(), E>,
+    enabled: bool,
+}
+
+impl<I, E> Trivia<I, E> {
+    /// Wrap another Stream, skipping `trivia` before each token
+    #[inline]
+    pub fn new(input: I, trivia: fn(&mut I) -> Result<(), E>) -> Self {
+        Self {
+            input,
+            trivia,
+            enabled: true,
+        }
+    }
+
+    /// Get the underlying input
+    #[inline(always)]
+    pub fn into_inner(self) -> I {
+        self.input
+    }
+}
+
+impl<I: Clone, E> Clone for Trivia<I, E> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            input: self.input.clone(),
+            trivia: self.trivia,
+            enabled: self.enabled,
+        }
+    }
+}
+
+impl<I: Copy, E> Copy for Trivia<I, E> {}
+
+impl<I, E> AsRef<I> for Trivia<I, E> {
+    #[inline(always)]
+    fn as_ref(&self) -> &I {
+        &self.input
+    }
+}
+
+impl<I, E> crate::lib::std::ops::Deref for Trivia<I, E> {
+    type Target = I;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.input
+    }
+}
+
+impl<I: crate::lib::std::fmt::Debug, E> crate::lib::std::fmt::Debug for Trivia<I, E> {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        f.debug_struct("Trivia")
+            .field("input", &self.input)
+            .field("enabled", &self.enabled)
+            .finish()
+    }
+}
+
+impl<I: PartialEq, E> PartialEq for Trivia<I, E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.input == other.input && self.enabled == other.enabled
+    }
+}
+
+impl<I: Eq, E> Eq for Trivia<I, E> {}
+
+impl<I: crate::lib::std::fmt::Display, E> crate::lib::std::fmt::Display for Trivia<I, E> {
+    fn fmt(&self, f: &mut crate::lib::std::fmt::Formatter<'_>) -> crate::lib::std::fmt::Result {
+        self.input.fmt(f)
+    }
+}
+
+impl<I, E> SliceLen for Trivia<I, E>
+where
+    I: SliceLen,
+{
+    #[inline(always)]
+    fn slice_len(&self) -> usize {
+        self.input.slice_len()
+    }
+}
+
+impl<I: Stream, E: ParserError<I>> Stream for Trivia<I, E> {
+    type Token = <I as Stream>::Token;
+    type Slice = <I as Stream>::Slice;
+
+    type IterOffsets = <I as Stream>::IterOffsets;
+
+    type Checkpoint = Checkpoint<TriviaCheckpoint<I::Checkpoint>, Self>;
+
+    #[inline(always)]
+    fn iter_offsets(&self) -> Self::IterOffsets {
+        self.input.iter_offsets()
+    }
+    #[inline(always)]
+    fn eof_offset(&self) -> usize {
+        self.input.eof_offset()
+    }
+
+    #[inline(always)]
+    fn next_token(&mut self) -> Option<Self::Token> {
+        self.input.next_token()
+    }
+
+    #[inline(always)]
+    fn peek_token(&self) -> Option<Self::Token> {
+        self.input.peek_token()
+    }
+
+    #[inline(always)]
+    fn offset_for<P>(&self, predicate: P) -> Option<usize>
+    where
+        P: Fn(Self::Token) -> bool,
+    {
+        self.input.offset_for(predicate)
+    }
+    #[inline(always)]
+    fn offset_at(&self, tokens: usize) -> Result<usize, Needed> {
+        self.input.offset_at(tokens)
+    }
+    #[inline(always)]
+    fn next_slice(&mut self, offset: usize) -> Self::Slice {
+        self.input.next_slice(offset)
+    }
+    #[inline(always)]
+    fn peek_slice(&self, offset: usize) -> Self::Slice {
+        self.input.peek_slice(offset)
+    }
+
+    #[inline(always)]
+    fn checkpoint(&self) -> Self::Checkpoint {
+        Checkpoint::<_, Self>::new(TriviaCheckpoint {
+            input: self.input.checkpoint(),
+            enabled: self.enabled,
+        })
+    }
+    #[inline(always)]
+    fn reset(&mut self, checkpoint: &Self::Checkpoint) {
+        self.input.reset(&checkpoint.inner.input);
+        self.enabled = checkpoint.inner.enabled;
+    }
+    #[inline(always)]
+    fn taken_since(&mut self, checkpoint: &Self::Checkpoint) -> Self::Slice {
+        self.input.taken_since(&checkpoint.inner.input)
+    }
+
+    #[inline]
+    fn skip_trivia(&mut self, _: SealedMarker) -> Result<(), TriviaError> {
+        if !self.enabled {
+            return Ok(());
+        }
+        let start = self.input.checkpoint();
+        match (self.trivia)(&mut self.input) {
+            Ok(()) => Ok(()),
+            Err(err) if err.is_backtrack() => {
+                self.input.reset(&start);
+                Ok(())
+            }
+            Err(err) => match err.needed() {
+                Some(needed) => Err(TriviaError::Incomplete(needed)),
+                None => Err(TriviaError::Cut),
+            },
+        }
+    }
+    #[inline(always)]
+    fn set_trivia(&mut self, enabled: bool, _: SealedMarker) -> bool {
+        crate::lib::std::mem::replace(&mut self.enabled, enabled)
+    }
+
+    #[inline(always)]
+    fn raw(&self) -> &dyn crate::lib::std::fmt::Debug {
+        &self.input
+    }
+}
+
+/// Position within a [`Trivia`], see [`Stream::checkpoint`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TriviaCheckpoint<C> {
+    input: C,
+    enabled: bool,
+}
+
+impl<C: Offset> Offset for TriviaCheckpoint<C> {
+    #[inline(always)]
+    fn offset_from(&self, start: &Self) -> usize {
+        self.input.offset_from(&start.input)
+    }
+}
+
+/// Failure to skip [`Trivia`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TriviaError {
+    /// The trivia parser needs more of a [`Partial`][crate::Partial] input
+    Incomplete(Needed),
+    /// The trivia parser failed without backtracking
+    Cut,
+}
+
+/// Skip [`Trivia`] ahead of a token, reporting its failure as `E`
+///
+/// Failing to parse trivia is treated as there being none, unless the trivia parser needs more
+/// input or can't backtrack.
+#[inline(always)]
+pub(crate) fn skip_trivia<I, E>(input: &mut I) -> Result<(), E>
+where
+    I: Stream,
+    E: ParserError<I>,
+{
+    match input.skip_trivia(SealedMarker) {
+        Ok(()) => Ok(()),
+        Err(TriviaError::Incomplete(needed)) => Err(E::incomplete(input, needed)),
+        Err(TriviaError::Cut) => Err(E::cut_from_input(input)),
+    }
+}
+
+/// Enable or disable skipping [`Trivia`], returning the prior setting
+#[inline(always)]
+pub(crate) fn set_trivia<I: Stream>(input: &mut I, enabled: bool) -> bool {
+    input.set_trivia(enabled, SealedMarker)
+}
+
+impl<I, E> Seek for Trivia<I, E>
+where
+    I: Seek,
+    E: ParserError<I>,
+{
+    #[inline(always)]
+    fn seek_offset(&self) -> usize {
+        self.input.seek_offset()
+    }
+    #[inline(always)]
+    fn seek_len(&self) -> usize {
+        self.input.seek_len()
+    }
+    #[inline(always)]
+    fn seek_to(&mut self, offset: usize) {
+        self.input.seek_to(offset);
+    }
+}
+
+impl<I, E> Location for Trivia<I, E>
+where
+    I: Location + Stream + Clone,
+    E: ParserError<I>,
+{
+    #[inline(always)]
+    fn previous_token_end(&self) -> usize {
+        self.input.previous_token_end()
+    }
+    #[inline]
+    fn current_token_start(&self) -> usize {
+        let mut input = self.clone();
+        let _ = input.skip_trivia(SealedMarker);
+        input.input.current_token_start()
+    }
+}
+
+impl<I, E, K> NodeSink<K> for Trivia<I, E>
+where
+    I: NodeSink<K>,
+{
+    #[inline(always)]
+    fn start_node(&mut self, kind: K) {
+        self.input.start_node(kind);
+    }
+    #[inline(always)]
+    fn finish_node(&mut self) {
+        self.input.finish_node();
+    }
+}
+
+#[cfg(feature = "unstable-recover")]
+#[cfg(feature = "std")]
+impl<I, E, R> Recover<R> for Trivia<I, E>
+where
+    I: Recover<R>,
+    I: Stream,
+    E: ParserError<I>,
+{
+    #[inline(always)]
+    fn record_err(
+        &mut self,
+        _token_start: &Self::Checkpoint,
+        _err_start: &Self::Checkpoint,
+        err: R,
+    ) -> Result<(), R> {
+        Err(err)
+    }
+
+    /// Report whether the [`Stream`] can save off errors for recovery
+    #[inline(always)]
+    fn is_recovery_supported() -> bool {
+        false
+    }
+}
+
+impl<I, E> StreamIsPartial for Trivia<I, E>
+where
+    I: StreamIsPartial,
+{
+    type PartialState = I::PartialState;
+
+    #[inline]
+    fn complete(&mut self) -> Self::PartialState {
+        self.input.complete()
+    }
+
+    #[inline]
+    fn restore_partial(&mut self, state: Self::PartialState) {
+        self.input.restore_partial(state);
+    }
+
+    #[inline(always)]
+    fn is_partial_supported() -> bool {
+        I::is_partial_supported()
+    }
+
+    #[inline(always)]
+    fn is_partial(&self) -> bool {
+        self.input.is_partial()
+    }
+}
+
+impl<I, E> Offset for Trivia<I, E>
+where
+    I: Stream,
+    E: ParserError<I>,
+{
+    #[inline(always)]
+    fn offset_from(&self, other: &Self) -> usize {
+        self.offset_from(&other.checkpoint())
+    }
+}
+
+impl<I, E> Offset<<Trivia<I, E> as Stream>::Checkpoint> for Trivia<I, E>
+where
+    I: Stream,
+    E: ParserError<I>,
+{
+    #[inline(always)]
+    fn offset_from(&self, other: &<Trivia<I, E> as Stream>::Checkpoint) -> usize {
+        self.checkpoint().offset_from(other)
+    }
+}
+
+impl<I, E> AsBytes for Trivia<I, E>
+where
+    I: AsBytes,
+{
+    #[inline(always)]
+    fn as_bytes(&self) -> &[u8] {
+        self.input.as_bytes()
+    }
+}
+
+impl<I, E> AsBStr for Trivia<I, E>
+where
+    I: AsBStr,
+{
+    #[inline(always)]
+    fn as_bstr(&self) -> &[u8] {
+        self.input.as_bstr()
+    }
+}
+
+impl<I, E, U> Compare<U> for Trivia<I, E>
+where
+    I: Compare<U>,
+{
+    #[inline(always)]
+    fn compare(&self, other: U) -> CompareResult {
+        self.input.compare(other)
+    }
+}
+
+impl<I, E, T> FindSlice<T> for Trivia<I, E>
+where
+    I: FindSlice<T>,
+{
+    #[inline(always)]
+    fn find_slice(&self, substr: T) -> Option<crate::lib::std::ops::Range<usize>> {
+        self.input.find_slice(substr)
+    }
+}
+
+impl<I, E> UpdateSlice for Trivia<I, E>
+where
+    I: UpdateSlice,
+    E: ParserError<I>,
+{
+    #[inline(always)]
+    fn update_slice(mut self, inner: Self::Slice) -> Self {
+        self.input = I::update_slice(self.input, inner);
+        self
+    }
+}
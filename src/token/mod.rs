@@ -1,9 +1,16 @@
 //! Parsers extracting tokens from the stream
 
+#[cfg(feature = "alloc")]
+mod regex;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "alloc")]
+pub use regex::{Regex, RegexError};
+
 use crate::combinator::trace;
+use crate::combinator::trace_call;
+use crate::combinator::CallCount;
 use crate::combinator::DisplayDebug;
 use crate::error::Needed;
 use crate::error::ParserError;
@@ -59,6 +66,7 @@ where
     Error: ParserError<Input>,
 {
     trace("any", move |input: &mut Input| {
+        crate::stream::skip_trivia(input)?;
         if <Input as StreamIsPartial>::is_partial_supported() {
             any_::<_, _, true>(input)
         } else {
@@ -157,22 +165,101 @@ where
 #[doc(alias = "tag")]
 #[doc(alias = "bytes")]
 #[doc(alias = "just")]
-pub fn literal<Literal, Input, Error>(
-    literal: Literal,
-) -> impl Parser<Input, <Input as Stream>::Slice, Error>
+pub fn literal<L, Input, Error>(
+    literal: L,
+) -> introspectable!(Literal<L, Input, Error>, impl Parser<Input, <Input as Stream>::Slice, Error>)
 where
-    Input: StreamIsPartial + Stream + Compare<Literal>,
-    Literal: Clone + crate::lib::std::fmt::Debug,
+    Input: StreamIsPartial + Stream + Compare<L>,
+    L: Clone + crate::lib::std::fmt::Debug,
     Error: ParserError<Input>,
 {
-    trace(DisplayDebug(literal.clone()), move |i: &mut Input| {
-        let t = literal.clone();
-        if <Input as StreamIsPartial>::is_partial_supported() {
-            literal_::<_, _, _, true>(i, t)
-        } else {
-            literal_::<_, _, _, false>(i, t)
-        }
-    })
+    Literal {
+        literal,
+        call_count: Default::default(),
+        i: Default::default(),
+        e: Default::default(),
+    }
+}
+
+/// [`Parser`] implementation for [`literal`]
+pub struct Literal<L, I, E>
+where
+    I: StreamIsPartial + Stream + Compare<L>,
+    L: Clone + crate::lib::std::fmt::Debug,
+    E: ParserError<I>,
+{
+    literal: L,
+    call_count: CallCount,
+    i: core::marker::PhantomData<I>,
+    e: core::marker::PhantomData<E>,
+}
+
+impl<L, I, E> Parser<I, <I as Stream>::Slice, E> for Literal<L, I, E>
+where
+    I: StreamIsPartial + Stream + Compare<L>,
+    L: Clone + crate::lib::std::fmt::Debug,
+    E: ParserError<I>,
+{
+    #[inline(always)]
+    fn parse_next(&mut self, input: &mut I) -> Result<<I as Stream>::Slice, E> {
+        trace_call(
+            DisplayDebug(self.literal.clone()),
+            &mut self.call_count,
+            |i: &mut I| {
+                crate::stream::skip_trivia(i)?;
+                let t = self.literal.clone();
+                if <I as StreamIsPartial>::is_partial_supported() {
+                    literal_::<_, _, _, true>(i, t)
+                } else {
+                    literal_::<_, _, _, false>(i, t)
+                }
+            },
+            input,
+        )
+    }
+}
+
+#[cfg(feature = "unstable-grammar")]
+impl<L, I, E> crate::describe::Describe for Literal<L, I, E>
+where
+    I: StreamIsPartial + Stream + Compare<L>,
+    L: Clone + crate::lib::std::fmt::Debug + crate::describe::Describe,
+    E: ParserError<I>,
+{
+    fn describe(&self, grammar: &mut crate::describe::Grammar) -> crate::describe::Expr {
+        self.literal.describe(grammar)
+    }
+}
+
+#[cfg(feature = "unstable-print")]
+impl<L, I, E> crate::print::PrintConst for Literal<L, I, E>
+where
+    I: StreamIsPartial + Stream + Compare<L>,
+    L: Clone + crate::lib::std::fmt::Debug + crate::print::PrintConst,
+    E: ParserError<I>,
+{
+    fn print_const(
+        &self,
+        sink: &mut dyn crate::print::Sink,
+    ) -> Result<(), crate::print::PrintError> {
+        crate::print::PrintConst::print_const(&self.literal, sink)
+    }
+}
+
+#[cfg(feature = "unstable-print")]
+impl<L, I, E, V: ?Sized> crate::print::Print<V> for Literal<L, I, E>
+where
+    I: StreamIsPartial + Stream + Compare<L>,
+    L: Clone + crate::lib::std::fmt::Debug + crate::print::PrintConst,
+    E: ParserError<I>,
+{
+    fn print(
+        &self,
+        _value: &V,
+        sink: &mut dyn crate::print::Sink,
+    ) -> Result<(), crate::print::PrintError> {
+        crate::print::PrintConst::print_const(&self.literal, sink)
+    }
 }
 
 fn literal_<T, I, Error: ParserError<I>, const PARTIAL: bool>(
@@ -259,17 +346,89 @@ where
 #[doc(alias = "char")]
 #[doc(alias = "token")]
 #[doc(alias = "satisfy")]
-pub fn one_of<Input, Set, Error>(set: Set) -> impl Parser<Input, <Input as Stream>::Token, Error>
+pub fn one_of<Input, Set, Error>(
+    set: Set,
+) -> introspectable!(OneOf<Set, Input, Error>, impl Parser<Input, <Input as Stream>::Token, Error>)
 where
     Input: StreamIsPartial + Stream,
     <Input as Stream>::Token: Clone,
     Set: ContainsToken<<Input as Stream>::Token>,
     Error: ParserError<Input>,
 {
-    trace(
-        "one_of",
-        any.verify(move |t: &<Input as Stream>::Token| set.contains_token(t.clone())),
-    )
+    OneOf {
+        set,
+        call_count: Default::default(),
+        i: Default::default(),
+        e: Default::default(),
+    }
+}
+
+/// [`Parser`] implementation for [`one_of`]
+pub struct OneOf<S, I, E>
+where
+    I: StreamIsPartial + Stream,
+    <I as Stream>::Token: Clone,
+    S: ContainsToken<<I as Stream>::Token>,
+    E: ParserError<I>,
+{
+    set: S,
+    call_count: CallCount,
+    i: core::marker::PhantomData<I>,
+    e: core::marker::PhantomData<E>,
+}
+
+impl<S, I, E> Parser<I, <I as Stream>::Token, E> for OneOf<S, I, E>
+where
+    I: StreamIsPartial + Stream,
+    <I as Stream>::Token: Clone,
+    S: ContainsToken<<I as Stream>::Token>,
+    E: ParserError<I>,
+{
+    #[inline(always)]
+    fn parse_next(&mut self, input: &mut I) -> Result<<I as Stream>::Token, E> {
+        let set = &self.set;
+        trace_call(
+            "one_of",
+            &mut self.call_count,
+            any.verify(move |t: &<I as Stream>::Token| set.contains_token(t.clone())),
+            input,
+        )
+    }
+}
+
+#[cfg(feature = "unstable-grammar")]
+impl<S, I, E> crate::describe::Describe for OneOf<S, I, E>
+where
+    I: StreamIsPartial + Stream,
+    <I as Stream>::Token: Clone,
+    S: ContainsToken<<I as Stream>::Token> + crate::describe::DescribeTokens,
+    E: ParserError<I>,
+{
+    fn describe(&self, _grammar: &mut crate::describe::Grammar) -> crate::describe::Expr {
+        let mut class = crate::lib::std::vec::Vec::new();
+        self.set.describe_tokens(&mut class);
+        crate::describe::Expr::Class(class)
+    }
+}
+
+#[cfg(feature = "unstable-print")]
+impl<S, I, E> crate::print::Print<<I as Stream>::Token> for OneOf<S, I, E>
+where
+    I: StreamIsPartial + Stream,
+    <I as Stream>::Token: Clone + crate::print::PrintConst,
+    S: ContainsToken<<I as Stream>::Token>,
+    E: ParserError<I>,
+{
+    fn print(
+        &self,
+        value: &<I as Stream>::Token,
+        sink: &mut dyn crate::print::Sink,
+    ) -> Result<(), crate::print::PrintError> {
+        if !self.set.contains_token(value.clone()) {
+            return Err(crate::print::PrintError::Unprintable);
+        }
+        crate::print::PrintConst::print_const(value, sink)
+    }
 }
 
 /// Recognize a token that does not match a [set of tokens][ContainsToken]
@@ -478,42 +637,124 @@ where
 pub fn take_while<Set, Input, Error>(
     occurrences: impl Into<Range>,
     set: Set,
-) -> impl Parser<Input, <Input as Stream>::Slice, Error>
+) -> introspectable!(
+       TakeWhile<Set, Input, Error>,
+       impl Parser<Input, <Input as Stream>::Slice, Error>
+   )
 where
     Input: StreamIsPartial + Stream,
     Set: ContainsToken<<Input as Stream>::Token>,
     Error: ParserError<Input>,
 {
-    let Range {
-        start_inclusive,
-        end_inclusive,
-    } = occurrences.into();
-    trace("take_while", move |i: &mut Input| {
-        match (start_inclusive, end_inclusive) {
-            (0, None) => {
-                if <Input as StreamIsPartial>::is_partial_supported() {
-                    take_till0::<_, _, _, true>(i, |c| !set.contains_token(c))
-                } else {
-                    take_till0::<_, _, _, false>(i, |c| !set.contains_token(c))
-                }
-            }
-            (1, None) => {
-                if <Input as StreamIsPartial>::is_partial_supported() {
-                    take_till1::<_, _, _, true>(i, |c| !set.contains_token(c))
-                } else {
-                    take_till1::<_, _, _, false>(i, |c| !set.contains_token(c))
-                }
-            }
-            (start, end) => {
-                let end = end.unwrap_or(usize::MAX);
-                if <Input as StreamIsPartial>::is_partial_supported() {
-                    take_till_m_n::<_, _, _, true>(i, start, end, |c| !set.contains_token(c))
-                } else {
-                    take_till_m_n::<_, _, _, false>(i, start, end, |c| !set.contains_token(c))
+    TakeWhile {
+        occurrences: occurrences.into(),
+        set,
+        call_count: Default::default(),
+        i: Default::default(),
+        e: Default::default(),
+    }
+}
+
+/// [`Parser`] implementation for [`take_while`]
+pub struct TakeWhile<S, I, E>
+where
+    I: StreamIsPartial + Stream,
+    S: ContainsToken<<I as Stream>::Token>,
+    E: ParserError<I>,
+{
+    occurrences: Range,
+    set: S,
+    call_count: CallCount,
+    i: core::marker::PhantomData<I>,
+    e: core::marker::PhantomData<E>,
+}
+
+impl<S, I, E> Parser<I, <I as Stream>::Slice, E> for TakeWhile<S, I, E>
+where
+    I: StreamIsPartial + Stream,
+    S: ContainsToken<<I as Stream>::Token>,
+    E: ParserError<I>,
+{
+    #[inline(always)]
+    fn parse_next(&mut self, input: &mut I) -> Result<<I as Stream>::Slice, E> {
+        let Range {
+            start_inclusive,
+            end_inclusive,
+        } = self.occurrences;
+        let set = &self.set;
+        trace_call(
+            "take_while",
+            &mut self.call_count,
+            move |i: &mut I| {
+                crate::stream::skip_trivia(i)?;
+                match (start_inclusive, end_inclusive) {
+                    (0, None) => {
+                        if <I as StreamIsPartial>::is_partial_supported() {
+                            take_till0::<_, _, _, true>(i, |c| !set.contains_token(c))
+                        } else {
+                            take_till0::<_, _, _, false>(i, |c| !set.contains_token(c))
+                        }
+                    }
+                    (1, None) => {
+                        if <I as StreamIsPartial>::is_partial_supported() {
+                            take_till1::<_, _, _, true>(i, |c| !set.contains_token(c))
+                        } else {
+                            take_till1::<_, _, _, false>(i, |c| !set.contains_token(c))
+                        }
+                    }
+                    (start, end) => {
+                        let end = end.unwrap_or(usize::MAX);
+                        if <I as StreamIsPartial>::is_partial_supported() {
+                            take_till_m_n::<_, _, _, true>(i, start, end, |c| {
+                                !set.contains_token(c)
+                            })
+                        } else {
+                            take_till_m_n::<_, _, _, false>(i, start, end, |c| {
+                                !set.contains_token(c)
+                            })
+                        }
+                    }
                 }
-            }
-        }
-    })
+            },
+            input,
+        )
+    }
+}
+
+#[cfg(feature = "unstable-grammar")]
+impl<S, I, E> crate::describe::Describe for TakeWhile<S, I, E>
+where
+    I: StreamIsPartial + Stream,
+    S: ContainsToken<<I as Stream>::Token> + crate::describe::DescribeTokens,
+    E: ParserError<I>,
+{
+    fn describe(&self, _grammar: &mut crate::describe::Grammar) -> crate::describe::Expr {
+        let mut class = crate::lib::std::vec::Vec::new();
+        self.set.describe_tokens(&mut class);
+        crate::describe::Expr::repeat(
+            crate::describe::Expr::Class(class),
+            self.occurrences.start_inclusive,
+            self.occurrences.end_inclusive,
+        )
+    }
+}
+
+/// Prints the value as-is
+#[cfg(feature = "unstable-print")]
+impl<S, I, E, V> crate::print::Print<V> for TakeWhile<S, I, E>
+where
+    I: StreamIsPartial + Stream,
+    S: ContainsToken<<I as Stream>::Token>,
+    E: ParserError<I>,
+    V: crate::print::PrintConst + ?Sized,
+{
+    fn print(
+        &self,
+        value: &V,
+        sink: &mut dyn crate::print::Sink,
+    ) -> Result<(), crate::print::PrintError> {
+        crate::print::PrintConst::print_const(value, sink)
+    }
 }
 
 fn take_till0<P, I: StreamIsPartial + Stream, E: ParserError<I>, const PARTIAL: bool>(
@@ -680,6 +921,7 @@ where
         end_inclusive,
     } = occurrences.into();
     trace("take_till", move |i: &mut Input| {
+        crate::stream::skip_trivia(i)?;
         match (start_inclusive, end_inclusive) {
             (0, None) => {
                 if <Input as StreamIsPartial>::is_partial_supported() {
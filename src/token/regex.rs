@@ -0,0 +1,757 @@
+use crate::combinator::trace;
+use crate::error::Needed;
+use crate::error::ParserError;
+use crate::lib::std::boxed::Box;
+use crate::lib::std::collections::BTreeMap;
+use crate::lib::std::fmt;
+use crate::lib::std::string::String;
+use crate::lib::std::vec::Vec;
+use crate::stream::AsChar;
+use crate::stream::{Stream, StreamIsPartial};
+use crate::Parser;
+use crate::Result;
+
+/// Largest repetition count accepted in `{n,m}`
+const MAX_REPEAT: u32 = 1_000;
+/// Largest number of DFA states a pattern may compile to
+const MAX_STATES: usize = 10_000;
+
+/// Largest number of NFA instructions a pattern may expand to, with its repetitions unrolled
+const MAX_INSTS: usize = 100_000;
+/// One past the largest `char`
+const CHAR_END: u32 = 0x11_0000;
+
+/// A regular expression, compiled to a DFA, for recognizing a token
+///
+/// `&Regex` is a [`Parser`] that returns the longest prefix of the input matching the pattern as
+/// a [`Stream::Slice`].  It works with any [`Stream`] whose tokens are [`AsChar`], with `u8`
+/// tokens being treated as the `char`s `'\0'..='\u{ff}'`.
+///
+/// The pattern is compiled once by [`Regex::new`]; parsing does not allocate.
+///
+/// *Complete version*: Will return an error if no prefix of the input matches.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if more input could
+/// change the longest match.
+///
+/// # Syntax
+///
+/// | Pattern        | Matches                                                  |
+/// |----------------|----------------------------------------------------------|
+/// | `x`            | the literal character `x`                                |
+/// | `.`            | any character except `\n`                                |
+/// | `[a-z_]`       | any character in the class                               |
+/// | `[^a-z]`       | any character not in the class                           |
+/// | `\d` `\w` `\s` | ASCII digit, word character, whitespace (and `\D` `\W` `\S`) |
+/// | `\n` `\r` `\t` `\0` `\xHH` | escaped characters                           |
+/// | `xy`           | `x` followed by `y`                                      |
+/// | `x\|y`         | `x` or `y`                                               |
+/// | `(x)` `(?:x)`  | grouping                                                 |
+/// | `x*` `x+` `x?` | zero or more, one or more, zero or one                   |
+/// | `x{n}` `x{n,}` `x{n,m}` | repetition counts                               |
+/// | `^`            | start of the match, only allowed at the start of the pattern |
+/// | `$`            | end of input                                             |
+///
+/// Matches are always anchored at the current position of the input.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::combinator::preceded;
+/// use winnow::token::Regex;
+///
+/// let version = Regex::new(r"\d+(\.\d+){0,2}(-[a-z0-9.]+)?").unwrap();
+///
+/// fn parser<'i>(version: &Regex, s: &mut &'i str) -> ModalResult<&'i str> {
+///     preceded('v', version).parse_next(s)
+/// }
+///
+/// assert_eq!(parser(&version, &mut "v1.2.3-rc.1 release"), Ok("1.2.3-rc.1"));
+/// assert_eq!(parser(&version, &mut "v10 apples"), Ok("10"));
+/// assert!(parser(&version, &mut "v.2").is_err());
+/// ```
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// # use winnow::{error::ErrMode, error::ContextError, error::Needed};
+/// # use winnow::Partial;
+/// use winnow::token::Regex;
+///
+/// let hex = Regex::new("0x[0-9a-fA-F]+").unwrap();
+///
+/// fn parser<'i>(hex: &Regex, s: &mut Partial<&'i [u8]>) -> ModalResult<&'i [u8]> {
+///     let mut hex = hex;
+///     hex.parse_next(s)
+/// }
+///
+/// assert_eq!(parser(&hex, &mut Partial::new(b"0xff;")), Ok(&b"0xff"[..]));
+/// assert_eq!(parser(&hex, &mut Partial::new(b"0xff")), Err(ErrMode::Incomplete(Needed::new(1))));
+/// ```
+#[derive(Clone)]
+pub struct Regex {
+    pattern: String,
+    states: Vec<State>,
+}
+
+impl Regex {
+    /// Compile `pattern`
+    pub fn new(pattern: &str) -> Result<Self, RegexError> {
+        let ast = PatternParser::new(pattern).parse()?;
+        let nfa = Nfa::compile(&ast)?;
+        let states = nfa.to_dfa()?;
+        Ok(Self {
+            pattern: pattern.into(),
+            states,
+        })
+    }
+
+    /// The pattern this was compiled from
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    fn longest_match<I>(&self, input: &I) -> Match
+    where
+        I: StreamIsPartial + Stream,
+        <I as Stream>::Token: AsChar,
+    {
+        let mut state = &self.states[0];
+        let mut last = None;
+        for (offset, token) in input.iter_offsets() {
+            if state.accept {
+                last = Some(offset);
+            }
+            match state.next(token.as_char()) {
+                Some(next) => state = &self.states[next],
+                None => return last.map(Match::Found).unwrap_or(Match::None),
+            }
+        }
+
+        if input.is_partial() && (!state.transitions.is_empty() || state.accept_at_eof) {
+            return Match::Incomplete;
+        }
+        if state.accept || state.accept_at_eof {
+            last = Some(input.eof_offset());
+        }
+        last.map(Match::Found).unwrap_or(Match::None)
+    }
+}
+
+impl<I, E> Parser<I, <I as Stream>::Slice, E> for &Regex
+where
+    I: StreamIsPartial,
+    I: Stream,
+    <I as Stream>::Token: AsChar,
+    E: ParserError<I>,
+{
+    #[inline(always)]
+    fn parse_next(&mut self, input: &mut I) -> Result<<I as Stream>::Slice, E> {
+        let regex = *self;
+        trace("regex", move |input: &mut I| {
+            crate::stream::skip_trivia(input)?;
+            match regex.longest_match(input) {
+                Match::Found(offset) => Ok(input.next_slice(offset)),
+                Match::Incomplete => Err(ParserError::incomplete(input, Needed::new(1))),
+                Match::None => Err(ParserError::from_input(input)),
+            }
+        })
+        .parse_next(input)
+    }
+}
+
+impl fmt::Debug for Regex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Regex").field(&self.pattern).finish()
+    }
+}
+
+impl fmt::Display for Regex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.pattern.fmt(f)
+    }
+}
+
+/// Invalid [`Regex`] pattern
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegexError {
+    offset: usize,
+    message: &'static str,
+}
+
+impl RegexError {
+    /// Byte offset into the pattern where the problem was found
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Description of the problem
+    pub fn message(&self) -> &str {
+        self.message
+    }
+}
+
+impl fmt::Display for RegexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid regex at offset {}: {}",
+            self.offset, self.message
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RegexError {}
+
+enum Match {
+    Found(usize),
+    Incomplete,
+    None,
+}
+
+#[derive(Clone)]
+struct State {
+    /// Sorted, non-overlapping `(start, end, next)` with `end` inclusive
+    transitions: Vec<(u32, u32, usize)>,
+    accept: bool,
+    accept_at_eof: bool,
+}
+
+impl State {
+    #[inline]
+    fn next(&self, c: char) -> Option<usize> {
+        let c = c as u32;
+        let index = self
+            .transitions
+            .binary_search_by(|&(start, end, _)| {
+                if end < c {
+                    crate::lib::std::cmp::Ordering::Less
+                } else if c < start {
+                    crate::lib::std::cmp::Ordering::Greater
+                } else {
+                    crate::lib::std::cmp::Ordering::Equal
+                }
+            })
+            .ok()?;
+        Some(self.transitions[index].2)
+    }
+}
+
+enum Node {
+    Empty,
+    Class(Vec<(u32, u32)>),
+    Eof,
+    Concat(Vec<Node>),
+    Alt(Vec<Node>),
+    Repeat {
+        node: Box<Node>,
+        min: u32,
+        max: Option<u32>,
+    },
+}
+
+struct PatternParser<'p> {
+    pattern: &'p str,
+    offset: usize,
+}
+
+impl<'p> PatternParser<'p> {
+    fn new(pattern: &'p str) -> Self {
+        Self { pattern, offset: 0 }
+    }
+
+    fn error(&self, message: &'static str) -> RegexError {
+        RegexError {
+            offset: self.offset,
+            message,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.pattern[self.offset..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.offset += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.bump();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse(mut self) -> Result<Node, RegexError> {
+        self.eat('^');
+        let node = self.alternation()?;
+        match self.peek() {
+            None => Ok(node),
+            Some(')') => Err(self.error("unopened group")),
+            Some(_) => Err(self.error("unexpected character")),
+        }
+    }
+
+    fn alternation(&mut self) -> Result<Node, RegexError> {
+        let mut branches = Vec::from([self.concat()?]);
+        while self.eat('|') {
+            branches.push(self.concat()?);
+        }
+        if branches.len() == 1 {
+            Ok(branches.pop().unwrap())
+        } else {
+            Ok(Node::Alt(branches))
+        }
+    }
+
+    fn concat(&mut self) -> Result<Node, RegexError> {
+        let mut items = Vec::new();
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            let atom = self.atom()?;
+            items.push(self.repetition(atom)?);
+        }
+        match items.len() {
+            0 => Ok(Node::Empty),
+            1 => Ok(items.pop().unwrap()),
+            _ => Ok(Node::Concat(items)),
+        }
+    }
+
+    fn atom(&mut self) -> Result<Node, RegexError> {
+        let start = self.offset;
+        let c = self.bump().expect("checked by caller");
+        match c {
+            '(' => {
+                if self.eat('?') && !self.eat(':') {
+                    return Err(self.error("unsupported group flag, expected `(?:`"));
+                }
+                let node = self.alternation()?;
+                if !self.eat(')') {
+                    return Err(RegexError {
+                        offset: start,
+                        message: "unclosed group",
+                    });
+                }
+                Ok(node)
+            }
+            '[' => self.class(),
+            '.' => Ok(Node::Class(negate(Vec::from([('\n' as u32, '\n' as u32)])))),
+            '$' => Ok(Node::Eof),
+            '\\' => self.escape().map(Node::Class),
+            '^' => Err(RegexError {
+                offset: start,
+                message: "`^` is only supported at the start of the pattern",
+            }),
+            '*' | '+' | '?' | '{' => Err(RegexError {
+                offset: start,
+                message: "repetition without an operand",
+            }),
+            c => Ok(Node::Class(Vec::from([(c as u32, c as u32)]))),
+        }
+    }
+
+    fn repetition(&mut self, mut node: Node) -> Result<Node, RegexError> {
+        loop {
+            let (min, max) = match self.peek() {
+                Some('*') => (0, None),
+                Some('+') => (1, None),
+                Some('?') => (0, Some(1)),
+                Some('{') => {
+                    let start = self.offset;
+                    self.bump();
+                    let min = self.number()?;
+                    let max = if self.eat(',') {
+                        if self.peek() == Some('}') {
+                            None
+                        } else {
+                            Some(self.number()?)
+                        }
+                    } else {
+                        Some(min)
+                    };
+                    if !self.eat('}') {
+                        return Err(self.error("expected `}`"));
+                    }
+                    if max.map(|max| max < min).unwrap_or(false) {
+                        return Err(RegexError {
+                            offset: start,
+                            message: "repetition range is reversed",
+                        });
+                    }
+                    node = Node::Repeat {
+                        node: Box::new(node),
+                        min,
+                        max,
+                    };
+                    continue;
+                }
+                _ => return Ok(node),
+            };
+            self.bump();
+            node = Node::Repeat {
+                node: Box::new(node),
+                min,
+                max,
+            };
+        }
+    }
+
+    fn number(&mut self) -> Result<u32, RegexError> {
+        let start = self.offset;
+        let mut value = 0u32;
+        while let Some(digit) = self.peek().and_then(|c| c.to_digit(10)) {
+            self.bump();
+            value = value.saturating_mul(10).saturating_add(digit);
+        }
+        if start == self.offset {
+            return Err(self.error("expected a repetition count"));
+        }
+        if MAX_REPEAT < value {
+            return Err(RegexError {
+                offset: start,
+                message: "repetition count is too large",
+            });
+        }
+        Ok(value)
+    }
+
+    fn class(&mut self) -> Result<Node, RegexError> {
+        let start = self.offset - 1;
+        let negated = self.eat('^');
+        let mut ranges = Vec::new();
+        let mut first = true;
+        loop {
+            let c = match self.bump() {
+                Some(c) => c,
+                None => {
+                    return Err(RegexError {
+                        offset: start,
+                        message: "unclosed character class",
+                    })
+                }
+            };
+            if c == ']' && !first {
+                break;
+            }
+            first = false;
+            let low = if c == '\\' {
+                let escaped = self.escape()?;
+                if escaped.len() != 1 || escaped[0].0 != escaped[0].1 {
+                    ranges.extend(escaped);
+                    continue;
+                }
+                escaped[0].0
+            } else {
+                c as u32
+            };
+            let rest = &self.pattern[self.offset..];
+            if rest.starts_with('-') && !rest.starts_with("-]") {
+                self.bump();
+                let high = match self.bump() {
+                    Some('\\') => {
+                        let escaped = self.escape()?;
+                        if escaped.len() != 1 || escaped[0].0 != escaped[0].1 {
+                            return Err(self.error("invalid range end in character class"));
+                        }
+                        escaped[0].0
+                    }
+                    Some(c) => c as u32,
+                    None => {
+                        return Err(RegexError {
+                            offset: start,
+                            message: "unclosed character class",
+                        })
+                    }
+                };
+                if high < low {
+                    return Err(self.error("character class range is reversed"));
+                }
+                ranges.push((low, high));
+            } else {
+                ranges.push((low, low));
+            }
+        }
+        let ranges = normalize(ranges);
+        Ok(Node::Class(if negated { negate(ranges) } else { ranges }))
+    }
+
+    /// Parse the escape sequence after a `\`
+    fn escape(&mut self) -> Result<Vec<(u32, u32)>, RegexError> {
+        let c = self
+            .bump()
+            .ok_or_else(|| self.error("incomplete escape sequence"))?;
+        let single = |c: char| Ok(Vec::from([(c as u32, c as u32)]));
+        match c {
+            'd' => Ok(DIGIT.to_vec()),
+            'D' => Ok(negate(DIGIT.to_vec())),
+            'w' => Ok(WORD.to_vec()),
+            'W' => Ok(negate(WORD.to_vec())),
+            's' => Ok(SPACE.to_vec()),
+            'S' => Ok(negate(SPACE.to_vec())),
+            'n' => single('\n'),
+            'r' => single('\r'),
+            't' => single('\t'),
+            '0' => single('\0'),
+            'x' => {
+                let mut value = 0;
+                for _ in 0..2 {
+                    let digit = self
+                        .bump()
+                        .and_then(|c| c.to_digit(16))
+                        .ok_or_else(|| self.error("expected two hex digits after `\\x`"))?;
+                    value = value * 16 + digit;
+                }
+                Ok(Vec::from([(value, value)]))
+            }
+            c if c.is_ascii_alphanumeric() => Err(self.error("unsupported escape sequence")),
+            c => single(c),
+        }
+    }
+}
+
+const DIGIT: &[(u32, u32)] = &[('0' as u32, '9' as u32)];
+const WORD: &[(u32, u32)] = &[
+    ('0' as u32, '9' as u32),
+    ('A' as u32, 'Z' as u32),
+    ('_' as u32, '_' as u32),
+    ('a' as u32, 'z' as u32),
+];
+const SPACE: &[(u32, u32)] = &[('\t' as u32, '\r' as u32), (' ' as u32, ' ' as u32)];
+
+/// Sort and merge overlapping or adjacent ranges
+fn normalize(mut ranges: Vec<(u32, u32)>) -> Vec<(u32, u32)> {
+    ranges.sort_unstable();
+    let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Complement of normalized ranges
+fn negate(ranges: Vec<(u32, u32)>) -> Vec<(u32, u32)> {
+    let mut negated = Vec::with_capacity(ranges.len() + 1);
+    let mut next = 0;
+    for (start, end) in ranges {
+        if next < start {
+            negated.push((next, start - 1));
+        }
+        next = end + 1;
+    }
+    if next < CHAR_END {
+        negated.push((next, CHAR_END - 1));
+    }
+    negated
+}
+
+enum Inst {
+    Class(Vec<(u32, u32)>, usize),
+    Split(usize, usize),
+    Eof(usize),
+    Match,
+}
+
+struct Nfa {
+    insts: Vec<Inst>,
+    start: usize,
+}
+
+impl Nfa {
+    fn compile(node: &Node) -> Result<Self, RegexError> {
+        let mut nfa = Self {
+            insts: Vec::from([Inst::Match]),
+            start: 0,
+        };
+        nfa.start = nfa.compile_node(node, 0)?;
+        Ok(nfa)
+    }
+
+    fn push(&mut self, inst: Inst) -> Result<usize, RegexError> {
+        if MAX_INSTS <= self.insts.len() {
+            return Err(too_complex());
+        }
+        self.insts.push(inst);
+        Ok(self.insts.len() - 1)
+    }
+
+    /// Compile `node` to continue on to `next`, returning its entry
+    ///
+    /// Repetitions are unrolled, so their size is checked as they are expanded.
+    fn compile_node(&mut self, node: &Node, next: usize) -> Result<usize, RegexError> {
+        match node {
+            Node::Empty => Ok(next),
+            Node::Class(ranges) => self.push(Inst::Class(ranges.clone(), next)),
+            Node::Eof => self.push(Inst::Eof(next)),
+            Node::Concat(items) => items
+                .iter()
+                .rev()
+                .try_fold(next, |next, item| self.compile_node(item, next)),
+            Node::Alt(branches) => {
+                let mut entries = branches
+                    .iter()
+                    .map(|branch| self.compile_node(branch, next))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let mut entry = entries.pop().expect("always at least two branches");
+                while let Some(other) = entries.pop() {
+                    entry = self.push(Inst::Split(other, entry))?;
+                }
+                Ok(entry)
+            }
+            Node::Repeat { node, min, max } => {
+                let mut entry = match max {
+                    None => {
+                        let split = self.push(Inst::Split(usize::MAX, next))?;
+                        let body = self.compile_node(node, split)?;
+                        self.insts[split] = Inst::Split(body, next);
+                        split
+                    }
+                    Some(max) => {
+                        let mut entry = next;
+                        for _ in *min..*max {
+                            let body = self.compile_node(node, entry)?;
+                            entry = self.push(Inst::Split(body, entry))?;
+                        }
+                        entry
+                    }
+                };
+                for _ in 0..*min {
+                    let len = self.insts.len();
+                    entry = self.compile_node(node, entry)?;
+                    if len == self.insts.len() {
+                        // Matches only the empty string, so more copies are redundant
+                        break;
+                    }
+                }
+                Ok(entry)
+            }
+        }
+    }
+
+    /// Add the instructions reachable from `inst` without consuming input
+    ///
+    /// With `through_eof`, end-of-input assertions are treated as satisfied.
+    fn closure(&self, inst: usize, through_eof: bool, set: &mut Vec<usize>, seen: &mut [bool]) {
+        let mut stack = Vec::from([inst]);
+        while let Some(inst) = stack.pop() {
+            if seen[inst] {
+                continue;
+            }
+            seen[inst] = true;
+            match self.insts[inst] {
+                Inst::Split(a, b) => {
+                    stack.push(b);
+                    stack.push(a);
+                }
+                Inst::Eof(next) if through_eof => stack.push(next),
+                Inst::Class(..) | Inst::Eof(_) | Inst::Match => set.push(inst),
+            }
+        }
+    }
+
+    fn to_dfa(&self) -> Result<Vec<State>, RegexError> {
+        let mut seen = Vec::new();
+        seen.resize(self.insts.len(), false);
+        let mut start = Vec::new();
+        self.closure(self.start, false, &mut start, &mut seen);
+        start.sort_unstable();
+
+        let mut ids = BTreeMap::new();
+        ids.insert(start.clone(), 0);
+        let mut pending = Vec::from([start]);
+        let mut states = Vec::new();
+        while let Some(set) = pending.pop() {
+            let accept = set.iter().any(|&i| matches!(self.insts[i], Inst::Match));
+
+            seen.fill(false);
+            let mut at_eof = Vec::new();
+            for &i in &set {
+                if let Inst::Eof(next) = self.insts[i] {
+                    self.closure(next, true, &mut at_eof, &mut seen);
+                }
+            }
+            let accept_at_eof = at_eof.iter().any(|&i| matches!(self.insts[i], Inst::Match));
+
+            let mut bounds = Vec::new();
+            for &i in &set {
+                if let Inst::Class(ranges, _) = &self.insts[i] {
+                    for &(start, end) in ranges {
+                        bounds.push(start);
+                        bounds.push(end + 1);
+                    }
+                }
+            }
+            bounds.sort_unstable();
+            bounds.dedup();
+
+            let mut transitions: Vec<(u32, u32, usize)> = Vec::new();
+            for window in bounds.windows(2) {
+                let (start, end) = (window[0], window[1] - 1);
+                seen.fill(false);
+                let mut target = Vec::new();
+                for &i in &set {
+                    if let Inst::Class(ranges, next) = &self.insts[i] {
+                        if ranges.iter().any(|&(s, e)| s <= start && start <= e) {
+                            self.closure(*next, false, &mut target, &mut seen);
+                        }
+                    }
+                }
+                if target.is_empty() {
+                    continue;
+                }
+                target.sort_unstable();
+                let id = match ids.get(&target) {
+                    Some(&id) => id,
+                    None => {
+                        let id = ids.len();
+                        ids.insert(target.clone(), id);
+                        pending.push(target);
+                        id
+                    }
+                };
+                match transitions.last_mut() {
+                    Some(last) if last.1 + 1 == start && last.2 == id => last.1 = end,
+                    _ => transitions.push((start, end, id)),
+                }
+            }
+
+            if MAX_STATES < ids.len() {
+                return Err(too_complex());
+            }
+            let id = ids[&set];
+            if states.len() <= id {
+                states.resize(
+                    id + 1,
+                    State {
+                        transitions: Vec::new(),
+                        accept: false,
+                        accept_at_eof: false,
+                    },
+                );
+            }
+            states[id] = State {
+                transitions,
+                accept,
+                accept_at_eof,
+            };
+        }
+        Ok(states)
+    }
+}
+
+fn too_complex() -> RegexError {
+    RegexError {
+        offset: 0,
+        message: "pattern is too complex",
+    }
+}
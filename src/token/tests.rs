@@ -2893,3 +2893,439 @@ Ok(
         .raw()
     );
 }
+
+#[test]
+#[cfg(feature = "alloc")]
+fn complete_regex_longest_match() {
+    let re = Regex::new("a|ab|abc").unwrap();
+    assert_parse!(
+        (&re).parse_peek("abcd"),
+        str![[r#"
+Ok(
+    (
+        "d",
+        "abc",
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        (&re).parse_peek("abd"),
+        str![[r#"
+Ok(
+    (
+        "d",
+        "ab",
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        (&re).parse_peek("bcd"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "bcd",
+        },
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        (&re).parse_peek(""),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "",
+        },
+    ),
+)
+
+"#]]
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn complete_regex_repetition() {
+    let re = Regex::new(r"[a-z_][a-z0-9_]{0,3}").unwrap();
+    assert_parse!(
+        (&re).parse_peek("foo_bar"),
+        str![[r#"
+Ok(
+    (
+        "bar",
+        "foo_",
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        (&re).parse_peek("x1 = 2"),
+        str![[r#"
+Ok(
+    (
+        " = 2",
+        "x1",
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        (&re).parse_peek("1x"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "1x",
+        },
+    ),
+)
+
+"#]]
+    );
+
+    let re = Regex::new(r"(?:ab)+c?").unwrap();
+    assert_parse!(
+        (&re).parse_peek("ababac"),
+        str![[r#"
+Ok(
+    (
+        "ac",
+        "abab",
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        (&re).parse_peek("ababc"),
+        str![[r#"
+Ok(
+    (
+        "",
+        "ababc",
+    ),
+)
+
+"#]]
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn complete_regex_classes() {
+    let re = Regex::new(r"[^\s,]+").unwrap();
+    assert_parse!(
+        (&re).parse_peek("héllo, world"),
+        str![[r#"
+Ok(
+    (
+        ", world",
+        "héllo",
+    ),
+)
+
+"#]]
+    );
+
+    let re = Regex::new(r"\d+[uU]?[lL]?").unwrap();
+    assert_parse!(
+        (&re).parse_peek(&b"42UL;"[..]),
+        str![[r#"
+Ok(
+    (
+        [
+            59,
+        ],
+        [
+            52,
+            50,
+            85,
+            76,
+        ],
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        (&re).parse_peek(&b"42x"[..]),
+        str![[r#"
+Ok(
+    (
+        [
+            120,
+        ],
+        [
+            52,
+            50,
+        ],
+    ),
+)
+
+"#]]
+    );
+
+    let re = Regex::new(r"[\x00-\x1f\-]+").unwrap();
+    assert_parse!(
+        (&re).parse_peek(&b"\x01-\x02a"[..]),
+        str![[r#"
+Ok(
+    (
+        [
+            97,
+        ],
+        [
+            1,
+            45,
+            2,
+        ],
+    ),
+)
+
+"#]]
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn complete_regex_anchors() {
+    let re = Regex::new(r"^[0-9]+$").unwrap();
+    assert_parse!(
+        (&re).parse_peek("123"),
+        str![[r#"
+Ok(
+    (
+        "",
+        "123",
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        (&re).parse_peek("123a"),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: "123a",
+        },
+    ),
+)
+
+"#]]
+    );
+
+    let re = Regex::new(r"a+($|b)").unwrap();
+    assert_parse!(
+        (&re).parse_peek("aa"),
+        str![[r#"
+Ok(
+    (
+        "",
+        "aa",
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        (&re).parse_peek("aabb"),
+        str![[r#"
+Ok(
+    (
+        "b",
+        "aab",
+    ),
+)
+
+"#]]
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn partial_regex() {
+    let re = Regex::new(r"[0-9]+(\.[0-9]+)?").unwrap();
+    assert_parse!(
+        (&re).parse_peek(Partial::new("1.5;")),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: ";",
+            partial: true,
+        },
+        "1.5",
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        (&re).parse_peek(Partial::new("1.5")),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        (&re).parse_peek(Partial::new("1.")),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        (&re).parse_peek(Partial::new("")),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        (&re).parse_peek(Partial::new("a")),
+        str![[r#"
+Err(
+    Backtrack(
+        InputError {
+            input: Partial {
+                input: "a",
+                partial: true,
+            },
+        },
+    ),
+)
+
+"#]]
+    );
+
+    let re = Regex::new(r"true|false").unwrap();
+    assert_parse!(
+        (&re).parse_peek(Partial::new("true")),
+        str![[r#"
+Ok(
+    (
+        Partial {
+            input: "",
+            partial: true,
+        },
+        "true",
+    ),
+)
+
+"#]]
+    );
+    assert_parse!(
+        (&re).parse_peek(Partial::new("tr")),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+    );
+
+    let re = Regex::new(r"abc$").unwrap();
+    assert_parse!(
+        (&re).parse_peek(Partial::new("abc")),
+        str![[r#"
+Err(
+    Incomplete(
+        Size(
+            1,
+        ),
+    ),
+)
+
+"#]]
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn regex_invalid_patterns() {
+    snapbox::assert_data_eq!(
+        Regex::new("(ab").unwrap_err().to_string(),
+        str!["invalid regex at offset 0: unclosed group"]
+    );
+    snapbox::assert_data_eq!(
+        Regex::new("ab)").unwrap_err().to_string(),
+        str!["invalid regex at offset 2: unopened group"]
+    );
+    snapbox::assert_data_eq!(
+        Regex::new("[a-").unwrap_err().to_string(),
+        str!["invalid regex at offset 0: unclosed character class"]
+    );
+    snapbox::assert_data_eq!(
+        Regex::new("[z-a]").unwrap_err().to_string(),
+        str!["invalid regex at offset 4: character class range is reversed"]
+    );
+    snapbox::assert_data_eq!(
+        Regex::new("a{3,2}").unwrap_err().to_string(),
+        str!["invalid regex at offset 1: repetition range is reversed"]
+    );
+    snapbox::assert_data_eq!(
+        Regex::new("*a").unwrap_err().to_string(),
+        str!["invalid regex at offset 0: repetition without an operand"]
+    );
+    snapbox::assert_data_eq!(
+        Regex::new(r"\q").unwrap_err().to_string(),
+        str!["invalid regex at offset 2: unsupported escape sequence"]
+    );
+    snapbox::assert_data_eq!(
+        Regex::new("a^").unwrap_err().to_string(),
+        str!["invalid regex at offset 1: `^` is only supported at the start of the pattern"]
+    );
+    // Rejected while unrolling, not after
+    snapbox::assert_data_eq!(
+        Regex::new("((a{1000}){1000}){1000}")
+            .unwrap_err()
+            .to_string(),
+        str!["invalid regex at offset 0: pattern is too complex"]
+    );
+    assert!(Regex::new("(((){1000}){1000}){1000}").is_ok());
+}
+
+#[cfg(feature = "std")]
+proptest! {
+  #[test]
+  #[cfg_attr(miri, ignore)]  // See https://github.com/AltSysrq/proptest/issues/253
+  fn regex_matches_take_while(input in "[a-c0-9]{0,20}") {
+      let re = Regex::new("[a-c]*").unwrap();
+      let expected = take_while::<_, _, InputError<_>>(0.., 'a'..='c').parse_peek(input.as_str());
+      let actual = (&re).parse_peek(input.as_str());
+      prop_assert_eq!(actual, expected);
+  }
+}
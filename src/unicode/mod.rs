@@ -0,0 +1,342 @@
+//! Unicode character classes and parsers
+//!
+//! Unlike [`ascii`][crate::ascii], these recognize characters by their Unicode properties, like
+//! identifiers per [UAX #31](https://www.unicode.org/reports/tr31/) and Unicode whitespace.
+//!
+//! The tables are from the Unicode Character Database, version 14.0.0, see [`UNICODE_VERSION`].
+//! This matches [`char::UNICODE_VERSION`] in Rust 1.65, the MSRV, so the classes are never newer
+//! than the [`char`] methods of a supported toolchain.
+
+mod tables;
+
+#[cfg(test)]
+mod tests;
+
+use crate::combinator::trace;
+use crate::error::Needed;
+use crate::error::ParserError;
+use crate::lib::std::cmp::Ordering;
+use crate::lib::std::str;
+use crate::stream::{AsBStr, ContainsToken, Stream, StreamIsPartial};
+use crate::Parser;
+use crate::Result;
+
+pub use tables::UNICODE_VERSION;
+
+/// Set of characters with a Unicode property, for use with [`take_while`][crate::token::take_while]
+/// and [`one_of`][crate::token::one_of]
+///
+/// Byte tokens are only in a set when they are ASCII, as other bytes are only part of UTF-8
+/// characters.  To recognize those, see [`identifier`] and [`unicode_whitespace1`].
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::token::take_while;
+/// use winnow::unicode::{DECIMAL_NUMBER, LETTER};
+///
+/// fn word<'i>(input: &mut &'i str) -> ModalResult<&'i str> {
+///     take_while(1.., (LETTER, DECIMAL_NUMBER, '-')).parse_next(input)
+/// }
+///
+/// assert_eq!(word.parse_peek("naïve-1٣ rest"), Ok((" rest", "naïve-1٣")));
+/// assert!(word.parse_peek("¿qué?").is_err());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CharClass {
+    /// Sorted, disjoint, inclusive ranges
+    ranges: &'static [(char, char)],
+}
+
+impl CharClass {
+    /// Whether `c` is in the set
+    pub fn contains(self, c: char) -> bool {
+        self.ranges
+            .binary_search_by(|&(start, end)| {
+                if end < c {
+                    Ordering::Less
+                } else if c < start {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+}
+
+impl ContainsToken<char> for CharClass {
+    #[inline(always)]
+    fn contains_token(&self, token: char) -> bool {
+        self.contains(token)
+    }
+}
+
+impl ContainsToken<&char> for CharClass {
+    #[inline(always)]
+    fn contains_token(&self, token: &char) -> bool {
+        self.contains(*token)
+    }
+}
+
+impl ContainsToken<u8> for CharClass {
+    #[inline(always)]
+    fn contains_token(&self, token: u8) -> bool {
+        token.is_ascii() && self.contains(char::from(token))
+    }
+}
+
+impl ContainsToken<&u8> for CharClass {
+    #[inline(always)]
+    fn contains_token(&self, token: &u8) -> bool {
+        self.contains_token(*token)
+    }
+}
+
+/// `XID_Start`: characters that can start an identifier
+pub const XID_START: CharClass = CharClass {
+    ranges: tables::XID_START,
+};
+/// `XID_Continue`: characters that can continue an identifier
+pub const XID_CONTINUE: CharClass = CharClass {
+    ranges: tables::XID_CONTINUE,
+};
+/// `White_Space`
+pub const WHITE_SPACE: CharClass = CharClass {
+    ranges: tables::WHITE_SPACE,
+};
+/// `Pattern_Syntax`: characters reserved for the syntax of patterns, like operators
+pub const PATTERN_SYNTAX: CharClass = CharClass {
+    ranges: tables::PATTERN_SYNTAX,
+};
+/// Letters, general category `L`
+pub const LETTER: CharClass = CharClass {
+    ranges: tables::LETTER,
+};
+/// Uppercase letters, general category `Lu`
+pub const UPPERCASE_LETTER: CharClass = CharClass {
+    ranges: tables::UPPERCASE_LETTER,
+};
+/// Lowercase letters, general category `Ll`
+pub const LOWERCASE_LETTER: CharClass = CharClass {
+    ranges: tables::LOWERCASE_LETTER,
+};
+/// Combining marks, general category `M`
+pub const MARK: CharClass = CharClass {
+    ranges: tables::MARK,
+};
+/// Decimal digits of any script, general category `Nd`
+pub const DECIMAL_NUMBER: CharClass = CharClass {
+    ranges: tables::DECIMAL_NUMBER,
+};
+/// Numbers, general category `N`
+pub const NUMBER: CharClass = CharClass {
+    ranges: tables::NUMBER,
+};
+/// Punctuation, general category `P`
+pub const PUNCTUATION: CharClass = CharClass {
+    ranges: tables::PUNCTUATION,
+};
+/// Symbols, general category `S`
+pub const SYMBOL: CharClass = CharClass {
+    ranges: tables::SYMBOL,
+};
+/// Space, line and paragraph separators, general category `Z`
+pub const SEPARATOR: CharClass = CharClass {
+    ranges: tables::SEPARATOR,
+};
+/// Control characters, general category `Cc`
+pub const CONTROL: CharClass = CharClass {
+    ranges: tables::CONTROL,
+};
+
+/// Recognizes an identifier: an [`XID_START`] character or `_`, then any [`XID_CONTINUE`]
+/// characters
+///
+/// This is the default identifier syntax of [UAX #31](https://www.unicode.org/reports/tr31/), with
+/// a leading `_` as in most programming languages.  Byte input is decoded as UTF-8.
+///
+/// *Complete version*: Will return an error if there's no identifier.
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if the identifier may continue past the available data.
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::unicode::identifier;
+/// use winnow::BStr;
+///
+/// fn parser<'i>(input: &mut &'i str) -> ModalResult<&'i str> {
+///     identifier.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("größe = 1"), Ok((" = 1", "größe")));
+/// assert_eq!(parser.parse_peek("_x1+"), Ok(("+", "_x1")));
+/// assert!(parser.parse_peek("1x").is_err());
+///
+/// fn bytes<'i>(input: &mut &'i BStr) -> ModalResult<&'i [u8]> {
+///     identifier.parse_next(input)
+/// }
+///
+/// assert_eq!(
+///     bytes.parse_peek(BStr::new("Δx;")),
+///     Ok((BStr::new(";"), "Δx".as_bytes()))
+/// );
+/// ```
+#[inline(always)]
+#[doc(alias = "xid")]
+pub fn identifier<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream + AsBStr,
+    Error: ParserError<Input>,
+{
+    trace("identifier", |input: &mut Input| {
+        take_chars(input, 1, (XID_START, '_'), XID_CONTINUE)
+    })
+    .parse_next(input)
+}
+
+/// Recognizes zero or more [`WHITE_SPACE`] characters
+///
+/// Byte input is decoded as UTF-8.
+///
+/// *Complete version*: Will return the whole input if no terminating token is found (a non
+/// whitespace character).
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data,
+/// or if no terminating token is found (a non whitespace character).
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::unicode::unicode_whitespace0;
+///
+/// fn parser<'i>(input: &mut &'i str) -> ModalResult<&'i str> {
+///     unicode_whitespace0.parse_next(input)
+/// }
+///
+/// assert_eq!(parser.parse_peek("\u{a0}\u{3000}\nx"), Ok(("x", "\u{a0}\u{3000}\n")));
+/// assert_eq!(parser.parse_peek("x"), Ok(("x", "")));
+/// ```
+#[inline(always)]
+pub fn unicode_whitespace0<Input, Error>(
+    input: &mut Input,
+) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream + AsBStr,
+    Error: ParserError<Input>,
+{
+    trace("unicode_whitespace0", |input: &mut Input| {
+        take_chars(input, 0, WHITE_SPACE, WHITE_SPACE)
+    })
+    .parse_next(input)
+}
+
+/// Recognizes one or more [`WHITE_SPACE`] characters
+///
+/// Byte input is decoded as UTF-8.
+///
+/// *Complete version*: Will return an error if there's not enough input data,
+/// or the whole input if no terminating token is found (a non whitespace character).
+///
+/// *[Partial version][crate::_topic::partial]*: Will return `Err(winnow::error::ErrMode::Incomplete(_))` if there's not enough input data,
+/// or if no terminating token is found (a non whitespace character).
+///
+/// # Example
+///
+/// ```rust
+/// # use winnow::prelude::*;
+/// use winnow::unicode::unicode_whitespace1;
+///
+/// fn parser<'i>(input: &mut &'i [u8]) -> ModalResult<&'i [u8]> {
+///     unicode_whitespace1.parse_next(input)
+/// }
+///
+/// assert_eq!(
+///     parser.parse_peek("\u{2003} x".as_bytes()),
+///     Ok((&b"x"[..], "\u{2003} ".as_bytes()))
+/// );
+/// assert!(parser.parse_peek(&b"x"[..]).is_err());
+/// ```
+#[inline(always)]
+pub fn unicode_whitespace1<Input, Error>(
+    input: &mut Input,
+) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream + AsBStr,
+    Error: ParserError<Input>,
+{
+    trace("unicode_whitespace1", |input: &mut Input| {
+        take_chars(input, 1, WHITE_SPACE, WHITE_SPACE)
+    })
+    .parse_next(input)
+}
+
+/// Take at least `min` characters of UTF-8, the first in `first` and the others in `rest`
+fn take_chars<Input, Error>(
+    input: &mut Input,
+    min: usize,
+    first: impl ContainsToken<char>,
+    rest: impl ContainsToken<char>,
+) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream + AsBStr,
+    Error: ParserError<Input>,
+{
+    let partial = <Input as StreamIsPartial>::is_partial_supported() && input.is_partial();
+    let bytes = input.as_bstr();
+    let mut offset = 0;
+    let mut count = 0;
+    loop {
+        match decode_char(&bytes[offset..]) {
+            Decoded::Char(c, len)
+                if (count == 0 && first.contains_token(c))
+                    || (count != 0 && rest.contains_token(c)) =>
+            {
+                offset += len;
+                count += 1;
+            }
+            Decoded::Truncated | Decoded::End if partial => {
+                return Err(ParserError::incomplete(input, Needed::new(1)));
+            }
+            _ => break,
+        }
+    }
+    if count < min {
+        return Err(ParserError::from_input(input));
+    }
+    Ok(input.next_slice(offset))
+}
+
+enum Decoded {
+    Char(char, usize),
+    /// The start of a character, cut off by the end of input
+    Truncated,
+    Invalid,
+    End,
+}
+
+/// Decode the UTF-8 character at the start of `bytes`
+fn decode_char(bytes: &[u8]) -> Decoded {
+    let Some(&lead) = bytes.first() else {
+        return Decoded::End;
+    };
+    let len = match lead {
+        0x00..=0x7f => 1,
+        0xc2..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf4 => 4,
+        _ => return Decoded::Invalid,
+    };
+    match str::from_utf8(&bytes[..len.min(bytes.len())]) {
+        Ok(encoded) => match encoded.chars().next() {
+            Some(c) => Decoded::Char(c, len),
+            None => Decoded::Invalid,
+        },
+        Err(err) if err.error_len().is_none() => Decoded::Truncated,
+        Err(_) => Decoded::Invalid,
+    }
+}
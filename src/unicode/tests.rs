@@ -0,0 +1,187 @@
+use super::*;
+
+use crate::error::ErrMode;
+use crate::error::InputError;
+use crate::prelude::*;
+use crate::token::take_while;
+use crate::BStr;
+use crate::Partial;
+
+#[test]
+fn unicode_version() {
+    // `char::UNICODE_VERSION` of the MSRV
+    assert_eq!(UNICODE_VERSION, (14, 0, 0));
+    assert!(UNICODE_VERSION <= char::UNICODE_VERSION);
+}
+
+#[test]
+fn tables_are_sorted() {
+    for class in [
+        XID_START,
+        XID_CONTINUE,
+        WHITE_SPACE,
+        PATTERN_SYNTAX,
+        LETTER,
+        UPPERCASE_LETTER,
+        LOWERCASE_LETTER,
+        MARK,
+        DECIMAL_NUMBER,
+        NUMBER,
+        PUNCTUATION,
+        SYMBOL,
+        SEPARATOR,
+        CONTROL,
+    ] {
+        for (start, end) in class.ranges {
+            assert!(start <= end, "{start:?} > {end:?}");
+        }
+        for pair in class.ranges.windows(2) {
+            assert!(
+                (pair[0].1 as u32) + 1 < pair[1].0 as u32,
+                "{:?} overlaps or touches {:?}",
+                pair[0],
+                pair[1]
+            );
+        }
+    }
+}
+
+#[test]
+fn stable_properties_match_std() {
+    for c in (0..=0x10ffff).filter_map(char::from_u32) {
+        assert_eq!(WHITE_SPACE.contains(c), c.is_whitespace(), "{c:?}");
+        assert_eq!(CONTROL.contains(c), c.is_control(), "{c:?}");
+    }
+}
+
+#[test]
+fn classes() {
+    assert!(XID_START.contains('a'));
+    assert!(XID_START.contains('ß'));
+    assert!(XID_START.contains('文'));
+    assert!(!XID_START.contains('_'));
+    assert!(!XID_START.contains('1'));
+    assert!(XID_CONTINUE.contains('_'));
+    assert!(XID_CONTINUE.contains('1'));
+    assert!(XID_CONTINUE.contains('\u{301}'));
+    assert!(!XID_CONTINUE.contains('-'));
+    assert!(PATTERN_SYNTAX.contains('+'));
+    assert!(PATTERN_SYNTAX.contains('→'));
+    assert!(!PATTERN_SYNTAX.contains('_'));
+    assert!(LETTER.contains('Ж'));
+    assert!(UPPERCASE_LETTER.contains('Ж'));
+    assert!(LOWERCASE_LETTER.contains('ж'));
+    assert!(MARK.contains('\u{301}'));
+    assert!(DECIMAL_NUMBER.contains('٣'));
+    assert!(NUMBER.contains('Ⅻ'));
+    assert!(!DECIMAL_NUMBER.contains('Ⅻ'));
+    assert!(PUNCTUATION.contains('¿'));
+    assert!(SYMBOL.contains('€'));
+    assert!(SEPARATOR.contains('\u{2028}'));
+    assert!(!SEPARATOR.contains('\n'));
+}
+
+#[test]
+fn byte_tokens() {
+    assert!(LETTER.contains_token(b'a'));
+    // Latin-1 `é`, which is only part of a character in UTF-8
+    assert!(!LETTER.contains_token(0xe9_u8));
+    assert_eq!(
+        take_while::<_, _, InputError<_>>(0.., LETTER).parse_peek(&b"ab\xc3\xa9"[..]),
+        Ok((&b"\xc3\xa9"[..], &b"ab"[..]))
+    );
+}
+
+#[test]
+fn identifier_str() {
+    fn parser<'i>(input: &mut &'i str) -> ModalResult<&'i str, InputError<&'i str>> {
+        identifier.parse_next(input)
+    }
+
+    assert_eq!(parser.parse_peek("_"), Ok(("", "_")));
+    assert_eq!(
+        parser.parse_peek("e\u{301}t\u{e9}!"),
+        Ok(("!", "e\u{301}t\u{e9}"))
+    );
+    assert_eq!(parser.parse_peek("変数1 = 0"), Ok((" = 0", "変数1")));
+    assert!(parser.parse_peek("\u{301}e").is_err());
+    assert!(parser.parse_peek("").is_err());
+}
+
+#[test]
+fn identifier_bytes() {
+    fn parser<'i>(input: &mut &'i BStr) -> ModalResult<&'i [u8], InputError<&'i BStr>> {
+        identifier.parse_next(input)
+    }
+
+    assert_eq!(
+        parser.parse_peek(BStr::new("naïve\u{2003}")),
+        Ok((BStr::new("\u{2003}"), "naïve".as_bytes()))
+    );
+    // Invalid and truncated UTF-8 end the identifier
+    assert_eq!(
+        parser.parse_peek(BStr::new(b"ab\xffc")),
+        Ok((BStr::new(b"\xffc"), &b"ab"[..]))
+    );
+    assert_eq!(
+        parser.parse_peek(BStr::new(b"ab\xc3")),
+        Ok((BStr::new(b"\xc3"), &b"ab"[..]))
+    );
+    assert!(parser.parse_peek(BStr::new(b"\xc3\xa9")).is_ok());
+    assert!(parser.parse_peek(BStr::new(b"\xe9")).is_err());
+}
+
+#[test]
+fn identifier_partial() {
+    fn parser<'i>(
+        input: &mut Partial<&'i [u8]>,
+    ) -> ModalResult<&'i [u8], InputError<Partial<&'i [u8]>>> {
+        identifier.parse_next(input)
+    }
+
+    assert_eq!(
+        parser.parse_peek(Partial::new(&b"ab;"[..])),
+        Ok((Partial::new(&b";"[..]), &b"ab"[..]))
+    );
+    assert_eq!(
+        parser.parse_peek(Partial::new(&b"ab"[..])),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+    assert_eq!(
+        parser.parse_peek(Partial::new(&b"ab\xc3"[..])),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+    assert_eq!(
+        parser.parse_peek(Partial::new(&b""[..])),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+}
+
+#[test]
+fn whitespace() {
+    fn ws0<'i>(input: &mut &'i str) -> ModalResult<&'i str, InputError<&'i str>> {
+        unicode_whitespace0.parse_next(input)
+    }
+    fn ws1<'i>(input: &mut &'i str) -> ModalResult<&'i str, InputError<&'i str>> {
+        unicode_whitespace1.parse_next(input)
+    }
+
+    assert_eq!(
+        ws0.parse_peek("\u{85}\u{2029} \tx"),
+        Ok(("x", "\u{85}\u{2029} \t"))
+    );
+    assert_eq!(ws0.parse_peek(""), Ok(("", "")));
+    // Zero width space isn't `White_Space`
+    assert_eq!(ws0.parse_peek("\u{200b}"), Ok(("\u{200b}", "")));
+    assert_eq!(ws1.parse_peek("\u{3000}"), Ok(("", "\u{3000}")));
+    assert!(ws1.parse_peek("x ").is_err());
+
+    assert_eq!(
+        unicode_whitespace0::<_, ErrMode<InputError<_>>>.parse_peek(Partial::new(" ")),
+        Err(ErrMode::Incomplete(Needed::new(1)))
+    );
+    assert_eq!(
+        unicode_whitespace1::<_, ErrMode<InputError<_>>>.parse_peek(Partial::new(" x")),
+        Ok((Partial::new("x"), " "))
+    );
+}